@@ -0,0 +1,50 @@
+//! Timing helpers shared by the benchmarks, which use a plain `main` rather than the
+//! unstable `#[bench]` harness so they run on stable with `cargo bench`
+
+use linalgrs::matrix::Matrix;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Builds a `rows x cols` matrix of values in `[0, 1)` that are fixed by `seed`, so every
+/// run times the same work
+pub fn matrix(rows: usize, cols: usize, seed: usize) -> Matrix<f64> {
+    Matrix {
+        mat: (0..rows)
+            .map(|i| {
+                (0..cols)
+                    .map(|j| ((i * 31 + j * 17 + seed * 7) % 101) as f64 / 101.0)
+                    .collect()
+            })
+            .collect(),
+        rows,
+        cols,
+    }
+}
+
+/// Runs `f` once to warm up and then `iterations` more times, printing the fastest run
+///
+/// ### Returns
+/// - The fastest of the timed runs
+pub fn bench<R>(name: &str, iterations: u32, mut f: impl FnMut() -> R) -> Duration {
+    black_box(f());
+    let fastest = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default();
+    println!("{:<36} {:>12.3?}", name, fastest);
+
+    fastest
+}
+
+/// Prints how many times faster `fast` ran than `slow`
+pub fn speedup(slow: Duration, fast: Duration) {
+    println!(
+        "{:<36} {:>11.2}x\n",
+        "speedup",
+        slow.as_secs_f64() / fast.as_secs_f64()
+    );
+}
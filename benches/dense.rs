@@ -0,0 +1,39 @@
+//! Compares `DenseMatrix`, with its single contiguous buffer, against the row-of-`Arc`
+//! `Matrix` for multiplication and transposition
+//!
+//! Run with `cargo bench --bench dense`
+
+mod common;
+
+use linalgrs::arith;
+use linalgrs::dense::DenseMatrix;
+use linalgrs::transform;
+
+fn main() {
+    for n in [256, 1000] {
+        let a = common::matrix(n, n, 1);
+        let b = common::matrix(n, n, 2);
+        let (dense_a, dense_b) = (DenseMatrix::from(&a), DenseMatrix::from(&b));
+
+        let rows = common::bench(&format!("Matrix multiply {n}x{n}"), 3, || {
+            arith::multiply(&a, &b).unwrap()
+        });
+        let dense = common::bench(&format!("DenseMatrix multiply {n}x{n}"), 3, || {
+            dense_a.multiply(&dense_b).unwrap()
+        });
+        common::speedup(rows, dense);
+    }
+
+    for n in [1000, 4000] {
+        let a = common::matrix(n, n, 3);
+        let dense_a = DenseMatrix::from(&a);
+
+        let rows = common::bench(&format!("Matrix transpose {n}x{n}"), 5, || {
+            transform::transpose(&a)
+        });
+        let dense = common::bench(&format!("DenseMatrix transpose {n}x{n}"), 5, || {
+            dense_a.transpose()
+        });
+        common::speedup(rows, dense);
+    }
+}
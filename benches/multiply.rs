@@ -0,0 +1,24 @@
+//! Compares the tiled `arith::multiply` kernel against the i-j-k loop of
+//! `arith::multiply_naive`, up to 1000 x 1000 inputs
+//!
+//! Run with `cargo bench --bench multiply`, adding `--features rayon` to split the tiles
+//! of `multiply` across threads
+
+mod common;
+
+use linalgrs::arith;
+
+fn main() {
+    for n in [128, 512, 1000] {
+        let a = common::matrix(n, n, 1);
+        let b = common::matrix(n, n, 2);
+
+        let naive = common::bench(&format!("multiply_naive {n}x{n}"), 3, || {
+            arith::multiply_naive(&a, &b).unwrap()
+        });
+        let tiled = common::bench(&format!("multiply {n}x{n}"), 3, || {
+            arith::multiply(&a, &b).unwrap()
+        });
+        common::speedup(naive, tiled);
+    }
+}
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use linalgrs::io::matfile;
+
+fuzz_target!(|bytes: &[u8]| {
+    if let Ok(variables) = matfile::read(bytes) {
+        for variable in variables {
+            variable
+                .matrix
+                .validate()
+                .expect("MAT-file matrices are always valid");
+        }
+    }
+});
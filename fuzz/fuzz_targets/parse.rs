@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use linalgrs::matrix::Matrix;
+
+fuzz_target!(|input: &str| {
+    if let Ok(m) = Matrix::<f64>::parse(input) {
+        m.validate().expect("parsed matrices are always valid");
+    }
+    let _ = Matrix::<i64>::parse(input);
+});
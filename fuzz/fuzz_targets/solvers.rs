@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use linalgrs::testing::exercise_operations;
+
+fuzz_target!(|bytes: &[u8]| {
+    exercise_operations(bytes);
+});
@@ -0,0 +1,43 @@
+/* C interface to linalgrs, enabled by building with `--features ffi`. */
+#ifndef LINALGRS_H
+#define LINALGRS_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct LinalgrsMatrix LinalgrsMatrix;
+
+typedef enum LinalgrsStatus {
+    LINALGRS_OK = 0,
+    LINALGRS_NULL_POINTER = 1,
+    LINALGRS_INVALID_ARGUMENT = 2,
+    LINALGRS_FAILED = 3,
+    LINALGRS_PANIC = 4,
+} LinalgrsStatus;
+
+/* Message for the last failed call on this thread, or NULL. Owned by linalgrs. */
+const char *linalgrs_last_error_message(void);
+
+/* Matrices are exchanged as flat, row-major buffers of rows * cols doubles.
+   Passing NULL for data creates a zero matrix. */
+LinalgrsStatus linalgrs_matrix_create(size_t rows, size_t cols, const double *data,
+                                      LinalgrsMatrix **out);
+void linalgrs_matrix_free(LinalgrsMatrix *m);
+size_t linalgrs_matrix_rows(const LinalgrsMatrix *m);
+size_t linalgrs_matrix_cols(const LinalgrsMatrix *m);
+LinalgrsStatus linalgrs_matrix_copy_data(const LinalgrsMatrix *m, double *out, size_t len);
+
+LinalgrsStatus linalgrs_matrix_multiply(const LinalgrsMatrix *a, const LinalgrsMatrix *b,
+                                        LinalgrsMatrix **out);
+LinalgrsStatus linalgrs_matrix_solve(const LinalgrsMatrix *a, const LinalgrsMatrix *b,
+                                     LinalgrsMatrix **out);
+LinalgrsStatus linalgrs_matrix_determinant(const LinalgrsMatrix *m, double *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LINALGRS_H */
@@ -0,0 +1,324 @@
+extern crate num;
+
+use crate::context::ComputeContext;
+use crate::decompose::{self, Factors};
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::solve;
+use num::Float;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// The largest estimated relative error `solve_adaptive` accepts before escalating
+pub const DEFAULT_ADAPTIVE_TOLERANCE: f64 = 1e-8;
+
+/// The most iterative refinement steps taken at either precision
+pub const MAX_REFINEMENT_STEPS: usize = 10;
+
+/// The path `solve_adaptive` took to reach its answer, from cheapest to most expensive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolvePath {
+    /// A single f32 solve was accurate enough
+    Single,
+    /// The f32 factorization was reused for f64 iterative refinement
+    SingleRefined {
+        /// The number of refinement steps kept, each of which lowered the backward error
+        iterations: usize,
+    },
+    /// The system was too ill-conditioned for f32 and was solved in f64
+    Double,
+    /// The f64 solution still needed iterative refinement
+    DoubleRefined {
+        /// The number of refinement steps kept, each of which lowered the backward error
+        iterations: usize,
+    },
+}
+
+/// The result of `solve_adaptive`: the solution plus a record of how it was obtained
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptiveSolution {
+    /// The solution `x` of `Ax = b`, with the same shape as `b`
+    pub solution: Matrix<f64>,
+    /// Which precision and refinement path produced `solution`
+    pub path: SolvePath,
+    /// The estimated infinity-norm condition number of `A`
+    pub condition: f64,
+    /// The normwise backward error `||b - Ax|| / (||A|| ||x|| + ||b||)` of `solution`
+    pub backward_error: f64,
+    /// The estimated relative forward error, `condition * backward_error`
+    pub error_estimate: f64,
+}
+
+/// Factors `a` as `PA = LU` with `decompose::plu_decomposition_with_context`
+///
+/// ### Returns
+/// - A `Result` containing the factors, or `None` if they are unusable at this precision:
+///   `a` overflowed on conversion, or `U` has a zero or non-finite pivot. An `Err` is only
+///   returned when `ctx` interrupts the factorization
+fn factor<F>(a: &Matrix<F>, ctx: &ComputeContext) -> Result<Option<Factors<F>>, LinalgError>
+where
+    F: Number + Neg<Output = F> + Float,
+{
+    let factors = match decompose::plu_decomposition_with_context(a, ctx) {
+        Ok(factors) => factors,
+        Err(LinalgError::NonFinite { .. }) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let usable = (0..a.rows).all(|i| {
+        let pivot = factors.2.mat[i][i];
+        pivot != F::zero() && pivot.is_finite()
+    });
+
+    Ok(usable.then_some(factors))
+}
+
+/// Estimates `||A^-1||` in the infinity norm from the factors of `A`, by forming the
+/// inverse column by column
+fn inverse_norm<F>(factors: &Factors<F>) -> F
+where
+    F: Number + Neg<Output = F> + Float,
+{
+    let n = factors.0.rows;
+    let mut row_sums = vec![F::zero(); n];
+
+    for j in 0..n {
+        let mut e = vec![F::zero(); n];
+        e[j] = F::one();
+        for (sum, x) in row_sums
+            .iter_mut()
+            .zip(solve::substitute(factors, e, false))
+        {
+            *sum += x.abs();
+        }
+    }
+
+    row_sums.into_iter().fold(F::zero(), F::max)
+}
+
+fn norm_inf(v: &[f64]) -> f64 {
+    v.iter().fold(0.0, |max, x| max.max(x.abs()))
+}
+
+fn residual(a: &[Vec<f64>], x: &[f64], b: &[f64]) -> Vec<f64> {
+    a.iter()
+        .zip(b)
+        .map(|(row, &bi)| bi - row.iter().zip(x).map(|(aij, xj)| aij * xj).sum::<f64>())
+        .collect()
+}
+
+fn backward_error(a_norm: f64, x: &[f64], b: &[f64], r: &[f64]) -> f64 {
+    let scale = a_norm * norm_inf(x) + norm_inf(b);
+    if scale == 0.0 {
+        0.0
+    } else {
+        norm_inf(r) / scale
+    }
+}
+
+/// Solves `Ax = b`, starting in fast f32 arithmetic and escalating to f64 only when needed
+///
+/// Equivalent to `solve_adaptive_with_tol` with `DEFAULT_ADAPTIVE_TOLERANCE`
+///
+/// ### Parameters
+/// - `a` - The square coefficient `Matrix`
+/// - `b` - The right-hand side, with one column per system to solve
+///
+/// ### Returns
+/// - A `Result` containing an `AdaptiveSolution`, or an `Err` if the shapes do not match
+///   or `a` is singular
+pub fn solve_adaptive(a: &Matrix<f64>, b: &Matrix<f64>) -> Result<AdaptiveSolution, LinalgError> {
+    solve_adaptive_with_tol(a, b, DEFAULT_ADAPTIVE_TOLERANCE)
+}
+
+/// Solves `Ax = b`, starting in fast f32 arithmetic and escalating to f64 only when needed
+///
+/// The system is first factored and solved in f32. The condition number of `a` and the
+/// backward error of the f32 answer (computed in f64) give an estimate of its relative
+/// error. If the estimate exceeds `tol`, the f32 factorization is reused for
+/// [mixed-precision iterative refinement](https://en.wikipedia.org/wiki/Iterative_refinement)
+/// with f64 residuals, provided `a` is well enough conditioned for f32 refinement to
+/// converge. Otherwise the system is refactored and solved in f64, refining again if
+/// necessary. The path taken is reported in `AdaptiveSolution::path`
+///
+/// The condition estimate forms `A^-1` explicitly, so this costs a few extra O(n^3) steps
+///
+/// ### Parameters
+/// - `a` - The square coefficient `Matrix`
+/// - `b` - The right-hand side, with one column per system to solve
+/// - `tol` - The largest acceptable estimated relative error
+///
+/// ### Returns
+/// - A `Result` based on whether the system could be solved
+///     - An `Ok` containing the solution, the path taken, and the final error estimates.
+///       If even refined f64 cannot reach `tol`, the best f64 answer is returned and
+///       `error_estimate` shows how far off it may be
+///     - An `Err` if `a` is not square, `b` has the wrong number of rows, either input
+///       holds a NaN or infinity, or `a` is singular in f64
+pub fn solve_adaptive_with_tol(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    tol: f64,
+) -> Result<AdaptiveSolution, LinalgError> {
+    solve_adaptive_with_context(a, b, tol, &ComputeContext::new())
+}
+
+/// Solves `Ax = b` like `solve_adaptive_with_tol`, but can be interrupted through `ctx`
+///
+/// `ctx` is checked once per pivot while factoring and once per refinement step. Each
+/// refinement step kept is reported to `ctx` with the new backward error as its residual
+///
+/// ### Parameters
+/// - `a` - The square coefficient `Matrix`
+/// - `b` - The right-hand side, with one column per system to solve
+/// - `tol` - The largest acceptable estimated relative error
+/// - `ctx` - The `ComputeContext` that can interrupt the computation
+///
+/// ### Returns
+/// - A `Result` containing an `AdaptiveSolution`, or an `Err` with
+///   `LinalgError::Cancelled`, `LinalgError::DeadlineExceeded`, or one of the failures
+///   described in `solve_adaptive_with_tol`
+pub fn solve_adaptive_with_context(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    tol: f64,
+    ctx: &ComputeContext,
+) -> Result<AdaptiveSolution, LinalgError> {
+    a.validate()?;
+    b.validate()?;
+    a.check_finite()?;
+    b.check_finite()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if b.rows != n {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (n, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("solve_adaptive", 2 * n * n * n + 2 * n * n * b.cols, n + 1);
+    let a64: Vec<Vec<f64>> = a.mat.iter().map(|row| row.to_vec()).collect();
+    let a32 = Matrix {
+        mat: a
+            .mat
+            .iter()
+            .map(|row| row.iter().map(|&x| x as f32).collect())
+            .collect(),
+        rows: n,
+        cols: n,
+    };
+    let a_norm = a64
+        .iter()
+        .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let columns: Vec<Vec<f64>> = (0..b.cols)
+        .map(|c| b.mat.iter().map(|row| row[c]).collect())
+        .collect();
+
+    let mut result = None;
+    if let Some(lu32) = factor(&a32, ctx)? {
+        let condition = a_norm * inverse_norm(&lu32) as f64;
+        if condition.is_finite() && condition * (f32::EPSILON as f64) < 1.0 {
+            let solve32 = |r: &[f64]| -> Vec<f64> {
+                let r32: Vec<f32> = r.iter().map(|&x| x as f32).collect();
+                solve::substitute(&lu32, r32, false)
+                    .into_iter()
+                    .map(f64::from)
+                    .collect()
+            };
+            result = refine(&a64, &columns, a_norm, condition, tol, solve32, false, ctx)?;
+        }
+    }
+
+    let solution = match result {
+        Some(solution) => solution,
+        None => {
+            let lu64 = factor(a, ctx)?.ok_or(LinalgError::Singular)?;
+            let condition = a_norm * inverse_norm(&lu64);
+            let solve64 = |r: &[f64]| solve::substitute(&lu64, r.to_vec(), false);
+            refine(&a64, &columns, a_norm, condition, tol, solve64, true, ctx)?
+                .ok_or(LinalgError::Singular)?
+        }
+    };
+
+    Ok(solution)
+}
+
+/// Solves every column with `solve`, then refines until the error estimate is within `tol`
+///
+/// Returns `None` when refinement at this precision cannot reach `tol`, unless `double` is
+/// set, in which case the best answer found is always returned
+#[allow(clippy::too_many_arguments)]
+fn refine<S: Fn(&[f64]) -> Vec<f64>>(
+    a: &[Vec<f64>],
+    columns: &[Vec<f64>],
+    a_norm: f64,
+    condition: f64,
+    tol: f64,
+    solve: S,
+    double: bool,
+    ctx: &ComputeContext,
+) -> Result<Option<AdaptiveSolution>, LinalgError> {
+    let mut solutions = Vec::with_capacity(columns.len());
+    let mut worst_backward = 0.0;
+    let mut iterations = 0;
+
+    for b in columns {
+        let mut x = solve(b);
+        let mut r = residual(a, &x, b);
+        let mut eta = backward_error(a_norm, &x, b, &r);
+        let mut steps = 0;
+
+        // Only a step that lowers the backward error is kept and counted
+        while condition * eta > tol && steps < MAX_REFINEMENT_STEPS {
+            ctx.check()?;
+            let d = solve(&r);
+            let candidate: Vec<f64> = x.iter().zip(&d).map(|(xi, di)| xi + di).collect();
+            let candidate_r = residual(a, &candidate, b);
+            let candidate_eta = backward_error(a_norm, &candidate, b, &candidate_r);
+            if candidate_eta.is_nan() || candidate_eta >= eta {
+                break;
+            }
+            (x, r, eta) = (candidate, candidate_r, candidate_eta);
+            steps += 1;
+            ctx.report(steps, eta);
+        }
+
+        if condition * eta > tol && !double {
+            return Ok(None);
+        }
+        worst_backward = f64::max(worst_backward, eta);
+        iterations = iterations.max(steps);
+        solutions.push(x);
+    }
+
+    let path = match (double, iterations) {
+        (false, 0) => SolvePath::Single,
+        (false, iterations) => SolvePath::SingleRefined { iterations },
+        (true, 0) => SolvePath::Double,
+        (true, iterations) => SolvePath::DoubleRefined { iterations },
+    };
+    let n = a.len();
+    let mat = (0..n)
+        .map(|i| Arc::from(solutions.iter().map(|x| x[i]).collect::<Vec<f64>>()))
+        .collect();
+
+    Ok(Some(AdaptiveSolution {
+        solution: Matrix {
+            mat,
+            rows: n,
+            cols: columns.len(),
+        },
+        path,
+        condition,
+        backward_error: worst_backward,
+        error_estimate: condition * worst_backward,
+    }))
+}
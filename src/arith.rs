@@ -0,0 +1,1142 @@
+use crate::diff::{CellDiff, DiffReport, MAX_REPORTED_CELLS};
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::parallel;
+use crate::transform;
+use crate::vector::{self, Vector};
+use std::cmp::Ordering;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// The side length of the square tiles `multiply` splits its operands into; three tiles of
+/// `f64` take 96 KiB, which fits in the L2 cache of current CPUs
+const MULTIPLY_TILE: usize = 64;
+
+/// The size at and below which `multiply_strassen` stops recursing and calls `multiply`
+const STRASSEN_CROSSOVER: usize = 128;
+
+/// Adds two `Matrix` instances together and returns a new `Matrix` representing
+/// their sum
+///
+/// ### Parameters
+/// - `a`: One `Matrix` operand addend
+/// - 'b': Another 'Matrix' operand addend
+///
+/// ### Returns
+/// - A `Result` based on whether the two matrices were added or not
+///     - An `Err` if the two matrices are different shapes
+///     - An `Ok` wrapped inside a `Matrix` instance that represents the sum
+///       of the two matrices `a` and `b`
+pub fn add<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if (a.rows, a.cols) != (b.rows, b.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("add", a.rows * a.cols, a.rows + 1);
+    let mut result = Vec::new();
+
+    for r in 0..a.rows {
+        let mut new_row = Vec::new();
+        for c in 0..a.cols {
+            new_row.push(a.mat[r][c] + b.mat[r][c]);
+        }
+        result.push(Arc::from(new_row.as_slice()));
+    }
+
+    Ok(Matrix {
+        mat: result,
+        rows: a.rows,
+        cols: a.cols,
+    })
+}
+
+/// Adds two integer `Matrix` instances like `add`, but fails instead of wrapping when a sum
+/// overflows
+///
+/// `Matrix::checked_add` only checks the shapes; this also checks every sum
+///
+/// ### Parameters
+/// - `a`: One `Matrix` operand addend
+/// - `b`: Another `Matrix` operand addend
+///
+/// ### Returns
+/// - A `Result` based on whether the sum fits in `T`
+///     - An `Ok` containing the sum of `a` and `b`
+///     - An `Err` of `LinalgError::ShapeMismatch` if the two matrices are different
+///       shapes, or `LinalgError::Overflow` if any element of the sum overflows
+pub fn checked_add<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + num::CheckedAdd,
+{
+    a.validate()?;
+    b.validate()?;
+    if (a.rows, a.cols) != (b.rows, b.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("checked_add", a.rows * a.cols, a.rows + 1);
+    let mat = a
+        .mat
+        .iter()
+        .zip(&b.mat)
+        .map(|(x, y)| {
+            x.iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.checked_add(y).ok_or(LinalgError::Overflow))
+                .collect::<Result<Vec<T>, _>>()
+                .map(Arc::from)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Matrix {
+        mat,
+        rows: a.rows,
+        cols: a.cols,
+    })
+}
+
+/// Subtracts two `Matrix` instances together and returns a new `Matrix` representing
+/// their difference
+///
+/// ### Parameters
+/// - `a`: A `Matrix` instance that will be one of the operands
+/// - `b`: Another 'Matrix' instance that will be the second operand to subtract from
+///
+/// ### Returns
+/// - An `Result` based on whether the two matrices were added
+///   - An `Err` value when the two matrices have different shapes
+///   - An `Ok` value wrapped with a `Matrix` instance that represents the difference
+///     of the two matrices `a` and `b`
+pub fn subtract<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if (a.rows, a.cols) != (b.rows, b.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("subtract", a.rows * a.cols, a.rows + 1);
+    let mut result = Vec::new();
+
+    for r in 0..a.rows {
+        let mut new_row = Vec::new();
+        for c in 0..a.cols {
+            new_row.push(a.mat[r][c] - b.mat[r][c]);
+        }
+        result.push(Arc::from(new_row.as_slice()));
+    }
+
+    Ok(Matrix {
+        mat: result,
+        rows: a.rows,
+        cols: a.cols,
+    })
+}
+
+/// Adds `b` to `a` like `add`, reusing the rows of `a` for the sum
+///
+/// Rows of `a` that no other `Matrix` shares are updated in place, so `a + b` for an `a`
+/// that is not needed afterwards allocates nothing
+///
+/// ### Parameters
+/// - `a`: The `Matrix` to add to, consumed
+/// - `b`: The `Matrix` to add
+///
+/// ### Returns
+/// - A `Result` containing the sum, or an `Err` if the two matrices are different shapes
+pub fn add_owned<T>(a: Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let _span = Span::new("add_owned", a.rows * a.cols, 0);
+    combine_owned(a, b, |x, y| *x += y)
+}
+
+/// Subtracts `b` from `a` like `subtract`, reusing the rows of `a` for the difference
+///
+/// Rows of `a` that no other `Matrix` shares are updated in place
+///
+/// ### Parameters
+/// - `a`: The `Matrix` to subtract from, consumed
+/// - `b`: The `Matrix` to subtract
+///
+/// ### Returns
+/// - A `Result` containing the difference, or an `Err` if the two matrices are different
+///   shapes
+pub fn subtract_owned<T>(a: Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let _span = Span::new("subtract_owned", a.rows * a.cols, 0);
+    combine_owned(a, b, |x, y| *x -= y)
+}
+
+/// Applies `op` to every element of `a` and the matching element of `b`, in place
+fn combine_owned<T, F>(mut a: Matrix<T>, b: &Matrix<T>, op: F) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number,
+    F: Fn(&mut T, T),
+{
+    a.validate()?;
+    b.validate()?;
+    if (a.rows, a.cols) != (b.rows, b.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    for (row, other) in a.mat.iter_mut().zip(&b.mat) {
+        for (x, &y) in Arc::make_mut(row).iter_mut().zip(other.iter()) {
+            op(x, y);
+        }
+    }
+
+    Ok(a)
+}
+
+/// Multiplies a given `Matrix` by a given scalar `constant`
+///
+/// ### Parameters
+/// - `matrix`: The given `Matrix` to be multiplied by a scalar constant
+/// - `constant`: The given scalar constant to multiply the given `Matrix` by
+///
+/// ### Returns
+/// - A new `Matrix` that contains the matrix after multiplying
+///   by a scalar constant
+pub fn multiply_by_scalar<T>(matrix: &Matrix<T>, constant: T) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    multiply_by_scalar_owned(matrix.clone(), constant)
+}
+
+/// Multiplies a given `Matrix` by a scalar `constant` like `multiply_by_scalar`, reusing
+/// its rows
+///
+/// Rows that no other `Matrix` shares are scaled in place
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` to scale, consumed
+/// - `constant`: The scalar to multiply every element by
+///
+/// ### Returns
+/// - `matrix` with every element multiplied by `constant`
+pub fn multiply_by_scalar_owned<T>(mut matrix: Matrix<T>, constant: T) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let _span = Span::new("multiply_by_scalar", matrix.rows * matrix.cols, 0);
+    for row in &mut matrix.mat {
+        for elem in Arc::make_mut(row) {
+            *elem *= constant;
+        }
+    }
+
+    matrix
+}
+
+/// Multiplies two `Matrix` instances together and returns their product as a
+/// new `Matrix` object
+///
+/// The product is computed in `MULTIPLY_TILE x MULTIPLY_TILE` tiles against a transposed
+/// copy of `b`, so the innermost loop walks two contiguous rows and each tile is reused
+/// while it is still in cache. Every element is still summed in the same order as
+/// `multiply_naive`, so the two return identical results
+///
+/// ### Parameters
+/// - `a`: One `Matrix` operand to be multiplied
+/// - 'b': Another `Matrix` operand to be multiplied
+///
+/// ### Returns
+/// - A `Result` based on whether the two matrices were multiplied
+///     - An `Err` if the columns of `Matrix` a does not equal the rows of `Matrix` b
+///     - An `Ok` wrapped inside a `Matrix` object that represents the product between two
+///       matrices
+pub fn multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if a.cols != b.rows {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.cols, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new(
+        "multiply",
+        2 * a.rows * a.cols * b.cols,
+        a.rows + b.cols + 1,
+    );
+    let b_t: Vec<Vec<T>> = (0..b.cols)
+        .map(|c| b.mat.iter().map(|row| row[c]).collect())
+        .collect();
+    let row_tiles = a.rows.div_ceil(MULTIPLY_TILE);
+    let tiles = parallel::map_range(row_tiles, a.rows * a.cols * b.cols, |tile| {
+        let first = tile * MULTIPLY_TILE;
+        let a_rows = &a.mat[first..(first + MULTIPLY_TILE).min(a.rows)];
+        let mut out = vec![vec![T::default(); b.cols]; a_rows.len()];
+        for jb in (0..b.cols).step_by(MULTIPLY_TILE) {
+            let b_cols = &b_t[jb..(jb + MULTIPLY_TILE).min(b.cols)];
+            for kb in (0..a.cols).step_by(MULTIPLY_TILE) {
+                let k_end = (kb + MULTIPLY_TILE).min(a.cols);
+                for (out_row, a_row) in out.iter_mut().zip(a_rows) {
+                    let a_row = &a_row[kb..k_end];
+                    for (sum, b_col) in out_row[jb..].iter_mut().zip(b_cols) {
+                        for (&x, &y) in a_row.iter().zip(&b_col[kb..k_end]) {
+                            *sum += x * y;
+                        }
+                    }
+                }
+            }
+        }
+        out
+    });
+
+    Ok(Matrix {
+        mat: tiles.into_iter().flatten().map(Arc::from).collect(),
+        rows: a.rows,
+        cols: b.cols,
+    })
+}
+
+/// Multiplies two `Matrix` instances with the textbook i-j-k triple loop on one thread
+///
+/// Returns exactly what `multiply` does, but strides down the columns of `b` in its
+/// innermost loop, so it slows sharply once `b` no longer fits in cache. Kept as a
+/// baseline for benchmarks and for checking `multiply`
+///
+/// ### Parameters
+/// - `a`: One `Matrix` operand to be multiplied
+/// - 'b': Another `Matrix` operand to be multiplied
+///
+/// ### Returns
+/// - A `Result` containing the product, or an `Err` if the columns of `a` do not equal
+///   the rows of `b`
+pub fn multiply_naive<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if a.cols != b.rows {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.cols, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("multiply_naive", 2 * a.rows * a.cols * b.cols, a.rows + 1);
+    let mut new_mat = vec![];
+    for r in 0..a.rows {
+        let mut new_row = vec![];
+        for c in 0..b.cols {
+            let mut sum = T::default();
+            for k in 0..a.cols {
+                sum += a.mat[r][k] * b.mat[k][c];
+            }
+            new_row.push(sum);
+        }
+        new_mat.push(Arc::from(new_row.as_slice()));
+    }
+
+    Ok(Matrix {
+        mat: new_mat,
+        rows: a.rows,
+        cols: b.cols,
+    })
+}
+
+/// Multiplies two integer `Matrix` instances like `multiply`, but fails instead of wrapping
+/// when a product or partial sum overflows
+///
+/// Every element is summed in the same order as `multiply_naive`, and each step is checked,
+/// so the result is exactly what unbounded integers would give whenever it is `Ok`. Like
+/// any fixed-width sum, a partial sum can overflow even when later terms would bring the
+/// total back in range, and that is reported as an overflow too
+///
+/// ### Parameters
+/// - `a`: One `Matrix` operand to be multiplied
+/// - `b`: Another `Matrix` operand to be multiplied
+///
+/// ### Returns
+/// - A `Result` based on whether the product fits in `T`
+///     - An `Ok` containing the product of `a` and `b`
+///     - An `Err` of `LinalgError::ShapeMismatch` if the columns of `a` do not equal the
+///       rows of `b`, or `LinalgError::Overflow` if any step overflows
+pub fn checked_multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + num::CheckedAdd + num::CheckedMul,
+{
+    a.validate()?;
+    b.validate()?;
+    if a.cols != b.rows {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.cols, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("checked_multiply", 2 * a.rows * a.cols * b.cols, a.rows + 1);
+    let mut mat = Vec::with_capacity(a.rows);
+    for r in 0..a.rows {
+        let mut row = Vec::with_capacity(b.cols);
+        for c in 0..b.cols {
+            let mut sum = T::default();
+            for k in 0..a.cols {
+                sum = a.mat[r][k]
+                    .checked_mul(&b.mat[k][c])
+                    .and_then(|product| sum.checked_add(&product))
+                    .ok_or(LinalgError::Overflow)?;
+            }
+            row.push(sum);
+        }
+        mat.push(Arc::from(row));
+    }
+
+    Ok(Matrix {
+        mat,
+        rows: a.rows,
+        cols: b.cols,
+    })
+}
+
+/// Multiplies two square matrices by the
+/// [Strassen algorithm](https://en.wikipedia.org/wiki/Strassen_algorithm)
+///
+/// Each level splits both operands into quadrants and forms the product from seven
+/// quadrant products instead of eight, for O(n^2.81) work in place of O(n^3). Quadrants
+/// of odd size are padded with a zero row and column. Below `STRASSEN_CROSSOVER` the
+/// extra additions cost more than they save, so smaller products, and any product of
+/// non-square or differently sized operands, are left to `multiply`. Float results can
+/// differ from `multiply` in the last few bits because the sums are regrouped
+///
+/// ### Parameters
+/// - `a`: One `Matrix` operand to be multiplied
+/// - `b`: Another `Matrix` operand to be multiplied
+///
+/// ### Returns
+/// - A `Result` containing the product, or an `Err` if the columns of `a` do not equal
+///   the rows of `b`
+pub fn multiply_strassen<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    let n = a.rows;
+    if (a.cols, b.rows, b.cols) != (n, n, n) || n <= STRASSEN_CROSSOVER {
+        return multiply(a, b);
+    }
+
+    strassen(a, b)
+}
+
+/// Multiplies two n x n matrices by one level of Strassen recursion
+fn strassen<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let n = a.rows;
+    if n <= STRASSEN_CROSSOVER {
+        return multiply(a, b);
+    }
+    if n % 2 == 1 {
+        let pad = |m: &Matrix<T>| {
+            transform::block(&[
+                &[m, &Matrix::zeros(n, 1)],
+                &[&Matrix::zeros(1, n), &Matrix::zeros(1, 1)],
+            ])
+        };
+        let padded = strassen(&pad(a)?, &pad(b)?)?;
+        return padded.split_at_row(n)?.0.split_at_col(n).map(|(c, _)| c);
+    }
+
+    let half = n / 2;
+    let quadrants = |m: &Matrix<T>| -> Result<[Matrix<T>; 4], LinalgError> {
+        let (top, bottom) = m.split_at_row(half)?;
+        let (m11, m12) = top.split_at_col(half)?;
+        let (m21, m22) = bottom.split_at_col(half)?;
+        Ok([m11, m12, m21, m22])
+    };
+    let [a11, a12, a21, a22] = quadrants(a)?;
+    let [b11, b12, b21, b22] = quadrants(b)?;
+
+    let m1 = strassen(&add(&a11, &a22)?, &add(&b11, &b22)?)?;
+    let m2 = strassen(&add(&a21, &a22)?, &b11)?;
+    let m3 = strassen(&a11, &subtract(&b12, &b22)?)?;
+    let m4 = strassen(&a22, &subtract(&b21, &b11)?)?;
+    let m5 = strassen(&add(&a11, &a12)?, &b22)?;
+    let m6 = strassen(&subtract(&a21, &a11)?, &add(&b11, &b12)?)?;
+    let m7 = strassen(&subtract(&a12, &a22)?, &add(&b21, &b22)?)?;
+
+    let c11 = add(&subtract(&add(&m1, &m4)?, &m5)?, &m7)?;
+    let c12 = add(&m3, &m5)?;
+    let c21 = add(&m2, &m4)?;
+    let c22 = add(&add(&subtract(&m1, &m2)?, &m3)?, &m6)?;
+
+    transform::block(&[&[&c11, &c12], &[&c21, &c22]])
+}
+
+/// Computes the Gram matrix `AᵀA` of `a`, whose `(i, j)` element is the dot product of
+/// columns `i` and `j` of `a`
+///
+/// The result is symmetric, so only the upper triangle is computed and then mirrored,
+/// which is half the work of `multiply(&transpose(a), a)`
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing the symmetric `n x n` Gram matrix, or an `Err` if `a` is
+///   malformed
+pub fn gram<T>(a: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    let (m, n) = (a.rows, a.cols);
+    let _span = Span::new("gram", m * n * (n + 1), n + 1);
+
+    Ok(symmetric(n, |i, j| {
+        let mut sum = T::default();
+        for k in 0..m {
+            sum += a.mat[k][i] * a.mat[k][j];
+        }
+        sum
+    }))
+}
+
+/// Computes the Gram matrix `AAᵀ` of the rows of `a`, whose `(i, j)` element is the dot
+/// product of rows `i` and `j` of `a`
+///
+/// Like `gram`, only the upper triangle is computed and then mirrored
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing the symmetric `m x m` Gram matrix, or an `Err` if `a` is
+///   malformed
+pub fn row_gram<T>(a: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    let (m, n) = (a.rows, a.cols);
+    let _span = Span::new("row_gram", n * m * (m + 1), m + 1);
+
+    Ok(symmetric(m, |i, j| {
+        let mut sum = T::default();
+        for k in 0..n {
+            sum += a.mat[i][k] * a.mat[j][k];
+        }
+        sum
+    }))
+}
+
+/// Computes the trace of the product `AB` without forming the product
+///
+/// Only the diagonal of `AB` contributes to its trace, so this takes O(mn) time instead
+/// of the O(m^2 n) of `multiply`
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+/// - `b` - An `n x m` `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing `tr(AB)`, or an `Err` of `LinalgError::ShapeMismatch` if `b`
+///   is not the shape of `Aᵀ`
+pub fn trace_of_product<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if (b.rows, b.cols) != (a.cols, a.rows) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.cols, a.rows),
+            found: (b.rows, b.cols),
+        });
+    }
+    let _span = Span::new("trace_of_product", 2 * a.rows * a.cols, 0);
+
+    let mut sum = T::default();
+    for i in 0..a.rows {
+        for k in 0..a.cols {
+            sum += a.mat[i][k] * b.mat[k][i];
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Computes the trace of the product `AᵀB` without forming the product
+///
+/// `tr(AᵀB)` is the sum of the elementwise products of `a` and `b`, so it takes O(mn)
+/// time and needs no transpose
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+/// - `b` - An `m x n` `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing `tr(AᵀB)`, or an `Err` of `LinalgError::ShapeMismatch` if the
+///   shapes differ
+pub fn trace_of_transpose_product<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if (b.rows, b.cols) != (a.rows, a.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+    let _span = Span::new("trace_of_transpose_product", 2 * a.rows * a.cols, 0);
+
+    let mut sum = T::default();
+    for (row_a, row_b) in a.mat.iter().zip(&b.mat) {
+        for (&x, &y) in row_a.iter().zip(row_b.iter()) {
+            sum += x * y;
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Computes the Frobenius inner product `⟨A, B⟩ = Σ aᵢⱼbᵢⱼ` of two matrices of equal shape
+///
+/// This is the dot product of the matrices read as flat vectors, and equals
+/// `trace_of_transpose_product(a, b)`
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+/// - `b` - An `m x n` `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing `⟨A, B⟩`, or an `Err` of `LinalgError::ShapeMismatch` if the
+///   shapes differ
+pub fn frobenius_inner<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    trace_of_transpose_product(a, b)
+}
+
+/// Builds an `n x n` symmetric `Matrix`, calling `entry(i, j)` only for `i <= j`
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn symmetric<T>(n: usize, entry: impl Fn(usize, usize) -> T) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let mut result = vec![vec![T::default(); n]; n];
+    for i in 0..n {
+        for j in i..n {
+            let value = entry(i, j);
+            result[i][j] = value;
+            result[j][i] = value;
+        }
+    }
+
+    Matrix {
+        mat: result.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: n,
+    }
+}
+
+/// Gets the dot product of two vectors stored as matrices `a` and `b`
+///
+/// Each operand may be a row (1 x n) or a column (n x 1) vector, in any combination;
+/// both are read in order, so a row and a column vector with the same elements give
+/// the same result
+///
+/// ### Parameters
+/// - `a`: One of the `Matrix` instance operands
+/// - `b`: Another `Matrix` instance operand
+///
+/// ### Returns
+/// - A `Result` based on whether there is a
+///   valid dot product for matrices `a` and `b`
+///     - An `Err` value if either operand has more than one row and more than one
+///       column, or if the two vectors have different lengths
+///     - An `Ok` wrapped in a T generic value, representing the
+///       dot product
+pub fn dot<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+
+    dot_slices(
+        &Vector::from_matrix(a)?.elements,
+        &Vector::from_matrix(b)?.elements,
+    )
+}
+
+/// Gets the dot product of two slices `a` and `b`
+///
+/// ### Parameters
+/// - `a`: One of the slice operands
+/// - `b`: Another slice operand
+///
+/// ### Returns
+/// - A `Result` based on whether the slices have the same length
+///     - An `Ok` containing the sum of the element-wise products
+///     - An `Err` of `LinalgError::LengthMismatch` if the lengths differ
+pub fn dot_slices<T>(a: &[T], b: &[T]) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    if a.len() != b.len() {
+        return Err(LinalgError::LengthMismatch {
+            expected: a.len(),
+            found: b.len(),
+        });
+    }
+
+    let _span = Span::new("dot", 2 * a.len(), 0);
+    let mut sum = T::default();
+    for i in 0..a.len() {
+        sum += a[i] * b[i];
+    }
+
+    Ok(sum)
+}
+
+/// Generates an `n` by `n` identity matrix
+///
+/// The identity `Matrix` is a matrix that when multiplied by another matrix yields that other
+/// matrix.
+///
+/// ### Returns
+/// - An `n` by `n` identity `Matrix`
+pub fn identity<T>(n: usize) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let mut output: Vec<Arc<[T]>> = vec![];
+    for i in 0..n {
+        let mut zeroes = vec![T::default(); n];
+        zeroes[i] = T::one();
+        let arr = &zeroes[..];
+        output.push(Arc::from(arr));
+    }
+
+    Matrix {
+        mat: output,
+        rows: n,
+        cols: n,
+    }
+}
+
+/// Computes the [Kronecker product](https://en.wikipedia.org/wiki/Kronecker_product)
+/// `A ⊗ B`
+///
+/// The result is the block matrix whose block `(i, j)` is `a[i][j] * B`, so an
+/// `m x n` `a` and a `p x q` `b` give an `mp x nq` `Matrix`
+///
+/// ### Parameters
+/// - `a` - The `Matrix` whose elements scale the blocks
+/// - `b` - The `Matrix` repeated in every block
+///
+/// ### Returns
+/// - A `Result` containing the Kronecker product, or an `Err` if either input is
+///   malformed
+pub fn kronecker<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+
+    let (rows, cols) = (a.rows * b.rows, a.cols * b.cols);
+    let _span = Span::new("kronecker", rows * cols, rows + 1);
+    let mat = (0..rows)
+        .map(|r| {
+            let (a_row, b_row) = (&a.mat[r / b.rows], &b.mat[r % b.rows]);
+            a_row
+                .iter()
+                .flat_map(|&x| b_row.iter().map(move |&y| x * y))
+                .collect()
+        })
+        .collect();
+
+    Ok(Matrix { mat, rows, cols })
+}
+
+/// Computes the [Hadamard product](https://en.wikipedia.org/wiki/Hadamard_product_(matrices))
+/// of two matrices, multiplying them element by element
+///
+/// ### Parameters
+/// - `a` - The first `Matrix`
+/// - `b` - The second `Matrix`, with the same shape as `a`
+///
+/// ### Returns
+/// - A `Result` based on whether the shapes match
+///     - An `Ok` containing the `Matrix` whose `(i, j)` element is `a[i][j] * b[i][j]`
+///     - An `Err` of `LinalgError::ShapeMismatch` if `a` and `b` differ in shape
+pub fn hadamard<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    if (a.rows, a.cols) != (b.rows, b.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new("hadamard", a.rows * a.cols, a.rows + 1);
+    let mat = a
+        .mat
+        .iter()
+        .zip(&b.mat)
+        .map(|(x, y)| x.iter().zip(y.iter()).map(|(&p, &q)| p * q).collect())
+        .collect();
+
+    Ok(Matrix {
+        mat,
+        rows: a.rows,
+        cols: a.cols,
+    })
+}
+
+/// Raises a square `Matrix` to a non-negative integer power
+///
+/// Uses [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring),
+/// so `A^k` takes about `2 log2(k)` matrix products rather than `k - 1`
+///
+/// ### Parameters
+/// - `matrix` - The square `Matrix` to raise
+/// - `exponent` - The power to raise it to; `A^0` is the identity
+///
+/// ### Returns
+/// - A `Result` containing `matrix` raised to `exponent`, or an `Err` of
+///   `LinalgError::NonSquare` if `matrix` is not square
+pub fn pow<T>(matrix: &Matrix<T>, exponent: u32) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    matrix.validate()?;
+    if matrix.rows != matrix.cols {
+        return Err(LinalgError::NonSquare {
+            rows: matrix.rows,
+            cols: matrix.cols,
+        });
+    }
+
+    let mut result: Option<Matrix<T>> = None;
+    let mut base = matrix.clone();
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = Some(match result {
+                Some(result) => multiply(&result, &base)?,
+                None => base.clone(),
+            });
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(&base, &base)?;
+        }
+    }
+
+    Ok(result.unwrap_or_else(|| identity(matrix.rows)))
+}
+
+/// Computes the [commutator](https://en.wikipedia.org/wiki/Commutator#Ring_theory)
+/// `[A, B] = AB − BA`, the Lie bracket of two square matrices
+///
+/// Both products are accumulated together element by element, so neither `AB` nor
+/// `BA` is ever stored
+///
+/// ### Parameters
+/// - `a` - The square `Matrix` `A`
+/// - `b` - The square `Matrix` `B`, with the same shape as `a`
+///
+/// ### Returns
+/// - A `Result` containing `AB − BA`, which is zero exactly when `a` and `b` commute, or
+///   an `Err` of `LinalgError::NonSquare` if `a` is not square or
+///   `LinalgError::ShapeMismatch` if `b` does not have the shape of `a`
+pub fn commutator<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    bracket("commutator", a, b, |ab, ba| ab - ba)
+}
+
+/// Computes the anticommutator `{A, B} = AB + BA` of two square matrices
+///
+/// Both products are accumulated together element by element, so neither `AB` nor
+/// `BA` is ever stored
+///
+/// ### Parameters
+/// - `a` - The square `Matrix` `A`
+/// - `b` - The square `Matrix` `B`, with the same shape as `a`
+///
+/// ### Returns
+/// - A `Result` containing `AB + BA`, or an `Err` of `LinalgError::NonSquare` if `a`
+///   is not square or `LinalgError::ShapeMismatch` if `b` does not have the shape of `a`
+pub fn anticommutator<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    bracket("anticommutator", a, b, |ab, ba| ab + ba)
+}
+
+/// Computes `combine((AB)[i][j], (BA)[i][j])` for every element in a single pass
+fn bracket<T>(
+    name: &'static str,
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    combine: impl Fn(T, T) -> T,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    b.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if (b.rows, b.cols) != (n, n) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (n, n),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let _span = Span::new(name, 4 * n * n * n, n + 1);
+    let mat = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let (mut ab, mut ba) = (T::default(), T::default());
+                    for k in 0..n {
+                        ab += a.mat[i][k] * b.mat[k][j];
+                        ba += b.mat[i][k] * a.mat[k][j];
+                    }
+                    combine(ab, ba)
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(Matrix {
+        mat,
+        rows: n,
+        cols: n,
+    })
+}
+
+/// Compares two `Matrix` instances cell by cell and produces a structured `DiffReport`
+///
+/// ### Parameters
+/// - `a`: The left-hand `Matrix` to compare
+/// - `b`: The right-hand `Matrix` to compare
+/// - `tol`: The largest absolute difference at which two cells are still considered equal
+///
+/// ### Returns
+/// - A `Result` based on whether the two matrices could be compared
+///     - An `Err` if the two matrices are different shapes
+///     - An `Ok` containing a `DiffReport` with the number of differing cells, the
+///       maximum absolute difference, and the worst offending cells
+pub fn diff_report<T>(a: &Matrix<T>, b: &Matrix<T>, tol: T) -> Result<DiffReport<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + PartialOrd,
+{
+    a.validate()?;
+    b.validate()?;
+    if (a.rows, a.cols) != (b.rows, b.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    let mut differing = Vec::new();
+    let mut max_abs_diff = T::default();
+
+    for r in 0..a.rows {
+        for c in 0..a.cols {
+            let (left, right) = (a.mat[r][c], b.mat[r][c]);
+            let diff = left - right;
+            let abs_diff = if diff < T::default() { -diff } else { diff };
+
+            // A NaN difference outranks every finite one and sticks once recorded
+            if abs_diff.is_nan_value() || abs_diff > max_abs_diff {
+                max_abs_diff = abs_diff;
+            }
+            // Incomparable (NaN) differences are always reported
+            if !matches!(
+                abs_diff.partial_cmp(&tol),
+                Some(Ordering::Less | Ordering::Equal)
+            ) {
+                differing.push(CellDiff {
+                    row: r,
+                    col: c,
+                    left,
+                    right,
+                    abs_diff,
+                });
+            }
+        }
+    }
+
+    let count = differing.len();
+    // NaN sorts as +inf so the ordering stays total and NaN cells lead the report
+    differing.sort_by(
+        |x, y| match (x.abs_diff.is_nan_value(), y.abs_diff.is_nan_value()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => y
+                .abs_diff
+                .partial_cmp(&x.abs_diff)
+                .unwrap_or(Ordering::Equal),
+        },
+    );
+    differing.truncate(MAX_REPORTED_CELLS);
+
+    Ok(DiffReport {
+        shape: (a.rows, a.cols),
+        tolerance: tol,
+        differing: count,
+        max_abs_diff,
+        worst: differing,
+    })
+}
+
+/// Checks whether `b` is `a` with its rows reordered
+///
+/// Pivoting algorithms are free to return rows in any order, so this compares such
+/// outputs without depending on the order chosen. Rows are compared exactly, as `==`
+/// compares matrices, so a matrix holding a NaN never matches
+///
+/// ### Parameters
+/// - `a` - The `Matrix` in its reference order
+/// - `b` - The `Matrix` to compare against it
+///
+/// ### Returns
+/// - `Some` with the permutation `p` such that row `i` of `b` is row `p[i]` of `a`, or
+///   `None` if the shapes differ, either matrix is malformed, or no reordering of the
+///   rows of `a` gives `b`
+pub fn eq_up_to_row_permutation<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Option<Vec<usize>> {
+    if a.validate().is_err() || b.validate().is_err() || (a.rows, a.cols) != (b.rows, b.cols) {
+        return None;
+    }
+
+    match_permutation(a.rows, |i, j| b.mat[i] == a.mat[j])
+}
+
+/// Checks whether `b` is `a` with its columns reordered
+///
+/// The column counterpart of `eq_up_to_row_permutation`
+///
+/// ### Parameters
+/// - `a` - The `Matrix` in its reference order
+/// - `b` - The `Matrix` to compare against it
+///
+/// ### Returns
+/// - `Some` with the permutation `p` such that column `j` of `b` is column `p[j]` of
+///   `a`, or `None` if the shapes differ, either matrix is malformed, or no reordering of
+///   the columns of `a` gives `b`
+pub fn eq_up_to_col_permutation<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Option<Vec<usize>> {
+    if a.validate().is_err() || b.validate().is_err() || (a.rows, a.cols) != (b.rows, b.cols) {
+        return None;
+    }
+
+    match_permutation(a.cols, |i, j| {
+        a.mat.iter().zip(&b.mat).all(|(x, y)| y[i] == x[j])
+    })
+}
+
+/// Pairs each of `n` items of one matrix with an unused, equal item of the other
+///
+/// Equal items are interchangeable, so taking the first unused match never blocks a later
+/// one and no backtracking is needed
+fn match_permutation(n: usize, same: impl Fn(usize, usize) -> bool) -> Option<Vec<usize>> {
+    let mut used = vec![false; n];
+    (0..n)
+        .map(|i| {
+            let j = (0..n).find(|&j| !used[j] && same(i, j))?;
+            used[j] = true;
+            Some(j)
+        })
+        .collect()
+}
+
+/// Computes the cosine similarity of two matrices of equal shape under the Frobenius
+/// inner product
+///
+/// This is the cosine of the angle between the matrices read as flat vectors, so `1`
+/// means one is a positive multiple of the other and `0` means they are orthogonal
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+/// - `b` - An `m x n` `Matrix`
+///
+/// ### Returns
+/// - A `Result` based on whether the similarity is defined
+///     - An `Ok` containing `⟨A, B⟩ / (‖A‖ ‖B‖)`, clamped to `[-1, 1]` to absorb
+///       rounding
+///     - An `Err` if the shapes differ or either matrix is all zeros, or
+///       `LinalgError::NonFinite` if either holds a NaN or infinity
+pub fn matrix_cosine_similarity<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    a.validate()?;
+    b.validate()?;
+    if (b.rows, b.cols) != (a.rows, a.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, a.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+    a.check_finite()?;
+    b.check_finite()?;
+
+    let pairs = a
+        .mat
+        .iter()
+        .zip(&b.mat)
+        .flat_map(|(x, y)| x.iter().copied().zip(y.iter().copied()));
+    vector::cosine(pairs).ok_or_else(|| {
+        LinalgError::InvalidArgument(
+            "Cosine similarity is undefined for the zero matrix!".to_string(),
+        )
+    })
+}
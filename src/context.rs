@@ -0,0 +1,152 @@
+use crate::error::LinalgError;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A cheaply cloneable flag used to ask a running computation to stop
+///
+/// Every clone shares the same flag, so a UI thread can keep one clone and hand another to
+/// the computation through a `ComputeContext`
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new, uncancelled token
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Requests cancellation of every computation watching this token
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks whether cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// One progress update from an iterative algorithm
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    /// The iteration that just finished, starting at 1
+    pub iteration: usize,
+    /// The algorithm's measure of how far it is from converging, such as a residual norm
+    pub residual: f64,
+}
+
+type ProgressCallback = Arc<dyn Fn(Progress) + Send + Sync>;
+
+/// Controls how long a long-running algorithm may keep going
+///
+/// Algorithms with a `_with_context` variant call `ComputeContext::check` once per outer
+/// iteration (e.g. once per pivot column), so they stop promptly after `cancel` is called
+/// or the deadline passes. Iterative algorithms also call `ComputeContext::report` after
+/// every iteration, forwarding a `Progress` to the callback or channel registered with
+/// `with_progress` or `with_progress_channel`. The default context never interrupts and
+/// reports nowhere
+#[derive(Clone, Default)]
+pub struct ComputeContext {
+    token: Option<CancellationToken>,
+    deadline: Option<Instant>,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl Debug for ComputeContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputeContext")
+            .field("token", &self.token)
+            .field("deadline", &self.deadline)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+impl ComputeContext {
+    /// Creates a context with no cancellation token and no deadline
+    pub fn new() -> ComputeContext {
+        ComputeContext::default()
+    }
+
+    /// Watches `token` for cancellation
+    pub fn with_cancellation(mut self, token: CancellationToken) -> ComputeContext {
+        self.token = Some(token);
+        self
+    }
+
+    /// Stops the computation once `deadline` has passed
+    pub fn with_deadline(mut self, deadline: Instant) -> ComputeContext {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops the computation once `timeout` has elapsed from now
+    pub fn with_timeout(self, timeout: Duration) -> ComputeContext {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    /// Calls `on_progress(iteration, residual)` after every iteration of an iterative
+    /// algorithm
+    ///
+    /// The callback runs on the computing thread, so it should return quickly
+    pub fn with_progress<F>(mut self, on_progress: F) -> ComputeContext
+    where
+        F: Fn(usize, f64) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(move |p: Progress| {
+            on_progress(p.iteration, p.residual)
+        }));
+        self
+    }
+
+    /// Sends a `Progress` through `sender` after every iteration of an iterative algorithm
+    ///
+    /// Updates are dropped silently once the receiving end has hung up
+    pub fn with_progress_channel(mut self, sender: Sender<Progress>) -> ComputeContext {
+        self.on_progress = Some(Arc::new(move |p: Progress| {
+            let _ = sender.send(p);
+        }));
+        self
+    }
+
+    /// Reports that `iteration` finished with the given `residual`
+    ///
+    /// ### Parameters
+    /// - `iteration` - The iteration that just finished, starting at 1
+    /// - `residual` - How far the algorithm is from converging
+    pub fn report(&self, iteration: usize, residual: f64) {
+        if let Some(on_progress) = &self.on_progress {
+            on_progress(Progress {
+                iteration,
+                residual,
+            });
+        }
+    }
+
+    /// Gets the deadline, if one was set
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Checks whether the computation should stop
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the computation may continue
+    ///     - An `Ok` if it may continue
+    ///     - An `Err` of `LinalgError::Cancelled` or `LinalgError::DeadlineExceeded`
+    pub fn check(&self) -> Result<(), LinalgError> {
+        if self.token.as_ref().is_some_and(|t| t.is_cancelled()) {
+            return Err(LinalgError::Cancelled);
+        }
+        if self.deadline.is_some_and(|d| Instant::now() >= d) {
+            return Err(LinalgError::DeadlineExceeded);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::functions;
+use crate::least_squares::least_squares;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::solve;
+use crate::transform;
+use crate::vector::Vector;
+use std::ops::Neg;
+
+/// Solves the continuous-time
+/// [algebraic Riccati equation](https://en.wikipedia.org/wiki/Algebraic_Riccati_equation)
+/// `AᵀX + XA − XBR⁻¹BᵀX + Q = 0` for its stabilizing solution `X`
+///
+/// Uses the matrix sign function of the Hamiltonian `H = [[A, −BR⁻¹Bᵀ], [−Q, −Aᵀ]]`.
+/// Splitting `sign(H)` into n x n blocks `W₁₁, W₁₂, W₂₁, W₂₂`, the stabilizing solution
+/// satisfies `[W₁₂; W₂₂ + I] X = −[W₁₁ + I; W₂₁]`, an overdetermined but consistent
+/// system that is solved column by column by least squares. The result is symmetrized to
+/// remove rounding noise
+///
+/// ### Parameters
+/// - `a` - The n x n state `Matrix` `A`
+/// - `b` - The n x m input `Matrix` `B`
+/// - `q` - The n x n symmetric positive semidefinite state cost `Q`
+/// - `r` - The m x m symmetric positive definite input cost `R`
+///
+/// ### Returns
+/// - A `Result` based on whether a stabilizing solution exists
+///     - An `Ok` containing the symmetric n x n solution `X`, for which `A − BR⁻¹BᵀX` is
+///       stable
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::ShapeMismatch` if `b`, `q`, or `r` does not fit `a`,
+///       `LinalgError::Singular` if `r` is singular, or `LinalgError::Singular`,
+///       `LinalgError::NotConverged`, or `LinalgError::RankDeficient` if `H` has an
+///       eigenvalue on the imaginary axis, as when `(A, B)` is not stabilizable
+pub fn solve_care<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    q: &Matrix<T>,
+    r: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_shapes(a, b, q, r)?;
+    let n = a.rows;
+
+    // G = BR⁻¹Bᵀ, the quadratic coefficient of the equation
+    let r_inv_bt = solve::solve(r, &transform::transpose(b))?;
+    let g = arith::multiply(b, &r_inv_bt)?;
+    let negate = |m: &Matrix<T>| arith::multiply_by_scalar(m, -T::one());
+    let hamiltonian = transform::block(&[
+        &[a, &negate(&g)],
+        &[&negate(q), &negate(&transform::transpose(a))],
+    ])?;
+    let w = functions::sign(&hamiltonian)?;
+
+    let (left, right) = w.split_at_col(n)?;
+    let identity = arith::identity(n);
+    let (w11, w21) = left.split_at_row(n)?;
+    let (w12, w22) = right.split_at_row(n)?;
+    let lhs = transform::vstack(&[&w12, &arith::add(&w22, &identity)?])?;
+    let rhs = negate(&transform::vstack(&[&arith::add(&w11, &identity)?, &w21])?);
+
+    let columns = rhs
+        .columns()
+        .map(|column| least_squares(&lhs, &Vector::new(column).to_column_matrix()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let x = transform::hstack(&columns.iter().collect::<Vec<_>>())?;
+    let half = T::from(0.5).unwrap_or_else(T::nan);
+
+    Ok(arith::multiply_by_scalar_owned(
+        arith::add(&x, &transform::transpose(&x))?,
+        half,
+    ))
+}
+
+/// Computes the infinite-horizon
+/// [linear-quadratic regulator](https://en.wikipedia.org/wiki/Linear%E2%80%93quadratic_regulator)
+/// for `x' = Ax + Bu`
+///
+/// The feedback `u = −Kx` with `K = R⁻¹BᵀX`, where `X` is the stabilizing solution of
+/// `solve_care`, minimizes the cost `∫ xᵀQx + uᵀRu dt`
+///
+/// ### Parameters
+/// - `a` - The n x n state `Matrix` `A`
+/// - `b` - The n x m input `Matrix` `B`
+/// - `q` - The n x n symmetric positive semidefinite state cost `Q`
+/// - `r` - The m x m symmetric positive definite input cost `R`
+///
+/// ### Returns
+/// - A `Result` containing the m x n gain `K` and the Riccati solution `X`, in that order,
+///   or any error of `solve_care`
+pub fn lqr<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    q: &Matrix<T>,
+    r: &Matrix<T>,
+) -> Result<(Matrix<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let x = solve_care(a, b, q, r)?;
+    let bt_x = arith::multiply(&transform::transpose(b), &x)?;
+    let gain = solve::solve(r, &bt_x)?;
+
+    Ok((gain, x))
+}
+
+/// Checks that `b`, `q`, and `r` have the shapes that fit the square `a`
+fn check_shapes<T: Number>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    q: &Matrix<T>,
+    r: &Matrix<T>,
+) -> Result<(), LinalgError> {
+    for m in [a, b, q, r] {
+        m.validate()?;
+    }
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    let m = b.cols;
+    for (matrix, expected) in [(b, (n, m)), (q, (n, n)), (r, (m, m))] {
+        if (matrix.rows, matrix.cols) != expected {
+            return Err(LinalgError::ShapeMismatch {
+                expected,
+                found: (matrix.rows, matrix.cols),
+            });
+        }
+    }
+
+    Ok(())
+}
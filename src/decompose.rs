@@ -0,0 +1,1100 @@
+use crate::arith;
+use crate::context::ComputeContext;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::parallel;
+use std::cmp::Ordering;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// The most sweeps of Jacobi rotations `svd` and `symmetric_eigen` perform before giving
+/// up with `LinalgError::NotConverged`; they normally converge in under ten
+const MAX_JACOBI_SWEEPS: usize = 60;
+
+/// Three square factors, in the order named by the decomposition returning them
+pub(crate) type Factors<T> = (Matrix<T>, Matrix<T>, Matrix<T>);
+
+/// The `(u, singular values, v)` factors returned by `svd`
+pub(crate) type Svd<T> = (Matrix<T>, Vec<T>, Matrix<T>);
+
+/// Returns the LU Decomposition of a `Matrix` in the form of a tuple
+///
+/// [LU Decomposition](https://en.wikipedia.org/wiki/LU_decomposition), or factorization,
+/// is a technique used in Linear Algebra to factor a matrix as the product of a lower
+/// triangular matrix and an upper triangular matrix. Typically viewed as that of the
+/// matrix form of Gaussian Elimination
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform LU decomposition on
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` is invertible
+///     - Returns an Ok form containing a `Matrix` tuple containing the
+///       `l` and `u` decomposed matrices respectively
+///     - Returns an error if the `matrix` is not invertible
+pub fn lu_decomposition<T>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    lu_decomposition_with_context(matrix, &ComputeContext::new())
+}
+
+/// Returns the LU Decomposition of a `Matrix` in the form of a tuple
+///
+/// [LU Decomposition](https://en.wikipedia.org/wiki/LU_decomposition), or factorization,
+/// is a technique used in Linear Algebra to factor a matrix as the product of a lower
+/// triangular matrix and an upper triangular matrix. Typically viewed as that of the
+/// matrix form of Gaussian Elimination
+///
+/// Checks `ctx` once per pivot and stops early if it is cancelled or its deadline passes
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform LU decomposition on
+/// - `ctx` - The `ComputeContext` that can interrupt the computation
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` is invertible
+///     - Returns an Ok form containing a `Matrix` tuple containing the
+///       `l` and `u` decomposed matrices respectively
+///     - Returns an error if the `matrix` is not invertible
+pub fn lu_decomposition_with_context<T>(
+    matrix: &Matrix<T>,
+    ctx: &ComputeContext,
+) -> Result<(Matrix<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let n = matrix.rows;
+    if n != matrix.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: matrix.cols,
+        });
+    }
+    let _span = Span::new("lu_decomposition", 2 * n * n * n / 3, 2);
+
+    let mut l = Matrix {
+        mat: vec![Arc::from(vec![T::default(); n].into_boxed_slice()); n],
+        rows: n,
+        cols: n,
+    };
+    let mut u = Matrix {
+        mat: vec![Arc::from(vec![T::default(); n].into_boxed_slice()); n],
+        rows: n,
+        cols: n,
+    };
+
+    for i in 0..n {
+        ctx.check()?;
+        for j in i..n {
+            let mut sum = matrix.mat[i][j];
+
+            for k in 0..i {
+                sum -= l.mat[i][k] * u.mat[k][j];
+            }
+
+            let row = Arc::make_mut(&mut u.mat[i]);
+            row[j] = sum;
+        }
+
+        for j in i..n {
+            if i == j {
+                let row = Arc::make_mut(&mut l.mat[i]);
+                row[i] = T::one();
+            } else {
+                if u.mat[i][i] == T::default() {
+                    return Err(LinalgError::ZeroPivot);
+                }
+                let mut sum = matrix.mat[j][i];
+
+                for k in 0..i {
+                    sum -= l.mat[j][k] * u.mat[k][i];
+                }
+
+                let row = Arc::make_mut(&mut l.mat[j]);
+                row[i] = sum / u.mat[i][i];
+            }
+        }
+    }
+
+    Ok((l, u))
+}
+
+/// Returns the LDU Decomposition of a `Matrix` in the form of a tuple
+///
+/// The LDU decomposition factors a square matrix `A` as `A = LDU`, where `L` is unit lower
+/// triangular, `D` is diagonal, and `U` is unit upper triangular. It is the LU
+/// decomposition with the pivots of `U` pulled out into `D`, so like `lu_decomposition`
+/// it does not exchange rows; use `plu_decomposition` for matrices that need them
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform LDU decomposition on
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` could be factored
+///     - Returns an Ok form containing the `l`, `d`, and `u` decomposed matrices
+///       respectively
+///     - Returns an error if the `matrix` is not square or elimination reaches a zero
+///       pivot
+pub fn ldu_decomposition<T>(matrix: &Matrix<T>) -> Result<Factors<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let (l, mut u) = lu_decomposition(matrix)?;
+    let n = u.rows;
+    let mut d = Matrix {
+        mat: vec![Arc::from(vec![T::default(); n].into_boxed_slice()); n],
+        rows: n,
+        cols: n,
+    };
+
+    for i in 0..n {
+        let pivot = u.mat[i][i];
+        Arc::make_mut(&mut d.mat[i])[i] = pivot;
+        let row = Arc::make_mut(&mut u.mat[i]);
+        row[i] = T::one();
+        for elem in &mut row[(i + 1)..] {
+            *elem /= pivot;
+        }
+    }
+
+    Ok((l, d, u))
+}
+
+/// Returns the PLU Decomposition of a `Matrix` in the form of a tuple
+///
+/// The PLU decomposition factors a square matrix `A` as `PA = LU`, where `P` is a
+/// permutation matrix, `L` is unit lower triangular, and `U` is upper triangular. Rows
+/// are exchanged by [partial pivoting](https://en.wikipedia.org/wiki/Pivot_element), so
+/// unlike `lu_decomposition` it factors every square matrix, including singular ones and
+/// invertible ones with a zero on the diagonal
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform PLU decomposition on
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` could be factored
+///     - Returns an Ok form containing the `p`, `l`, and `u` decomposed matrices
+///       respectively
+///     - Returns an error if the `matrix` is not square or holds a NaN or infinity
+pub fn plu_decomposition<T>(matrix: &Matrix<T>) -> Result<Factors<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    plu_decomposition_with_context(matrix, &ComputeContext::new())
+}
+
+/// Returns the PLU Decomposition of a `Matrix` in the form of a tuple
+///
+/// Factors `PA = LU` like `plu_decomposition`, but checks `ctx` once per pivot and stops
+/// early if it is cancelled or its deadline passes
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform PLU decomposition on
+/// - `ctx` - The `ComputeContext` that can interrupt the computation
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` could be factored
+///     - Returns an Ok form containing the `p`, `l`, and `u` decomposed matrices
+///       respectively
+///     - Returns an error if the `matrix` is not square or holds a NaN or infinity, or if
+///       `ctx` interrupts the computation
+pub fn plu_decomposition_with_context<T>(
+    matrix: &Matrix<T>,
+    ctx: &ComputeContext,
+) -> Result<Factors<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let n = matrix.rows;
+    if n != matrix.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: matrix.cols,
+        });
+    }
+    let _span = Span::new("plu_decomposition", 2 * n * n * n / 3, 3);
+
+    let mut u: Vec<Vec<T>> = matrix.mat.iter().map(|row| row.to_vec()).collect();
+    let mut l = vec![vec![T::zero(); n]; n];
+    let mut perm: Vec<usize> = (0..n).collect();
+    for k in 0..n {
+        ctx.check()?;
+        let mut pivot = k;
+        for i in (k + 1)..n {
+            if u[i][k].abs() > u[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+        if pivot != k {
+            u.swap(pivot, k);
+            l.swap(pivot, k);
+            perm.swap(pivot, k);
+        }
+        l[k][k] = T::one();
+        if u[k][k] == T::zero() {
+            continue;
+        }
+
+        let (upper, lower) = u.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for (i, row) in lower.iter_mut().enumerate() {
+            let factor = row[k] / pivot_row[k];
+            l[k + 1 + i][k] = factor;
+            row[k] = T::zero();
+            for j in (k + 1)..n {
+                row[j] -= factor * pivot_row[j];
+            }
+        }
+    }
+
+    let to_matrix = |rows: Vec<Vec<T>>| Matrix {
+        mat: rows.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: n,
+    };
+    let p = perm
+        .iter()
+        .map(|&col| {
+            (0..n)
+                .map(|j| if j == col { T::one() } else { T::zero() })
+                .collect()
+        })
+        .collect();
+
+    Ok((to_matrix(p), to_matrix(l), to_matrix(u)))
+}
+
+/// Returns the Cholesky Decomposition of a symmetric positive definite `Matrix`
+///
+/// [Cholesky Decomposition](https://en.wikipedia.org/wiki/Cholesky_decomposition) factors
+/// a symmetric positive definite matrix `A` as `A = LLᵀ`, where `L` is lower triangular
+/// with a positive diagonal. It takes half the work of an LU decomposition and needs no
+/// pivoting
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform Cholesky decomposition on
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` is positive definite
+///     - Returns an Ok form containing the lower triangular factor `l`
+///     - Returns an error if the `matrix` is not square, holds a NaN or infinity, or is
+///       not symmetric positive definite
+#[allow(clippy::needless_range_loop)]
+pub fn cholesky_decomposition<T>(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let n = matrix.rows;
+    if n != matrix.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: matrix.cols,
+        });
+    }
+    let _span = Span::new("cholesky_decomposition", n * n * n / 3, 1);
+
+    let mut l = vec![vec![T::zero(); n]; n];
+    for j in 0..n {
+        let mut diagonal = matrix.mat[j][j];
+        for k in 0..j {
+            diagonal -= l[j][k] * l[j][k];
+        }
+        if diagonal.is_nan() || diagonal <= T::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        l[j][j] = diagonal.sqrt();
+
+        for i in (j + 1)..n {
+            if matrix.mat[i][j] != matrix.mat[j][i] {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let mut sum = matrix.mat[i][j];
+            for k in 0..j {
+                sum -= l[i][k] * l[j][k];
+            }
+            l[i][j] = sum / l[j][j];
+        }
+    }
+
+    Ok(Matrix {
+        mat: l.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: n,
+    })
+}
+
+/// A Cholesky factorization `A = LLᵀ` that can follow rank-one changes to `A`
+///
+/// `update` and `downdate` change the factor in O(n²) when one observation is added to or
+/// removed from a covariance or Gram matrix, where calling `cholesky_decomposition` again
+/// would cost O(n³). This is the step recursive least squares and square-root Kalman
+/// filters repeat on every measurement
+#[derive(Clone, Debug, PartialEq)]
+pub struct CholeskyFactorization<T: Number> {
+    /// `Lᵀ`, stored by rows, so that each rotation runs along a row
+    r: Vec<Vec<T>>,
+}
+
+impl<T> CholeskyFactorization<T>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    /// Factors a symmetric positive definite `matrix`
+    ///
+    /// ### Parameters
+    /// - `matrix` - The `Matrix` to factor
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `CholeskyFactorization`, or any error of
+    ///   `cholesky_decomposition`
+    pub fn new(matrix: &Matrix<T>) -> Result<CholeskyFactorization<T>, LinalgError> {
+        let l = cholesky_decomposition(matrix)?;
+        let n = l.rows;
+
+        Ok(CholeskyFactorization {
+            r: (0..n)
+                .map(|i| (0..n).map(|j| l.mat[j][i]).collect())
+                .collect(),
+        })
+    }
+
+    /// Gets the lower triangular factor `L`
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.r.len();
+        Matrix {
+            mat: (0..n)
+                .map(|i| (0..n).map(|j| self.r[j][i]).collect())
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    /// Refactors `A + xxᵀ` from the current factorization of `A`
+    ///
+    /// ### Parameters
+    /// - `x` - The n elements of the vector to add
+    ///
+    /// ### Returns
+    /// - A `Result` that is `Ok` once the factor is updated, or an `Err` of
+    ///   `LinalgError::LengthMismatch` if `x` does not hold n elements or
+    ///   `LinalgError::NonFinite` if it holds a NaN or infinity
+    pub fn update(&mut self, x: &[T]) -> Result<(), LinalgError> {
+        self.check_vector(x)?;
+        let n = self.r.len();
+        let _span = Span::new("cholesky_update", 3 * n * n, 1);
+
+        rank_one_update(&mut self.r, x.to_vec());
+        Ok(())
+    }
+
+    /// Refactors `A − xxᵀ` from the current factorization of `A`
+    ///
+    /// ### Parameters
+    /// - `x` - The n elements of the vector to remove
+    ///
+    /// ### Returns
+    /// - A `Result` that is `Ok` once the factor is downdated, or an `Err` of
+    ///   `LinalgError::LengthMismatch` if `x` does not hold n elements,
+    ///   `LinalgError::NonFinite` if it holds a NaN or infinity, or
+    ///   `LinalgError::NotPositiveDefinite` if `A − xxᵀ` is not positive definite to
+    ///   working precision, in which case the factorization is left unchanged
+    pub fn downdate(&mut self, x: &[T]) -> Result<(), LinalgError> {
+        self.check_vector(x)?;
+        let n = self.r.len();
+        let _span = Span::new("cholesky_downdate", 4 * n * n, 2);
+
+        let min_slack = T::from(n).unwrap_or_else(T::one) * T::epsilon();
+        if !rank_one_downdate(&mut self.r, x, min_slack) {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        Ok(())
+    }
+
+    /// Solves `AX = B` with the current factorization by forward and back substitution
+    ///
+    /// ### Parameters
+    /// - `b` - An n x k `Matrix` holding one right-hand side per column
+    ///
+    /// ### Returns
+    /// - A `Result` containing the n x k solution `X`, or an `Err` of
+    ///   `LinalgError::ShapeMismatch` if `b` does not have n rows or
+    ///   `LinalgError::NonFinite` if it holds a NaN or infinity
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        b.validate()?;
+        b.check_finite()?;
+        let n = self.r.len();
+        if b.rows != n {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, b.cols),
+                found: (b.rows, b.cols),
+            });
+        }
+
+        let mut x: Vec<Vec<T>> = b.mat.iter().map(|row| row.to_vec()).collect();
+        for c in 0..b.cols {
+            // Lz = b with L = Rᵀ, then Rx = z
+            for i in 0..n {
+                for k in 0..i {
+                    let update = self.r[k][i] * x[k][c];
+                    x[i][c] -= update;
+                }
+                x[i][c] /= self.r[i][i];
+            }
+            for i in (0..n).rev() {
+                for k in (i + 1)..n {
+                    let update = self.r[i][k] * x[k][c];
+                    x[i][c] -= update;
+                }
+                x[i][c] /= self.r[i][i];
+            }
+        }
+
+        Ok(Matrix {
+            mat: x.into_iter().map(Arc::from).collect(),
+            rows: n,
+            cols: b.cols,
+        })
+    }
+
+    /// Checks that `x` is a finite vector with one element per row of the factor
+    fn check_vector(&self, x: &[T]) -> Result<(), LinalgError> {
+        if x.len() != self.r.len() {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.r.len(),
+                found: x.len(),
+            });
+        }
+        if let Some(col) = x.iter().position(|v| !v.is_finite()) {
+            return Err(LinalgError::NonFinite { row: 0, col });
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces the upper triangle `r` by the triangle of `RᵀR + xxᵀ`, zeroing `x` into it
+/// with one Givens rotation per row. A non-negative diagonal stays non-negative
+pub(crate) fn rank_one_update<T>(r: &mut [Vec<T>], mut x: Vec<T>)
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    for k in 0..x.len() {
+        if x[k] == T::zero() {
+            continue;
+        }
+        let radius = r[k][k].hypot(x[k]);
+        let (c, s) = (r[k][k] / radius, x[k] / radius);
+        for j in k..x.len() {
+            let (rkj, xj) = (r[k][j], x[j]);
+            r[k][j] = c * rkj + s * xj;
+            x[j] = c * xj - s * rkj;
+        }
+    }
+}
+
+/// Replaces the upper triangle `r` by the triangle of `RᵀR − xxᵀ`, as LINPACK's `dchdd`
+/// does
+///
+/// Solves `Rᵀp = x`; the result is positive definite exactly when `1 − ‖p‖²` is positive.
+/// Returns `false`, leaving `r` untouched, when that slack is at most `min_slack`
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn rank_one_downdate<T>(r: &mut [Vec<T>], x: &[T], min_slack: T) -> bool
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let size = x.len();
+    let mut p = vec![T::zero(); size];
+    for i in 0..size {
+        if r[i][i] == T::zero() {
+            return false;
+        }
+        let sum = (0..i).fold(x[i], |sum, k| sum - r[k][i] * p[k]);
+        p[i] = sum / r[i][i];
+    }
+    let slack = T::one() - p.iter().fold(T::zero(), |sum, &v| sum + v * v);
+    if slack.is_nan() || slack <= min_slack {
+        return false;
+    }
+
+    let mut alpha = slack.sqrt();
+    let mut rotations = vec![(T::one(), T::zero()); size];
+    for i in (0..size).rev() {
+        let radius = alpha.hypot(p[i]);
+        rotations[i] = (alpha / radius, p[i] / radius);
+        alpha = radius;
+    }
+    for j in 0..size {
+        let mut carry = T::zero();
+        for i in (0..=j).rev() {
+            let (c, s) = rotations[i];
+            let rij = r[i][j];
+            r[i][j] = c * rij - s * carry;
+            carry = c * carry + s * rij;
+        }
+    }
+
+    true
+}
+
+/// Returns the QR Decomposition of a `Matrix` in the form of a tuple
+///
+/// [QR Decomposition](https://en.wikipedia.org/wiki/QR_decomposition) factors an m x n
+/// matrix `A` as `A = QR`, where `Q` is an m x m orthogonal matrix and `R` is an m x n
+/// upper triangular matrix. The factorization is computed with
+/// [Householder reflections](https://en.wikipedia.org/wiki/Householder_transformation),
+/// which stay numerically stable even when the columns of `A` are nearly dependent
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform QR decomposition on
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` has any elements
+///     - Returns an Ok form containing a `Matrix` tuple containing the
+///       `q` and `r` decomposed matrices respectively
+///     - Returns an error if the `matrix` is empty
+pub fn qr_decomposition<T>(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    qr_decomposition_with_context(matrix, &ComputeContext::new())
+}
+
+/// Returns the QR Decomposition of a `Matrix` in the form of a tuple
+///
+/// [QR Decomposition](https://en.wikipedia.org/wiki/QR_decomposition) factors an m x n
+/// matrix `A` as `A = QR`, where `Q` is an m x m orthogonal matrix and `R` is an m x n
+/// upper triangular matrix. The factorization is computed with
+/// [Householder reflections](https://en.wikipedia.org/wiki/Householder_transformation),
+/// which stay numerically stable even when the columns of `A` are nearly dependent
+///
+/// Checks `ctx` once per pivot and stops early if it is cancelled or its deadline passes
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform QR decomposition on
+/// - `ctx` - The `ComputeContext` that can interrupt the computation
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` has any elements
+///     - Returns an Ok form containing a `Matrix` tuple containing the
+///       `q` and `r` decomposed matrices respectively
+///     - Returns an error if the `matrix` is empty
+#[allow(clippy::needless_range_loop)]
+pub fn qr_decomposition_with_context<T>(
+    matrix: &Matrix<T>,
+    ctx: &ComputeContext,
+) -> Result<(Matrix<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let (m, n) = (matrix.rows, matrix.cols);
+    if m == 0 || n == 0 {
+        return Err(LinalgError::Empty);
+    }
+    let _span = Span::new("qr_decomposition", 4 * m * m * n, 2 * m + 2);
+
+    let mut r: Vec<Vec<T>> = matrix.mat.iter().map(|row| row.to_vec()).collect();
+    let mut q: Vec<Vec<T>> = arith::identity(m)
+        .mat
+        .iter()
+        .map(|row| row.to_vec())
+        .collect();
+
+    for k in 0..n.min(m - 1) {
+        ctx.check()?;
+        // Build the Householder vector v that reflects column k onto the k-th axis
+        let norm = (k..m)
+            .map(|i| r[i][k] * r[i][k])
+            .fold(T::zero(), |sum, x| sum + x)
+            .sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+
+        let alpha = if r[k][k] > T::zero() { -norm } else { norm };
+        let mut v = vec![T::zero(); m];
+        v[k] = r[k][k] - alpha;
+        for i in (k + 1)..m {
+            v[i] = r[i][k];
+        }
+        let v_norm_sq = (k..m).map(|i| v[i] * v[i]).fold(T::zero(), |s, x| s + x);
+        if v_norm_sq == T::zero() {
+            continue;
+        }
+        let two = T::one() + T::one();
+
+        // R <- (I - 2vv^T / v^Tv) R
+        for j in 0..n {
+            let dot = (k..m).map(|i| v[i] * r[i][j]).fold(T::zero(), |s, x| s + x);
+            let factor = two * dot / v_norm_sq;
+            for i in k..m {
+                r[i][j] -= factor * v[i];
+            }
+        }
+
+        // Q <- Q (I - 2vv^T / v^Tv)
+        for i in 0..m {
+            let dot = (k..m).map(|j| q[i][j] * v[j]).fold(T::zero(), |s, x| s + x);
+            let factor = two * dot / v_norm_sq;
+            for j in k..m {
+                q[i][j] -= factor * v[j];
+            }
+        }
+
+        for i in (k + 1)..m {
+            r[i][k] = T::zero();
+        }
+    }
+
+    let q = Matrix {
+        mat: q.into_iter().map(Arc::from).collect(),
+        rows: m,
+        cols: m,
+    };
+    let r = Matrix {
+        mat: r.into_iter().map(Arc::from).collect(),
+        rows: m,
+        cols: n,
+    };
+
+    Ok((q, r))
+}
+
+/// Computes the `R` factor of a tall-skinny `Matrix` by
+/// [TSQR](https://en.wikipedia.org/wiki/QR_decomposition#Communication-avoiding_QR),
+/// without forming `Q`
+///
+/// The rows are split into blocks of `block_rows`, each block is reduced to an n x n
+/// triangle by Householder reflections, and pairs of stacked triangles are reduced again
+/// until one remains. Only one block and a few n x n triangles are live at a time, so an
+/// m x n matrix with millions of rows never needs the m x m `Q` of `qr_decomposition`.
+/// With the `rayon` feature the blocks are reduced on several threads. The diagonal of
+/// the result is made non-negative, so it equals the Cholesky factor of `AᵀA` transposed
+///
+/// ### Parameters
+/// - `matrix` - An m x n `Matrix`, usually with m much larger than n
+/// - `block_rows` - The rows per block, at least n; a few times n balances the work
+///
+/// ### Returns
+/// - A `Result` based on whether the `matrix` can be factored
+///     - An `Ok` containing the n x n upper triangular `R` with `AᵀA = RᵀR`
+///     - An `Err` of `LinalgError::Empty` if the `matrix` is empty,
+///       `LinalgError::InvalidArgument` if `block_rows` is smaller than n, or
+///       `LinalgError::NonFinite` if it holds a NaN or infinity
+pub fn tsqr<T>(matrix: &Matrix<T>, block_rows: usize) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let (m, n) = (matrix.rows, matrix.cols);
+    if m == 0 || n == 0 {
+        return Err(LinalgError::Empty);
+    }
+    if block_rows < n {
+        return Err(LinalgError::InvalidArgument(format!(
+            "TSQR needs at least {} rows per block, but {} were given!",
+            n, block_rows
+        )));
+    }
+    let _span = Span::new("tsqr", 2 * m * n * n, m.div_ceil(block_rows) + 1);
+
+    let blocks = m.div_ceil(block_rows);
+    let mut triangles = parallel::map_range(blocks, m * n * n, |b| {
+        let rows = &matrix.mat[b * block_rows..((b + 1) * block_rows).min(m)];
+        householder_triangle(rows.iter().map(|row| row.to_vec()).collect(), n)
+    });
+    while triangles.len() > 1 {
+        let mut pairs = Vec::with_capacity(triangles.len() / 2);
+        let mut rest = triangles.into_iter();
+        while let Some(top) = rest.next() {
+            pairs.push(match rest.next() {
+                Some(bottom) => top.into_iter().chain(bottom).collect(),
+                None => top,
+            });
+        }
+        let work = pairs.len() * n * n * n;
+        parallel::for_each_mut(&mut pairs, work, |_, rows| {
+            *rows = householder_triangle(std::mem::take(rows), n);
+        });
+        triangles = pairs;
+    }
+
+    let mut r = triangles.pop().unwrap_or_default();
+    for row in &mut r {
+        if let Some(i) = row.iter().position(|&x| x != T::zero()) {
+            if row[i] < T::zero() {
+                row.iter_mut().for_each(|x| *x = -*x);
+            }
+        }
+    }
+
+    Ok(Matrix {
+        mat: r.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: n,
+    })
+}
+
+/// Reduces `rows`, each of length `n`, to an n x n upper triangle by Householder
+/// reflections, discarding the reflections. Fewer than `n` rows are padded with zeros
+fn householder_triangle<T>(mut rows: Vec<Vec<T>>, n: usize) -> Vec<Vec<T>>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let m = rows.len();
+    for k in 0..n.min(m) {
+        let norm = (k..m)
+            .fold(T::zero(), |sum, i| sum + rows[i][k] * rows[i][k])
+            .sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+        let alpha = if rows[k][k] > T::zero() { -norm } else { norm };
+        let mut v: Vec<T> = (k..m).map(|i| rows[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm_sq = v.iter().fold(T::zero(), |s, &x| s + x * x);
+        if v_norm_sq == T::zero() {
+            continue;
+        }
+        let two = T::one() + T::one();
+
+        for j in k..n {
+            let dot = v
+                .iter()
+                .zip(&rows[k..])
+                .fold(T::zero(), |s, (&vi, row)| s + vi * row[j]);
+            let factor = two * dot / v_norm_sq;
+            for (vi, row) in v.iter().zip(&mut rows[k..]) {
+                row[j] -= factor * *vi;
+            }
+        }
+        for row in &mut rows[k + 1..] {
+            row[k] = T::zero();
+        }
+    }
+
+    rows.resize(n, vec![T::zero(); n]);
+    rows
+}
+
+/// Computes an orthonormal basis for the columns of a `Matrix` by
+/// [modified Gram–Schmidt](https://en.wikipedia.org/wiki/Gram%E2%80%93Schmidt_process#Numerical_stability)
+///
+/// Each column has its components along the basis found so far removed one at a time and
+/// is then scaled to unit length. When that removal cancels more than half of a column,
+/// it is repeated once, which keeps the basis orthogonal to working precision even for
+/// nearly dependent columns. A column whose remainder is within `max(m, n)` rounding
+/// errors of zero, relative to its own length, is reported as dependent
+///
+/// ### Parameters
+/// - `matrix` - An `m x n` `Matrix` whose columns are the vectors to orthonormalize
+///
+/// ### Returns
+/// - A `Result` based on whether the columns are linearly independent
+///     - An `Ok` containing the `m x n` `Matrix` whose columns are orthonormal and span
+///       the same nested subspaces as the columns of `matrix`, in order
+///     - An `Err` of `LinalgError::LinearlyDependent` naming the first column that is a
+///       combination of the ones before it, or `LinalgError::NonFinite` if `matrix` holds
+///       a NaN or infinity
+pub fn orthonormalize<T>(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let (m, n) = (matrix.rows, matrix.cols);
+    let _span = Span::new("orthonormalize", 4 * m * n * n, n + 1);
+    let size = T::from(m.max(n)).unwrap_or_else(T::one);
+    let half = T::from(0.5).unwrap_or_else(T::nan);
+    let length = |v: &[T]| v.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt();
+
+    let mut basis: Vec<Vec<T>> = Vec::with_capacity(n);
+    for (col, mut v) in matrix.columns().enumerate() {
+        let original = length(&v);
+        let mut remaining = original;
+        for _ in 0..2 {
+            for q in &basis {
+                let dot = q
+                    .iter()
+                    .zip(&v)
+                    .fold(T::zero(), |sum, (&x, &y)| sum + x * y);
+                for (x, &y) in v.iter_mut().zip(q) {
+                    *x -= dot * y;
+                }
+            }
+            let before = remaining;
+            remaining = length(&v);
+            if remaining > half * before {
+                break;
+            }
+        }
+        if remaining <= size * T::epsilon() * original {
+            return Err(LinalgError::LinearlyDependent { col });
+        }
+
+        for x in &mut v {
+            *x /= remaining;
+        }
+        basis.push(v);
+    }
+
+    Ok(Matrix {
+        mat: (0..m)
+            .map(|i| basis.iter().map(|q| q[i]).collect())
+            .collect(),
+        rows: m,
+        cols: n,
+    })
+}
+
+/// Returns the Singular Value Decomposition of a `Matrix` in the form of a tuple
+///
+/// [SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition) factors an m x n
+/// matrix `A` as `A = UΣVᵀ`. It is computed with one-sided
+/// [Jacobi rotations](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm), which
+/// orthogonalize the columns of `A` directly and so find small singular values to high
+/// relative accuracy
+///
+/// `V` is always a full n x n orthogonal matrix, so its trailing columns span the null
+/// space of `A` even when `m < n`. `U` is m x n, and its columns for zero singular
+/// values are zero
+///
+/// ### Parameters
+/// - `matrix` - The matrix to perform SVD on
+///
+/// ### Returns
+/// - A `Result` type based on whether the decomposition converged
+///     - Returns an Ok form containing `u`, the singular values in descending order,
+///       and `v` respectively
+///     - Returns an error if the `matrix` is malformed or holds a NaN or infinity, or
+///       `LinalgError::NotConverged` if a pair of columns is still not orthogonal after
+///       the last sweep
+pub fn svd<T>(matrix: &Matrix<T>) -> Result<Svd<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let (m, n) = (matrix.rows, matrix.cols);
+    let _span = Span::new("svd", 6 * m * n * n, 2 * n + 2);
+
+    let mut w: Vec<Vec<T>> = matrix.mat.iter().map(|row| row.to_vec()).collect();
+    let mut v: Vec<Vec<T>> = arith::identity(n)
+        .mat
+        .iter()
+        .map(|row| row.to_vec())
+        .collect();
+
+    // A column of a rank-deficient matrix shrinks towards zero without ever becoming
+    // orthogonal to the rest within rounding, so once its squared norm is this far below
+    // that of the whole matrix, or near underflow, it counts as zero
+    let total = w.iter().flatten().fold(T::zero(), |sum, &x| sum + x * x);
+    let negligible = (T::epsilon().powi(4) * total).max(T::min_positive_value() / T::epsilon());
+    let mut converged = false;
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for row in &w {
+                    alpha += row[p] * row[p];
+                    beta += row[q] * row[q];
+                    gamma += row[p] * row[q];
+                }
+                if alpha.min(beta) <= negligible
+                    || gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt()
+                {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotate columns p and q so that they become orthogonal
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                let s = c * t;
+                for row in w.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NotConverged {
+            iterations: MAX_JACOBI_SWEEPS,
+        });
+    }
+
+    let norms: Vec<T> = (0..n)
+        .map(|j| {
+            w.iter()
+                .fold(T::zero(), |sum, row| sum + row[j] * row[j])
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+
+    let u = (0..m)
+        .map(|i| {
+            order
+                .iter()
+                .map(|&j| {
+                    if norms[j] == T::zero() {
+                        T::zero()
+                    } else {
+                        w[i][j] / norms[j]
+                    }
+                })
+                .collect::<Vec<T>>()
+                .into()
+        })
+        .collect();
+    let v = v
+        .iter()
+        .map(|row| order.iter().map(|&j| row[j]).collect::<Vec<T>>().into())
+        .collect();
+
+    Ok((
+        Matrix {
+            mat: u,
+            rows: m,
+            cols: n,
+        },
+        order.iter().map(|&j| norms[j]).collect(),
+        Matrix {
+            mat: v,
+            rows: n,
+            cols: n,
+        },
+    ))
+}
+
+/// Returns the eigenvalues and eigenvectors of a symmetric `Matrix`
+///
+/// Uses the cyclic [Jacobi eigenvalue algorithm](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm),
+/// which applies plane rotations until the off-diagonal elements vanish. It is slower
+/// than tridiagonal QR iteration for large matrices but simple, robust, and accurate
+/// even for tightly clustered eigenvalues
+///
+/// ### Parameters
+/// - `matrix` - The symmetric matrix to decompose
+///
+/// ### Returns
+/// - A `Result` type based on whether or not the `matrix` is symmetric
+///     - Returns an Ok form containing the eigenvalues in ascending order and a `Matrix`
+///       whose columns are the matching orthonormal eigenvectors
+///     - Returns an error if the `matrix` is not square, holds a NaN or infinity, or is
+///       not symmetric, or `LinalgError::NotConverged` if an off-diagonal element is still
+///       above tolerance after the last sweep
+#[allow(clippy::needless_range_loop)]
+pub fn symmetric_eigen<T>(matrix: &Matrix<T>) -> Result<(Vec<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let n = matrix.rows;
+    if n != matrix.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: matrix.cols,
+        });
+    }
+    let _span = Span::new("symmetric_eigen", 12 * n * n * n, 2);
+
+    let mut a: Vec<Vec<T>> = matrix.mat.iter().map(|row| row.to_vec()).collect();
+    let largest = a
+        .iter()
+        .flatten()
+        .fold(T::zero(), |max, x| max.max(x.abs()));
+    let size = T::from(n).unwrap_or_else(T::one);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if (a[i][j] - a[j][i]).abs() > size * T::epsilon() * largest {
+                return Err(LinalgError::InvalidArgument(
+                    "The matrix must be symmetric!".to_string(),
+                ));
+            }
+        }
+    }
+    let mut v: Vec<Vec<T>> = arith::identity(n)
+        .mat
+        .iter()
+        .map(|row| row.to_vec())
+        .collect();
+
+    let mut converged = false;
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let scale = a[p][p].abs() + a[q][q].abs();
+                if a[p][q].abs() <= T::epsilon() * (scale + T::epsilon() * largest) {
+                    continue;
+                }
+                rotated = true;
+
+                // Choose the rotation that zeroes a[p][q], taking the smaller angle
+                let theta = (a[q][q] - a[p][p]) / (a[p][q] + a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                for k in 0..n {
+                    let (x, y) = (a[p][k], a[q][k]);
+                    a[p][k] = c * x - s * y;
+                    a[q][k] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NotConverged {
+            iterations: MAX_JACOBI_SWEEPS,
+        });
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap_or(Ordering::Equal));
+    let vectors = v
+        .iter()
+        .map(|row| order.iter().map(|&j| row[j]).collect::<Vec<T>>().into())
+        .collect();
+
+    Ok((
+        order.iter().map(|&i| a[i][i]).collect(),
+        Matrix {
+            mat: vectors,
+            rows: n,
+            cols: n,
+        },
+    ))
+}
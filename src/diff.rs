@@ -0,0 +1,86 @@
+use crate::number::Number;
+use std::fmt::Display;
+
+/// The maximum number of worst offending cells kept in a `DiffReport`
+pub const MAX_REPORTED_CELLS: usize = 10;
+
+/// A single cell where two matrices differ
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellDiff<T: Number> {
+    /// The row index of the cell
+    pub row: usize,
+
+    /// The column index of the cell
+    pub col: usize,
+
+    /// The value of the cell in the left-hand matrix
+    pub left: T,
+
+    /// The value of the cell in the right-hand matrix
+    pub right: T,
+
+    /// The absolute difference between `left` and `right`
+    pub abs_diff: T,
+}
+
+/// A structured comparison between two equally shaped matrices, produced by
+/// `arith::diff_report`
+///
+/// Printing a `DiffReport` with `{}` gives a short human-readable summary that is far more
+/// useful than the `assert_eq!` dump of two full matrices when debugging numerical tests
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffReport<T: Number> {
+    /// The shape shared by both compared matrices
+    pub shape: (usize, usize),
+
+    /// The tolerance that was used to decide whether two cells differ
+    pub tolerance: T,
+
+    /// The number of cells whose absolute difference exceeds `tolerance`
+    pub differing: usize,
+
+    /// The largest absolute difference found over every cell
+    pub max_abs_diff: T,
+
+    /// Up to `MAX_REPORTED_CELLS` differing cells, sorted from worst to least bad
+    pub worst: Vec<CellDiff<T>>,
+}
+
+impl<T: Number> DiffReport<T> {
+    /// Checks whether every cell was within tolerance
+    ///
+    /// ### Returns
+    /// - `true` if no cell differs by more than the tolerance, `false` otherwise
+    pub fn is_match(&self) -> bool {
+        self.differing == 0
+    }
+}
+
+impl<T: Number> Display for DiffReport<T> {
+    /// Writes a `DiffReport` as a human-readable summary
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.shape.0 * self.shape.1;
+        writeln!(
+            f,
+            "{} of {} cells differ by more than {} (shape {}x{})",
+            self.differing, total, self.tolerance, self.shape.0, self.shape.1
+        )?;
+        writeln!(f, "max abs difference: {}", self.max_abs_diff)?;
+
+        for cell in &self.worst {
+            writeln!(
+                f,
+                "  ({}, {}): {} vs {} (diff {})",
+                cell.row, cell.col, cell.left, cell.right, cell.abs_diff
+            )?;
+        }
+        if self.differing > self.worst.len() {
+            writeln!(f, "  ... and {} more", self.differing - self.worst.len())?;
+        }
+
+        Ok(())
+    }
+}
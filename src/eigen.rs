@@ -0,0 +1,841 @@
+use crate::arith;
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::solve;
+use crate::vector::Vector;
+use num::Complex;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// An eigenvalue and eigenvector of a matrix, plus how they were found
+#[derive(Clone, Debug, PartialEq)]
+pub struct Eigenpair<T: Number> {
+    /// The eigenvalue `λ`
+    pub value: T,
+
+    /// A unit eigenvector `x` with `Ax = λx`
+    pub vector: Vector<T>,
+
+    /// The number of iterations taken
+    pub iterations: usize,
+}
+
+/// A [Gershgorin disc](https://en.wikipedia.org/wiki/Gershgorin_circle_theorem) in the
+/// complex plane, centered on a diagonal element of a matrix
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GershgorinDisc<T: Number> {
+    /// The diagonal element `a[i][i]`, which lies on the real axis
+    pub center: T,
+
+    /// The sum of the absolute values of the other elements of row `i`
+    pub radius: T,
+}
+
+/// The result of `balance`: a matrix with the same eigenvalues, scaled to be better
+/// conditioned
+///
+/// `matrix` is `D⁻¹ A D`, where `D` is the diagonal matrix of `scale`. Every scale factor
+/// is a power of two, so the scaling introduces no rounding error
+#[derive(Clone, Debug, PartialEq)]
+pub struct Balanced<T: Number> {
+    /// The balanced matrix `D⁻¹ A D`
+    pub matrix: Matrix<T>,
+
+    /// The diagonal of `D`
+    pub scale: Vec<T>,
+}
+
+impl<T: Number + num::Float> Balanced<T> {
+    /// Maps eigenvectors of the balanced matrix back to eigenvectors of the original
+    ///
+    /// If `(D⁻¹ A D) y = λy` then `A (Dy) = λ (Dy)`, so this multiplies row `i` of
+    /// `vectors` by `scale[i]`. The results are no longer unit vectors
+    ///
+    /// ### Parameters
+    /// - `vectors` - An n x k `Matrix` whose columns are eigenvectors of `matrix`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the n x k eigenvectors of the original matrix, or an `Err`
+    ///   of `LinalgError::ShapeMismatch` if `vectors` does not have n rows
+    pub fn back_transform(&self, vectors: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        vectors.validate()?;
+        if vectors.rows != self.scale.len() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.scale.len(), vectors.cols),
+                found: (vectors.rows, vectors.cols),
+            });
+        }
+
+        Ok(Matrix {
+            mat: vectors
+                .mat
+                .iter()
+                .zip(&self.scale)
+                .map(|(row, &d)| row.iter().map(|&x| x * d).collect())
+                .collect(),
+            rows: vectors.rows,
+            cols: vectors.cols,
+        })
+    }
+}
+
+/// Computes the Gershgorin discs of a square `Matrix`, one per row
+///
+/// Every eigenvalue lies in the union of the discs, and a connected group of `k` discs
+/// disjoint from the rest holds exactly `k` of them. A row whose radius is smaller than
+/// the absolute value of its center is strictly diagonally dominant, so a disc that avoids
+/// the origin means the matrix cannot be singular. Costs O(n^2), with no iteration
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing the n discs in row order, or an `Err` of
+///   `LinalgError::NonSquare` if `a` is not square
+pub fn gershgorin_discs<T>(a: &Matrix<T>) -> Result<Vec<GershgorinDisc<T>>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    a.validate()?;
+    if a.rows != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+
+    Ok(a.mat
+        .iter()
+        .enumerate()
+        .map(|(i, row)| GershgorinDisc {
+            center: row[i],
+            radius: row
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(T::zero(), |sum, (_, x)| sum + x.abs()),
+        })
+        .collect())
+}
+
+/// Bounds the real parts of the eigenvalues of a square `Matrix` by its Gershgorin discs
+///
+/// For a symmetric matrix, whose eigenvalues are real, this bounds the eigenvalues
+/// themselves, which is enough to pick a shift that makes `A − σI` definite
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing `(lower, upper)`, the least `center − radius` and the greatest
+///   `center + radius` over all discs, or an `Err` of `LinalgError::NonSquare` if `a` is
+///   not square or `LinalgError::Empty` if it is empty
+pub fn eigenvalue_bounds<T>(a: &Matrix<T>) -> Result<(T, T), LinalgError>
+where
+    T: Number + num::Float,
+{
+    gershgorin_discs(a)?
+        .into_iter()
+        .map(|disc| (disc.center - disc.radius, disc.center + disc.radius))
+        .reduce(|(lo, hi), (l, h)| (lo.min(l), hi.max(h)))
+        .ok_or(LinalgError::Empty)
+}
+
+/// Computes the [Rayleigh quotient](https://en.wikipedia.org/wiki/Rayleigh_quotient)
+/// `xᵀAx / xᵀx`
+///
+/// When `x` is an eigenvector of `A` this is its eigenvalue, and for a symmetric `A` it is
+/// the eigenvalue estimate whose error is smallest, quadratic in the error of `x`
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix` `A`
+/// - `x` - A nonzero `Vector` with n elements
+///
+/// ### Returns
+/// - A `Result` containing the quotient, or an `Err` of `LinalgError::NonSquare` if `a` is
+///   not square, `LinalgError::LengthMismatch` if `x` does not have n elements, or
+///   `LinalgError::InvalidArgument` if `x` is the zero vector
+pub fn rayleigh_quotient<T>(a: &Matrix<T>, x: &Vector<T>) -> Result<T, LinalgError>
+where
+    T: Number + num::Float,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if x.len() != n {
+        return Err(LinalgError::LengthMismatch {
+            expected: n,
+            found: x.len(),
+        });
+    }
+    let norm_squared = x.norm_squared();
+    if norm_squared == T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The Rayleigh quotient is undefined for the zero vector!".to_string(),
+        ));
+    }
+
+    let ax = mul_vec(a, &x.elements);
+    Ok(dot(&x.elements, &ax) / norm_squared)
+}
+
+/// Refines an eigenpair of `a` from the guess `x0` by
+/// [Rayleigh quotient iteration](https://en.wikipedia.org/wiki/Rayleigh_quotient_iteration)
+///
+/// Each iteration takes the Rayleigh quotient `μ` of the current vector and solves
+/// `(A − μI)y = x` for the next one, an inverse iteration whose shift keeps improving. It
+/// converges to the eigenpair nearest the guess, cubically for a symmetric `A`, so a few
+/// iterations usually suffice where power iteration would need hundreds. Stops once the
+/// residual `‖Ax − μx‖` is at most `tol`, or once `A − μI` is singular to working
+/// precision, which means `μ` is already an eigenvalue
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix` `A`
+/// - `x0` - A nonzero guess for the eigenvector, with n elements
+/// - `tol` - The residual `‖Ax − μx‖` to reach, greater than `0`
+/// - `max_iter` - The most iterations to take
+///
+/// ### Returns
+/// - A `Result` based on whether the iteration converged
+///     - An `Ok` containing the `Eigenpair`, with a unit eigenvector
+///     - An `Err` of `LinalgError::InvalidArgument` if `tol` is not positive or `x0` is the
+///       zero vector, `LinalgError::NotConverged` if `max_iter` iterations were not
+///       enough, `LinalgError::NonFinite` if `a` or `x0` holds a NaN or infinity, or any
+///       shape error of `rayleigh_quotient`
+pub fn rayleigh_quotient_iteration<T>(
+    a: &Matrix<T>,
+    x0: &Vector<T>,
+    tol: T,
+    max_iter: usize,
+) -> Result<Eigenpair<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    if tol.is_nan() || tol <= T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The tolerance must be positive!".to_string(),
+        ));
+    }
+    let mut mu = rayleigh_quotient(a, x0)?;
+    a.check_finite()?;
+    x0.to_column_matrix().check_finite()?;
+
+    let n = a.rows;
+    let mut x = x0.normalize()?;
+    for iterations in 0..=max_iter {
+        let ax = mul_vec(a, &x.elements);
+        let residual = ax
+            .iter()
+            .zip(&x.elements)
+            .fold(T::zero(), |sum, (&p, &q)| sum + (p - mu * q) * (p - mu * q))
+            .sqrt();
+        if residual <= tol {
+            return Ok(Eigenpair {
+                value: mu,
+                vector: x,
+                iterations,
+            });
+        }
+        if iterations == max_iter {
+            break;
+        }
+
+        let mut shifted = a.clone();
+        for i in 0..n {
+            shifted[(i, i)] -= mu;
+        }
+        let y = match solve::solve(&shifted, &x.to_column_matrix()) {
+            Ok(y) => Vector::from_matrix(&y)?,
+            Err(LinalgError::Singular) => {
+                return Ok(Eigenpair {
+                    value: mu,
+                    vector: x,
+                    iterations,
+                })
+            }
+            Err(e) => return Err(e),
+        };
+        let norm = y.norm();
+        if !norm.is_finite() {
+            // The shift is so close to an eigenvalue that the solve overflowed
+            return Ok(Eigenpair {
+                value: mu,
+                vector: x,
+                iterations,
+            });
+        }
+
+        x = y.multiply_by_scalar(T::one() / norm);
+        mu = dot(&x.elements, &mul_vec(a, &x.elements));
+    }
+
+    Err(LinalgError::NotConverged {
+        iterations: max_iter,
+    })
+}
+
+/// Computes the `k` eigenpairs of largest magnitude of a symmetric `Matrix` by
+/// [power iteration](https://en.wikipedia.org/wiki/Power_iteration) with Hotelling
+/// deflation
+///
+/// After each eigenpair `(λ, v)` converges, `λvvᵀ` is subtracted from the matrix so the
+/// next power iteration finds the next largest. Each iteration costs O(n^2), so this is
+/// far cheaper than `decompose::symmetric_eigen` when `k` is small and the leading
+/// eigenvalues are well separated; power iteration converges at the rate `|λ₂ / λ₁|` and
+/// stalls when two eigenvalues share a magnitude, such as `3` and `-3`
+///
+/// ### Parameters
+/// - `a` - The n x n symmetric `Matrix`
+/// - `k` - How many eigenpairs to compute, at most n
+/// - `tol` - The residual `‖Ax − λx‖` each eigenpair must reach, greater than `0`
+/// - `max_iter` - The most iterations to take for each eigenpair
+///
+/// ### Returns
+/// - A `Result` based on whether every eigenpair converged
+///     - An `Ok` containing `k` `Eigenpair`s with unit, mutually orthogonal eigenvectors,
+///       sorted by descending absolute eigenvalue
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::InvalidArgument` if `a` is not symmetric, `k` exceeds n, or `tol`
+///       is not positive, `LinalgError::NonFinite` if `a` holds a NaN or infinity, or
+///       `LinalgError::NotConverged` if an eigenpair needed more than `max_iter` iterations
+#[allow(clippy::needless_range_loop)]
+pub fn top_k<T>(
+    a: &Matrix<T>,
+    k: usize,
+    tol: T,
+    max_iter: usize,
+) -> Result<Vec<Eigenpair<T>>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if k > n {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Cannot compute {} eigenpairs of a {}x{} matrix!",
+            k, n, n
+        )));
+    }
+    if tol.is_nan() || tol <= T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The tolerance must be positive!".to_string(),
+        ));
+    }
+    a.check_finite()?;
+
+    let mut b: Vec<Vec<T>> = a.mat.iter().map(|row| row.to_vec()).collect();
+    let largest = b
+        .iter()
+        .flatten()
+        .fold(T::zero(), |max, x| max.max(x.abs()));
+    let size = T::from(n).unwrap_or_else(T::one);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if (b[i][j] - b[j][i]).abs() > size * T::epsilon() * largest {
+                return Err(LinalgError::InvalidArgument(
+                    "The matrix must be symmetric!".to_string(),
+                ));
+            }
+        }
+    }
+
+    let mut pairs: Vec<Eigenpair<T>> = Vec::with_capacity(k);
+    for _ in 0..k {
+        // A fixed start vector with no structure that could make it orthogonal to the
+        // eigenvector being sought
+        let mut x: Vec<T> = (0..n)
+            .map(|i| T::from(i + 1).map_or_else(T::one, |t| t.sin()))
+            .collect();
+        let norm = dot(&x, &x).sqrt();
+        x.iter_mut().for_each(|xi| *xi /= norm);
+
+        let mut converged = None;
+        for iterations in 0..=max_iter {
+            let bx: Vec<T> = b.iter().map(|row| dot(row, &x)).collect();
+            let lambda = dot(&x, &bx);
+            let residual = bx
+                .iter()
+                .zip(&x)
+                .fold(T::zero(), |sum, (&p, &q)| {
+                    sum + (p - lambda * q) * (p - lambda * q)
+                })
+                .sqrt();
+            if residual <= tol {
+                converged = Some((lambda, iterations));
+                break;
+            }
+            if iterations == max_iter {
+                break;
+            }
+
+            let norm = dot(&bx, &bx).sqrt();
+            x = bx.into_iter().map(|y| y / norm).collect();
+        }
+        let Some((lambda, iterations)) = converged else {
+            return Err(LinalgError::NotConverged {
+                iterations: max_iter,
+            });
+        };
+
+        for i in 0..n {
+            for j in 0..n {
+                b[i][j] -= lambda * x[i] * x[j];
+            }
+        }
+        pairs.push(Eigenpair {
+            value: lambda,
+            vector: Vector::new(x),
+            iterations,
+        });
+    }
+    pairs.sort_by(|p, q| {
+        q.value
+            .abs()
+            .partial_cmp(&p.value.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(pairs)
+}
+
+/// Computes an orthonormal basis of the dominant `k`-dimensional invariant subspace of a
+/// square `Matrix` by orthogonal iteration
+///
+/// Runs power iteration on `k` vectors at once, re-orthonormalizing them by a QR
+/// decomposition after every multiplication so they do not all collapse onto the leading
+/// eigenvector. The columns converge to the span of the eigenvectors of the `k` largest
+/// eigenvalues in magnitude at the rate `|λₖ₊₁ / λₖ|`, which suits users who need the
+/// subspace rather than individual eigenvectors; for those, use `top_k` or `eigenvalues`.
+/// `a` need not be symmetric
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix` `A`
+/// - `k` - The dimension of the subspace, at most n
+/// - `iters` - How many multiplications by `A` to perform
+///
+/// ### Returns
+/// - A `Result` based on whether the iteration could run
+///     - An `Ok` containing the n x k `Matrix` whose orthonormal columns span the
+///       approximate invariant subspace
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::InvalidArgument` if `k` exceeds n, or `LinalgError::NonFinite` if
+///       `a` holds a NaN or infinity
+pub fn orthogonal_iteration<T>(
+    a: &Matrix<T>,
+    k: usize,
+    iters: usize,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if k > n {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Cannot find a {}-dimensional subspace of a {}x{} matrix!",
+            k, n, n
+        )));
+    }
+    a.check_finite()?;
+    if k == 0 {
+        return Ok(Matrix::zeros(n, 0));
+    }
+
+    // Fixed start vectors with no structure that could leave them orthogonal to the
+    // subspace being sought; the first matches the start vector of `top_k`
+    let start = Matrix {
+        mat: (0..n)
+            .map(|i| {
+                (0..k)
+                    .map(|j| T::from((i + 1) * (j + 1)).map_or_else(T::one, |t| t.sin()))
+                    .collect()
+            })
+            .collect(),
+        rows: n,
+        cols: k,
+    };
+    let mut q = leading_columns(&start, k)?;
+    for _ in 0..iters {
+        q = leading_columns(&arith::multiply(a, &q)?, k)?;
+    }
+
+    Ok(q)
+}
+
+/// Gets the first `k` columns of the orthogonal factor of `z`, which span its columns
+/// even when they are dependent
+fn leading_columns<T>(z: &Matrix<T>, k: usize) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (q, _) = decompose::qr_decomposition(z)?;
+
+    Ok(Matrix {
+        mat: q.mat.iter().map(|row| Arc::from(&row[..k])).collect(),
+        rows: q.rows,
+        cols: k,
+    })
+}
+
+/// The most shifted QR sweeps spent on one eigenvalue, or one pair, before `eigenvalues`
+/// gives up
+const MAX_QR_SWEEPS: usize = 30;
+
+/// Balances a square `Matrix` before its eigenvalues are computed, by the
+/// Parlett–Reinsch algorithm
+///
+/// Rounding errors in the QR algorithm are proportional to the norm of the matrix, so a
+/// matrix whose rows and columns differ in scale by many orders of magnitude loses
+/// accuracy in its small eigenvalues. A diagonal similarity `D⁻¹ A D` leaves the
+/// eigenvalues unchanged; this picks power-of-two scale factors that make the
+/// off-diagonal norm of each row and its column about equal, which typically reduces the
+/// norm a great deal. `eigenvalues` balances automatically
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix`
+///
+/// ### Returns
+/// - A `Result` containing the `Balanced` matrix and its scale factors, or an `Err` of
+///   `LinalgError::NonSquare` if `a` is not square or `LinalgError::NonFinite` if it holds
+///   a NaN or infinity
+#[allow(clippy::needless_range_loop)]
+pub fn balance<T>(a: &Matrix<T>) -> Result<Balanced<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    a.check_finite()?;
+
+    let radix = T::one() + T::one();
+    let radix2 = radix * radix;
+    let mut b: Vec<Vec<T>> = a.mat.iter().map(|row| row.to_vec()).collect();
+    let mut scale = vec![T::one(); n];
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 0..n {
+            let (mut c, r) = (0..n)
+                .filter(|&j| j != i)
+                .fold((T::zero(), T::zero()), |(c, r), j| {
+                    (c + b[j][i].abs(), r + b[i][j].abs())
+                });
+            if c == T::zero() || r == T::zero() {
+                continue;
+            }
+
+            // Find the power of two f that brings c·f and r/f closest together
+            let total = c + r;
+            let mut f = T::one();
+            while c < r / radix {
+                f *= radix;
+                c *= radix2;
+            }
+            while c > r * radix {
+                f /= radix;
+                c /= radix2;
+            }
+            if (c + r) / f < T::from(0.95).unwrap() * total {
+                done = false;
+                scale[i] *= f;
+                for j in 0..n {
+                    b[i][j] /= f;
+                    b[j][i] *= f;
+                }
+            }
+        }
+    }
+
+    Ok(Balanced {
+        matrix: Matrix {
+            mat: b.into_iter().map(Arc::from).collect(),
+            rows: n,
+            cols: n,
+        },
+        scale,
+    })
+}
+
+/// Computes every eigenvalue of a real square `Matrix`, including complex ones
+///
+/// `a` is first scaled by `balance`, then reduced to upper Hessenberg form by Householder
+/// reflections, then the
+/// [Francis double-shift QR algorithm](https://en.wikipedia.org/wiki/QR_algorithm) splits
+/// off one real eigenvalue or one complex conjugate pair at a time. Costs about `10 n^3`
+/// operations. Complex eigenvalues come in conjugate pairs, and for a matrix with real
+/// eigenvalues the imaginary parts are exactly zero
+///
+/// ### Parameters
+/// - `a` - The n x n `Matrix`, which need not be symmetric
+///
+/// ### Returns
+/// - A `Result` containing the n eigenvalues sorted by real part and then imaginary part,
+///   or an `Err` of `LinalgError::NonSquare` if `a` is not square,
+///   `LinalgError::NonFinite` if it holds a NaN or infinity, or
+///   `LinalgError::NotConverged` if an eigenvalue is not found within `MAX_QR_SWEEPS`
+///   sweeps
+pub fn eigenvalues<T>(a: &Matrix<T>) -> Result<Vec<Complex<T>>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let balanced = balance(a)?;
+    let mut h: Vec<Vec<T>> = balanced.matrix.mat.iter().map(|row| row.to_vec()).collect();
+    to_hessenberg(&mut h);
+    let mut values = hessenberg_qr(h)?;
+    values.sort_by(|p, q| {
+        (p.re, p.im)
+            .partial_cmp(&(q.re, q.im))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(values)
+}
+
+/// Reduces `h` in place to upper Hessenberg form by a similarity transform made of
+/// Householder reflections, which leaves its eigenvalues unchanged
+#[allow(clippy::needless_range_loop)]
+fn to_hessenberg<T: Number + num::Float>(h: &mut [Vec<T>]) {
+    let n = h.len();
+    for k in 0..n.saturating_sub(2) {
+        let norm = (k + 1..n)
+            .fold(T::zero(), |sum, i| sum + h[i][k] * h[i][k])
+            .sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+
+        // v = x − αe₁ with α of the opposite sign to x₀, so that nothing cancels
+        let alpha = if h[k + 1][k] > T::zero() { -norm } else { norm };
+        let mut v: Vec<T> = (k + 1..n).map(|i| h[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm2 = v.iter().fold(T::zero(), |sum, &x| sum + x * x);
+        let scale = (T::one() + T::one()) / v_norm2;
+
+        // H ← (I − scale vvᵀ) H (I − scale vvᵀ), acting on rows and columns k + 1..n
+        for j in k..n {
+            let proj = (0..v.len()).fold(T::zero(), |sum, i| sum + v[i] * h[k + 1 + i][j]);
+            for (i, &vi) in v.iter().enumerate() {
+                h[k + 1 + i][j] -= scale * proj * vi;
+            }
+        }
+        for row in h.iter_mut() {
+            let proj = (0..v.len()).fold(T::zero(), |sum, j| sum + row[k + 1 + j] * v[j]);
+            for (j, &vj) in v.iter().enumerate() {
+                row[k + 1 + j] -= scale * proj * vj;
+            }
+        }
+        for i in k + 2..n {
+            h[i][k] = T::zero();
+        }
+    }
+}
+
+/// Finds the eigenvalues of the upper Hessenberg matrix `h` by Francis double-shift QR,
+/// following the EISPACK routine `hqr`
+///
+/// The active block is rows and columns `l..=nn`; a negligible subdiagonal element
+/// splits it, and a 1 x 1 or 2 x 2 block at the bottom deflates as eigenvalues
+#[allow(clippy::needless_range_loop)]
+fn hessenberg_qr<T: Number + Neg<Output = T> + num::Float>(
+    mut h: Vec<Vec<T>>,
+) -> Result<Vec<Complex<T>>, LinalgError> {
+    let n = h.len();
+    let eps = T::epsilon();
+    let sign = |a: T, b: T| if b >= T::zero() { a.abs() } else { -a.abs() };
+    let half = T::from(0.5).unwrap();
+    let mut norm = T::zero();
+    for i in 0..n {
+        for j in i.saturating_sub(1)..n {
+            norm += h[i][j].abs();
+        }
+    }
+
+    let mut values = Vec::with_capacity(n);
+    // The shifts already subtracted from the diagonal, added back onto each eigenvalue
+    let mut shift = T::zero();
+    let mut end = n;
+    while end > 0 {
+        let nn = end - 1;
+        let mut sweeps = 0;
+        loop {
+            let mut l = nn;
+            while l > 0 {
+                let mut s = h[l - 1][l - 1].abs() + h[l][l].abs();
+                if s == T::zero() {
+                    s = norm;
+                }
+                if h[l][l - 1].abs() <= eps * s {
+                    h[l][l - 1] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = h[nn][nn];
+            if l == nn {
+                values.push(Complex::new(x + shift, T::zero()));
+                end -= 1;
+                break;
+            }
+            let mut y = h[nn - 1][nn - 1];
+            let mut w = h[nn][nn - 1] * h[nn - 1][nn];
+            if l == nn - 1 {
+                let p = half * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += shift;
+                if q >= T::zero() {
+                    let z = p + sign(z, p);
+                    let lower = if z != T::zero() { x - w / z } else { x + z };
+                    values.push(Complex::new(x + z, T::zero()));
+                    values.push(Complex::new(lower, T::zero()));
+                } else {
+                    values.push(Complex::new(x + p, z));
+                    values.push(Complex::new(x + p, -z));
+                }
+                end -= 2;
+                break;
+            }
+
+            if sweeps == MAX_QR_SWEEPS {
+                return Err(LinalgError::NotConverged { iterations: sweeps });
+            }
+            if sweeps == 10 || sweeps == 20 {
+                // An exceptional shift breaks the cycles ordinary shifts can fall into
+                shift += x;
+                for i in 0..=nn {
+                    h[i][i] -= x;
+                }
+                let s = h[nn][nn - 1].abs() + h[nn - 1][nn - 2].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+            sweeps += 1;
+
+            // Look for two consecutive small subdiagonal elements to start the sweep at
+            let (mut p, mut q, mut r);
+            let mut m = nn - 2;
+            loop {
+                let z = h[m][m];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - rr - ss;
+                r = h[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = h[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs());
+                if u <= eps * v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m..nn - 1 {
+                h[i + 2][i] = T::zero();
+                if i != m {
+                    h[i + 2][i - 1] = T::zero();
+                }
+            }
+
+            // Chase the bulge down the subdiagonal with 3 x 3 Householder reflections
+            for k in m..nn {
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if k + 1 != nn {
+                        h[k + 2][k - 1]
+                    } else {
+                        T::zero()
+                    };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != T::zero() {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s == T::zero() {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        h[k][k - 1] = -h[k][k - 1];
+                    }
+                } else {
+                    h[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+                for j in k..=nn {
+                    let mut p = h[k][j] + q * h[k + 1][j];
+                    if k + 1 != nn {
+                        p += r * h[k + 2][j];
+                        h[k + 2][j] -= p * z;
+                    }
+                    h[k + 1][j] -= p * y;
+                    h[k][j] -= p * x;
+                }
+                for i in l..=nn.min(k + 3) {
+                    let mut p = x * h[i][k] + y * h[i][k + 1];
+                    if k + 1 != nn {
+                        p += z * h[i][k + 2];
+                        h[i][k + 2] -= p * r;
+                    }
+                    h[i][k + 1] -= p * q;
+                    h[i][k] -= p;
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+/// Computes the product `Ax` of a square `Matrix` and a slice of matching length
+fn mul_vec<T: Number>(a: &Matrix<T>, x: &[T]) -> Vec<T> {
+    a.mat.iter().map(|row| dot(row, x)).collect()
+}
+
+/// Computes the dot product of two slices of equal length
+fn dot<T: Number>(u: &[T], v: &[T]) -> T {
+    u.iter()
+        .zip(v)
+        .fold(T::default(), |sum, (&p, &q)| sum + p * q)
+}
@@ -0,0 +1,137 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// The error returned by every fallible operation on a `Matrix` or `Vector`
+///
+/// Each variant carries enough structure to be handled programmatically; its `Display`
+/// output is a human-readable description suitable for logs and error messages
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinalgError {
+    /// An operand had shape `found` where shape `expected` was required, given as
+    /// `(rows, cols)`
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// An operand had `found` elements where `expected` were required
+    LengthMismatch { expected: usize, found: usize },
+    /// The operation requires a square matrix but got a `rows` x `cols` one
+    NonSquare { rows: usize, cols: usize },
+    /// The matrix is singular, so it cannot be inverted or solved against
+    Singular,
+    /// The matrix is invertible but so ill-conditioned that its inverse would be dominated
+    /// by rounding error
+    IllConditioned,
+    /// The matrix does not have the full row or column rank the operation requires
+    RankDeficient,
+    /// Column `col` is a linear combination of the columns before it
+    LinearlyDependent { col: usize },
+    /// The matrix is not symmetric positive definite, so it has no Cholesky factor
+    NotPositiveDefinite,
+    /// Elimination without row exchanges reached a zero pivot; the matrix is singular or
+    /// needs row exchanges
+    ZeroPivot,
+    /// `index` was out of bounds for an axis of length `len`
+    OutOfBounds { index: usize, len: usize },
+    /// The system of equations is inconsistent
+    NoSolution,
+    /// The system of equations has a free variable
+    InfiniteSolutions,
+    /// An iterative solver used up its `iterations` without meeting its tolerance
+    NotConverged { iterations: usize },
+    /// The element at `(row, col)` is NaN or infinite
+    NonFinite { row: usize, col: usize },
+    /// An integer result did not fit in its type
+    Overflow,
+    /// The operation is undefined for an empty matrix
+    Empty,
+    /// The `mat`, `rows`, and `cols` fields of a `Matrix` disagree
+    Malformed(String),
+    /// An argument was invalid for a reason no other variant describes
+    InvalidArgument(String),
+    /// The `CancellationToken` of a `ComputeContext` was cancelled
+    Cancelled,
+    /// The deadline of a `ComputeContext` passed before the computation finished
+    DeadlineExceeded,
+    /// A background task panicked or was dropped before it finished
+    TaskFailed(String),
+}
+
+impl Display for LinalgError {
+    /// Writes a human-readable description of the error
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinalgError::ShapeMismatch { expected, found } => write!(
+                f,
+                "Expected a {}x{} matrix but got {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            LinalgError::LengthMismatch { expected, found } => {
+                write!(f, "Expected {} elements but got {}", expected, found)
+            }
+            LinalgError::NonSquare { rows, cols } => {
+                write!(f, "Expected a square matrix but got {}x{}", rows, cols)
+            }
+            LinalgError::Singular => write!(f, "The matrix is singular"),
+            LinalgError::IllConditioned => {
+                write!(f, "The matrix is too ill-conditioned to invert reliably")
+            }
+            LinalgError::RankDeficient => write!(f, "The matrix is rank deficient"),
+            LinalgError::LinearlyDependent { col } => write!(
+                f,
+                "Column {} is linearly dependent on the columns before it",
+                col
+            ),
+            LinalgError::NotPositiveDefinite => {
+                write!(f, "The matrix is not symmetric positive definite")
+            }
+            LinalgError::ZeroPivot => write!(
+                f,
+                "Encountered a zero pivot; the matrix is singular or needs row exchanges"
+            ),
+            LinalgError::OutOfBounds { index, len } => {
+                write!(f, "Index {} is out of bounds for length {}", index, len)
+            }
+            LinalgError::NoSolution => write!(f, "No solution exists for the given matrix"),
+            LinalgError::InfiniteSolutions => {
+                write!(f, "Infinitely many solutions exist for the given matrix")
+            }
+            LinalgError::NotConverged { iterations } => {
+                write!(
+                    f,
+                    "The solver did not converge within {} iterations",
+                    iterations
+                )
+            }
+            LinalgError::NonFinite { row, col } => {
+                write!(
+                    f,
+                    "Non-finite input: element ({}, {}) is NaN or infinite",
+                    row, col
+                )
+            }
+            LinalgError::Overflow => write!(f, "The result overflowed its integer type"),
+            LinalgError::Empty => write!(f, "The operation is undefined for an empty matrix"),
+            LinalgError::Malformed(message) => write!(f, "Malformed matrix: {}", message),
+            LinalgError::InvalidArgument(message) => write!(f, "{}", message),
+            LinalgError::Cancelled => write!(f, "The computation was cancelled"),
+            LinalgError::DeadlineExceeded => write!(f, "The computation passed its deadline"),
+            LinalgError::TaskFailed(message) => {
+                write!(f, "The blocking task failed: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for LinalgError {}
+
+impl From<LinalgError> for String {
+    /// Converts the error into its `Display` message, so `?` still works in functions that
+    /// return `Result<_, String>`
+    fn from(error: LinalgError) -> String {
+        error.to_string()
+    }
+}
@@ -0,0 +1,185 @@
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::solve;
+use num::rational::Ratio;
+use num::{Integer, Num, Zero};
+use std::fmt::Display;
+
+/// A matrix of exact, arbitrary-precision numbers such as `BigInt` or `BigRational`
+///
+/// `Matrix` requires `Copy` elements, which heap-allocated big numbers cannot be, so exact
+/// arithmetic lives on this separate `Clone`-based type. Its results carry no rounding
+/// error at all, which makes it suited to teaching and to number-theoretic work where
+/// `1/3` must stay `1/3`
+///
+/// Convert an integer `Matrix` with `ExactMatrix::from`, then use `map` to move between
+/// element types, e.g. `ExactMatrix::<BigInt>::from(&m).map(BigRational::from_integer)`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExactMatrix<T: Clone + Num> {
+    /// The elements of the matrix, stored row by row
+    pub mat: Vec<Vec<T>>,
+
+    /// Stores the number of rows in the matrix
+    pub rows: usize,
+
+    /// Stores the number of columns in the matrix
+    pub cols: usize,
+}
+
+impl<T: Clone + Num> ExactMatrix<T> {
+    /// Creates an `ExactMatrix` from its rows
+    ///
+    /// ### Parameters
+    /// - `rows` - The rows of the matrix, which must all have the same length
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `ExactMatrix`, or an `Err` of `LinalgError::Malformed`
+    ///   if the rows have different lengths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<ExactMatrix<T>, LinalgError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if let Some(i) = rows.iter().position(|row| row.len() != cols) {
+            return Err(LinalgError::Malformed(format!(
+                "Row {} has {} elements but row 0 has {}",
+                i,
+                rows[i].len(),
+                cols
+            )));
+        }
+
+        Ok(ExactMatrix {
+            rows: rows.len(),
+            cols,
+            mat: rows,
+        })
+    }
+
+    /// Applies `f` to every element, e.g. to turn a `BigInt` matrix into a `BigRational` one
+    ///
+    /// ### Parameters
+    /// - `f` - The conversion applied to each element
+    ///
+    /// ### Returns
+    /// - A new `ExactMatrix` of the same shape holding the converted elements
+    pub fn map<U: Clone + Num>(&self, f: impl Fn(T) -> U) -> ExactMatrix<U> {
+        ExactMatrix {
+            mat: self
+                .mat
+                .iter()
+                .map(|row| row.iter().cloned().map(&f).collect())
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Computes the determinant of this `ExactMatrix` exactly
+    ///
+    /// Uses the [Bareiss algorithm](https://en.wikipedia.org/wiki/Bareiss_algorithm), a
+    /// fraction-free elimination whose every division is exact. It takes O(n^3) operations
+    /// and works over the integers as well as the rationals, without intermediate values
+    /// growing beyond the size of the minors of the matrix
+    ///
+    /// ### Returns
+    /// - A `Result` containing the exact determinant, which is `1` for an empty matrix, or
+    ///   an `Err` of `LinalgError::NonSquare` if this `ExactMatrix` is not square
+    pub fn determinant(&self) -> Result<T, LinalgError> {
+        let n = self.rows;
+        if n != self.cols {
+            return Err(LinalgError::NonSquare {
+                rows: n,
+                cols: self.cols,
+            });
+        }
+        solve::bareiss(
+            self.mat.clone(),
+            T::zero(),
+            T::one(),
+            |a_ij, a_kk, a_ik, a_kj, previous| {
+                let cross = a_ij.clone() * a_kk.clone() - a_ik.clone() * a_kj.clone();
+                Ok(cross / previous.clone())
+            },
+            |det| Ok(T::zero() - det),
+        )
+    }
+}
+
+impl<I: Clone + Integer> ExactMatrix<Ratio<I>> {
+    /// Computes the reduced row echelon form (RREF) of this `ExactMatrix` exactly
+    ///
+    /// Rows are exchanged whenever a column's pivot is zero, so the result is the unique
+    /// RREF for any input
+    ///
+    /// ### Returns
+    /// - A new `ExactMatrix` holding this one in reduced row echelon form
+    #[allow(clippy::needless_range_loop)]
+    pub fn rref(&self) -> ExactMatrix<Ratio<I>> {
+        let mut a = self.mat.clone();
+        let mut pivot_row = 0;
+
+        for col in 0..self.cols {
+            if pivot_row == self.rows {
+                break;
+            }
+            let Some(i) = (pivot_row..self.rows).find(|&i| !a[i][col].is_zero()) else {
+                continue;
+            };
+            a.swap(i, pivot_row);
+
+            let pivot = a[pivot_row][col].clone();
+            for x in &mut a[pivot_row][col..] {
+                *x = x.clone() / pivot.clone();
+            }
+            for i in 0..self.rows {
+                if i == pivot_row || a[i][col].is_zero() {
+                    continue;
+                }
+                let factor = a[i][col].clone();
+                for j in col..self.cols {
+                    let update = factor.clone() * a[pivot_row][j].clone();
+                    a[i][j] = a[i][j].clone() - update;
+                }
+            }
+            pivot_row += 1;
+        }
+
+        ExactMatrix {
+            mat: a,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<N: Number, T: Clone + Num + From<N>> From<&Matrix<N>> for ExactMatrix<T> {
+    /// Converts every element of a `Matrix`, e.g. from `i64` to `BigInt`
+    fn from(matrix: &Matrix<N>) -> ExactMatrix<T> {
+        ExactMatrix {
+            mat: matrix
+                .mat
+                .iter()
+                .map(|row| row.iter().map(|&x| T::from(x)).collect())
+                .collect(),
+            rows: matrix.rows,
+            cols: matrix.cols,
+        }
+    }
+}
+
+impl<T: Clone + Num + Display> Display for ExactMatrix<T> {
+    /// Writes an `ExactMatrix` as a pretty-printable string, in the same layout as `Matrix`
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.mat {
+            write!(f, "|")?;
+            for x in row {
+                write!(f, " {}", x)?;
+            }
+            writeln!(f, " |")?;
+        }
+
+        Ok(())
+    }
+}
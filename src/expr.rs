@@ -0,0 +1,601 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::parallel;
+use crate::solve;
+use crate::transform;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Write};
+use std::mem::{self, Discriminant};
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// A node whose operands do not fit, as reported by `Expr::validate`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeIssue {
+    /// The id of the node, matching its `n<id>` in `Expr::to_dot`
+    pub node: usize,
+
+    /// The name and operation of the node, as shown in dumps
+    pub label: String,
+
+    /// The error the operation of the node would return
+    pub error: LinalgError,
+}
+
+impl Display for ShapeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "n{} ({}): {}", self.node, self.label, self.error)
+    }
+}
+
+/// A recorded matrix computation, built by composing operations on `Expr::input`s instead of
+/// running them one at a time
+///
+/// Building an `Expr` only records what to compute; nothing runs until `eval`. The graph
+/// can be printed with `{}` as an indented tree, or exported with `to_dot` for Graphviz,
+/// and both show the shape inferred at every node. A node whose own operands do not fit is
+/// marked with the error, so in a long pipeline the first bad step is easy to find.
+/// Cloning an `Expr` is cheap, and a clone used twice is one shared node in the graph
+#[derive(Clone, Debug)]
+pub struct Expr<T: Number> {
+    node: Arc<Node<T>>,
+}
+
+/// The shape, or shape error, inferred for each node visited so far
+type Shapes<T> = HashMap<*const Node<T>, Result<(usize, usize), LinalgError>>;
+
+#[derive(Debug)]
+struct Node<T: Number> {
+    name: Option<String>,
+    op: Op<T>,
+}
+
+#[derive(Debug)]
+enum Op<T: Number> {
+    Input(Matrix<T>),
+    Add(Expr<T>, Expr<T>),
+    Subtract(Expr<T>, Expr<T>),
+    Multiply(Expr<T>, Expr<T>),
+    Scale(Expr<T>, T),
+    Transpose(Expr<T>),
+    Inverse(Expr<T>),
+    Solve(Expr<T>, Expr<T>),
+}
+
+impl<T: Number> Expr<T> {
+    /// Records a named input `Matrix`
+    ///
+    /// ### Parameters
+    /// - `name` - The name shown for this input in dumps and error reports
+    /// - `matrix` - The `Matrix` the input holds
+    pub fn input(name: &str, matrix: Matrix<T>) -> Expr<T> {
+        Expr::with_op(Some(name.to_string()), Op::Input(matrix))
+    }
+
+    /// Names this node, so that dumps and error reports show `name` beside its operation
+    pub fn named(&self, name: &str) -> Expr<T> {
+        let op = match &self.node.op {
+            Op::Input(m) => Op::Input(m.clone()),
+            Op::Add(a, b) => Op::Add(a.clone(), b.clone()),
+            Op::Subtract(a, b) => Op::Subtract(a.clone(), b.clone()),
+            Op::Multiply(a, b) => Op::Multiply(a.clone(), b.clone()),
+            Op::Scale(a, c) => Op::Scale(a.clone(), *c),
+            Op::Transpose(a) => Op::Transpose(a.clone()),
+            Op::Inverse(a) => Op::Inverse(a.clone()),
+            Op::Solve(a, b) => Op::Solve(a.clone(), b.clone()),
+        };
+        Expr::with_op(Some(name.to_string()), op)
+    }
+
+    /// Records `self + other`
+    pub fn add(&self, other: &Expr<T>) -> Expr<T> {
+        Expr::with_op(None, Op::Add(self.clone(), other.clone()))
+    }
+
+    /// Records `self − other`
+    pub fn subtract(&self, other: &Expr<T>) -> Expr<T> {
+        Expr::with_op(None, Op::Subtract(self.clone(), other.clone()))
+    }
+
+    /// Records the matrix product `self × other`
+    pub fn multiply(&self, other: &Expr<T>) -> Expr<T> {
+        Expr::with_op(None, Op::Multiply(self.clone(), other.clone()))
+    }
+
+    /// Records `constant × self`
+    pub fn scale(&self, constant: T) -> Expr<T> {
+        Expr::with_op(None, Op::Scale(self.clone(), constant))
+    }
+
+    /// Records the transpose of `self`
+    pub fn transpose(&self) -> Expr<T> {
+        Expr::with_op(None, Op::Transpose(self.clone()))
+    }
+
+    /// Records the inverse of `self`
+    ///
+    /// `eval` never forms the inverse when it is only multiplied on the left of something
+    /// else: `a.inverse().multiply(&b)` is computed as `a.solve(&b)`
+    pub fn inverse(&self) -> Expr<T> {
+        Expr::with_op(None, Op::Inverse(self.clone()))
+    }
+
+    /// Records the solution `X` of `self · X = b`, as `solve::solve` computes it
+    pub fn solve(&self, b: &Expr<T>) -> Expr<T> {
+        Expr::with_op(None, Op::Solve(self.clone(), b.clone()))
+    }
+
+    /// Gets the name given by `input` or `named`, if any
+    pub fn name(&self) -> Option<&str> {
+        self.node.name.as_deref()
+    }
+
+    /// Infers the shape of the result without computing anything
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `(rows, cols)` of the result, or an `Err` with the
+    ///   first error met below this node, such as `LinalgError::ShapeMismatch` for the
+    ///   operands of an `add` that differ in shape
+    pub fn shape(&self) -> Result<(usize, usize), LinalgError> {
+        self.infer(&mut HashMap::new())
+    }
+
+    /// Infers the shape of this node and everything below it, once per shared node
+    fn infer(&self, shapes: &mut Shapes<T>) -> Result<(usize, usize), LinalgError> {
+        if let Some(shape) = shapes.get(&Arc::as_ptr(&self.node)) {
+            return shape.clone();
+        }
+        let children: Vec<_> = self
+            .children()
+            .iter()
+            .map(|child| child.infer(shapes))
+            .collect();
+        let shape = children
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|children| self.own_shape(&children));
+        shapes.insert(Arc::as_ptr(&self.node), shape.clone());
+
+        shape
+    }
+
+    fn with_op(name: Option<String>, op: Op<T>) -> Expr<T> {
+        Expr {
+            node: Arc::new(Node { name, op }),
+        }
+    }
+
+    /// Gets the operands of this node, in order
+    fn children(&self) -> Vec<&Expr<T>> {
+        match &self.node.op {
+            Op::Input(_) => vec![],
+            Op::Scale(a, _) | Op::Transpose(a) | Op::Inverse(a) => vec![a],
+            Op::Add(a, b) | Op::Subtract(a, b) | Op::Multiply(a, b) | Op::Solve(a, b) => {
+                vec![a, b]
+            }
+        }
+    }
+
+    /// Infers the shape of this node from the shapes of its operands
+    fn own_shape(&self, children: &[(usize, usize)]) -> Result<(usize, usize), LinalgError> {
+        match (&self.node.op, children) {
+            (Op::Input(m), _) => {
+                m.validate()?;
+                Ok(m.shape())
+            }
+            (Op::Add(..) | Op::Subtract(..), &[a, b]) => {
+                if a != b {
+                    return Err(LinalgError::ShapeMismatch {
+                        expected: a,
+                        found: b,
+                    });
+                }
+                Ok(a)
+            }
+            (Op::Multiply(..), &[a, b]) => {
+                if a.1 != b.0 {
+                    return Err(LinalgError::ShapeMismatch {
+                        expected: (a.1, b.1),
+                        found: b,
+                    });
+                }
+                Ok((a.0, b.1))
+            }
+            (Op::Scale(..), &[a]) => Ok(a),
+            (Op::Transpose(_), &[a]) => Ok((a.1, a.0)),
+            (Op::Inverse(_), &[a]) => {
+                if a.0 != a.1 {
+                    return Err(LinalgError::NonSquare {
+                        rows: a.0,
+                        cols: a.1,
+                    });
+                }
+                Ok(a)
+            }
+            (Op::Solve(..), &[a, b]) => {
+                if a.0 != a.1 {
+                    return Err(LinalgError::NonSquare {
+                        rows: a.0,
+                        cols: a.1,
+                    });
+                }
+                if b.0 != a.0 {
+                    return Err(LinalgError::ShapeMismatch {
+                        expected: (a.0, b.1),
+                        found: b,
+                    });
+                }
+                Ok(b)
+            }
+            _ => unreachable!("every operation has a fixed number of operands"),
+        }
+    }
+
+    /// Gets the label of this node: its name, its operation, or both
+    fn label(&self) -> String {
+        let op = match &self.node.op {
+            Op::Input(_) => return self.name().unwrap_or("input").to_string(),
+            Op::Add(..) => "add".to_string(),
+            Op::Subtract(..) => "subtract".to_string(),
+            Op::Multiply(..) => "multiply".to_string(),
+            Op::Scale(_, c) => format!("scale by {}", c),
+            Op::Transpose(_) => "transpose".to_string(),
+            Op::Inverse(_) => "inverse".to_string(),
+            Op::Solve(..) => "solve".to_string(),
+        };
+        match self.name() {
+            Some(name) => format!("{} = {}", name, op),
+            None => op,
+        }
+    }
+
+    /// Describes the shape of this node for dumps: the shape, the error this node itself
+    /// causes, or `?` when an operand has already failed
+    fn shape_text(&self, shapes: &mut Shapes<T>) -> String {
+        let operands_ok = self.children().iter().all(|c| c.infer(shapes).is_ok());
+        match self.infer(shapes) {
+            Ok((rows, cols)) => format!("{}x{}", rows, cols),
+            Err(e) if operands_ok => format!("error: {}", e),
+            Err(_) => "?".to_string(),
+        }
+    }
+
+    /// Exports the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language
+    ///
+    /// Each node is labelled with its name or operation and its inferred shape, and a node
+    /// shared by several operations appears once with an edge to each. Nodes that cause a
+    /// shape error are drawn in red
+    ///
+    /// ### Returns
+    /// - The DOT source of a `digraph`, ready for `dot -Tsvg`
+    pub fn to_dot(&self) -> String {
+        let mut shapes = HashMap::new();
+        let mut out = String::from("digraph expr {\n");
+        self.visit(&mut HashMap::new(), &mut |node, id, children| {
+            let shape = node.shape_text(&mut shapes);
+            let color = if shape.starts_with("error") {
+                ", color=red"
+            } else {
+                ""
+            };
+            let label = format!("{}\\n{}", node.label(), shape).replace('"', "\\\"");
+            let _ = writeln!(out, "    n{} [label=\"{}\"{}];", id, label, color);
+            for child in children {
+                let _ = writeln!(out, "    n{} -> n{};", child, id);
+            }
+        });
+        out.push_str("}\n");
+
+        out
+    }
+
+    /// Checks the shapes throughout the graph before anything is computed, reporting every
+    /// node whose operands do not fit
+    ///
+    /// A node that fails has no shape, so the operations above it cannot be checked, but
+    /// every branch that does not depend on it still is. `eval` only finds the first
+    /// problem, and only after computing everything before it
+    ///
+    /// ### Returns
+    /// - A `Result` containing the shape of the result, or an `Err` listing a `ShapeIssue`
+    ///   for every failing node, in the order `to_dot` numbers them
+    pub fn validate(&self) -> Result<(usize, usize), Vec<ShapeIssue>> {
+        let mut shapes = HashMap::new();
+        let mut issues = Vec::new();
+        self.visit(&mut HashMap::new(), &mut |node, id, _| {
+            let operands_ok = node.children().iter().all(|c| c.infer(&mut shapes).is_ok());
+            if let (true, Err(error)) = (operands_ok, node.infer(&mut shapes)) {
+                issues.push(ShapeIssue {
+                    node: id,
+                    label: node.label(),
+                    error,
+                });
+            }
+        });
+
+        match self.infer(&mut shapes) {
+            Ok(shape) if issues.is_empty() => Ok(shape),
+            _ => Err(issues),
+        }
+    }
+
+    /// Calls `f` on every node once, operands first, with the node's id and the ids of its
+    /// operands; ids count up from `0` in that order
+    fn visit(
+        &self,
+        ids: &mut HashMap<*const Node<T>, usize>,
+        f: &mut impl FnMut(&Expr<T>, usize, &[usize]),
+    ) -> usize {
+        if let Some(&id) = ids.get(&Arc::as_ptr(&self.node)) {
+            return id;
+        }
+        let children: Vec<usize> = self
+            .children()
+            .iter()
+            .map(|child| child.visit(ids, f))
+            .collect();
+
+        let id = ids.len();
+        ids.insert(Arc::as_ptr(&self.node), id);
+        f(self, id, &children);
+
+        id
+    }
+
+    fn write_tree(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        depth: usize,
+        shapes: &mut Shapes<T>,
+        seen: &mut HashSet<*const Node<T>>,
+    ) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        if !seen.insert(Arc::as_ptr(&self.node)) && !self.children().is_empty() {
+            return writeln!(f, "{}{}: (shown above)", indent, self.label());
+        }
+        writeln!(f, "{}{}: {}", indent, self.label(), self.shape_text(shapes))?;
+        for child in self.children() {
+            child.write_tree(f, depth + 1, shapes, seen)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Number + Neg<Output = T> + num::Float> Expr<T> {
+    /// Runs the recorded computation
+    ///
+    /// Evaluation is planned over the whole graph rather than one node at a time:
+    /// - Structurally identical subexpressions, even when built separately, are computed
+    ///   once, so `a.transpose().multiply(&a)` written twice costs one product
+    /// - `scale` and `transpose` around the operands of a `multiply`, or around the
+    ///   product itself, are folded into a single product kernel, so no transposed copy
+    ///   or scaled intermediate is ever allocated for them
+    /// - `inverse` on the left of a `multiply` becomes a `solve`, so `(AᵀA)⁻¹Aᵀb` factors
+    ///   `AᵀA` once and never forms its inverse
+    ///
+    /// ### Returns
+    /// - A `Result` containing the resulting `Matrix`, or an `Err` with the first error
+    ///   any operation returns. Shapes are checked first, so a mismatch anywhere in the
+    ///   graph is reported before anything is computed
+    pub fn eval(&self) -> Result<Matrix<T>, LinalgError> {
+        self.shape()?;
+        self.run(&mut Plan::default())
+    }
+
+    /// Computes this node, reusing the value of any structurally identical node already run
+    fn run(&self, plan: &mut Plan<T>) -> Result<Matrix<T>, LinalgError> {
+        let id = self.canonical_id(plan);
+        if let Some(value) = plan.values.get(&id) {
+            return Ok(value.clone());
+        }
+        let value = match &self.node.op {
+            Op::Input(m) => {
+                m.validate()?;
+                m.clone()
+            }
+            Op::Add(a, b) => arith::add(&a.run(plan)?, &b.run(plan)?)?,
+            Op::Subtract(a, b) => arith::subtract(&a.run(plan)?, &b.run(plan)?)?,
+            Op::Multiply(a, b) => Expr::product(a, b, T::one(), false, plan)?,
+            Op::Scale(..) | Op::Transpose(_) => {
+                let (alpha, base, transposed) = self.peel();
+                match &base.node.op {
+                    Op::Multiply(a, b) => Expr::product(a, b, alpha, transposed, plan)?,
+                    _ => scaled(alpha, transposed, base.run(plan)?),
+                }
+            }
+            Op::Inverse(a) => {
+                let a = a.run(plan)?;
+                solve::solve(&a, &arith::identity(a.rows))?
+            }
+            Op::Solve(a, b) => solve::solve(&a.run(plan)?, &b.run(plan)?)?,
+        };
+        plan.values.insert(id, value.clone());
+
+        Ok(value)
+    }
+
+    /// Computes `alpha · (left × right)`, or its transpose, from the operands of a
+    /// `multiply`, with any `scale` and `transpose` around them folded in
+    fn product(
+        left: &Expr<T>,
+        right: &Expr<T>,
+        alpha: T,
+        transposed: bool,
+        plan: &mut Plan<T>,
+    ) -> Result<Matrix<T>, LinalgError> {
+        let (left_alpha, left, left_t) = left.peel();
+        let (right_alpha, right, right_t) = right.peel();
+        let alpha = alpha * left_alpha * right_alpha;
+
+        if let (Op::Inverse(a), false) = (&left.node.op, left_t) {
+            let b = scaled(T::one(), right_t, right.run(plan)?);
+            let x = solve::solve(&a.run(plan)?, &b)?;
+            return Ok(scaled(alpha, transposed, x));
+        }
+
+        let (a, b) = (left.run(plan)?, right.run(plan)?);
+        Ok(if transposed {
+            gemm(alpha, &b, !right_t, &a, !left_t)
+        } else {
+            gemm(alpha, &a, left_t, &b, right_t)
+        })
+    }
+
+    /// Strips the `scale` and `transpose` nodes off the top of this node
+    ///
+    /// ### Returns
+    /// - The product of the constants stripped, the first node below them, and whether an
+    ///   odd number of transposes was stripped
+    fn peel(&self) -> (T, &Expr<T>, bool) {
+        let (mut alpha, mut node, mut transposed) = (T::one(), self, false);
+        loop {
+            match &node.node.op {
+                Op::Scale(a, c) => {
+                    alpha *= *c;
+                    node = a;
+                }
+                Op::Transpose(a) => {
+                    transposed = !transposed;
+                    node = a;
+                }
+                _ => return (alpha, node, transposed),
+            }
+        }
+    }
+
+    /// Numbers this node so that structurally identical nodes share a number: the same
+    /// operation on the same inputs, with equal constants
+    fn canonical_id(&self, plan: &mut Plan<T>) -> usize {
+        if let Some(&id) = plan.ids.get(&Arc::as_ptr(&self.node)) {
+            return id;
+        }
+        let children: Vec<usize> = self
+            .children()
+            .iter()
+            .map(|child| child.canonical_id(plan))
+            .collect();
+        let next = plan.ids.len();
+        let id = match &self.node.op {
+            Op::Input(_) => next,
+            op => {
+                let constant = match op {
+                    Op::Scale(_, c) => Some(*c),
+                    _ => None,
+                };
+                let key = (mem::discriminant(op), children);
+                let same = plan.structures.entry(key).or_default();
+                match same.iter().find(|(c, _)| *c == constant) {
+                    Some(&(_, id)) => id,
+                    None => {
+                        same.push((constant, next));
+                        next
+                    }
+                }
+            }
+        };
+        plan.ids.insert(Arc::as_ptr(&self.node), id);
+
+        id
+    }
+}
+
+/// The canonical ids given so far, grouped by operation and operand ids, with the constant
+/// of each `scale`
+type Structures<T> = HashMap<(Discriminant<Op<T>>, Vec<usize>), Vec<(Option<T>, usize)>>;
+
+/// The state of one `Expr::eval`: canonical ids by node, and the values computed so far
+struct Plan<T: Number> {
+    ids: HashMap<*const Node<T>, usize>,
+    structures: Structures<T>,
+    values: HashMap<usize, Matrix<T>>,
+}
+
+impl<T: Number> Default for Plan<T> {
+    fn default() -> Self {
+        Plan {
+            ids: HashMap::new(),
+            structures: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+}
+
+/// Applies a folded `scale` and `transpose` to a computed value, in at most one pass
+fn scaled<T>(alpha: T, transposed: bool, matrix: Matrix<T>) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let matrix = if transposed {
+        transform::transpose(&matrix)
+    } else {
+        matrix
+    };
+    if alpha == T::one() {
+        matrix
+    } else {
+        arith::multiply_by_scalar_owned(matrix, alpha)
+    }
+}
+
+/// Computes `alpha · op(a) × op(b)`, where `op` transposes its operand when the matching
+/// flag is set, reading the operands in place instead of copying them
+///
+/// The shapes are assumed to fit, as `Expr::eval` checks them before running anything
+fn gemm<T>(alpha: T, a: &Matrix<T>, a_t: bool, b: &Matrix<T>, b_t: bool) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let (rows, inner) = if a_t {
+        (a.cols, a.rows)
+    } else {
+        (a.rows, a.cols)
+    };
+    let cols = if b_t { b.rows } else { b.cols };
+    let _span = Span::new("expr_gemm", 2 * rows * inner * cols, rows + 1);
+
+    let out = parallel::map_range(rows, rows * inner * cols, |i| {
+        let a_row: Vec<T> = if a_t {
+            a.mat.iter().map(|row| row[i]).collect()
+        } else {
+            a.mat[i].to_vec()
+        };
+        let mut out = vec![T::default(); cols];
+        if b_t {
+            for (sum, b_row) in out.iter_mut().zip(&b.mat) {
+                for (&x, &y) in a_row.iter().zip(b_row.iter()) {
+                    *sum += x * y;
+                }
+            }
+        } else {
+            for (&x, b_row) in a_row.iter().zip(&b.mat) {
+                for (sum, &y) in out.iter_mut().zip(b_row.iter()) {
+                    *sum += x * y;
+                }
+            }
+        }
+        if alpha != T::one() {
+            for elem in &mut out {
+                *elem *= alpha;
+            }
+        }
+        Arc::from(out)
+    });
+
+    Matrix {
+        mat: out,
+        rows,
+        cols,
+    }
+}
+
+impl<T: Number> Display for Expr<T> {
+    /// Writes the graph as an indented tree, one node per line with its inferred shape;
+    /// an operation used more than once is written out in full only the first time
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_tree(f, 0, &mut HashMap::new(), &mut HashSet::new())
+    }
+}
@@ -0,0 +1,291 @@
+use crate::arith;
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::{Fingerprint, Matrix};
+use crate::number::Number;
+use crate::solve;
+use crate::transform;
+use std::collections::HashMap;
+use std::ops::Neg;
+
+/// The kinds of factorization a `FactorizationCache` holds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FactorizationKind {
+    /// `PA = LU`, from `decompose::plu_decomposition`
+    Lu,
+    /// `A = LLᵀ`, from `decompose::cholesky_decomposition`
+    Cholesky,
+    /// `A = QR`, from `decompose::qr_decomposition`
+    Qr,
+}
+
+/// A factorization held by a `FactorizationCache`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Factorization<T: Number> {
+    /// `PA = LU`
+    Lu {
+        /// The permutation matrix `P`
+        p: Matrix<T>,
+        /// The unit lower triangular `L`
+        l: Matrix<T>,
+        /// The upper triangular `U`
+        u: Matrix<T>,
+    },
+    /// `A = LLᵀ`
+    Cholesky {
+        /// The lower triangular `L`, with a positive diagonal
+        l: Matrix<T>,
+    },
+    /// `A = QR`
+    Qr {
+        /// The orthogonal `Q`
+        q: Matrix<T>,
+        /// The upper triangular `R`
+        r: Matrix<T>,
+    },
+}
+
+impl<T: Number> Factorization<T> {
+    /// Gets which kind of factorization this is
+    pub fn kind(&self) -> FactorizationKind {
+        match self {
+            Factorization::Lu { .. } => FactorizationKind::Lu,
+            Factorization::Cholesky { .. } => FactorizationKind::Cholesky,
+            Factorization::Qr { .. } => FactorizationKind::Qr,
+        }
+    }
+}
+
+/// A cached factorization, or the error computing it returned, with the matrix it came
+/// from
+#[derive(Debug)]
+struct Entry<T: Number> {
+    source: Matrix<T>,
+    result: Result<Factorization<T>, LinalgError>,
+    last_used: u64,
+}
+
+/// A least-recently-used cache of LU, Cholesky, and QR factorizations, keyed by the
+/// `Matrix::fingerprint` of the factored matrix
+///
+/// Every call site that solves against the same matrix through one cache shares a single
+/// factorization, however the matrix was built or cloned. Each entry keeps the matrix it
+/// was computed from, sharing its rows rather than copying them, and a hit is only
+/// returned when that matrix equals the one asked about, so a fingerprint collision can
+/// never return the wrong factors. A failed factorization is cached too, since it would
+/// fail the same way again. Once more than `capacity` factorizations are held, the one
+/// used longest ago is dropped
+#[derive(Debug)]
+pub struct FactorizationCache<T: Number> {
+    entries: HashMap<(Fingerprint, FactorizationKind), Entry<T>>,
+    capacity: usize,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl<T: Number> FactorizationCache<T> {
+    /// Creates an empty `FactorizationCache`
+    ///
+    /// ### Parameters
+    /// - `capacity` - The most factorizations kept at once; a `capacity` of `0` caches
+    ///   nothing
+    ///
+    /// ### Returns
+    /// - An empty `FactorizationCache`
+    pub fn new(capacity: usize) -> FactorizationCache<T> {
+        FactorizationCache {
+            entries: HashMap::new(),
+            capacity,
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Gets the most factorizations kept at once
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the number of factorizations currently held
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks whether the cache holds no factorizations
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the number of requests answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Gets the number of requests that had to factor the matrix
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Drops every factorization of `matrix`, for use once it is about to change
+    ///
+    /// ### Returns
+    /// - The number of factorizations dropped
+    pub fn invalidate(&mut self, matrix: &Matrix<T>) -> usize {
+        self.invalidate_fingerprint(matrix.fingerprint())
+    }
+
+    /// Drops every factorization of the matrix with `fingerprint`, for use when that
+    /// matrix itself is no longer at hand
+    ///
+    /// ### Returns
+    /// - The number of factorizations dropped
+    pub fn invalidate_fingerprint(&mut self, fingerprint: Fingerprint) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|(key, _), _| *key != fingerprint);
+
+        before - self.entries.len()
+    }
+
+    /// Drops every factorization, keeping the hit and miss counts
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Gets the factorization of `matrix` of the given `kind`, computing and storing it
+    /// with `compute` unless it is already held
+    fn get_or_compute(
+        &mut self,
+        matrix: &Matrix<T>,
+        kind: FactorizationKind,
+        compute: impl FnOnce(&Matrix<T>) -> Result<Factorization<T>, LinalgError>,
+    ) -> Result<Factorization<T>, LinalgError> {
+        self.clock += 1;
+        let key = (matrix.fingerprint(), kind);
+        if let Some(entry) = self.entries.get_mut(&key) {
+            if entry.source == *matrix {
+                self.hits += 1;
+                entry.last_used = self.clock;
+                return entry.result.clone();
+            }
+        }
+
+        self.misses += 1;
+        let result = compute(matrix);
+        if self.capacity == 0 {
+            return result;
+        }
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(
+            key,
+            Entry {
+                source: matrix.clone(),
+                result: result.clone(),
+                last_used: self.clock,
+            },
+        );
+
+        result
+    }
+}
+
+impl<T: Number + Neg<Output = T> + num::Float> FactorizationCache<T> {
+    /// Gets the factorization of `matrix` of the given `kind`, factoring it only if the
+    /// cache does not already hold it
+    ///
+    /// ### Parameters
+    /// - `matrix` - The `Matrix` to factor
+    /// - `kind` - Which factorization to get
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `Factorization`, or an `Err` with whatever error the
+    ///   matching function in `decompose` returns for `matrix`
+    pub fn get(
+        &mut self,
+        matrix: &Matrix<T>,
+        kind: FactorizationKind,
+    ) -> Result<Factorization<T>, LinalgError> {
+        self.get_or_compute(matrix, kind, |matrix| match kind {
+            FactorizationKind::Lu => {
+                let (p, l, u) = decompose::plu_decomposition(matrix)?;
+                Ok(Factorization::Lu { p, l, u })
+            }
+            FactorizationKind::Cholesky => {
+                let l = decompose::cholesky_decomposition(matrix)?;
+                Ok(Factorization::Cholesky { l })
+            }
+            FactorizationKind::Qr => {
+                let (q, r) = decompose::qr_decomposition(matrix)?;
+                Ok(Factorization::Qr { q, r })
+            }
+        })
+    }
+
+    /// Gets `(P, L, U)` with `PA = LU`, as `decompose::plu_decomposition` computes it
+    pub fn lu(&mut self, matrix: &Matrix<T>) -> Result<decompose::Factors<T>, LinalgError> {
+        match self.get(matrix, FactorizationKind::Lu)? {
+            Factorization::Lu { p, l, u } => Ok((p, l, u)),
+            _ => unreachable!("entries are keyed by kind"),
+        }
+    }
+
+    /// Gets `L` with `A = LLᵀ`, as `decompose::cholesky_decomposition` computes it
+    pub fn cholesky(&mut self, matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        match self.get(matrix, FactorizationKind::Cholesky)? {
+            Factorization::Cholesky { l } => Ok(l),
+            _ => unreachable!("entries are keyed by kind"),
+        }
+    }
+
+    /// Gets `(Q, R)` with `A = QR`, as `decompose::qr_decomposition` computes it
+    pub fn qr(&mut self, matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        match self.get(matrix, FactorizationKind::Qr)? {
+            Factorization::Qr { q, r } => Ok((q, r)),
+            _ => unreachable!("entries are keyed by kind"),
+        }
+    }
+
+    /// Solves `AX = B` as `solve::solve` does, reusing a cached factorization of `a`
+    ///
+    /// Like `solve::solve`, a symmetric positive definite `a` is solved through its
+    /// Cholesky factorization and any other square `a` through its LU factorization, and
+    /// whichever is used stays cached for the next call
+    ///
+    /// ### Parameters
+    /// - `a` - The n x n coefficient `Matrix`
+    /// - `b` - An n x k `Matrix` holding one right-hand side per column
+    ///
+    /// ### Returns
+    /// - A `Result` containing the n x k solution `X`, or an `Err` with one of the
+    ///   failures described in `solve::solve`
+    pub fn solve(&mut self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        b.validate()?;
+        let factors = match self.cholesky(a) {
+            Ok(l) => {
+                let u = transform::transpose(&l);
+                (arith::identity(a.rows), l, u)
+            }
+            Err(LinalgError::NotPositiveDefinite) => self.lu(a)?,
+            Err(e) => return Err(e),
+        };
+        if b.rows != a.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (a.rows, b.cols),
+                found: (b.rows, b.cols),
+            });
+        }
+        b.check_finite()?;
+
+        solve::solve_with_factors(&factors, b)
+    }
+}
@@ -0,0 +1,257 @@
+use crate::adaptive::solve_adaptive;
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::solve;
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::fmt::Display;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Arc;
+
+/// An opaque, heap-allocated `Matrix<f64>` handed across the C ABI
+///
+/// Create with `linalgrs_matrix_create` and release with `linalgrs_matrix_free`
+pub struct LinalgrsMatrix {
+    matrix: Matrix<f64>,
+}
+
+/// The status code returned by every fallible FFI function
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinalgrsStatus {
+    /// The call succeeded
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// An argument was invalid, e.g. a buffer of the wrong length
+    InvalidArgument = 2,
+    /// The operation failed, e.g. mismatched shapes or a singular matrix
+    Failed = 3,
+    /// The operation panicked; this is always a bug in linalgrs
+    Panic = 4,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: &str) {
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+/// Runs `f`, recording its error message and turning panics into `LinalgrsStatus::Panic`
+fn guard<F: FnOnce() -> Result<(), (LinalgrsStatus, String)>>(f: F) -> LinalgrsStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => LinalgrsStatus::Ok,
+        Ok(Err((status, message))) => {
+            set_last_error(&message);
+            status
+        }
+        Err(_) => {
+            set_last_error("linalgrs panicked");
+            LinalgrsStatus::Panic
+        }
+    }
+}
+
+fn null_pointer() -> (LinalgrsStatus, String) {
+    (
+        LinalgrsStatus::NullPointer,
+        "A required pointer was null".to_string(),
+    )
+}
+
+fn failed<E: Display>(error: E) -> (LinalgrsStatus, String) {
+    (LinalgrsStatus::Failed, error.to_string())
+}
+
+unsafe fn matrix_ref<'a>(
+    m: *const LinalgrsMatrix,
+) -> Result<&'a Matrix<f64>, (LinalgrsStatus, String)> {
+    m.as_ref().map(|m| &m.matrix).ok_or_else(null_pointer)
+}
+
+unsafe fn write_matrix(
+    out: *mut *mut LinalgrsMatrix,
+    matrix: Matrix<f64>,
+) -> Result<(), (LinalgrsStatus, String)> {
+    if out.is_null() {
+        return Err(null_pointer());
+    }
+    *out = Box::into_raw(Box::new(LinalgrsMatrix { matrix }));
+
+    Ok(())
+}
+
+/// Gets the message describing the last error on the calling thread
+///
+/// # Safety
+/// The returned string is owned by linalgrs and is valid only until the next failing call
+/// on the same thread. It is null if no call on this thread has failed
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// Creates a `rows` x `cols` matrix from a flat, row-major buffer
+///
+/// # Safety
+/// `data` must be null, which creates a zero matrix, or point to `rows * cols` readable
+/// `f64` values. `out` must be a valid pointer to write the new matrix handle to
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_create(
+    rows: usize,
+    cols: usize,
+    data: *const f64,
+    out: *mut *mut LinalgrsMatrix,
+) -> LinalgrsStatus {
+    guard(|| {
+        let len = rows.checked_mul(cols).ok_or_else(|| {
+            (
+                LinalgrsStatus::InvalidArgument,
+                "rows * cols overflows".to_string(),
+            )
+        })?;
+        let values = if data.is_null() {
+            vec![0.0; len]
+        } else {
+            std::slice::from_raw_parts(data, len).to_vec()
+        };
+        let mat = (0..rows)
+            .map(|r| Arc::from(&values[r * cols..(r + 1) * cols]))
+            .collect();
+
+        write_matrix(out, Matrix { mat, rows, cols })
+    })
+}
+
+/// Releases a matrix created by linalgrs. Passing null does nothing
+///
+/// # Safety
+/// `m` must be null or a handle returned by linalgrs that has not already been freed
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_free(m: *mut LinalgrsMatrix) {
+    if !m.is_null() {
+        drop(Box::from_raw(m));
+    }
+}
+
+/// Gets the number of rows of a matrix, or 0 if `m` is null
+///
+/// # Safety
+/// `m` must be null or a live handle returned by linalgrs
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_rows(m: *const LinalgrsMatrix) -> usize {
+    m.as_ref().map_or(0, |m| m.matrix.rows)
+}
+
+/// Gets the number of columns of a matrix, or 0 if `m` is null
+///
+/// # Safety
+/// `m` must be null or a live handle returned by linalgrs
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_cols(m: *const LinalgrsMatrix) -> usize {
+    m.as_ref().map_or(0, |m| m.matrix.cols)
+}
+
+/// Copies the elements of a matrix, row-major, into `out`
+///
+/// # Safety
+/// `m` must be a live handle returned by linalgrs and `out` must point to `len` writable
+/// `f64` values. `len` must equal `rows * cols`
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_copy_data(
+    m: *const LinalgrsMatrix,
+    out: *mut f64,
+    len: usize,
+) -> LinalgrsStatus {
+    guard(|| {
+        let matrix = matrix_ref(m)?;
+        if out.is_null() {
+            return Err(null_pointer());
+        }
+        if len != matrix.rows * matrix.cols {
+            return Err((
+                LinalgrsStatus::InvalidArgument,
+                format!(
+                    "Expected a buffer of {} values but got {}",
+                    matrix.rows * matrix.cols,
+                    len
+                ),
+            ));
+        }
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (r, row) in matrix.mat.iter().enumerate() {
+            out[r * matrix.cols..(r + 1) * matrix.cols].copy_from_slice(row);
+        }
+
+        Ok(())
+    })
+}
+
+/// Multiplies `a` by `b`, writing a new matrix handle to `out`
+///
+/// # Safety
+/// `a` and `b` must be live handles returned by linalgrs and `out` must be a valid
+/// pointer to write the product's handle to
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_multiply(
+    a: *const LinalgrsMatrix,
+    b: *const LinalgrsMatrix,
+    out: *mut *mut LinalgrsMatrix,
+) -> LinalgrsStatus {
+    guard(|| {
+        let product = arith::multiply(matrix_ref(a)?, matrix_ref(b)?).map_err(failed)?;
+        write_matrix(out, product)
+    })
+}
+
+/// Solves `Ax = b`, writing a new handle to the solution `x` to `out`
+///
+/// `b` may have several columns, one per right-hand side
+///
+/// # Safety
+/// `a` and `b` must be live handles returned by linalgrs and `out` must be a valid
+/// pointer to write the solution's handle to
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_solve(
+    a: *const LinalgrsMatrix,
+    b: *const LinalgrsMatrix,
+    out: *mut *mut LinalgrsMatrix,
+) -> LinalgrsStatus {
+    guard(|| {
+        let solution = solve_adaptive(matrix_ref(a)?, matrix_ref(b)?).map_err(failed)?;
+        write_matrix(out, solution.solution)
+    })
+}
+
+/// Computes the determinant of a square matrix, writing it to `out`
+///
+/// # Safety
+/// `m` must be a live handle returned by linalgrs and `out` must be a valid pointer to
+/// write an `f64` to
+#[no_mangle]
+pub unsafe extern "C" fn linalgrs_matrix_determinant(
+    m: *const LinalgrsMatrix,
+    out: *mut f64,
+) -> LinalgrsStatus {
+    guard(|| {
+        let matrix = matrix_ref(m)?;
+        if out.is_null() {
+            return Err(null_pointer());
+        }
+        if matrix.rows == 0 || matrix.rows != matrix.cols {
+            return Err(failed(LinalgError::NonSquare {
+                rows: matrix.rows,
+                cols: matrix.cols,
+            }));
+        }
+
+        *out = solve::determinant_fast(matrix).map_err(failed)?;
+        Ok(())
+    })
+}
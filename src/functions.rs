@@ -0,0 +1,124 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::{Matrix, NormKind};
+use crate::number::Number;
+use crate::solve;
+use std::ops::Neg;
+
+/// The most Newton iterations `sign` takes; with scaling it normally converges in under
+/// twenty
+const MAX_SIGN_ITERATIONS: usize = 100;
+
+/// Computes the [matrix sign function](https://en.wikipedia.org/wiki/Matrix_sign_function)
+/// `sign(A)`
+///
+/// `sign(A)` has the eigenvectors of `A`, with each eigenvalue replaced by `+1` or `-1`
+/// according to the sign of its real part. It is found by the Newton iteration
+/// `X ← (X + X⁻¹) / 2` from `X = A`, scaled by `|det X|^(-1/n)` during the early
+/// iterations to reach the quadratic phase sooner. The iteration stops one step after the
+/// relative change first drops below `√ε`, by which point quadratic convergence has
+/// reached working precision
+///
+/// ### Parameters
+/// - `a` - The square `Matrix` `A`, with no eigenvalue on the imaginary axis
+///
+/// ### Returns
+/// - A `Result` based on whether `sign(A)` is defined
+///     - An `Ok` containing `sign(A)`, whose square is the identity
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square, `LinalgError::NonFinite`
+///       if it holds a NaN or infinity, or `LinalgError::Singular` or
+///       `LinalgError::NotConverged` if it has an eigenvalue on or too near the imaginary
+///       axis
+pub fn sign<T>(a: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    a.validate()?;
+    a.check_finite()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+
+    let identity = arith::identity(n);
+    let half = T::from(0.5).unwrap_or_else(T::nan);
+    let threshold = T::epsilon().sqrt();
+    let scaling_threshold = T::from(0.01).unwrap_or_else(T::nan);
+    let size = T::from(n).unwrap_or_else(T::one);
+
+    let mut x = a.clone();
+    let mut change = T::infinity();
+    let mut finishing = false;
+    for _ in 0..MAX_SIGN_ITERATIONS {
+        let x_inv = solve::solve(&x, &identity)?;
+        let mu = if change > scaling_threshold {
+            let det = solve::determinant_fast(&x)?.abs();
+            if det > T::zero() && det.is_finite() {
+                det.powf(-size.recip())
+            } else {
+                T::one()
+            }
+        } else {
+            T::one()
+        };
+
+        let next = arith::add(
+            &arith::multiply_by_scalar(&x, half * mu),
+            &arith::multiply_by_scalar_owned(x_inv, half / mu),
+        )?;
+        change = arith::subtract(&next, &x)?.norm(NormKind::One)? / next.norm(NormKind::One)?;
+        x = next;
+
+        if finishing || change == T::zero() {
+            return Ok(x);
+        }
+        finishing = change <= threshold;
+    }
+
+    Err(LinalgError::NotConverged {
+        iterations: MAX_SIGN_ITERATIONS,
+    })
+}
+
+/// Computes the spectral projectors of a square `Matrix` onto the invariant subspaces of
+/// its eigenvalues right and left of the vertical line `Re λ = shift`
+///
+/// With `S = sign(A − shift·I)` they are `P₊ = (I + S) / 2` and `P₋ = (I − S) / 2`. Both
+/// are idempotent, they sum to the identity, and the trace of each is the number of
+/// eigenvalues on its side, so they split `A` into two smaller problems without
+/// computing any eigenvalue
+///
+/// ### Parameters
+/// - `a` - The square `Matrix` `A`
+/// - `shift` - The real part separating the two groups of eigenvalues; no eigenvalue may
+///   have it as its real part
+///
+/// ### Returns
+/// - A `Result` containing `(P₊, P₋)`, or any error of `sign`
+pub fn spectral_projectors<T>(
+    a: &Matrix<T>,
+    shift: T,
+) -> Result<(Matrix<T>, Matrix<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    a.validate()?;
+    let mut shifted = a.clone();
+    for i in 0..a.rows.min(a.cols) {
+        shifted[(i, i)] -= shift;
+    }
+    let s = sign(&shifted)?;
+
+    let identity = arith::identity(a.rows);
+    let half = T::from(0.5).unwrap_or_else(T::nan);
+    let positive = arith::add(&identity, &s)?;
+    let negative = arith::subtract(&identity, &s)?;
+
+    Ok((
+        arith::multiply_by_scalar_owned(positive, half),
+        arith::multiply_by_scalar_owned(negative, half),
+    ))
+}
@@ -0,0 +1,591 @@
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::{Arc, OnceLock};
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 256;
+const MAX_WORKGROUPS_PER_DIMENSION: u32 = 65535;
+
+const MULTIPLY_SHADER: &str = r#"
+struct Dims {
+    m: u32,
+    k: u32,
+    n: u32,
+    _pad: u32,
+}
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> result: array<f32>;
+@group(0) @binding(3) var<uniform> dims: Dims;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = id.y;
+    let col = id.x;
+    if (row >= dims.m || col >= dims.n) {
+        return;
+    }
+
+    var sum = 0.0;
+    for (var i = 0u; i < dims.k; i = i + 1u) {
+        sum = sum + a[row * dims.k + i] * b[i * dims.n + col];
+    }
+    result[row * dims.n + col] = sum;
+}
+"#;
+
+const ELEMENTWISE_SHADER: &str = r#"
+struct Params {
+    len: u32,
+    stride: u32,
+    op: u32,
+    value: f32,
+}
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> result: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.y * params.stride + id.x;
+    if (i >= params.len) {
+        return;
+    }
+
+    let x = a[i];
+    switch params.op {
+        case 0u: { result[i] = x + b[i]; }
+        case 1u: { result[i] = x - b[i]; }
+        case 2u: { result[i] = x * b[i]; }
+        case 3u: { result[i] = x * params.value; }
+        case 4u: { result[i] = x + params.value; }
+        case 5u: { result[i] = abs(x); }
+        case 6u: { result[i] = sqrt(x); }
+        case 7u: { result[i] = exp(x); }
+        case 8u: { result[i] = max(x, 0.0); }
+        default: { result[i] = x; }
+    }
+}
+"#;
+
+/// An error produced by a `GpuMatrix` operation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GpuError {
+    /// No GPU adapter or device could be opened
+    Unavailable(String),
+    /// The matrix is larger than the device can store in one buffer or process in one
+    /// dispatch
+    TooLarge,
+    /// Waiting on the GPU or reading a buffer back from it failed
+    Readback(String),
+    /// The operands were invalid for the operation, e.g. their shapes disagree
+    Linalg(LinalgError),
+}
+
+impl Display for GpuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GpuError::Unavailable(message) => write!(f, "No GPU is available: {}", message),
+            GpuError::TooLarge => write!(f, "Matrix is too large for a single GPU dispatch"),
+            GpuError::Readback(message) => write!(f, "Failed to read from the GPU: {}", message),
+            GpuError::Linalg(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for GpuError {}
+
+impl From<LinalgError> for GpuError {
+    fn from(error: LinalgError) -> GpuError {
+        GpuError::Linalg(error)
+    }
+}
+
+/// An element-wise operation that can be applied to every entry of a `GpuMatrix`
+/// on the GPU through `GpuMatrix::map`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GpuMap {
+    /// Multiplies every element by the given scalar
+    Scale(f32),
+    /// Adds the given scalar to every element
+    Offset(f32),
+    /// Takes the absolute value of every element
+    Abs,
+    /// Takes the square root of every element
+    Sqrt,
+    /// Raises `e` to the power of every element
+    Exp,
+    /// Clamps every negative element to zero
+    Relu,
+}
+
+impl GpuMap {
+    fn encode(self) -> (u32, f32) {
+        match self {
+            GpuMap::Scale(value) => (3, value),
+            GpuMap::Offset(value) => (4, value),
+            GpuMap::Abs => (5, 0.0),
+            GpuMap::Sqrt => (6, 0.0),
+            GpuMap::Exp => (7, 0.0),
+            GpuMap::Relu => (8, 0.0),
+        }
+    }
+}
+
+/// The device, queue, and compiled compute pipelines shared by every `GpuMatrix`
+///
+/// The context is created lazily the first time a matrix is uploaded and lives for the
+/// rest of the program
+struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    multiply_pipeline: wgpu::ComputePipeline,
+    elementwise_pipeline: wgpu::ComputePipeline,
+
+    /// The largest buffer, in bytes, that can be created and bound as storage on `device`
+    max_buffer_bytes: u64,
+}
+
+static CONTEXT: OnceLock<Result<Arc<GpuContext>, GpuError>> = OnceLock::new();
+
+impl GpuContext {
+    fn get() -> Result<Arc<GpuContext>, GpuError> {
+        CONTEXT
+            .get_or_init(|| pollster::block_on(GpuContext::new()).map(Arc::new))
+            .clone()
+    }
+
+    async fn new() -> Result<GpuContext, GpuError> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| GpuError::Unavailable(e.to_string()))?;
+
+        // Ask for everything the adapter supports rather than the conservative defaults, so
+        // the largest matrices the hardware can hold are accepted
+        let limits = adapter.limits();
+        let max_buffer_bytes = limits
+            .max_storage_buffer_binding_size
+            .min(limits.max_buffer_size);
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                required_limits: limits,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| GpuError::Unavailable(e.to_string()))?;
+
+        let multiply_pipeline = GpuContext::create_pipeline(&device, "multiply", MULTIPLY_SHADER);
+        let elementwise_pipeline =
+            GpuContext::create_pipeline(&device, "elementwise", ELEMENTWISE_SHADER);
+
+        Ok(GpuContext {
+            device,
+            queue,
+            multiply_pipeline,
+            elementwise_pipeline,
+            max_buffer_bytes,
+        })
+    }
+
+    /// Checks that a buffer of `len` `f32` values fits within the device limits, since
+    /// wgpu reports an oversized buffer through its panicking validation handler
+    fn check_len(&self, len: usize) -> Result<(), GpuError> {
+        let bytes = (len.max(1) as u64).checked_mul(4);
+        match bytes {
+            Some(bytes) if bytes <= self.max_buffer_bytes => Ok(()),
+            _ => Err(GpuError::TooLarge),
+        }
+    }
+
+    fn create_pipeline(device: &wgpu::Device, label: &str, source: &str) -> wgpu::ComputePipeline {
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(label),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
+        });
+
+        device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some(label),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        })
+    }
+
+    fn storage_buffer(&self, data: &[f32]) -> wgpu::Buffer {
+        // wgpu does not allow zero-sized bindings, so empty matrices still get one element
+        let contents = if data.is_empty() {
+            vec![0u8; 4]
+        } else {
+            data.iter().flat_map(|x| x.to_ne_bytes()).collect()
+        };
+
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("linalgrs matrix"),
+                contents: &contents,
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_SRC
+                    | wgpu::BufferUsages::COPY_DST,
+            })
+    }
+
+    fn output_buffer(&self, len: usize) -> wgpu::Buffer {
+        self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("linalgrs result"),
+            size: (len.max(1) * 4) as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn uniform_buffer(&self, words: [u32; 4]) -> wgpu::Buffer {
+        let contents: Vec<u8> = words.iter().flat_map(|x| x.to_ne_bytes()).collect();
+
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("linalgrs params"),
+                contents: &contents,
+                usage: wgpu::BufferUsages::UNIFORM,
+            })
+    }
+
+    fn dispatch(
+        &self,
+        pipeline: &wgpu::ComputePipeline,
+        buffers: [&wgpu::Buffer; 4],
+        workgroups: (u32, u32),
+    ) {
+        let layout = pipeline.get_bind_group_layout(0);
+        let entries: Vec<wgpu::BindGroupEntry> = buffers
+            .iter()
+            .enumerate()
+            .map(|(i, buffer)| wgpu::BindGroupEntry {
+                binding: i as u32,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &layout,
+            entries: &entries,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(workgroups.0, workgroups.1, 1);
+        }
+        self.queue.submit(Some(encoder.finish()));
+    }
+
+    fn read(&self, buffer: &wgpu::Buffer, len: usize) -> Result<Vec<f32>, GpuError> {
+        let size = (len.max(1) * 4) as u64;
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("linalgrs staging"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|e| GpuError::Readback(e.to_string()))?;
+        receiver
+            .recv()
+            .map_err(|e| GpuError::Readback(e.to_string()))?
+            .map_err(|e| GpuError::Readback(e.to_string()))?;
+
+        let data = {
+            let view = slice
+                .get_mapped_range()
+                .map_err(|e| GpuError::Readback(e.to_string()))?;
+            view.chunks_exact(4)
+                .take(len)
+                .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                .collect()
+        };
+        staging.unmap();
+
+        Ok(data)
+    }
+}
+
+/// A `Matrix<f32>` that lives in GPU memory
+///
+/// A `GpuMatrix` is created by explicitly uploading a `Matrix` with `GpuMatrix::upload` (or
+/// `GpuMatrix::try_from`) and is brought back to the CPU with `GpuMatrix::download`. Every
+/// operation in between stays on the GPU, so chains of operations only pay for the
+/// transfers at either end
+pub struct GpuMatrix {
+    context: Arc<GpuContext>,
+    buffer: wgpu::Buffer,
+
+    /// Stores the number of rows in the matrix
+    pub rows: usize,
+
+    /// Stores the number of columns in the matrix
+    pub cols: usize,
+}
+
+impl GpuMatrix {
+    /// Uploads a `Matrix` into GPU memory
+    ///
+    /// ### Parameters
+    /// - `matrix` - The `Matrix` to copy onto the GPU
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether a GPU was available
+    ///     - An `Ok` containing the uploaded `GpuMatrix`
+    ///     - An `Err` of `GpuError::Unavailable` if no GPU adapter or device could be
+    ///       opened, or `GpuError::TooLarge` if `matrix` is larger than the device can
+    ///       store in one buffer
+    pub fn upload(matrix: &Matrix<f32>) -> Result<GpuMatrix, GpuError> {
+        let context = GpuContext::get()?;
+        context.check_len(
+            matrix
+                .rows
+                .checked_mul(matrix.cols)
+                .ok_or(GpuError::TooLarge)?,
+        )?;
+        let data: Vec<f32> = matrix
+            .mat
+            .iter()
+            .flat_map(|row| row.iter().copied())
+            .collect();
+        let buffer = context.storage_buffer(&data);
+
+        Ok(GpuMatrix {
+            context,
+            buffer,
+            rows: matrix.rows,
+            cols: matrix.cols,
+        })
+    }
+
+    /// Copies this `GpuMatrix` back into CPU memory
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the GPU buffer could be read back
+    ///     - An `Ok` containing the downloaded `Matrix`
+    ///     - An `Err` of `GpuError::Readback` if mapping the GPU buffer failed
+    pub fn download(&self) -> Result<Matrix<f32>, GpuError> {
+        let data = self.context.read(&self.buffer, self.rows * self.cols)?;
+        let mat = if self.cols == 0 {
+            vec![Arc::from(&[][..]); self.rows]
+        } else {
+            data.chunks(self.cols).map(Arc::from).collect()
+        };
+
+        Ok(Matrix {
+            mat,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Compute the shape of this `GpuMatrix`
+    ///
+    /// ### Returns
+    /// - A tuple of two positive integers - `(usize, usize)` - representing
+    ///   the rows and columns of the matrix
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Multiplies this `GpuMatrix` by `other` on the GPU
+    ///
+    /// ### Parameters
+    /// - `other` - The right-hand `GpuMatrix` operand
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices could be multiplied
+    ///     - An `Err` of `GpuError::Linalg` holding `LinalgError::ShapeMismatch` if the
+    ///       columns of this matrix do not equal the rows of `other`, or
+    ///       `GpuError::TooLarge` if the product is larger than the device can store in one
+    ///       buffer
+    ///     - An `Ok` containing the product as a new `GpuMatrix`
+    pub fn multiply(&self, other: &GpuMatrix) -> Result<GpuMatrix, GpuError> {
+        if self.cols != other.rows {
+            return Err(GpuError::Linalg(LinalgError::ShapeMismatch {
+                expected: (self.cols, other.cols),
+                found: (other.rows, other.cols),
+            }));
+        }
+
+        let (m, k, n) = (self.rows, self.cols, other.cols);
+        self.context
+            .check_len(m.checked_mul(n).ok_or(GpuError::TooLarge)?)?;
+        let buffer = self.context.output_buffer(m * n);
+        if m * n > 0 {
+            let (m32, k32, n32) = (dimension(m)?, dimension(k)?, dimension(n)?);
+            let dims = self.context.uniform_buffer([m32, k32, n32, 0]);
+            let workgroups = (n32.div_ceil(16), m32.div_ceil(16));
+            if workgroups.0 > MAX_WORKGROUPS_PER_DIMENSION
+                || workgroups.1 > MAX_WORKGROUPS_PER_DIMENSION
+            {
+                return Err(GpuError::TooLarge);
+            }
+
+            self.context.dispatch(
+                &self.context.multiply_pipeline,
+                [&self.buffer, &other.buffer, &buffer, &dims],
+                workgroups,
+            );
+        }
+
+        Ok(self.with_buffer(buffer, m, n))
+    }
+
+    /// Adds this `GpuMatrix` and `other` element by element on the GPU
+    ///
+    /// ### Parameters
+    /// - `other` - The `GpuMatrix` to add
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices were added
+    ///     - An `Err` of `GpuError::Linalg` holding `LinalgError::ShapeMismatch` if the
+    ///       two matrices are different shapes
+    ///     - An `Ok` containing the sum as a new `GpuMatrix`
+    pub fn add(&self, other: &GpuMatrix) -> Result<GpuMatrix, GpuError> {
+        self.zip(other, 0)
+    }
+
+    /// Subtracts `other` from this `GpuMatrix` element by element on the GPU
+    ///
+    /// ### Parameters
+    /// - `other` - The `GpuMatrix` to subtract
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices were subtracted
+    ///     - An `Err` of `GpuError::Linalg` holding `LinalgError::ShapeMismatch` if the
+    ///       two matrices are different shapes
+    ///     - An `Ok` containing the difference as a new `GpuMatrix`
+    pub fn subtract(&self, other: &GpuMatrix) -> Result<GpuMatrix, GpuError> {
+        self.zip(other, 1)
+    }
+
+    /// Multiplies this `GpuMatrix` and `other` element by element on the GPU
+    ///
+    /// ### Parameters
+    /// - `other` - The `GpuMatrix` to multiply with
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices were multiplied
+    ///     - An `Err` of `GpuError::Linalg` holding `LinalgError::ShapeMismatch` if the
+    ///       two matrices are different shapes
+    ///     - An `Ok` containing the element-wise product as a new `GpuMatrix`
+    pub fn multiply_elementwise(&self, other: &GpuMatrix) -> Result<GpuMatrix, GpuError> {
+        self.zip(other, 2)
+    }
+
+    /// Applies an element-wise `GpuMap` operation to every entry of this `GpuMatrix`
+    ///
+    /// ### Parameters
+    /// - `op` - The `GpuMap` operation to apply
+    ///
+    /// ### Returns
+    /// - A `Result` containing the mapped matrix as a new `GpuMatrix`, or an `Err` of
+    ///   `GpuError::TooLarge` if the matrix is too large to dispatch
+    pub fn map(&self, op: GpuMap) -> Result<GpuMatrix, GpuError> {
+        let (code, value) = op.encode();
+        self.elementwise(&self.buffer, code, value)
+    }
+
+    fn zip(&self, other: &GpuMatrix, code: u32) -> Result<GpuMatrix, GpuError> {
+        if (self.rows, self.cols) != (other.rows, other.cols) {
+            return Err(GpuError::Linalg(LinalgError::ShapeMismatch {
+                expected: (self.rows, self.cols),
+                found: (other.rows, other.cols),
+            }));
+        }
+
+        self.elementwise(&other.buffer, code, 0.0)
+    }
+
+    fn elementwise(
+        &self,
+        other: &wgpu::Buffer,
+        code: u32,
+        value: f32,
+    ) -> Result<GpuMatrix, GpuError> {
+        let len = self.rows * self.cols;
+        self.context.check_len(len)?;
+        let buffer = self.context.output_buffer(len);
+
+        if len > 0 {
+            let len32 = dimension(len)?;
+            let groups = len32.div_ceil(WORKGROUP_SIZE);
+            let groups_x = groups.min(MAX_WORKGROUPS_PER_DIMENSION);
+            let groups_y = groups.div_ceil(groups_x);
+            if groups_y > MAX_WORKGROUPS_PER_DIMENSION {
+                return Err(GpuError::TooLarge);
+            }
+
+            let params = self.context.uniform_buffer([
+                len32,
+                groups_x * WORKGROUP_SIZE,
+                code,
+                value.to_bits(),
+            ]);
+            self.context.dispatch(
+                &self.context.elementwise_pipeline,
+                [&self.buffer, other, &buffer, &params],
+                (groups_x, groups_y),
+            );
+        }
+
+        Ok(self.with_buffer(buffer, self.rows, self.cols))
+    }
+
+    fn with_buffer(&self, buffer: wgpu::Buffer, rows: usize, cols: usize) -> GpuMatrix {
+        GpuMatrix {
+            context: Arc::clone(&self.context),
+            buffer,
+            rows,
+            cols,
+        }
+    }
+}
+
+/// Narrows a size to the `u32` the shaders index with, rejecting sizes that do not fit
+fn dimension(value: usize) -> Result<u32, GpuError> {
+    u32::try_from(value).map_err(|_| GpuError::TooLarge)
+}
+
+impl TryFrom<&Matrix<f32>> for GpuMatrix {
+    type Error = GpuError;
+
+    /// Uploads a `Matrix` into GPU memory; equivalent to `GpuMatrix::upload`
+    fn try_from(matrix: &Matrix<f32>) -> Result<Self, Self::Error> {
+        GpuMatrix::upload(matrix)
+    }
+}
@@ -0,0 +1,345 @@
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::stats;
+use crate::system::System;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// The most k-means passes `spectral_clustering` takes on the embedded nodes
+const SPECTRAL_KMEANS_ITERATIONS: usize = 300;
+
+/// Computes the graph [Laplacian](https://en.wikipedia.org/wiki/Laplacian_matrix)
+/// `L = D - A` of a weighted undirected graph
+///
+/// ### Parameters
+/// - `adjacency` - The symmetric `n x n` adjacency `Matrix`, whose `(i, j)` element is the
+///   non-negative weight of the edge between nodes `i` and `j`
+///
+/// ### Returns
+/// - A `Result` containing the `n x n` Laplacian, or an `Err` if `adjacency` is not square,
+///   not symmetric, or has a negative weight
+pub fn laplacian<T>(adjacency: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + PartialOrd,
+{
+    let degrees = check_adjacency(adjacency)?;
+
+    Ok(build(adjacency, |i, j, weight| {
+        if i == j {
+            degrees[i] - weight
+        } else {
+            -weight
+        }
+    }))
+}
+
+/// Computes the symmetric normalized Laplacian `I - D^(-1/2) A D^(-1/2)` of a weighted
+/// undirected graph
+///
+/// Its eigenvalues lie in `[0, 2]` whatever the degrees are, which makes it the usual
+/// choice for spectral clustering. An isolated node gets a row and column of zeros apart
+/// from the `1` on the diagonal
+///
+/// ### Parameters
+/// - `adjacency` - The symmetric `n x n` adjacency `Matrix` with non-negative weights
+///
+/// ### Returns
+/// - A `Result` containing the `n x n` normalized Laplacian, or an `Err` if `adjacency`
+///   is not square, not symmetric, or has a negative weight
+pub fn normalized_laplacian<T>(adjacency: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let scale: Vec<T> = check_adjacency(adjacency)?
+        .into_iter()
+        .map(|degree| {
+            if degree > T::zero() {
+                degree.sqrt().recip()
+            } else {
+                T::zero()
+            }
+        })
+        .collect();
+
+    Ok(build(adjacency, |i, j, weight| {
+        let entry = -weight * scale[i] * scale[j];
+        if i == j {
+            T::one() + entry
+        } else {
+            entry
+        }
+    }))
+}
+
+/// Partitions the nodes of a weighted undirected graph into `k` clusters by
+/// [spectral clustering](https://en.wikipedia.org/wiki/Spectral_clustering)
+///
+/// Follows Ng, Jordan and Weiss: each node is embedded as its row of the eigenvectors
+/// belonging to the `k` smallest eigenvalues of the normalized Laplacian, the rows are
+/// scaled to unit length, and the embedded points are grouped by `stats::kmeans`. Unlike
+/// k-means on raw coordinates, this finds clusters that are well connected internally
+/// whatever their shape
+///
+/// ### Parameters
+/// - `adjacency` - The symmetric `n x n` adjacency `Matrix` with non-negative weights
+/// - `k` - The number of clusters, between `1` and `n`
+///
+/// ### Returns
+/// - A `Result` based on whether the clustering could be computed
+///     - An `Ok` containing the cluster of each node, in `0..k`
+///     - An `Err` if `adjacency` is not a valid adjacency matrix or `k` is not between `1`
+///       and `n`
+pub fn spectral_clustering<T>(adjacency: &Matrix<T>, k: usize) -> Result<Vec<usize>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let n = adjacency.rows;
+    if k == 0 || k > n {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Cannot form {} clusters from {} nodes!",
+            k, n
+        )));
+    }
+
+    let (_, vectors) = decompose::symmetric_eigen(&normalized_laplacian(adjacency)?)?;
+    let embedding = vectors
+        .mat
+        .iter()
+        .map(|row| {
+            let row = &row[..k];
+            let norm = row.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt();
+            if norm > T::zero() {
+                row.iter().map(|&x| x / norm).collect()
+            } else {
+                Arc::from(row)
+            }
+        })
+        .collect();
+
+    let clusters = stats::kmeans(
+        &Matrix {
+            mat: embedding,
+            rows: n,
+            cols: k,
+        },
+        k,
+        SPECTRAL_KMEANS_ITERATIONS,
+    )?;
+
+    Ok(clusters.labels)
+}
+
+/// Checks that `adjacency` is a square, symmetric matrix of non-negative weights
+///
+/// ### Returns
+/// - A `Result` containing the weighted degree of every node
+fn check_adjacency<T>(adjacency: &Matrix<T>) -> Result<Vec<T>, LinalgError>
+where
+    T: Number + PartialOrd,
+{
+    adjacency.validate()?;
+    adjacency.check_finite()?;
+    let n = adjacency.rows;
+    if n != adjacency.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: adjacency.cols,
+        });
+    }
+    for i in 0..n {
+        for j in 0..n {
+            let weight = adjacency.mat[i][j];
+            if weight < T::default() || weight != adjacency.mat[j][i] {
+                return Err(LinalgError::InvalidArgument(format!(
+                    "Element ({}, {}) breaks the symmetric, non-negative adjacency matrix!",
+                    i, j
+                )));
+            }
+        }
+    }
+
+    Ok(adjacency
+        .mat
+        .iter()
+        .map(|row| row.iter().fold(T::default(), |sum, &w| sum + w))
+        .collect())
+}
+
+/// Builds an `n x n` `Matrix` from `entry(i, j, adjacency[i][j])`
+fn build<T: Number>(adjacency: &Matrix<T>, entry: impl Fn(usize, usize, T) -> T) -> Matrix<T> {
+    let mat = adjacency
+        .mat
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, &w)| entry(i, j, w))
+                .collect()
+        })
+        .collect();
+
+    Matrix {
+        mat,
+        rows: adjacency.rows,
+        cols: adjacency.cols,
+    }
+}
+
+/// Builds the [incidence matrix](https://en.wikipedia.org/wiki/Incidence_matrix) of a
+/// directed graph
+///
+/// Column `e` describes `edges[e] = (from, to)`: it holds `1` in row `from`, `-1` in row
+/// `to`, and `0` elsewhere, so multiplying by a vector of edge flows gives the net flow
+/// out of every node
+///
+/// ### Parameters
+/// - `edges` - The `(from, to)` node pairs of the edges, in column order
+/// - `n_nodes` - The number of nodes, which are numbered `0..n_nodes`
+///
+/// ### Returns
+/// - A `Result` based on whether the edges are valid
+///     - An `Ok` containing the `n_nodes x edges.len()` incidence matrix
+///     - An `Err` of `LinalgError::OutOfBounds` if an edge names a node outside
+///       `0..n_nodes`, or `LinalgError::InvalidArgument` if an edge is a self-loop
+pub fn incidence_matrix<T>(
+    edges: &[(usize, usize)],
+    n_nodes: usize,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    check_edges(edges, n_nodes)?;
+
+    let mut mat = vec![vec![T::default(); edges.len()]; n_nodes];
+    for (e, &(from, to)) in edges.iter().enumerate() {
+        mat[from][e] = T::one();
+        mat[to][e] = -T::one();
+    }
+
+    Ok(Matrix {
+        mat: mat.into_iter().map(Arc::from).collect(),
+        rows: n_nodes,
+        cols: edges.len(),
+    })
+}
+
+/// Builds the [nodal analysis](https://en.wikipedia.org/wiki/Nodal_analysis) system of a
+/// resistive network from Kirchhoff's current law
+///
+/// With incidence matrix `B` and diagonal edge conductances `G`, conservation of current
+/// at every node reads `B G Bᵀ v = s`, where `v` are the node potentials and `s` the
+/// currents injected into the nodes. Potentials are only defined relative to a reference,
+/// so the `ground` node is held at `0` and its row and column are dropped, leaving an
+/// `(n_nodes - 1)`-unknown system that is nonsingular whenever the network is connected
+///
+/// The same system describes any conserved flow driven by potential differences, such as
+/// heat through thermal resistances or water through a pipe network
+///
+/// ### Parameters
+/// - `edges` - The `(from, to)` node pairs of the branches; a positive branch current
+///   flows from `from` to `to`
+/// - `conductances` - The conductance (`1 / resistance`) of each branch
+/// - `injections` - The current injected into each node by external sources; the entry
+///   for `ground` is ignored, since the ground absorbs whatever balances the rest
+/// - `ground` - The reference node held at potential `0`
+///
+/// ### Returns
+/// - A `Result` based on whether the network is valid
+///     - An `Ok` containing the `System` whose solution holds the potentials of every node
+///       except `ground`, in node order
+///     - An `Err` of `LinalgError::LengthMismatch` if `conductances` does not have one
+///       entry per edge or `injections` one per node, `LinalgError::OutOfBounds` if an
+///       edge or `ground` names a missing node, or `LinalgError::InvalidArgument` for a
+///       self-loop or a negative conductance
+pub fn kirchhoff_system<T>(
+    edges: &[(usize, usize)],
+    conductances: &[T],
+    injections: &[T],
+    ground: usize,
+) -> Result<System<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + PartialOrd,
+{
+    let n_nodes = injections.len();
+    check_edges(edges, n_nodes)?;
+    if conductances.len() != edges.len() {
+        return Err(LinalgError::LengthMismatch {
+            expected: edges.len(),
+            found: conductances.len(),
+        });
+    }
+    if ground >= n_nodes {
+        return Err(LinalgError::OutOfBounds {
+            index: ground,
+            len: n_nodes,
+        });
+    }
+    if let Some(e) = conductances.iter().position(|&g| g < T::default()) {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Edge {} has a negative conductance!",
+            e
+        )));
+    }
+
+    // Node i maps to unknown i, or i - 1 past the ground
+    let unknown = |node: usize| match node.cmp(&ground) {
+        std::cmp::Ordering::Less => Some(node),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Greater => Some(node - 1),
+    };
+    let n = n_nodes - 1;
+    let mut coefficients = vec![vec![T::default(); n]; n];
+    for (&(from, to), &g) in edges.iter().zip(conductances) {
+        let (from, to) = (unknown(from), unknown(to));
+        if let Some(i) = from {
+            coefficients[i][i] += g;
+        }
+        if let Some(j) = to {
+            coefficients[j][j] += g;
+        }
+        if let (Some(i), Some(j)) = (from, to) {
+            coefficients[i][j] -= g;
+            coefficients[j][i] -= g;
+        }
+    }
+
+    Ok(System {
+        coefficients: Matrix {
+            mat: coefficients.into_iter().map(Arc::from).collect(),
+            rows: n,
+            cols: n,
+        },
+        constants: Matrix {
+            mat: (0..n_nodes)
+                .filter(|&node| node != ground)
+                .map(|node| Arc::from([injections[node]]))
+                .collect(),
+            rows: n,
+            cols: 1,
+        },
+        variables: None,
+    })
+}
+
+/// Checks that every edge joins two distinct nodes in `0..n_nodes`
+fn check_edges(edges: &[(usize, usize)], n_nodes: usize) -> Result<(), LinalgError> {
+    for (e, &(from, to)) in edges.iter().enumerate() {
+        if let Some(&index) = [from, to].iter().find(|&&node| node >= n_nodes) {
+            return Err(LinalgError::OutOfBounds {
+                index,
+                len: n_nodes,
+            });
+        }
+        if from == to {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Edge {} is a self-loop on node {}!",
+                e, from
+            )));
+        }
+    }
+
+    Ok(())
+}
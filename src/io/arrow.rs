@@ -0,0 +1,243 @@
+use crate::matrix::Matrix;
+use arrow_array::{Array, ArrayRef, Float64Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::Arc;
+
+/// An error produced while converting a `Matrix` to or from Arrow or Parquet
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrowError {
+    /// The matrix has no columns, so a batch cannot record how many rows it has
+    NoColumns,
+    /// `found` column names were given for a matrix with `expected` columns
+    ColumnNames { expected: usize, found: usize },
+    /// A batch has `found` columns where the first batch has `expected`
+    ColumnCount { expected: usize, found: usize },
+    /// The named column holds `data_type` values rather than `Float64`
+    NotFloat64 { column: String, data_type: String },
+    /// The named column contains null values
+    Nulls(String),
+    /// Arrow rejected the data
+    Arrow(String),
+    /// Reading or writing the Parquet file failed
+    Parquet(String),
+    /// The underlying file could not be opened or created
+    Io(String),
+}
+
+impl Display for ArrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrowError::NoColumns => write!(f, "Cannot export a matrix with no columns to Arrow"),
+            ArrowError::ColumnNames { expected, found } => {
+                write!(f, "Expected {} column names but got {}", expected, found)
+            }
+            ArrowError::ColumnCount { expected, found } => write!(
+                f,
+                "Expected {} columns in every batch but found {}",
+                expected, found
+            ),
+            ArrowError::NotFloat64 { column, data_type } => write!(
+                f,
+                "Column `{}` has type {} but Float64 is required",
+                column, data_type
+            ),
+            ArrowError::Nulls(column) => write!(f, "Column `{}` contains null values", column),
+            ArrowError::Arrow(message) => write!(f, "Arrow error: {}", message),
+            ArrowError::Parquet(message) => write!(f, "Parquet error: {}", message),
+            ArrowError::Io(message) => write!(f, "I/O error: {}", message),
+        }
+    }
+}
+
+impl Error for ArrowError {}
+
+impl From<arrow_schema::ArrowError> for ArrowError {
+    fn from(error: arrow_schema::ArrowError) -> ArrowError {
+        ArrowError::Arrow(error.to_string())
+    }
+}
+
+impl From<std::io::Error> for ArrowError {
+    fn from(error: std::io::Error) -> ArrowError {
+        ArrowError::Io(error.to_string())
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for ArrowError {
+    fn from(error: parquet::errors::ParquetError) -> ArrowError {
+        ArrowError::Parquet(error.to_string())
+    }
+}
+
+/// Converts a `Matrix<f64>` into an Arrow `RecordBatch` with one `Float64` column per
+/// matrix column
+///
+/// The export is column-major: each Arrow column holds one contiguous matrix column, which
+/// is the natural layout for columnar pipelines
+///
+/// ### Parameters
+/// - `matrix` - The `Matrix` to export
+/// - `column_names` - Optional names for the Arrow columns; when `None` the columns are
+///   named `c0`, `c1`, and so on
+///
+/// ### Returns
+/// - A `Result` based on whether the batch could be built
+///     - An `Ok` containing the `RecordBatch`
+///     - An `Err` of `ArrowError::NoColumns` if `matrix` has no columns,
+///       `ArrowError::ColumnNames` if the number of names does not match the number of
+///       columns, or `ArrowError::Arrow` if Arrow rejects the batch
+pub fn to_record_batch(
+    matrix: &Matrix<f64>,
+    column_names: Option<&[&str]>,
+) -> Result<RecordBatch, ArrowError> {
+    // A batch without columns cannot record how many rows it has
+    if matrix.cols == 0 {
+        return Err(ArrowError::NoColumns);
+    }
+
+    let names: Vec<String> = match column_names {
+        Some(names) if names.len() != matrix.cols => {
+            return Err(ArrowError::ColumnNames {
+                expected: matrix.cols,
+                found: names.len(),
+            })
+        }
+        Some(names) => names.iter().map(|name| name.to_string()).collect(),
+        None => (0..matrix.cols).map(|c| format!("c{}", c)).collect(),
+    };
+
+    let fields: Vec<Field> = names
+        .iter()
+        .map(|name| Field::new(name, DataType::Float64, false))
+        .collect();
+    let columns: Vec<ArrayRef> = (0..matrix.cols)
+        .map(|c| {
+            let values: Vec<f64> = (0..matrix.rows).map(|r| matrix.mat[r][c]).collect();
+            Arc::new(Float64Array::from(values)) as ArrayRef
+        })
+        .collect();
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+/// Converts an Arrow `RecordBatch` of `Float64` columns into a `Matrix<f64>`
+///
+/// ### Parameters
+/// - `batch` - The `RecordBatch` to import
+///
+/// ### Returns
+/// - A `Result` based on whether every column could be imported
+///     - An `Ok` containing a `Matrix` with one row per batch row and one column per
+///       batch column
+///     - An `Err` of `ArrowError::NotFloat64` if a column is not `Float64`, or
+///       `ArrowError::Nulls` if a column contains nulls
+pub fn from_record_batch(batch: &RecordBatch) -> Result<Matrix<f64>, ArrowError> {
+    from_record_batches(std::slice::from_ref(batch))
+}
+
+/// Converts a sequence of Arrow `RecordBatch` instances sharing one schema into a single
+/// `Matrix<f64>`, stacking their rows in order
+///
+/// ### Parameters
+/// - `batches` - The `RecordBatch` instances to import
+///
+/// ### Returns
+/// - A `Result` based on whether every batch could be imported
+///     - An `Ok` containing the stacked `Matrix`
+///     - An `Err` of `ArrowError::ColumnCount` if the batches have differing column
+///       counts, `ArrowError::NotFloat64` if a column is not `Float64`, or
+///       `ArrowError::Nulls` if a column contains nulls
+pub fn from_record_batches(batches: &[RecordBatch]) -> Result<Matrix<f64>, ArrowError> {
+    let cols = batches.first().map_or(0, |batch| batch.num_columns());
+    let mut mat = Vec::new();
+
+    for batch in batches {
+        if batch.num_columns() != cols {
+            return Err(ArrowError::ColumnCount {
+                expected: cols,
+                found: batch.num_columns(),
+            });
+        }
+
+        let mut columns = Vec::with_capacity(cols);
+        for (c, column) in batch.columns().iter().enumerate() {
+            let values = column
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or_else(|| ArrowError::NotFloat64 {
+                    column: batch.schema().field(c).name().clone(),
+                    data_type: column.data_type().to_string(),
+                })?;
+            if values.null_count() > 0 {
+                return Err(ArrowError::Nulls(batch.schema().field(c).name().clone()));
+            }
+            columns.push(values);
+        }
+
+        for r in 0..batch.num_rows() {
+            let row: Vec<f64> = columns.iter().map(|column| column.value(r)).collect();
+            mat.push(Arc::from(row));
+        }
+    }
+
+    Ok(Matrix {
+        rows: mat.len(),
+        cols,
+        mat,
+    })
+}
+
+/// Writes a `Matrix<f64>` to a Parquet file with one `Float64` column per matrix column
+///
+/// ### Parameters
+/// - `path` - The path of the Parquet file to create or overwrite
+/// - `matrix` - The `Matrix` to write
+/// - `column_names` - Optional column names, as for `to_record_batch`
+///
+/// ### Returns
+/// - A `Result` based on whether the file could be written
+///     - An `Ok` if the file was written
+///     - An `Err` with the same errors as `to_record_batch`, or `ArrowError::Io` or
+///       `ArrowError::Parquet` if the file could not be written
+#[cfg(feature = "parquet")]
+pub fn write_parquet<P: AsRef<std::path::Path>>(
+    path: P,
+    matrix: &Matrix<f64>,
+    column_names: Option<&[&str]>,
+) -> Result<(), ArrowError> {
+    let batch = to_record_batch(matrix, column_names)?;
+    let file = std::fs::File::create(path)?;
+
+    let mut writer = parquet::arrow::ArrowWriter::try_new(file, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+
+    Ok(())
+}
+
+/// Reads a Parquet file whose columns are all `Float64` into a `Matrix<f64>`
+///
+/// ### Parameters
+/// - `path` - The path of the Parquet file to read
+///
+/// ### Returns
+/// - A `Result` based on whether the file could be read
+///     - An `Ok` containing the `Matrix`
+///     - An `Err` of `ArrowError::Io` or `ArrowError::Parquet` if the file could not be
+///       read, or the same errors as `from_record_batches`
+#[cfg(feature = "parquet")]
+pub fn read_parquet<P: AsRef<std::path::Path>>(path: P) -> Result<Matrix<f64>, ArrowError> {
+    let file = std::fs::File::open(path)?;
+    let reader =
+        parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+
+    let batches = reader.collect::<Result<Vec<RecordBatch>, _>>()?;
+
+    from_record_batches(&batches)
+}
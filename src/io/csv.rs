@@ -0,0 +1,175 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An error produced while reading or writing a `Matrix` as CSV
+///
+/// Line and field numbers are 1-based, matching what a spreadsheet or text editor shows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CsvError {
+    /// The underlying reader or writer failed
+    Io(String),
+    /// A field could not be parsed as a number
+    InvalidNumber {
+        line: usize,
+        field: usize,
+        value: String,
+    },
+    /// A row had `found` fields where the first row had `expected`
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Io(message) => write!(f, "CSV I/O error: {}", message),
+            CsvError::InvalidNumber { line, field, value } => write!(
+                f,
+                "Invalid number `{}` in field {} of line {}",
+                value, field, line
+            ),
+            CsvError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} fields but the first row has {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {}
+
+impl From<std::io::Error> for CsvError {
+    fn from(error: std::io::Error) -> CsvError {
+        CsvError::Io(error.to_string())
+    }
+}
+
+impl<T: Number + FromStr> Matrix<T> {
+    /// Reads a `Matrix` from CSV data, one matrix row per line
+    ///
+    /// Fields are trimmed of surrounding whitespace and blank lines are skipped. Quoted
+    /// fields and header rows are not supported, since every field must be a number
+    ///
+    /// ### Parameters
+    /// - `reader` - The source of the CSV text
+    /// - `delimiter` - The character separating fields, such as `,`, `;`, or `\t`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the data is a valid numeric table
+    ///     - An `Ok` containing the parsed `Matrix`
+    ///     - An `Err` of `CsvError::Ragged` naming the first line whose field count differs
+    ///       from the first row, `CsvError::InvalidNumber` for a field that is not a
+    ///       number, or `CsvError::Io` if reading failed
+    pub fn from_csv<R: Read>(reader: R, delimiter: char) -> Result<Matrix<T>, CsvError> {
+        let mut mat: Vec<Arc<[T]>> = Vec::new();
+        let mut cols = None;
+
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let row = line
+                .split(delimiter)
+                .enumerate()
+                .map(|(j, field)| {
+                    field
+                        .trim()
+                        .parse::<T>()
+                        .map_err(|_| CsvError::InvalidNumber {
+                            line: i + 1,
+                            field: j + 1,
+                            value: field.trim().to_string(),
+                        })
+                })
+                .collect::<Result<Vec<T>, CsvError>>()?;
+
+            let expected = *cols.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(CsvError::Ragged {
+                    line: i + 1,
+                    expected,
+                    found: row.len(),
+                });
+            }
+            mat.push(Arc::from(row));
+        }
+
+        Ok(Matrix {
+            rows: mat.len(),
+            cols: cols.unwrap_or(0),
+            mat,
+        })
+    }
+
+    /// Reads a `Matrix` from the CSV file at `path`
+    ///
+    /// ### Parameters
+    /// - `path` - The path to the CSV file
+    /// - `delimiter` - The character separating fields
+    ///
+    /// ### Returns
+    /// - The same `Result` as `from_csv`, or an `Err` of `CsvError::Io` if the file could
+    ///   not be opened
+    pub fn from_csv_file<P: AsRef<Path>>(path: P, delimiter: char) -> Result<Matrix<T>, CsvError> {
+        Matrix::from_csv(File::open(path)?, delimiter)
+    }
+}
+
+impl<T: Number> Matrix<T> {
+    /// Writes this `Matrix` as CSV, one matrix row per line
+    ///
+    /// Elements are written with their `Display` form, so `from_csv` reads the output
+    /// back unchanged
+    ///
+    /// ### Parameters
+    /// - `writer` - The destination for the CSV text
+    /// - `delimiter` - The character separating fields, such as `,`, `;`, or `\t`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the data could be written, with an `Err` of
+    ///   `CsvError::Io` if writing failed
+    pub fn to_csv<W: Write>(&self, writer: W, delimiter: char) -> Result<(), CsvError> {
+        let mut writer = BufWriter::new(writer);
+        for row in &self.mat {
+            for (j, x) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(writer, "{}", delimiter)?;
+                }
+                write!(writer, "{}", x)?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(writer.flush()?)
+    }
+
+    /// Writes this `Matrix` as CSV to the file at `path`
+    ///
+    /// ### Parameters
+    /// - `path` - The path of the CSV file to create or overwrite
+    /// - `delimiter` - The character separating fields
+    ///
+    /// ### Returns
+    /// - The same `Result` as `to_csv`, or an `Err` of `CsvError::Io` if the file could not
+    ///   be created
+    pub fn to_csv_file<P: AsRef<Path>>(&self, path: P, delimiter: char) -> Result<(), CsvError> {
+        self.to_csv(File::create(path)?, delimiter)
+    }
+}
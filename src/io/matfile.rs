@@ -0,0 +1,434 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use num::ToPrimitive;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+const HEADER_LEN: usize = 128;
+const HEADER_TEXT_LEN: usize = 116;
+
+const MI_INT8: u32 = 1;
+const MI_UINT8: u32 = 2;
+const MI_INT16: u32 = 3;
+const MI_UINT16: u32 = 4;
+const MI_INT32: u32 = 5;
+const MI_UINT32: u32 = 6;
+const MI_SINGLE: u32 = 7;
+const MI_DOUBLE: u32 = 9;
+const MI_INT64: u32 = 12;
+const MI_UINT64: u32 = 13;
+const MI_MATRIX: u32 = 14;
+const MI_COMPRESSED: u32 = 15;
+
+const MX_DOUBLE_CLASS: u8 = 6;
+const MX_UINT64_CLASS: u8 = 15;
+const COMPLEX_FLAG: u8 = 0x08;
+
+/// The most rows accepted for a variable with no columns, which would otherwise let a
+/// tiny, malformed file request billions of empty rows
+const MAX_EMPTY_ROWS: usize = 1 << 20;
+
+/// An error produced while reading or writing a Level 5 MAT-file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MatError {
+    /// The underlying reader or writer failed
+    Io(String),
+    /// The bytes do not start with a Level 5 MAT-file header
+    NotMatFile,
+    /// The file ended in the middle of a data element
+    Truncated,
+    /// The file contains a compressed variable, which is not supported
+    Compressed,
+    /// A data element has a numeric type this reader does not know
+    UnsupportedDataType(u32),
+    /// A data element is structurally invalid
+    Malformed(String),
+    /// A variable name is empty or longer than 63 bytes
+    InvalidName(String),
+    /// Element (`row`, `col`) of the variable `name` cannot be represented as an `f64`
+    NotRepresentable {
+        name: String,
+        row: usize,
+        col: usize,
+    },
+}
+
+impl Display for MatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatError::Io(message) => write!(f, "MAT-file I/O error: {}", message),
+            MatError::NotMatFile => write!(f, "Not a Level 5 MAT-file"),
+            MatError::Truncated => write!(f, "Unexpected end of MAT-file"),
+            MatError::Compressed => write!(
+                f,
+                "Compressed MAT-file variables are not supported; \
+                save the file uncompressed (MATLAB -v6)"
+            ),
+            MatError::UnsupportedDataType(data_type) => {
+                write!(f, "Unsupported MAT-file data type {}", data_type)
+            }
+            MatError::Malformed(message) => write!(f, "Malformed MAT-file: {}", message),
+            MatError::InvalidName(name) => write!(
+                f,
+                "Invalid MAT-file variable name `{}`; names must be 1 to 63 bytes",
+                name
+            ),
+            MatError::NotRepresentable { name, row, col } => write!(
+                f,
+                "Element ({}, {}) of `{}` is not representable as f64",
+                row, col, name
+            ),
+        }
+    }
+}
+
+impl Error for MatError {}
+
+impl From<std::io::Error> for MatError {
+    fn from(error: std::io::Error) -> MatError {
+        MatError::Io(error.to_string())
+    }
+}
+
+/// A named 2-D numeric variable read from or written to a MAT-file
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatVariable {
+    /// The variable name as stored in the MAT-file
+    pub name: String,
+
+    /// The variable's values converted to `f64`
+    pub matrix: Matrix<f64>,
+}
+
+/// Reads every 2-D real numeric variable from a Level 5 MAT-file
+///
+/// Numeric arrays of any storage class (`double`, `single`, and the integer classes) are
+/// converted to `Matrix<f64>`. Variables that are not 2-D real numeric arrays (cells,
+/// structs, character arrays, sparse or complex arrays, N-D arrays) are skipped
+///
+/// Compressed variables, which MATLAB writes by default since v7, are not supported; save
+/// with `save -v6` in MATLAB or `save -6` in Octave to produce uncompressed files
+///
+/// ### Parameters
+/// - `reader` - The source of the MAT-file bytes
+///
+/// ### Returns
+/// - A `Result` based on whether the file could be read
+///     - An `Ok` containing every supported variable in file order
+///     - An `Err` of `MatError::NotMatFile` if the file is not a Level 5 MAT-file,
+///       `MatError::Truncated` or `MatError::Malformed` if it is damaged,
+///       `MatError::Compressed` if it contains a compressed variable, or `MatError::Io`
+///       if reading failed
+pub fn read<R: Read>(mut reader: R) -> Result<Vec<MatVariable>, MatError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    if bytes.len() < HEADER_LEN {
+        return Err(MatError::NotMatFile);
+    }
+    let big_endian = match &bytes[126..128] {
+        b"IM" => false,
+        b"MI" => true,
+        _ => return Err(MatError::NotMatFile),
+    };
+
+    let mut parser = Parser {
+        bytes: &bytes,
+        pos: HEADER_LEN,
+        big_endian,
+    };
+    let mut variables = Vec::new();
+
+    while parser.pos < bytes.len() {
+        let (data_type, data) = parser.element()?;
+        match data_type {
+            MI_MATRIX => {
+                if let Some(variable) = parse_matrix(data, big_endian)? {
+                    variables.push(variable);
+                }
+            }
+            MI_COMPRESSED => return Err(MatError::Compressed),
+            _ => {}
+        }
+    }
+
+    Ok(variables)
+}
+
+/// Reads every 2-D real numeric variable from the Level 5 MAT-file at `path`
+///
+/// ### Parameters
+/// - `path` - The path to the MAT-file
+///
+/// ### Returns
+/// - The same `Result` as `read`, or an `Err` of `MatError::Io` if the file could not
+///   be opened
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Vec<MatVariable>, MatError> {
+    let file = File::open(path)?;
+    read(BufReader::new(file))
+}
+
+/// Writes named matrices as `double` variables into an uncompressed Level 5 MAT-file
+///
+/// ### Parameters
+/// - `writer` - The destination for the MAT-file bytes
+/// - `variables` - Pairs of variable names and the `Matrix` to store under each name
+///
+/// ### Returns
+/// - A `Result` based on whether the file could be written
+///     - An `Ok` if every variable was written
+///     - An `Err` of `MatError::InvalidName` if a name is empty or too long,
+///       `MatError::NotRepresentable` if an element cannot be represented as an `f64`,
+///       or `MatError::Io` if an I/O error occurred
+pub fn write<W: Write, T: Number + ToPrimitive>(
+    mut writer: W,
+    variables: &[(&str, &Matrix<T>)],
+) -> Result<(), MatError> {
+    let mut header = format!(
+        "MATLAB 5.0 MAT-file, Platform: {}, Created by: linalgrs",
+        std::env::consts::OS
+    )
+    .into_bytes();
+    header.resize(HEADER_TEXT_LEN, b' ');
+    header.extend_from_slice(&[0; 8]);
+    header.extend_from_slice(&0x0100u16.to_le_bytes());
+    header.extend_from_slice(b"IM");
+
+    let mut out = header;
+    for (name, matrix) in variables {
+        if name.is_empty() || name.len() > 63 {
+            return Err(MatError::InvalidName(name.to_string()));
+        }
+
+        let mut body = Vec::new();
+        push_element(
+            &mut body,
+            MI_UINT32,
+            &[MX_DOUBLE_CLASS, 0, 0, 0, 0, 0, 0, 0],
+        );
+
+        let dims = [matrix.rows as i32, matrix.cols as i32];
+        let dims: Vec<u8> = dims.iter().flat_map(|d| d.to_le_bytes()).collect();
+        push_element(&mut body, MI_INT32, &dims);
+        push_element(&mut body, MI_INT8, name.as_bytes());
+
+        let mut real = Vec::with_capacity(matrix.rows * matrix.cols * 8);
+        for c in 0..matrix.cols {
+            for r in 0..matrix.rows {
+                let value =
+                    matrix.mat[r][c]
+                        .to_f64()
+                        .ok_or_else(|| MatError::NotRepresentable {
+                            name: name.to_string(),
+                            row: r,
+                            col: c,
+                        })?;
+                real.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        push_element(&mut body, MI_DOUBLE, &real);
+
+        push_element(&mut out, MI_MATRIX, &body);
+    }
+
+    writer.write_all(&out)?;
+    Ok(())
+}
+
+/// Writes named matrices into an uncompressed Level 5 MAT-file at `path`
+///
+/// ### Parameters
+/// - `path` - The path of the MAT-file to create or overwrite
+/// - `variables` - Pairs of variable names and the `Matrix` to store under each name
+///
+/// ### Returns
+/// - The same `Result` as `write`, or an `Err` of `MatError::Io` if the file could not
+///   be created
+pub fn write_file<P: AsRef<Path>, T: Number + ToPrimitive>(
+    path: P,
+    variables: &[(&str, &Matrix<T>)],
+) -> Result<(), MatError> {
+    let file = File::create(path)?;
+    let mut writer = BufWriter::new(file);
+    write(&mut writer, variables)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Appends a data element with its tag, using the small data element format for
+/// payloads of 4 bytes or fewer just like MATLAB does
+fn push_element(out: &mut Vec<u8>, data_type: u32, data: &[u8]) {
+    if !data.is_empty() && data.len() <= 4 && data_type != MI_MATRIX {
+        out.extend_from_slice(&(((data.len() as u32) << 16) | data_type).to_le_bytes());
+        out.extend_from_slice(data);
+        out.resize(out.len() + 4 - data.len(), 0);
+        return;
+    }
+
+    out.extend_from_slice(&data_type.to_le_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    out.resize(out.len() + (8 - data.len() % 8) % 8, 0);
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    big_endian: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn u32_at(&self, pos: usize) -> Result<u32, MatError> {
+        let raw: [u8; 4] = self
+            .bytes
+            .get(pos..pos + 4)
+            .ok_or(MatError::Truncated)?
+            .try_into()
+            .expect("slice has four bytes");
+
+        Ok(if self.big_endian {
+            u32::from_be_bytes(raw)
+        } else {
+            u32::from_le_bytes(raw)
+        })
+    }
+
+    /// Reads the next data element, returning its type and payload
+    fn element(&mut self) -> Result<(u32, &'a [u8]), MatError> {
+        let first = self.u32_at(self.pos)?;
+
+        if first >> 16 != 0 {
+            let len = (first >> 16) as usize;
+            if len > 4 {
+                return Err(MatError::Malformed(
+                    "small data element longer than 4 bytes".to_string(),
+                ));
+            }
+            let start = self.pos + 4;
+            self.pos += 8;
+            let data = self
+                .bytes
+                .get(start..start + len)
+                .ok_or(MatError::Truncated)?;
+            return Ok((first & 0xFFFF, data));
+        }
+
+        let len = self.u32_at(self.pos + 4)? as usize;
+        let start = self.pos + 8;
+        let data = self
+            .bytes
+            .get(start..start + len)
+            .ok_or(MatError::Truncated)?;
+
+        // Compressed elements are not padded to an 8 byte boundary
+        self.pos = if first == MI_COMPRESSED {
+            start + len
+        } else {
+            start + len.div_ceil(8) * 8
+        };
+        Ok((first, data))
+    }
+}
+
+fn parse_matrix(data: &[u8], big_endian: bool) -> Result<Option<MatVariable>, MatError> {
+    let mut parser = Parser {
+        bytes: data,
+        pos: 0,
+        big_endian,
+    };
+
+    // An empty miMATRIX element is how MATLAB stores some placeholder values
+    if data.is_empty() {
+        return Ok(None);
+    }
+
+    let (_, flags) = parser.element()?;
+    if flags.len() < 8 {
+        return Err(MatError::Malformed(
+            "array flags shorter than 8 bytes".to_string(),
+        ));
+    }
+    let (class, flag_bits) = if big_endian {
+        (flags[3], flags[2])
+    } else {
+        (flags[0], flags[1])
+    };
+
+    let (dims_type, dims) = parser.element()?;
+    let dims = decode(dims_type, dims, big_endian)?;
+    let (_, name) = parser.element()?;
+    let name = String::from_utf8_lossy(name).into_owned();
+
+    let numeric = (MX_DOUBLE_CLASS..=MX_UINT64_CLASS).contains(&class);
+    if !numeric || flag_bits & COMPLEX_FLAG != 0 || dims.len() != 2 {
+        return Ok(None);
+    }
+
+    let (rows, cols) = (dims[0] as usize, dims[1] as usize);
+    if cols == 0 && rows > MAX_EMPTY_ROWS {
+        return Err(MatError::Malformed(format!(
+            "variable `{}` claims {} empty rows; refusing to allocate them",
+            name, rows
+        )));
+    }
+    let (real_type, real) = parser.element()?;
+    let values = decode(real_type, real, big_endian)?;
+    if values.len() != rows * cols {
+        return Err(MatError::Malformed(format!(
+            "variable `{}` has {} values but its dimensions are {}x{}",
+            name,
+            values.len(),
+            rows,
+            cols
+        )));
+    }
+
+    let mat = (0..rows)
+        .map(|r| {
+            let row: Vec<f64> = (0..cols).map(|c| values[c * rows + r]).collect();
+            Arc::from(row)
+        })
+        .collect();
+
+    Ok(Some(MatVariable {
+        name,
+        matrix: Matrix { mat, rows, cols },
+    }))
+}
+
+/// Decodes a numeric data element payload into `f64` values
+fn decode(data_type: u32, data: &[u8], big_endian: bool) -> Result<Vec<f64>, MatError> {
+    macro_rules! convert {
+        ($t:ty) => {
+            data.chunks_exact(std::mem::size_of::<$t>())
+                .map(|chunk| {
+                    let raw = chunk.try_into().expect("chunk has the element size");
+                    let value = if big_endian {
+                        <$t>::from_be_bytes(raw)
+                    } else {
+                        <$t>::from_le_bytes(raw)
+                    };
+                    value as f64
+                })
+                .collect()
+        };
+    }
+
+    Ok(match data_type {
+        MI_INT8 => convert!(i8),
+        MI_UINT8 => convert!(u8),
+        MI_INT16 => convert!(i16),
+        MI_UINT16 => convert!(u16),
+        MI_INT32 => convert!(i32),
+        MI_UINT32 => convert!(u32),
+        MI_SINGLE => convert!(f32),
+        MI_DOUBLE => convert!(f64),
+        MI_INT64 => convert!(i64),
+        MI_UINT64 => convert!(u64),
+        _ => return Err(MatError::UnsupportedDataType(data_type)),
+    })
+}
@@ -0,0 +1,8 @@
+pub mod csv;
+pub mod matfile;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "serde")]
+mod serde;
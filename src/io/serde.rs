@@ -0,0 +1,52 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use ::serde::de::Error;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::Arc;
+
+/// The serialized form of a `Matrix`: its shape plus its rows as a nested array
+#[derive(Serialize)]
+#[serde(rename = "Matrix")]
+struct MatrixRef<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<&'a [T]>,
+}
+
+/// The owned counterpart of `MatrixRef`, checked against its shape before it becomes a
+/// `Matrix`
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixData<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<Vec<T>>,
+}
+
+impl<T: Number + Serialize> Serialize for Matrix<T> {
+    /// Serializes a `Matrix` as `{ "rows": .., "cols": .., "data": [[..], ..] }`
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRef {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.mat.iter().map(|row| &row[..]).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Number + Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    /// Deserializes a `Matrix` written by `Serialize`, failing if `data` does not have
+    /// `rows` rows of `cols` elements each
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Matrix<T>, D::Error> {
+        let MatrixData { rows, cols, data } = MatrixData::deserialize(deserializer)?;
+        let matrix = Matrix {
+            mat: data.into_iter().map(Arc::from).collect(),
+            rows,
+            cols,
+        };
+        matrix.validate().map_err(D::Error::custom)?;
+
+        Ok(matrix)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::slice;
+use std::sync::Arc;
+
+/// A row-major iterator over references to the elements of a `Matrix`, created by
+/// `Matrix::iter`
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T> {
+    rows: slice::Iter<'a, Arc<[T]>>,
+    row: slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(x) = self.row.next() {
+                self.remaining -= 1;
+                return Some(x);
+            }
+            self.row = self.rows.next()?.iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: Number> Matrix<T> {
+    /// Iterates over the elements of this `Matrix` in row-major order
+    ///
+    /// ### Returns
+    /// - An iterator yielding a reference to every element, row by row
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            rows: self.mat.iter(),
+            row: [].iter(),
+            remaining: self.mat.iter().map(|row| row.len()).sum(),
+        }
+    }
+
+    /// Iterates over the rows of this `Matrix`, from top to bottom
+    ///
+    /// ### Returns
+    /// - An iterator yielding each row as a `&[T]` slice
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        self.mat.iter().map(|row| &row[..])
+    }
+
+    /// Iterates over copies of the columns of this `Matrix`, from left to right; the same
+    /// as `columns`
+    ///
+    /// ### Returns
+    /// - An iterator yielding each column as a `Vec<T>`
+    pub fn iter_cols(&self) -> impl ExactSizeIterator<Item = Vec<T>> + '_ {
+        self.columns()
+    }
+
+    /// Iterates over the elements of this `Matrix` in row-major order along with their
+    /// positions
+    ///
+    /// ### Returns
+    /// - An iterator yielding `(row, col, value)` for every element
+    pub fn enumerate_elements(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        self.mat
+            .iter()
+            .enumerate()
+            .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, &x)| (r, c, x)))
+    }
+}
+
+impl<'a, T: Number> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
@@ -0,0 +1,146 @@
+use crate::context::ComputeContext;
+use crate::error::LinalgError;
+use crate::number::Number;
+use crate::sparse::SparseMatrix;
+
+/// The result of an iterative solver: the solution plus how it was reached
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeSolution<T: Number> {
+    /// The solution `x` of `Ax = b`
+    pub solution: Vec<T>,
+
+    /// The number of iterations taken
+    pub iterations: usize,
+
+    /// The final relative residual `‖b − Ax‖ / ‖b‖`
+    pub residual: T,
+}
+
+/// Solves the symmetric positive definite system `Ax = b` by the
+/// [conjugate gradient method](https://en.wikipedia.org/wiki/Conjugate_gradient_method)
+///
+/// Each iteration costs one sparse product and a few vector updates, so large sparse
+/// systems such as `pde::poisson_2d` are solved without ever forming a dense matrix.
+/// Starts from `x = 0` and stops once the relative residual is at most `tol`
+///
+/// ### Parameters
+/// - `a` - The `n x n` symmetric positive definite coefficient `SparseMatrix`
+/// - `b` - The right-hand side, with `n` elements
+/// - `tol` - The relative residual `‖b − Ax‖ / ‖b‖` to reach, greater than `0`
+/// - `max_iter` - The most iterations to take; `n` suffice in exact arithmetic
+///
+/// ### Returns
+/// - A `Result` based on whether the solver converged
+///     - An `Ok` containing the `IterativeSolution`
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::LengthMismatch` if `b` does not have `n` elements,
+///       `LinalgError::InvalidArgument` if `tol` is not positive,
+///       `LinalgError::NotPositiveDefinite` if the iteration finds a direction of
+///       non-positive curvature, or `LinalgError::NotConverged` if `max_iter` iterations
+///       were not enough
+pub fn conjugate_gradient<T>(
+    a: &SparseMatrix<T>,
+    b: &[T],
+    tol: T,
+    max_iter: usize,
+) -> Result<IterativeSolution<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    conjugate_gradient_with_context(a, b, tol, max_iter, &ComputeContext::new())
+}
+
+/// Solves `Ax = b` like `conjugate_gradient`, but can be interrupted through `ctx`
+///
+/// `ctx` is checked once per iteration, and each iteration is reported to it with the
+/// current relative residual
+///
+/// ### Parameters
+/// - `a` - The `n x n` symmetric positive definite coefficient `SparseMatrix`
+/// - `b` - The right-hand side, with `n` elements
+/// - `tol` - The relative residual to reach, greater than `0`
+/// - `max_iter` - The most iterations to take
+/// - `ctx` - The `ComputeContext` that can interrupt the computation
+///
+/// ### Returns
+/// - A `Result` containing the `IterativeSolution`, or an `Err` with
+///   `LinalgError::Cancelled`, `LinalgError::DeadlineExceeded`, or one of the failures
+///   described in `conjugate_gradient`
+pub fn conjugate_gradient_with_context<T>(
+    a: &SparseMatrix<T>,
+    b: &[T],
+    tol: T,
+    max_iter: usize,
+    ctx: &ComputeContext,
+) -> Result<IterativeSolution<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if b.len() != n {
+        return Err(LinalgError::LengthMismatch {
+            expected: n,
+            found: b.len(),
+        });
+    }
+    if tol.is_nan() || tol <= T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The tolerance must be positive!".to_string(),
+        ));
+    }
+
+    let dot = |u: &[T], v: &[T]| u.iter().zip(v).fold(T::zero(), |sum, (&p, &q)| sum + p * q);
+    let b_norm = dot(b, b).sqrt();
+    let mut x = vec![T::zero(); n];
+    if b_norm == T::zero() {
+        return Ok(IterativeSolution {
+            solution: x,
+            iterations: 0,
+            residual: T::zero(),
+        });
+    }
+
+    let mut r = b.to_vec();
+    let mut p = r.clone();
+    let mut rs = dot(&r, &r);
+    for iteration in 1..=max_iter {
+        ctx.check()?;
+        let ap = a.mul_vec(&p)?;
+        let curvature = dot(&p, &ap);
+        if curvature <= T::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+
+        let alpha = rs / curvature;
+        for i in 0..n {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * ap[i];
+        }
+        let rs_next = dot(&r, &r);
+        let residual = rs_next.sqrt() / b_norm;
+        ctx.report(iteration, residual.to_f64().unwrap_or(f64::NAN));
+        if residual <= tol {
+            return Ok(IterativeSolution {
+                solution: x,
+                iterations: iteration,
+                residual,
+            });
+        }
+
+        let beta = rs_next / rs;
+        for i in 0..n {
+            p[i] = r[i] + beta * p[i];
+        }
+        rs = rs_next;
+    }
+
+    Err(LinalgError::NotConverged {
+        iterations: max_iter,
+    })
+}
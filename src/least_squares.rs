@@ -0,0 +1,446 @@
+use crate::arith;
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::transform;
+use crate::vector::Vector;
+use std::collections::VecDeque;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// Finds the `x` minimizing `‖Ax − b‖` using a QR decomposition of `a`
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix` with `m >= n`
+/// - `b` - An `m x 1` column `Matrix`
+///
+/// ### Returns
+/// - A `Result` based on whether the problem has a unique solution
+///     - An `Ok` containing the `n x 1` least squares solution
+///     - An `Err` of `LinalgError::ShapeMismatch` if `b` is not an `m x 1` column,
+///       `LinalgError::NonFinite` if either input holds a NaN or infinity, or
+///       `LinalgError::RankDeficient` if `a` does not have full column rank
+pub fn least_squares<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_inputs(a, b)?;
+
+    Ok(Vector::new(solve_full_rank(a, &column(b))?).to_column_matrix())
+}
+
+/// Finds the `x` minimizing `‖Ax − b‖` subject to the equality constraints `Cx = d`
+///
+/// Uses the [null-space method](https://en.wikipedia.org/wiki/Constrained_least_squares):
+/// a QR decomposition of `Cᵀ` splits `x` into a part fixed by the constraints and a part
+/// free to move within the null space of `C`, and the free part is then fitted by ordinary
+/// least squares. This avoids forming the often ill-conditioned KKT system
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix`
+/// - `b` - An `m x 1` column `Matrix`
+/// - `c` - A `p x n` constraint `Matrix` with `p <= n`
+/// - `d` - A `p x 1` column `Matrix` of constraint values
+///
+/// ### Returns
+/// - A `Result` based on whether the problem has a unique solution
+///     - An `Ok` containing the `n x 1` solution, which satisfies `Cx = d` up to rounding
+///     - An `Err` of `LinalgError::ShapeMismatch` if the shapes disagree,
+///       `LinalgError::NonFinite` if any input holds a NaN or infinity, or
+///       `LinalgError::RankDeficient` if `c` does not have full row rank or `a` does not
+///       have full column rank on the null space of `c`
+#[allow(clippy::needless_range_loop)]
+pub fn least_squares_constrained<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    c: &Matrix<T>,
+    d: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_inputs(a, b)?;
+    check_inputs(c, d)?;
+    let (n, p) = (a.cols, c.rows);
+    if c.cols != n {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (p, n),
+            found: (c.rows, c.cols),
+        });
+    }
+    if p == 0 {
+        return least_squares(a, b);
+    }
+    if p > n {
+        return Err(LinalgError::RankDeficient);
+    }
+
+    // Cᵀ = QR, so C = R₁ᵀQ₁ᵀ and x = Q₁y₁ + Q₂y₂ with R₁ᵀy₁ = d fixing the constrained part
+    let (q, r) = decompose::qr_decomposition(&transform::transpose(c))?;
+    let tol = rank_tolerance(&r, p);
+    let d = column(d);
+    let mut y1 = vec![T::zero(); p];
+    for i in 0..p {
+        if r.mat[i][i].abs() <= tol {
+            return Err(LinalgError::RankDeficient);
+        }
+        let mut sum = d[i];
+        for k in 0..i {
+            sum -= r.mat[k][i] * y1[k];
+        }
+        y1[i] = sum / r.mat[i][i];
+    }
+
+    let mut x: Vec<T> = (0..n)
+        .map(|i| (0..p).fold(T::zero(), |sum, k| sum + q.mat[i][k] * y1[k]))
+        .collect();
+    if p == n {
+        return Ok(Vector::new(x).to_column_matrix());
+    }
+
+    // Fit the free part: minimize ‖(AQ₂)y₂ − (b − Ax₁)‖
+    let q2 = q.sub_matrix(0..n, p..n)?;
+    let aq2 = arith::multiply(a, &q2)?;
+    let ax1 = arith::multiply(a, &Vector::new(x.clone()).to_column_matrix())?;
+    let rhs: Vec<T> = column(b)
+        .iter()
+        .zip(column(&ax1))
+        .map(|(&bi, axi)| bi - axi)
+        .collect();
+    let y2 = solve_full_rank(&aq2, &rhs)?;
+
+    for i in 0..n {
+        for k in 0..n - p {
+            x[i] += q2.mat[i][k] * y2[k];
+        }
+    }
+
+    Ok(Vector::new(x).to_column_matrix())
+}
+
+/// Finds the total least squares solution of `Ax ≈ b`, which allows for errors in both `a`
+/// and `b`
+///
+/// Ordinary least squares assumes `a` is exact and only `b` is noisy. [Total least
+/// squares](https://en.wikipedia.org/wiki/Total_least_squares) instead finds the smallest
+/// perturbation of `[A b]` that makes the system consistent, which for a single regressor
+/// is orthogonal distance regression. It is read off the right singular vector of `[A b]`
+/// belonging to the smallest singular value
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix` with `m >= n`
+/// - `b` - An `m x 1` column `Matrix`
+///
+/// ### Returns
+/// - A `Result` based on whether the problem has a solution
+///     - An `Ok` containing the `n x 1` total least squares solution
+///     - An `Err` of `LinalgError::ShapeMismatch` if `b` is not an `m x 1` column,
+///       `LinalgError::NonFinite` if either input holds a NaN or infinity,
+///       `LinalgError::RankDeficient` if `m < n`, or `LinalgError::NoSolution` if the best
+///       fit is parallel to `b`, so no finite `x` attains it
+pub fn total_least_squares<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_inputs(a, b)?;
+    let n = a.cols;
+    if a.rows < n {
+        return Err(LinalgError::RankDeficient);
+    }
+
+    let augmented = Matrix {
+        mat: a
+            .mat
+            .iter()
+            .zip(&b.mat)
+            .map(|(row, bi)| row.iter().chain(bi.iter()).copied().collect())
+            .collect(),
+        rows: a.rows,
+        cols: n + 1,
+    };
+    let (_, _, v) = decompose::svd(&augmented)?;
+
+    // The singular values are sorted, so the last column of V belongs to the smallest
+    let last = v.mat[n][n];
+    let size = T::from(n + 1).unwrap_or_else(T::one);
+    if last.abs() <= size * T::epsilon() {
+        return Err(LinalgError::NoSolution);
+    }
+
+    Ok(Vector::new((0..n).map(|i| -v.mat[i][n] / last).collect()).to_column_matrix())
+}
+
+/// Finds the `x` minimizing `‖Ax − b‖` for a very tall `a` using `decompose::tsqr`
+///
+/// The augmented matrix `[A | b]` is reduced by TSQR to an (n + 1) x (n + 1) triangle
+/// whose leading n x n block is `R` and whose last column holds `Qᵀb`, so `Q` is never
+/// formed and memory stays proportional to one block of rows. Prefer this over
+/// `least_squares` once `a` has many thousands of rows
+///
+/// ### Parameters
+/// - `a` - An `m x n` `Matrix` with `m >= n`
+/// - `b` - An `m x 1` column `Matrix`
+/// - `block_rows` - The rows per TSQR block, at least n + 1
+///
+/// ### Returns
+/// - A `Result` based on whether the problem has a unique solution
+///     - An `Ok` containing the `n x 1` least squares solution
+///     - An `Err` of `LinalgError::ShapeMismatch` if `b` is not an `m x 1` column,
+///       `LinalgError::NonFinite` if either input holds a NaN or infinity,
+///       `LinalgError::InvalidArgument` if `block_rows` is at most n, or
+///       `LinalgError::RankDeficient` if `a` does not have full column rank
+#[allow(clippy::needless_range_loop)]
+pub fn least_squares_tsqr<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    block_rows: usize,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_inputs(a, b)?;
+    let n = a.cols;
+    if n == 0 {
+        return Ok(Matrix::zeros(0, 1));
+    }
+    if a.rows < n {
+        return Err(LinalgError::RankDeficient);
+    }
+
+    let augmented = transform::hstack(&[a, b])?;
+    let r = decompose::tsqr(&augmented, block_rows)?;
+    let tol = rank_tolerance(&r, n);
+
+    let mut x = vec![T::zero(); n];
+    for i in (0..n).rev() {
+        if r.mat[i][i].abs() <= tol {
+            return Err(LinalgError::RankDeficient);
+        }
+        let mut sum = r.mat[i][n];
+        for k in (i + 1)..n {
+            sum -= r.mat[i][k] * x[k];
+        }
+        x[i] = sum / r.mat[i][i];
+    }
+
+    Ok(Vector::new(x).to_column_matrix())
+}
+
+/// A least squares problem `min ‖Ax − b‖` whose rows can be added and removed one at a
+/// time, as for regression over a sliding window
+///
+/// Keeps the (n + 1) x (n + 1) triangular factor of the augmented matrix `[A | b]`, whose
+/// leading n x n block is `R` and whose last column holds `Qᵀb`. `add_row` folds a row in
+/// with n + 1 Givens rotations and `remove_row` takes one out with the LINPACK Cholesky
+/// downdate, so each costs O(n²) rather than the O(mn²) of a fresh QR. The rows themselves
+/// are kept so that a removal can be replayed when a downdate would lose too much accuracy
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncrementalQr<T: Number> {
+    /// The augmented triangle, stored by rows
+    r: Vec<Vec<T>>,
+
+    /// Every row currently in the problem, with its right-hand side appended
+    rows: VecDeque<Vec<T>>,
+}
+
+impl<T> IncrementalQr<T>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    /// Creates an empty `IncrementalQr` for problems with `n` unknowns
+    pub fn new(n: usize) -> IncrementalQr<T> {
+        IncrementalQr {
+            r: vec![vec![T::zero(); n + 1]; n + 1],
+            rows: VecDeque::new(),
+        }
+    }
+
+    /// Gets the number of unknowns
+    pub fn cols(&self) -> usize {
+        self.r.len() - 1
+    }
+
+    /// Gets the number of rows currently in the problem
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Checks whether the problem has no rows
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Appends the equation `row · x = value` to the problem
+    ///
+    /// ### Parameters
+    /// - `row` - The n coefficients of the new row of `A`
+    /// - `value` - The matching element of `b`
+    ///
+    /// ### Returns
+    /// - A `Result` that is `Ok` once the row is added, or an `Err` of
+    ///   `LinalgError::LengthMismatch` if `row` does not hold n elements or
+    ///   `LinalgError::NonFinite` if it or `value` is a NaN or infinity
+    pub fn add_row(&mut self, row: &[T], value: T) -> Result<(), LinalgError> {
+        let n = self.cols();
+        if row.len() != n {
+            return Err(LinalgError::LengthMismatch {
+                expected: n,
+                found: row.len(),
+            });
+        }
+        if let Some(col) = row.iter().chain([&value]).position(|x| !x.is_finite()) {
+            return Err(LinalgError::NonFinite {
+                row: self.len(),
+                col,
+            });
+        }
+
+        let _span = Span::new("incremental_qr_add_row", 3 * (n + 1) * (n + 1), 1);
+        let augmented: Vec<T> = row.iter().copied().chain([value]).collect();
+        decompose::rank_one_update(&mut self.r, augmented.clone());
+        self.rows.push_back(augmented);
+
+        Ok(())
+    }
+
+    /// Removes row `i`, counting from the oldest row still in the problem
+    ///
+    /// ### Parameters
+    /// - `i` - The index of the row to remove
+    ///
+    /// ### Returns
+    /// - A `Result` containing the removed coefficients and right-hand side, or an `Err` of
+    ///   `LinalgError::OutOfBounds` if there is no row `i`
+    pub fn remove_row(&mut self, i: usize) -> Result<(Vec<T>, T), LinalgError> {
+        let mut removed = self.rows.remove(i).ok_or(LinalgError::OutOfBounds {
+            index: i,
+            len: self.len(),
+        })?;
+        let n = self.cols();
+        let _span = Span::new("incremental_qr_remove_row", 4 * (n + 1) * (n + 1), 2);
+
+        let min_slack = T::from(n + 1).unwrap_or_else(T::one) * T::epsilon().sqrt();
+        if !decompose::rank_one_downdate(&mut self.r, &removed, min_slack) {
+            // The downdate would cancel away most of R, so rebuild it from what is left
+            self.r = vec![vec![T::zero(); n + 1]; n + 1];
+            for k in 0..self.rows.len() {
+                decompose::rank_one_update(&mut self.r, self.rows[k].clone());
+            }
+        }
+
+        let value = removed.pop().unwrap_or_else(T::zero);
+        Ok((removed, value))
+    }
+
+    /// Gets the current n x n upper triangular factor `R` of `A`
+    pub fn r(&self) -> Matrix<T> {
+        let n = self.cols();
+        Matrix {
+            mat: self.r[..n].iter().map(|row| Arc::from(&row[..n])).collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    /// Gets the norm of the residual `‖Ax − b‖` at the least squares solution
+    pub fn residual_norm(&self) -> T {
+        let n = self.cols();
+        self.r[n][n].abs()
+    }
+
+    /// Solves the current least squares problem by back substitution on `R`, in O(n²)
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the problem has a unique solution
+    ///     - An `Ok` containing the `n x 1` least squares solution
+    ///     - An `Err` of `LinalgError::RankDeficient` if the rows so far do not determine
+    ///       every unknown
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self) -> Result<Matrix<T>, LinalgError> {
+        let n = self.cols();
+        let r = self.r();
+        // The same threshold `least_squares` applies to the R of the full m x n matrix
+        let largest = (0..n).fold(T::zero(), |max, i| max.max(r.mat[i][i].abs()));
+        let tol = largest * T::from(self.len().max(n)).unwrap_or_else(T::one) * T::epsilon();
+
+        let mut x = vec![T::zero(); n];
+        for i in (0..n).rev() {
+            if r.mat[i][i].abs() <= tol {
+                return Err(LinalgError::RankDeficient);
+            }
+            let mut sum = self.r[i][n];
+            for k in (i + 1)..n {
+                sum -= r.mat[i][k] * x[k];
+            }
+            x[i] = sum / r.mat[i][i];
+        }
+
+        Ok(Vector::new(x).to_column_matrix())
+    }
+}
+
+/// Checks that `b` is a finite column with one element per row of a finite `a`
+fn check_inputs<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Result<(), LinalgError> {
+    a.validate()?;
+    b.validate()?;
+    a.check_finite()?;
+    b.check_finite()?;
+    if (b.rows, b.cols) != (a.rows, 1) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, 1),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    Ok(())
+}
+
+fn column<T: Number>(m: &Matrix<T>) -> Vec<T> {
+    m.mat.iter().map(|row| row[0]).collect()
+}
+
+/// The magnitude below which a diagonal element of the `k x k` triangle of `r` is treated
+/// as zero
+fn rank_tolerance<T: Number + num::Float>(r: &Matrix<T>, k: usize) -> T {
+    let largest = (0..k).fold(T::zero(), |max, i| max.max(r.mat[i][i].abs()));
+    let size = T::from(r.rows.max(r.cols)).unwrap_or_else(T::one);
+
+    largest * size * T::epsilon()
+}
+
+/// Solves the least squares problem for a full column rank `a` by QR and back substitution
+#[allow(clippy::needless_range_loop)]
+fn solve_full_rank<T>(a: &Matrix<T>, b: &[T]) -> Result<Vec<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (m, n) = (a.rows, a.cols);
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    if m < n {
+        return Err(LinalgError::RankDeficient);
+    }
+
+    let (q, r) = decompose::qr_decomposition(a)?;
+    let tol = rank_tolerance(&r, n);
+    let qtb: Vec<T> = (0..n)
+        .map(|j| (0..m).fold(T::zero(), |sum, i| sum + q.mat[i][j] * b[i]))
+        .collect();
+
+    let mut x = vec![T::zero(); n];
+    for i in (0..n).rev() {
+        if r.mat[i][i].abs() <= tol {
+            return Err(LinalgError::RankDeficient);
+        }
+        let mut sum = qtb[i];
+        for k in (i + 1)..n {
+            sum -= r.mat[i][k] * x[k];
+        }
+        x[i] = sum / r.mat[i][i];
+    }
+
+    Ok(x)
+}
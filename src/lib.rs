@@ -1,3 +1,52 @@
+pub mod adaptive;
+pub mod arith;
+pub mod context;
+pub mod control;
+pub mod decompose;
+pub mod dense;
+pub mod diff;
+pub mod eigen;
+pub mod error;
+pub mod exact;
+pub mod expr;
+pub mod factorization_cache;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod functions;
+pub mod graph;
+pub mod io;
+pub mod iterative;
+pub mod iter;
+pub mod least_squares;
 pub mod matrix;
+pub mod matrix_pool;
 pub mod matrix_utilities;
-pub mod number;
\ No newline at end of file
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(not(feature = "metrics"))]
+pub(crate) mod metrics;
+pub mod named_matrix;
+pub mod number;
+mod ops;
+mod parallel;
+pub mod parse;
+pub mod pde;
+pub mod polynomial;
+pub mod prelude;
+pub mod shared_matrix;
+pub mod solve;
+pub mod solver_registry;
+pub mod solvers;
+pub mod sparse;
+pub mod stats;
+pub mod system;
+pub mod testing;
+pub mod transform;
+pub mod units;
+pub mod vector;
+pub mod view;
+
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(feature = "async")]
+pub mod task;
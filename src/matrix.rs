@@ -1,8 +1,15 @@
 extern crate num;
 
+use crate::arith;
+use crate::decompose;
+use crate::error::LinalgError;
 use crate::number::Number;
+use crate::solve;
+use crate::transform;
+use crate::view::{check_ranges, MatrixView};
 use std::fmt::Display;
-use std::ops::Range;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::{Index, IndexMut, Neg, Range};
 use std::sync::Arc;
 
 /// A struct representing that of a `Matrix` in linear algebra. This example models a `Matrix`
@@ -14,6 +21,10 @@ use std::sync::Arc;
 ///
 /// Matrices are used to represent and solve systems of linear equations, perform
 /// linear transformations, and more
+///
+/// A `Matrix` has value semantics: cloning one may share row storage, but rows are only
+/// ever edited through `Arc::make_mut`, so changes are never visible in another clone.
+/// Use a `SharedMatrix` when a single matrix should be deliberately shared between owners
 #[derive(Clone, Debug)]
 pub struct Matrix<T: Number + PartialEq> {
     /// Represents a vector of `Arc` atomic reference counting `[T]` arrays,
@@ -27,6 +38,125 @@ pub struct Matrix<T: Number + PartialEq> {
     pub cols: usize,
 }
 
+/// A matrix norm computed by `Matrix::norm`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormKind {
+    /// The square root of the sum of the squared elements
+    Frobenius,
+    /// The largest sum of absolute values in a column, the norm induced by the vector 1-norm
+    One,
+    /// The largest sum of absolute values in a row, the norm induced by the vector ∞-norm
+    Infinity,
+    /// The largest singular value, the norm induced by the vector 2-norm
+    Spectral,
+}
+
+/// The shape and a content hash of a `Matrix`, from `Matrix::fingerprint`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    /// The number of rows of the matrix
+    pub rows: usize,
+
+    /// The number of columns of the matrix
+    pub cols: usize,
+
+    /// A 64-bit hash of the shape and every element, in order
+    pub hash: u64,
+}
+
+impl Display for Fingerprint {
+    /// Writes the fingerprint as `RxC:hash`, with the hash in 16 hex digits
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}:{:016x}", self.rows, self.cols, self.hash)
+    }
+}
+
+/// A fast hasher with output fixed across platforms and Rust releases, for `fingerprint`
+///
+/// Every value is fed as one little-endian `u64` word and mixed in with a rotate and a
+/// multiply, as in FxHash; `finish` then scrambles the state with the SplitMix64
+/// finalizer so that nearby inputs give unrelated hashes
+struct FingerprintHasher {
+    state: u64,
+    words: u64,
+}
+
+impl FingerprintHasher {
+    fn new() -> FingerprintHasher {
+        FingerprintHasher {
+            state: 0x243f_6a88_85a3_08d3,
+            words: 0,
+        }
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    fn finish(&self) -> u64 {
+        let mut z = self.state ^ self.words;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u64(&mut self, word: u64) {
+        self.state = (self.state.rotate_left(5) ^ word).wrapping_mul(0x517c_c1b7_2722_0a95);
+        self.words += 1;
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64);
+    }
+}
+
 impl<T: PartialEq + Number + num::One> PartialEq for Matrix<T> {
     fn eq(&self, other: &Self) -> bool {
         self.rows == other.rows && self.cols == other.cols && self.mat == other.mat
@@ -76,6 +206,31 @@ impl<T: Number + num::One> Default for Matrix<T> {
     }
 }
 
+impl<T: Number> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Gets a reference to the element at `(row, col)`
+    ///
+    /// # Panics
+    /// Panics if `row` or `col` is out of bounds; use `Matrix::get` for a checked read
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.mat[row][col]
+    }
+}
+
+impl<T: Number> IndexMut<(usize, usize)> for Matrix<T> {
+    /// Gets a mutable reference to the element at `(row, col)`
+    ///
+    /// The row is made unique with `Arc::make_mut` first, so the write is never visible
+    /// through other matrices that shared the row
+    ///
+    /// # Panics
+    /// Panics if `row` or `col` is out of bounds; use `Matrix::set` for a checked write
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut Arc::make_mut(&mut self.mat[row])[col]
+    }
+}
+
 impl<T: Number + num::One> Display for Matrix<T> {
     /// Writes a `Matrix` as a pretty-printable string
     ///
@@ -86,7 +241,7 @@ impl<T: Number + num::One> Display for Matrix<T> {
             let mut curr_line = String::new();
             curr_line.push('|');
 
-            for num in i.into_iter() {
+            for num in i.iter() {
                 curr_line.push(' ');
                 curr_line.push_str(&format!("{}", num));
             }
@@ -100,43 +255,187 @@ impl<T: Number + num::One> Display for Matrix<T> {
 }
 
 impl<T: Number + num::One> Matrix<T> {
+    /// Creates a `rows` x `cols` `Matrix` with every element set to `value`
+    ///
+    /// All rows start out sharing one allocation and are copied on first write
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    /// - `value` - The value of every element
+    ///
+    /// ### Returns
+    /// - The filled `Matrix`
+    pub fn full(rows: usize, cols: usize, value: T) -> Matrix<T> {
+        let row: Arc<[T]> = Arc::from(vec![value; cols]);
+
+        Matrix {
+            mat: vec![row; rows],
+            rows,
+            cols,
+        }
+    }
+
+    /// Creates a `rows` x `cols` `Matrix` of zeros
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix::full(rows, cols, T::default())
+    }
+
+    /// Creates a `rows` x `cols` `Matrix` of ones
+    pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix::full(rows, cols, T::one())
+    }
+
+    /// Creates a square [diagonal matrix](https://en.wikipedia.org/wiki/Diagonal_matrix)
+    /// with `values` on its main diagonal and zeros everywhere else
+    ///
+    /// ### Parameters
+    /// - `values` - The diagonal elements, from the top left down
+    ///
+    /// ### Returns
+    /// - The `values.len()` x `values.len()` diagonal `Matrix`
+    pub fn from_diagonal(values: &[T]) -> Matrix<T> {
+        let n = values.len();
+
+        Matrix {
+            mat: values
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| {
+                    let mut row = vec![T::default(); n];
+                    row[i] = x;
+                    Arc::from(row)
+                })
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
     /// Compute the shape of this `Matrix`
     ///
     /// The shape of a matrix is defined by the number of rows and
     /// columns it contains and is typically represented as a tuple pair -
     /// `(rows, columns)`
     ///
+    /// Every method that adds or removes rows or columns keeps `rows` and `cols` up to
+    /// date, so this reads them without touching `mat`
+    ///
     /// ### Returns
     /// - A tuple of two positive integers - `(usize, usize)` - representing
     ///   the rows and columns of the matrix
-    pub fn shape(&mut self) -> (usize, usize) {
-        self.rows = self.mat.len();
-        self.cols = if self.rows > 0 { self.mat[0].len() } else { 0 };
-
+    pub fn shape(&self) -> (usize, usize) {
         (self.rows, self.cols)
     }
 
+    /// Checks that this `Matrix` is internally consistent
+    ///
+    /// Because `mat`, `rows`, and `cols` are public, a `Matrix` can be built whose fields
+    /// disagree. Fallible operations across the crate call this first and return its
+    /// error instead of panicking on such a matrix
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the fields agree
+    ///     - An `Ok` if `mat` holds exactly `rows` rows of exactly `cols` elements each
+    ///     - An `Err` of `LinalgError::Malformed` describing the first mismatch
+    pub fn validate(&self) -> Result<(), LinalgError> {
+        if self.mat.len() != self.rows {
+            return Err(LinalgError::Malformed(format!(
+                "Matrix claims {} rows but holds {}",
+                self.rows,
+                self.mat.len()
+            )));
+        }
+        if let Some((r, row)) = self
+            .mat
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != self.cols)
+        {
+            return Err(LinalgError::Malformed(format!(
+                "Row {} has {} elements but the matrix has {} columns",
+                r,
+                row.len(),
+                self.cols
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether any element of this `Matrix` is NaN
+    ///
+    /// ### Returns
+    /// - `true` if at least one element is NaN, which is never the case for integer matrices
+    pub fn has_nan(&self) -> bool {
+        self.mat
+            .iter()
+            .any(|row| row.iter().any(|x| x.is_nan_value()))
+    }
+
+    /// Checks whether any element of this `Matrix` is positive or negative infinity
+    ///
+    /// ### Returns
+    /// - `true` if at least one element is infinite, which is never the case for integer
+    ///   matrices
+    pub fn has_inf(&self) -> bool {
+        self.mat
+            .iter()
+            .any(|row| row.iter().any(|x| x.is_infinite_value()))
+    }
+
+    /// Checks that every element of this `Matrix` is finite
+    ///
+    /// Solvers and decompositions call this before doing any work, so a NaN or infinity in
+    /// their input is reported as an error rather than turned into a meaningless answer
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether every element is finite
+    ///     - An `Ok` if there are no NaN or infinite elements
+    ///     - An `Err` of `LinalgError::NonFinite` locating the first one otherwise
+    pub fn check_finite(&self) -> Result<(), LinalgError> {
+        for (r, row) in self.mat.iter().enumerate() {
+            if let Some(c) = row
+                .iter()
+                .position(|x| x.is_nan_value() || x.is_infinite_value())
+            {
+                return Err(LinalgError::NonFinite { row: r, col: c });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get a sub-matrix of this `Matrix`
     ///
     /// ### Parameters
     /// - `row_range` - A `Range<usize>` indicating the range of
-    ///    rows to extract from this `Matrix`
+    ///   rows to extract from this `Matrix`
     /// - `col_range` - A `Range<usize>` indicating the range of
-    ///    columns to extract from this `Matrix`
+    ///   columns to extract from this `Matrix`
     ///
     /// ### Returns
     /// - A `Result` containing whether this `Matrix` could be extracted
     ///   into a sub-matrix or not
     ///     - An `Ok` variant containing the new sub-matrix as a `Matrix` instance
-    ///     - An `Err` with a custom `String` error message if either or
+    ///     - An `Err` of `LinalgError::OutOfBounds` if either or
     ///       both provided ranges were out of bounds
     pub fn sub_matrix(
-        &mut self,
+        &self,
         row_range: Range<usize>,
         col_range: Range<usize>,
-    ) -> Result<Matrix<T>, String> {
-        if row_range.end > self.rows || col_range.end > self.cols {
-            return Err("Range out of bounds!".to_string());
+    ) -> Result<Matrix<T>, LinalgError> {
+        if row_range.end > self.rows {
+            return Err(LinalgError::OutOfBounds {
+                index: row_range.end,
+                len: self.rows,
+            });
+        }
+        if col_range.end > self.cols {
+            return Err(LinalgError::OutOfBounds {
+                index: col_range.end,
+                len: self.cols,
+            });
         }
 
         let mut new_mat = Vec::new();
@@ -151,4 +450,1089 @@ impl<T: Number + num::One> Matrix<T> {
             cols: col_range.len(),
         })
     }
+
+    /// Borrows a block of this `Matrix` as a `MatrixView`, without copying any elements
+    ///
+    /// Unlike `sub_matrix`, which copies every row of the block, this takes O(1) time,
+    /// which suits block algorithms that only read their blocks
+    ///
+    /// ### Parameters
+    /// - `row_range` - The rows of the block
+    /// - `col_range` - The columns of the block
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `MatrixView`, or an `Err` of `LinalgError::OutOfBounds`
+    ///   if either range ends past this `Matrix`, `LinalgError::InvalidArgument` if a range
+    ///   starts after it ends, or `LinalgError::Malformed` if this `Matrix` is malformed
+    pub fn view(
+        &self,
+        row_range: Range<usize>,
+        col_range: Range<usize>,
+    ) -> Result<MatrixView<'_, T>, LinalgError> {
+        self.validate()?;
+        check_ranges(self.rows, self.cols, &row_range, &col_range)?;
+
+        Ok(MatrixView::new(&self.mat[row_range], col_range))
+    }
+
+    /// Borrows all of this `Matrix` as a `MatrixView`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `MatrixView`, or an `Err` of `LinalgError::Malformed` if
+    ///   this `Matrix` is malformed
+    pub fn as_view(&self) -> Result<MatrixView<'_, T>, LinalgError> {
+        self.view(0..self.rows, 0..self.cols)
+    }
+
+    /// Splits this `Matrix` into the rows above and from `row`
+    ///
+    /// Both halves share their rows with this `Matrix` until one of them is written to
+    ///
+    /// ### Parameters
+    /// - `row` - The index of the first row of the lower half, between `0` and `rows`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the upper `row x cols` and lower `(rows - row) x cols`
+    ///   halves, or an `Err` of `LinalgError::OutOfBounds` if `row` exceeds `rows`
+    pub fn split_at_row(&self, row: usize) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        self.validate()?;
+        if row > self.rows {
+            return Err(LinalgError::OutOfBounds {
+                index: row,
+                len: self.rows,
+            });
+        }
+        let (upper, lower) = self.mat.split_at(row);
+
+        Ok((
+            Matrix {
+                mat: upper.to_vec(),
+                rows: row,
+                cols: self.cols,
+            },
+            Matrix {
+                mat: lower.to_vec(),
+                rows: self.rows - row,
+                cols: self.cols,
+            },
+        ))
+    }
+
+    /// Splits this `Matrix` into the columns left of and from `col`
+    ///
+    /// ### Parameters
+    /// - `col` - The index of the first column of the right half, between `0` and `cols`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the left `rows x col` and right `rows x (cols - col)`
+    ///   halves, or an `Err` of `LinalgError::OutOfBounds` if `col` exceeds `cols`
+    pub fn split_at_col(&self, col: usize) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        self.validate()?;
+        if col > self.cols {
+            return Err(LinalgError::OutOfBounds {
+                index: col,
+                len: self.cols,
+            });
+        }
+        let (left, right) = self
+            .mat
+            .iter()
+            .map(|row| (Arc::from(&row[..col]), Arc::from(&row[col..])))
+            .unzip();
+
+        Ok((
+            Matrix {
+                mat: left,
+                rows: self.rows,
+                cols: col,
+            },
+            Matrix {
+                mat: right,
+                rows: self.rows,
+                cols: self.cols - col,
+            },
+        ))
+    }
+
+    /// Transposes the arrangement of the blocks of this `Matrix`, leaving every block intact
+    ///
+    /// This `Matrix` is viewed as a grid of `block_rows x block_cols` blocks, and block
+    /// `(I, J)` moves to position `(J, I)`. For a Kronecker product this is the partial
+    /// transpose over the first factor: `A ⊗ B` becomes `Aᵀ ⊗ B`
+    ///
+    /// ### Parameters
+    /// - `block_rows` - The number of rows in each block, which must divide `rows`
+    /// - `block_cols` - The number of columns in each block, which must divide `cols`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the block-transposed `Matrix`, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if the blocks do not tile this `Matrix`
+    pub fn block_transpose(
+        &self,
+        block_rows: usize,
+        block_cols: usize,
+    ) -> Result<Matrix<T>, LinalgError> {
+        let (grid_rows, grid_cols) = self.block_grid(block_rows, block_cols)?;
+
+        let mat = (0..grid_cols * block_rows)
+            .map(|r| {
+                let (block, i) = (r / block_rows, r % block_rows);
+                (0..grid_rows)
+                    .flat_map(|j| {
+                        let source = &self.mat[j * block_rows + i];
+                        source[block * block_cols..(block + 1) * block_cols]
+                            .iter()
+                            .copied()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Matrix {
+            mat,
+            rows: grid_cols * block_rows,
+            cols: grid_rows * block_cols,
+        })
+    }
+
+    /// Transposes every block of this `Matrix` in place, leaving their arrangement intact
+    ///
+    /// This `Matrix` is viewed as a grid of `block_rows x block_cols` blocks, each of which
+    /// is replaced by its transpose. For a Kronecker product this is the
+    /// [partial transpose](https://en.wikipedia.org/wiki/Peres%E2%80%93Horodecki_criterion)
+    /// over the second factor: `A ⊗ B` becomes `A ⊗ Bᵀ`. Combined with `block_transpose`
+    /// it gives the full transpose
+    ///
+    /// ### Parameters
+    /// - `block_rows` - The number of rows in each block, which must divide `rows`
+    /// - `block_cols` - The number of columns in each block, which must divide `cols`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the partially transposed `Matrix`, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if the blocks do not tile this `Matrix`
+    pub fn partial_transpose(
+        &self,
+        block_rows: usize,
+        block_cols: usize,
+    ) -> Result<Matrix<T>, LinalgError> {
+        let (grid_rows, grid_cols) = self.block_grid(block_rows, block_cols)?;
+
+        let mat = (0..grid_rows * block_cols)
+            .map(|r| {
+                let (block, j) = (r / block_cols, r % block_cols);
+                (0..grid_cols)
+                    .flat_map(|col_block| {
+                        (0..block_rows).map(move |i| {
+                            self.mat[block * block_rows + i][col_block * block_cols + j]
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Matrix {
+            mat,
+            rows: grid_rows * block_cols,
+            cols: grid_cols * block_rows,
+        })
+    }
+
+    /// Checks that `block_rows x block_cols` blocks tile this `Matrix`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the number of block rows and block columns
+    fn block_grid(
+        &self,
+        block_rows: usize,
+        block_cols: usize,
+    ) -> Result<(usize, usize), LinalgError> {
+        self.validate()?;
+        if block_rows == 0
+            || block_cols == 0
+            || !self.rows.is_multiple_of(block_rows)
+            || !self.cols.is_multiple_of(block_cols)
+        {
+            return Err(LinalgError::InvalidArgument(format!(
+                "{}x{} blocks do not tile a {}x{} matrix!",
+                block_rows, block_cols, self.rows, self.cols
+            )));
+        }
+
+        Ok((self.rows / block_rows, self.cols / block_cols))
+    }
+
+    /// Runs `f` with mutable access to a single row of this `Matrix`
+    ///
+    /// The row is made unique with `Arc::make_mut` before `f` is called, so edits made by `f`
+    /// are never visible through other matrices that shared the row
+    ///
+    /// ### Parameters
+    /// - `row` - The index of the row to edit
+    /// - `f` - A closure receiving the row as a `&mut [T]` slice
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the row exists
+    ///     - An `Ok` containing the value returned by `f`
+    ///     - An `Err` of `LinalgError::OutOfBounds` if `row` is out of bounds
+    pub fn with_row_mut<R>(
+        &mut self,
+        row: usize,
+        f: impl FnOnce(&mut [T]) -> R,
+    ) -> Result<R, LinalgError> {
+        if row >= self.mat.len() {
+            return Err(LinalgError::OutOfBounds {
+                index: row,
+                len: self.mat.len(),
+            });
+        }
+
+        Ok(f(Arc::make_mut(&mut self.mat[row])))
+    }
+
+    /// Runs `f` with mutable access to two distinct rows of this `Matrix` at once
+    ///
+    /// This is the building block for elimination steps such as `row_j -= factor * row_i`,
+    /// which otherwise require splitting `mat` by hand to borrow both rows
+    ///
+    /// ### Parameters
+    /// - `rows` - A tuple `(i, j)` of the two row indices to edit
+    /// - `f` - A closure receiving rows `i` and `j`, in that order, as `&mut [T]` slices
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether both rows could be borrowed
+    ///     - An `Ok` containing the value returned by `f`
+    ///     - An `Err` of `LinalgError::OutOfBounds` if either index is out of bounds, or
+    ///       of `LinalgError::InvalidArgument` if `i` and `j` are the same row
+    pub fn with_rows_mut<R>(
+        &mut self,
+        rows: (usize, usize),
+        f: impl FnOnce(&mut [T], &mut [T]) -> R,
+    ) -> Result<R, LinalgError> {
+        let (i, j) = rows;
+        if i.max(j) >= self.mat.len() {
+            return Err(LinalgError::OutOfBounds {
+                index: i.max(j),
+                len: self.mat.len(),
+            });
+        }
+        if i == j {
+            return Err(LinalgError::InvalidArgument(
+                "Cannot borrow the same row twice!".to_string(),
+            ));
+        }
+
+        let (low, high) = (i.min(j), i.max(j));
+        let (upper, lower) = self.mat.split_at_mut(high);
+        let low_row = Arc::make_mut(&mut upper[low]);
+        let high_row = Arc::make_mut(&mut lower[0]);
+
+        if i < j {
+            Ok(f(low_row, high_row))
+        } else {
+            Ok(f(high_row, low_row))
+        }
+    }
+
+    /// Inserts `row` before row `idx` of this `Matrix`, shifting later rows down
+    ///
+    /// ### Parameters
+    /// - `idx` - The index the new row will have, from `0` up to and including `rows`
+    /// - `row` - The elements of the new row
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the row could be inserted
+    ///     - An `Ok` if the row was inserted
+    ///     - An `Err` of `LinalgError::OutOfBounds` if `idx` is greater than `rows`, or of
+    ///       `LinalgError::LengthMismatch` if `row` does not have `cols` elements. Any
+    ///       length is accepted when this `Matrix` has no rows
+    pub fn insert_row(&mut self, idx: usize, row: &[T]) -> Result<(), LinalgError> {
+        self.validate()?;
+        if idx > self.rows {
+            return Err(LinalgError::OutOfBounds {
+                index: idx,
+                len: self.rows + 1,
+            });
+        }
+        if self.rows > 0 && row.len() != self.cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.cols,
+                found: row.len(),
+            });
+        }
+
+        self.mat.insert(idx, Arc::from(row));
+        self.rows += 1;
+        self.cols = row.len();
+
+        Ok(())
+    }
+
+    /// Removes row `idx` from this `Matrix`, shifting later rows up
+    ///
+    /// ### Parameters
+    /// - `idx` - The index of the row to remove
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the row exists
+    ///     - An `Ok` containing the removed row's elements
+    ///     - An `Err` of `LinalgError::OutOfBounds` if `idx` is out of bounds
+    pub fn remove_row(&mut self, idx: usize) -> Result<Vec<T>, LinalgError> {
+        self.validate()?;
+        if idx >= self.rows {
+            return Err(LinalgError::OutOfBounds {
+                index: idx,
+                len: self.rows,
+            });
+        }
+
+        self.rows -= 1;
+        Ok(self.mat.remove(idx).to_vec())
+    }
+
+    /// Swaps rows `i` and `j` of this `Matrix`
+    ///
+    /// Only the row handles are exchanged, so no elements are copied
+    ///
+    /// ### Parameters
+    /// - `i` - The index of one row
+    /// - `j` - The index of the other row
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether both rows exist
+    ///     - An `Ok` if the rows were swapped, which does nothing when `i == j`
+    ///     - An `Err` of `LinalgError::OutOfBounds` if either index is out of bounds
+    pub fn swap_rows(&mut self, i: usize, j: usize) -> Result<(), LinalgError> {
+        if i.max(j) >= self.mat.len() {
+            return Err(LinalgError::OutOfBounds {
+                index: i.max(j),
+                len: self.mat.len(),
+            });
+        }
+        self.mat.swap(i, j);
+
+        Ok(())
+    }
+
+    /// Multiplies every element of row `idx` of this `Matrix` by `factor`
+    ///
+    /// ### Parameters
+    /// - `idx` - The index of the row to scale
+    /// - `factor` - The scalar to multiply the row by
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the row exists
+    ///     - An `Ok` if the row was scaled
+    ///     - An `Err` of `LinalgError::OutOfBounds` if `idx` is out of bounds
+    pub fn scale_row(&mut self, idx: usize, factor: T) -> Result<(), LinalgError> {
+        self.with_row_mut(idx, |row| {
+            for x in row {
+                *x *= factor;
+            }
+        })
+    }
+
+    /// Gets the element at `(row, col)`, or `None` if either index is out of bounds
+    ///
+    /// ### Parameters
+    /// - `row` - The row index
+    /// - `col` - The column index
+    ///
+    /// ### Returns
+    /// - An `Option` containing the element, or `None` if the position does not exist
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        self.mat.get(row).and_then(|r| r.get(col)).copied()
+    }
+
+    /// Sets the element at `(row, col)` to `value`
+    ///
+    /// ### Parameters
+    /// - `row` - The row index
+    /// - `col` - The column index
+    /// - `value` - The new value of the element
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the position exists
+    ///     - An `Ok` if the element was set
+    ///     - An `Err` of `LinalgError::OutOfBounds` if either index is out of bounds
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<(), LinalgError> {
+        let Some(r) = self.mat.get(row) else {
+            return Err(LinalgError::OutOfBounds {
+                index: row,
+                len: self.mat.len(),
+            });
+        };
+        if col >= r.len() {
+            return Err(LinalgError::OutOfBounds {
+                index: col,
+                len: r.len(),
+            });
+        }
+        Arc::make_mut(&mut self.mat[row])[col] = value;
+
+        Ok(())
+    }
+
+    /// Gets a copy of column `idx` of this `Matrix`
+    ///
+    /// ### Parameters
+    /// - `idx` - The column index
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the column exists
+    ///     - An `Ok` containing the column's elements from top to bottom
+    ///     - An `Err` of `LinalgError::OutOfBounds` if `idx` is out of bounds
+    pub fn column(&self, idx: usize) -> Result<Vec<T>, LinalgError> {
+        self.check_column(idx)?;
+
+        Ok(self.mat.iter().map(|row| row[idx]).collect())
+    }
+
+    /// Iterates over copies of the columns of this `Matrix`, from left to right
+    ///
+    /// Columns are not contiguous in memory, so each one is gathered into a new `Vec`
+    ///
+    /// ### Returns
+    /// - An iterator yielding each column as a `Vec<T>`
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = Vec<T>> + '_ {
+        (0..self.cols).map(move |c| self.mat.iter().map(|row| row[c]).collect())
+    }
+
+    /// Overwrites column `idx` of this `Matrix` with `values`
+    ///
+    /// Every row is made unique with `Arc::make_mut`, so the change is never visible
+    /// through other matrices that shared the rows
+    ///
+    /// ### Parameters
+    /// - `idx` - The column index
+    /// - `values` - The new column, from top to bottom
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the column could be set
+    ///     - An `Ok` if the column was overwritten
+    ///     - An `Err` of `LinalgError::OutOfBounds` if `idx` is out of bounds, or of
+    ///       `LinalgError::LengthMismatch` if `values` does not have one element per row
+    pub fn set_column(&mut self, idx: usize, values: &[T]) -> Result<(), LinalgError> {
+        self.check_column(idx)?;
+        if values.len() != self.rows {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.rows,
+                found: values.len(),
+            });
+        }
+
+        for (row, &value) in self.mat.iter_mut().zip(values) {
+            Arc::make_mut(row)[idx] = value;
+        }
+
+        Ok(())
+    }
+
+    /// Swaps columns `i` and `j` of this `Matrix` in place
+    ///
+    /// ### Parameters
+    /// - `i` - The index of one column
+    /// - `j` - The index of the other column
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether both columns exist
+    ///     - An `Ok` if the columns were swapped, which does nothing when `i == j`
+    ///     - An `Err` of `LinalgError::OutOfBounds` if either index is out of bounds
+    pub fn swap_columns(&mut self, i: usize, j: usize) -> Result<(), LinalgError> {
+        self.check_column(i.max(j))?;
+        if i == j {
+            return Ok(());
+        }
+
+        for row in &mut self.mat {
+            Arc::make_mut(row).swap(i, j);
+        }
+
+        Ok(())
+    }
+
+    fn check_column(&self, idx: usize) -> Result<(), LinalgError> {
+        self.validate()?;
+        if idx >= self.cols {
+            return Err(LinalgError::OutOfBounds {
+                index: idx,
+                len: self.cols,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Computes the trace of this `Matrix`: the sum of its main diagonal
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether this `Matrix` is square
+    ///     - An `Ok` containing the sum of the diagonal elements (zero for an empty matrix)
+    ///     - An `Err` of `LinalgError::NonSquare` if this `Matrix` is not square
+    pub fn trace(&self) -> Result<T, LinalgError> {
+        self.validate()?;
+        if self.rows != self.cols {
+            return Err(LinalgError::NonSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let mut sum = T::default();
+        for i in 0..self.rows {
+            sum += self.mat[i][i];
+        }
+
+        Ok(sum)
+    }
+
+    /// Gets the main diagonal of this `Matrix`
+    ///
+    /// ### Returns
+    /// - The elements `(i, i)` for every `i` below both `rows` and `cols`, so a non-square
+    ///   `Matrix` gives as many as its shorter side
+    pub fn diagonal(&self) -> Vec<T> {
+        self.mat
+            .iter()
+            .take(self.cols)
+            .enumerate()
+            .filter_map(|(i, row)| row.get(i).copied())
+            .collect()
+    }
+
+    /// Checks whether every element off the main diagonal of this `Matrix` is zero
+    ///
+    /// Non-square matrices are allowed, so a `Matrix` that is both upper and lower
+    /// triangular is diagonal
+    pub fn is_diagonal(&self) -> bool {
+        self.all_zero_where(|i, j| i != j)
+    }
+
+    /// Checks whether every element below the main diagonal of this `Matrix` is zero
+    pub fn is_upper_triangular(&self) -> bool {
+        self.all_zero_where(|i, j| i > j)
+    }
+
+    /// Checks whether every element above the main diagonal of this `Matrix` is zero
+    pub fn is_lower_triangular(&self) -> bool {
+        self.all_zero_where(|i, j| i < j)
+    }
+
+    /// Checks whether this `Matrix` is square and equal to its transpose
+    ///
+    /// Elements are compared exactly, so a float `Matrix` built by arithmetic that should be
+    /// symmetric may differ from its transpose by rounding
+    pub fn is_symmetric(&self) -> bool {
+        self.rows == self.cols
+            && self.validate().is_ok()
+            && (0..self.rows).all(|i| (0..i).all(|j| self.mat[i][j] == self.mat[j][i]))
+    }
+
+    /// Checks whether this `Matrix` is valid and zero at every `(i, j)` where `zero` holds
+    fn all_zero_where(&self, zero: impl Fn(usize, usize) -> bool) -> bool {
+        self.validate().is_ok()
+            && self.mat.iter().enumerate().all(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .all(|(j, &x)| !zero(i, j) || x == T::default())
+            })
+    }
+
+    /// Computes the sum of every element of this `Matrix`
+    ///
+    /// ### Returns
+    /// - The sum of all elements, or zero for an empty matrix
+    pub fn sum(&self) -> T {
+        let mut sum = T::default();
+        for row in &self.mat {
+            for &x in row.iter() {
+                sum += x;
+            }
+        }
+
+        sum
+    }
+
+    /// Hashes this `Matrix` in a form that ignores the order of its rows
+    ///
+    /// Each row is hashed on its own and the row hashes are sorted before being combined
+    /// with the shape, so a system and the same equations listed in another order hash
+    /// equally, as do any two matrices that compare equal. Rows that are multiples or
+    /// combinations of each other are not recognised as equivalent. The hash comes from
+    /// `DefaultHasher`, so it is stable within a process but may change between Rust
+    /// releases and should not be persisted
+    ///
+    /// ### Returns
+    /// - A `u64` hash of the shape and the multiset of rows
+    pub fn canonical_hash(&self) -> u64 {
+        let mut row_hashes: Vec<u64> = self
+            .mat
+            .iter()
+            .map(|row| {
+                let mut state = DefaultHasher::new();
+                for &x in row.iter() {
+                    x.hash_value(&mut state);
+                }
+                state.finish()
+            })
+            .collect();
+        row_hashes.sort_unstable();
+
+        let mut state = DefaultHasher::new();
+        (self.rows, self.cols).hash(&mut state);
+        row_hashes.hash(&mut state);
+        state.finish()
+    }
+
+    /// Computes a fast fingerprint of the shape and contents of this `Matrix`, for telling
+    /// whether a matrix is still the one a cached result was computed from
+    ///
+    /// Unlike `canonical_hash`, the order of rows matters and the hash is fixed across
+    /// processes, platforms, and releases, so fingerprints can be stored alongside saved
+    /// results. Matrices that compare equal have equal fingerprints: `-0.0` hashes as
+    /// `0.0`, and every NaN alike. The hash is not cryptographic, so it detects stale
+    /// or corrupted data, not deliberate forgery
+    ///
+    /// ### Returns
+    /// - A `Fingerprint` holding the shape and a 64-bit hash of every element
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut state = FingerprintHasher::new();
+        state.write_usize(self.rows);
+        state.write_usize(self.cols);
+        for row in &self.mat {
+            state.write_usize(row.len());
+            for &x in row.iter() {
+                x.hash_value(&mut state);
+            }
+        }
+
+        Fingerprint {
+            rows: self.rows,
+            cols: self.cols,
+            hash: state.finish(),
+        }
+    }
+}
+
+impl<T: Number + PartialOrd> Matrix<T> {
+    /// Finds the smallest element of this `Matrix`
+    ///
+    /// ### Returns
+    /// - `Some` with the smallest element, `None` if the matrix is empty. If any element is
+    ///   NaN, that NaN is returned
+    pub fn min(&self) -> Option<T> {
+        self.reduce_by(|x, best| x < best)
+    }
+
+    /// Finds the largest element of this `Matrix`
+    ///
+    /// ### Returns
+    /// - `Some` with the largest element, `None` if the matrix is empty. If any element is
+    ///   NaN, that NaN is returned
+    pub fn max(&self) -> Option<T> {
+        self.reduce_by(|x, best| x > best)
+    }
+
+    fn reduce_by(&self, better: impl Fn(T, T) -> bool) -> Option<T> {
+        let mut elements = self.mat.iter().flat_map(|row| row.iter().copied());
+        let mut best = elements.next()?;
+
+        for x in elements {
+            if x.partial_cmp(&x).is_none() {
+                return Some(x);
+            }
+            if better(x, best) {
+                best = x;
+            }
+        }
+
+        Some(best)
+    }
+}
+
+impl<T: Number + num::FromPrimitive + num::ToPrimitive> Matrix<T> {
+    /// Computes the arithmetic mean of every element of this `Matrix`
+    ///
+    /// For integer matrices the division truncates, just like `/` on the element type. When
+    /// the number of elements does not fit in `T`, as for 200 elements of an `i8` matrix,
+    /// the sum and division are carried out in `i128` instead; a mean of values of `T`
+    /// always fits back in `T`
+    ///
+    /// ### Returns
+    /// - `Some` with the mean, or `None` if the matrix is empty
+    pub fn mean(&self) -> Option<T> {
+        let count = self.mat.iter().map(|row| row.len()).sum::<usize>();
+        if count == 0 {
+            return None;
+        }
+
+        match T::from_usize(count) {
+            Some(n) => Some(self.sum() / n),
+            // Only integer types narrower than `usize` get here, and `i128` holds their
+            // elements and sums exactly
+            None => {
+                let sum = self.iter().map(|x| x.to_i128()).sum::<Option<i128>>()?;
+                T::from_i128(sum / count as i128)
+            }
+        }
+    }
+}
+
+impl<T: Number + num::One + Neg<Output = T>> Matrix<T> {
+    /// Creates the `n x n` [Hadamard matrix](https://en.wikipedia.org/wiki/Hadamard_matrix)
+    /// of Sylvester's construction
+    ///
+    /// Element `(i, j)` is `(-1)^popcount(i & j)`, which is the same as doubling
+    /// `[[H, H], [H, −H]]` from `H = [1]`. Every element is `1` or `-1` and `H Hᵀ = n I`, so
+    /// `H / √n` is orthogonal
+    ///
+    /// ### Parameters
+    /// - `n` - The order, a power of two
+    ///
+    /// ### Returns
+    /// - A `Result` containing the Hadamard matrix, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if `n` is not a power of two
+    pub fn hadamard(n: usize) -> Result<Matrix<T>, LinalgError> {
+        check_power_of_two(n)?;
+
+        Ok(Matrix::sign_pattern(n, |i| i))
+    }
+
+    /// Creates the `n x n` [Walsh matrix](https://en.wikipedia.org/wiki/Walsh_matrix) in
+    /// sequency order
+    ///
+    /// Holds the rows of `Matrix::hadamard(n)` reordered so that row `k` changes sign
+    /// exactly `k` times, the order in which Walsh functions are used like frequencies in
+    /// signal processing. Row `k` is Hadamard row `reverse_bits(gray(k))`
+    ///
+    /// ### Parameters
+    /// - `n` - The order, a power of two
+    ///
+    /// ### Returns
+    /// - A `Result` containing the Walsh matrix, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if `n` is not a power of two
+    pub fn walsh(n: usize) -> Result<Matrix<T>, LinalgError> {
+        check_power_of_two(n)?;
+        let bits = n.trailing_zeros();
+
+        Ok(Matrix::sign_pattern(n, |k| {
+            let gray = k ^ (k >> 1);
+            gray.reverse_bits()
+                .checked_shr(usize::BITS - bits)
+                .unwrap_or(0)
+        }))
+    }
+
+    /// Builds the `n x n` matrix whose row `k` is row `hadamard_row(k)` of Sylvester's
+    /// Hadamard matrix
+    fn sign_pattern<F: Fn(usize) -> usize>(n: usize, hadamard_row: F) -> Matrix<T> {
+        let mat = (0..n)
+            .map(|k| {
+                let i = hadamard_row(k);
+                (0..n)
+                    .map(|j| match (i & j).count_ones() % 2 {
+                        0 => T::one(),
+                        _ => -T::one(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Matrix {
+            mat,
+            rows: n,
+            cols: n,
+        }
+    }
+}
+
+/// Checks that `n` is a valid order for `Matrix::hadamard` and `Matrix::walsh`
+fn check_power_of_two(n: usize) -> Result<(), LinalgError> {
+    if !n.is_power_of_two() {
+        return Err(LinalgError::InvalidArgument(format!(
+            "The order {} is not a power of two",
+            n
+        )));
+    }
+
+    Ok(())
+}
+
+impl<T: Number + Neg<Output = T> + num::Float> Matrix<T> {
+    /// Creates the [companion matrix](https://en.wikipedia.org/wiki/Companion_matrix) of a
+    /// polynomial, whose eigenvalues are the roots of the polynomial
+    ///
+    /// For `p(x) = c₀ xⁿ + c₁ xⁿ⁻¹ + … + cₙ` the first row is `[−c₁/c₀, −c₂/c₀, …, −cₙ/c₀]`
+    /// and the subdiagonal holds ones, so the matrix is already upper Hessenberg and its
+    /// characteristic polynomial is `p(x) / c₀`
+    ///
+    /// ### Parameters
+    /// - `coeffs` - The coefficients `c₀, c₁, …, cₙ`, highest degree first
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the polynomial has a companion matrix
+    ///     - An `Ok` containing the `n x n` companion matrix
+    ///     - An `Err` of `LinalgError::InvalidArgument` if `coeffs` has fewer than two
+    ///       elements, its leading coefficient is zero, or a coefficient is NaN or
+    ///       infinite
+    pub fn companion(coeffs: &[T]) -> Result<Matrix<T>, LinalgError> {
+        let (&lead, rest) = coeffs
+            .split_first()
+            .filter(|(_, rest)| !rest.is_empty())
+            .ok_or_else(|| {
+                LinalgError::InvalidArgument(
+                    "A companion matrix needs a polynomial of degree at least 1".to_string(),
+                )
+            })?;
+        if coeffs.iter().any(|c| !c.is_finite()) {
+            return Err(LinalgError::InvalidArgument(
+                "Every coefficient must be finite".to_string(),
+            ));
+        }
+        if lead == T::zero() {
+            return Err(LinalgError::InvalidArgument(
+                "The leading coefficient must not be zero".to_string(),
+            ));
+        }
+
+        let n = rest.len();
+        let mut mat: Vec<Arc<[T]>> = Vec::with_capacity(n);
+        mat.push(rest.iter().map(|&c| -c / lead).collect());
+        for i in 1..n {
+            let mut row = vec![T::zero(); n];
+            row[i - 1] = T::one();
+            mat.push(Arc::from(row));
+        }
+
+        Ok(Matrix {
+            mat,
+            rows: n,
+            cols: n,
+        })
+    }
+
+    /// Scales this square `Matrix` so that its determinant is `1` or `-1`
+    ///
+    /// Dividing an n x n matrix by `|det|^(1/n)` removes any uniform scaling while keeping
+    /// its orientation, which stops long chains of composed transforms from slowly growing
+    /// or shrinking through rounding. The scale is found as `exp(-Σ ln|uᵢᵢ| / n)` from the
+    /// pivots `uᵢᵢ` of its LU factorization, so it stays accurate when `det` itself would
+    /// overflow or underflow
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether this `Matrix` can be normalized
+    ///     - An `Ok` containing the scaled `Matrix`, whose determinant has the same sign as
+    ///       this one's
+    ///     - An `Err` of `LinalgError::NonSquare` if this `Matrix` is not square,
+    ///       `LinalgError::NonFinite` if it holds a NaN or infinity, or
+    ///       `LinalgError::Singular` if its determinant is zero
+    pub fn normalize_determinant(&self) -> Result<Matrix<T>, LinalgError> {
+        let (_, pivots) = solve::lu_pivots(self)?;
+        if pivots.contains(&T::zero()) {
+            return Err(LinalgError::Singular);
+        }
+        if self.rows == 0 {
+            return Ok(self.clone());
+        }
+        let n = T::from(self.rows).ok_or(LinalgError::Singular)?;
+        let log_det = pivots.iter().fold(T::zero(), |sum, p| sum + p.abs().ln());
+
+        Ok(arith::multiply_by_scalar(self, (-log_det / n).exp()))
+    }
+
+    /// Replaces this square `Matrix` with the nearest orthogonal matrix
+    ///
+    /// The nearest orthogonal matrix in the Frobenius norm is `UVᵀ`, where `A = UΣVᵀ` is the
+    /// SVD, so this snaps a rotation matrix that has drifted through rounding back onto an
+    /// exact rotation without favouring any one of its columns
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether this `Matrix` can be orthonormalized
+    ///     - An `Ok` containing the orthogonal `Matrix`
+    ///     - An `Err` of `LinalgError::NonSquare` if this `Matrix` is not square,
+    ///       `LinalgError::NonFinite` if it holds a NaN or infinity, or
+    ///       `LinalgError::Singular` if it is singular, since then the nearest orthogonal
+    ///       matrix is not unique
+    pub fn orthonormalize(&self) -> Result<Matrix<T>, LinalgError> {
+        if self.rows != self.cols {
+            return Err(LinalgError::NonSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let (u, sigma, v) = decompose::svd(self)?;
+        let size = T::from(self.rows).unwrap_or_else(T::one);
+        if let (Some(&largest), Some(&smallest)) = (sigma.first(), sigma.last()) {
+            if smallest <= largest * size * T::epsilon() {
+                return Err(LinalgError::Singular);
+            }
+        }
+
+        arith::multiply(&u, &transform::transpose(&v))
+    }
+
+    /// Computes a norm of this `Matrix`
+    ///
+    /// ### Parameters
+    /// - `kind` - Which `NormKind` to compute
+    ///
+    /// ### Returns
+    /// - A `Result` containing the norm, which is `0` for an empty `Matrix`, or an `Err` if
+    ///   this `Matrix` is malformed or, for `NormKind::Spectral`, holds a NaN or infinity
+    pub fn norm(&self, kind: NormKind) -> Result<T, LinalgError> {
+        self.validate()?;
+
+        Ok(match kind {
+            NormKind::Frobenius => self
+                .mat
+                .iter()
+                .flat_map(|row| row.iter())
+                .fold(T::zero(), |sum, &x| sum + x * x)
+                .sqrt(),
+            NormKind::One => (0..self.cols)
+                .map(|j| {
+                    self.mat
+                        .iter()
+                        .fold(T::zero(), |sum, row| sum + row[j].abs())
+                })
+                .fold(T::zero(), T::max),
+            NormKind::Infinity => self
+                .mat
+                .iter()
+                .map(|row| row.iter().fold(T::zero(), |sum, x| sum + x.abs()))
+                .fold(T::zero(), T::max),
+            NormKind::Spectral => decompose::svd(self)?
+                .1
+                .first()
+                .copied()
+                .unwrap_or_else(T::zero),
+        })
+    }
+
+    /// Computes the 2-norm [condition number](https://en.wikipedia.org/wiki/Condition_number)
+    /// `σ_max / σ_min` of this square `Matrix`
+    ///
+    /// Solving a system with this `Matrix`, or inverting it, can lose up to `log10` of the
+    /// condition number in decimal digits of accuracy, so a result near `1 / T::epsilon()`
+    /// means `inverse` and `solve` results should not be trusted
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the condition number is defined
+    ///     - An `Ok` containing the condition number, which is infinite for a singular
+    ///       `Matrix`
+    ///     - An `Err` of `LinalgError::NonSquare` if this `Matrix` is not square,
+    ///       `LinalgError::Empty` if it is empty, or `LinalgError::NonFinite` if it holds a
+    ///       NaN or infinity
+    pub fn condition_number(&self) -> Result<T, LinalgError> {
+        self.validate()?;
+        if self.rows != self.cols {
+            return Err(LinalgError::NonSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let (_, sigma, _) = decompose::svd(self)?;
+        let (Some(&largest), Some(&smallest)) = (sigma.first(), sigma.last()) else {
+            return Err(LinalgError::Empty);
+        };
+
+        Ok(if smallest > T::zero() {
+            largest / smallest
+        } else {
+            T::infinity()
+        })
+    }
+
+    /// Checks whether this `Matrix` and `other` have the same shape and every pair of
+    /// corresponding elements differs by at most `epsilon`
+    ///
+    /// Equal infinities compare equal, while a NaN is never equal to anything
+    ///
+    /// ### Parameters
+    /// - `other` - The `Matrix` to compare against
+    /// - `epsilon` - The largest absolute difference allowed between two elements
+    ///
+    /// ### Returns
+    /// - `true` if the matrices are equal to within `epsilon`, `false` otherwise
+    pub fn approx_eq(&self, other: &Matrix<T>, epsilon: T) -> bool {
+        self.shape() == other.shape()
+            && self.mat.iter().zip(&other.mat).all(|(a, b)| {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(&x, &y)| within(x, y, epsilon))
+            })
+    }
+}
+
+/// Checks whether `x` and `y` are equal or differ by at most `epsilon`
+pub(crate) fn within<T: num::Float>(x: T, y: T, epsilon: T) -> bool {
+    x == y || (x - y).abs() <= epsilon
+}
+
+#[cfg(feature = "rand")]
+impl<T: Number + rand::distr::uniform::SampleUniform> Matrix<T> {
+    /// Creates a `rows` x `cols` `Matrix` of values drawn uniformly from `range` using the
+    /// thread-local random number generator
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    /// - `range` - The range to sample from, such as `-1.0..1.0` or `0..=9`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether `range` can be sampled
+    ///     - An `Ok` containing the random `Matrix`
+    ///     - An `Err` of `LinalgError::InvalidArgument` if `range` is empty or its width
+    ///       `high - low` is not finite
+    pub fn random<R>(rows: usize, cols: usize, range: R) -> Result<Matrix<T>, LinalgError>
+    where
+        R: TryInto<rand::distr::Uniform<T>, Error = rand::distr::uniform::Error>,
+    {
+        Matrix::random_with_rng(rows, cols, range, &mut rand::rng())
+    }
+
+    /// Creates a `rows` x `cols` `Matrix` of values drawn uniformly from `range` using `rng`
+    ///
+    /// Pass a seeded generator, such as `rand::rngs::StdRng::seed_from_u64`, for
+    /// reproducible matrices
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    /// - `range` - The range to sample from, such as `-1.0..1.0` or `0..=9`
+    /// - `rng` - The random number generator to draw from
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether `range` can be sampled
+    ///     - An `Ok` containing the random `Matrix`
+    ///     - An `Err` of `LinalgError::InvalidArgument` if `range` is empty or its width
+    ///       `high - low` is not finite
+    pub fn random_with_rng<R, G>(
+        rows: usize,
+        cols: usize,
+        range: R,
+        rng: &mut G,
+    ) -> Result<Matrix<T>, LinalgError>
+    where
+        R: TryInto<rand::distr::Uniform<T>, Error = rand::distr::uniform::Error>,
+        G: rand::Rng + ?Sized,
+    {
+        use rand::distr::uniform::Error;
+        use rand::distr::Distribution;
+
+        let uniform = range.try_into().map_err(|e| {
+            LinalgError::InvalidArgument(match e {
+                Error::EmptyRange => "Cannot sample from an empty range!".to_string(),
+                Error::NonFinite => "Cannot sample from a range of non-finite width!".to_string(),
+            })
+        })?;
+
+        let mat = (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| uniform.sample(rng))
+                    .collect::<Vec<T>>()
+                    .into()
+            })
+            .collect();
+
+        Ok(Matrix { mat, rows, cols })
+    }
 }
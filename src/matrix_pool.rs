@@ -0,0 +1,249 @@
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A pool that recycles the row buffers of temporary `Matrix` instances
+///
+/// Long computations (iterative solvers, repeated forward passes, etc.) tend to create and
+/// drop intermediate matrices of the same shapes over and over. Handing those matrices back
+/// to the pool with `MatrixPool::recycle` keeps their row allocations around, and the next
+/// `MatrixPool::multiply`, `MatrixPool::add`, etc. writes its result into them instead of
+/// allocating fresh rows
+///
+/// Only rows that are not shared with any other `Matrix` are kept; shared rows are simply
+/// dropped since their storage is still in use elsewhere
+#[derive(Debug)]
+pub struct MatrixPool<T: Number> {
+    free_rows: HashMap<usize, Vec<Arc<[T]>>>,
+    max_rows_per_width: usize,
+}
+
+impl<T: Number> Default for MatrixPool<T> {
+    fn default() -> Self {
+        MatrixPool::new()
+    }
+}
+
+impl<T: Number> MatrixPool<T> {
+    /// Creates an empty `MatrixPool` with no limit on the number of recycled rows
+    ///
+    /// ### Returns
+    /// - An empty `MatrixPool`
+    pub fn new() -> MatrixPool<T> {
+        MatrixPool::with_capacity_limit(usize::MAX)
+    }
+
+    /// Creates an empty `MatrixPool` that keeps at most `max_rows_per_width` recycled rows
+    /// for each row length
+    ///
+    /// ### Parameters
+    /// - `max_rows_per_width` - The maximum number of free rows kept per row length;
+    ///   any extra recycled rows are dropped
+    ///
+    /// ### Returns
+    /// - An empty `MatrixPool`
+    pub fn with_capacity_limit(max_rows_per_width: usize) -> MatrixPool<T> {
+        MatrixPool {
+            free_rows: HashMap::new(),
+            max_rows_per_width,
+        }
+    }
+
+    /// Gets the total number of free rows currently held by the pool
+    ///
+    /// ### Returns
+    /// - The number of row buffers available for reuse
+    pub fn available_rows(&self) -> usize {
+        self.free_rows.values().map(Vec::len).sum()
+    }
+
+    /// Drops every free row held by the pool
+    pub fn clear(&mut self) {
+        self.free_rows.clear();
+    }
+
+    /// Hands a `Matrix` that is no longer needed back to the pool so its rows can be reused
+    ///
+    /// ### Parameters
+    /// - `matrix` - The `Matrix` to recycle
+    pub fn recycle(&mut self, matrix: Matrix<T>) {
+        for mut row in matrix.mat {
+            if Arc::get_mut(&mut row).is_none() {
+                continue;
+            }
+
+            let free = self.free_rows.entry(row.len()).or_default();
+            if free.len() < self.max_rows_per_width {
+                free.push(row);
+            }
+        }
+    }
+
+    /// Creates a `rows` by `cols` `Matrix` filled with zeroes, reusing recycled rows
+    /// where possible
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows in the new `Matrix`
+    /// - `cols` - The number of columns in the new `Matrix`
+    ///
+    /// ### Returns
+    /// - A zero-filled `Matrix` of the requested shape
+    pub fn zeros(&mut self, rows: usize, cols: usize) -> Matrix<T> {
+        let mut matrix = self.uninitialized(rows, cols);
+        for row in &mut matrix.mat {
+            Arc::get_mut(row)
+                .expect("pooled rows are unique")
+                .fill(T::default());
+        }
+
+        matrix
+    }
+
+    /// Multiplies two `Matrix` instances together, writing the product into recycled rows
+    ///
+    /// ### Parameters
+    /// - `a`: One `Matrix` operand to be multiplied
+    /// - `b`: Another `Matrix` operand to be multiplied
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices were multiplied
+    ///     - An `Err` if the columns of `Matrix` a does not equal the rows of `Matrix` b
+    ///     - An `Ok` wrapped inside a `Matrix` object that represents the product between two
+    ///       matrices
+    #[allow(clippy::needless_range_loop)]
+    pub fn multiply(&mut self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        if a.cols != b.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (a.cols, b.cols),
+                found: (b.rows, b.cols),
+            });
+        }
+
+        let mut result = self.uninitialized(a.rows, b.cols);
+        for r in 0..a.rows {
+            let row = Arc::get_mut(&mut result.mat[r]).expect("pooled rows are unique");
+            for c in 0..b.cols {
+                let mut sum = T::default();
+                for k in 0..a.cols {
+                    sum += a.mat[r][k] * b.mat[k][c];
+                }
+                row[c] = sum;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Adds two `Matrix` instances together, writing the sum into recycled rows
+    ///
+    /// ### Parameters
+    /// - `a`: One `Matrix` operand addend
+    /// - `b`: Another `Matrix` operand addend
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices were added or not
+    ///     - An `Err` if the two matrices are different shapes
+    ///     - An `Ok` wrapped inside a `Matrix` instance that represents the sum
+    ///       of the two matrices `a` and `b`
+    pub fn add(&mut self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        self.zip(a, b, |x, y| x + y)
+    }
+
+    /// Subtracts `b` from `a`, writing the difference into recycled rows
+    ///
+    /// ### Parameters
+    /// - `a`: The `Matrix` to subtract from
+    /// - `b`: The `Matrix` to subtract
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the two matrices were subtracted
+    ///   - An `Err` value when the two matrices have different shapes
+    ///   - An `Ok` value wrapped with a `Matrix` instance that represents the difference
+    ///     of the two matrices `a` and `b`
+    pub fn subtract(&mut self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        self.zip(a, b, |x, y| x - y)
+    }
+
+    /// Multiplies a `Matrix` by a scalar `constant`, writing the result into recycled rows
+    ///
+    /// ### Parameters
+    /// - `matrix`: The `Matrix` to be multiplied by a scalar constant
+    /// - `constant`: The scalar constant to multiply the `Matrix` by
+    ///
+    /// ### Returns
+    /// - A new `Matrix` that contains the matrix after multiplying by a scalar constant
+    #[allow(clippy::needless_range_loop)]
+    pub fn multiply_by_scalar(&mut self, matrix: &Matrix<T>, constant: T) -> Matrix<T> {
+        let mut result = self.uninitialized(matrix.rows, matrix.cols);
+        for r in 0..matrix.rows {
+            let row = Arc::get_mut(&mut result.mat[r]).expect("pooled rows are unique");
+            for c in 0..matrix.cols {
+                row[c] = matrix.mat[r][c] * constant;
+            }
+        }
+
+        result
+    }
+
+    /// Computes the transpose of a `Matrix`, writing the result into recycled rows
+    ///
+    /// ### Parameters
+    /// - `matrix` - An existing `Matrix` to transpose
+    ///
+    /// ### Returns
+    /// - A `Matrix` instance containing the transposed matrix
+    #[allow(clippy::needless_range_loop)]
+    pub fn transpose(&mut self, matrix: &Matrix<T>) -> Matrix<T> {
+        let mut result = self.uninitialized(matrix.cols, matrix.rows);
+        for c in 0..matrix.cols {
+            let row = Arc::get_mut(&mut result.mat[c]).expect("pooled rows are unique");
+            for r in 0..matrix.rows {
+                row[r] = matrix.mat[r][c];
+            }
+        }
+
+        result
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn zip(
+        &mut self,
+        a: &Matrix<T>,
+        b: &Matrix<T>,
+        op: impl Fn(T, T) -> T,
+    ) -> Result<Matrix<T>, LinalgError> {
+        if (a.rows, a.cols) != (b.rows, b.cols) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (a.rows, a.cols),
+                found: (b.rows, b.cols),
+            });
+        }
+
+        let mut result = self.uninitialized(a.rows, a.cols);
+        for r in 0..a.rows {
+            let row = Arc::get_mut(&mut result.mat[r]).expect("pooled rows are unique");
+            for c in 0..a.cols {
+                row[c] = op(a.mat[r][c], b.mat[r][c]);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Takes `rows` unique row buffers of length `cols` out of the pool (allocating any that
+    /// are missing). The contents of reused rows are whatever was left in them
+    fn uninitialized(&mut self, rows: usize, cols: usize) -> Matrix<T> {
+        let free = self.free_rows.entry(cols).or_default();
+        let mut mat = Vec::with_capacity(rows);
+        for _ in 0..rows {
+            let row = free
+                .pop()
+                .unwrap_or_else(|| Arc::from(vec![T::default(); cols]));
+            mat.push(row);
+        }
+
+        Matrix { mat, rows, cols }
+    }
+}
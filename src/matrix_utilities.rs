@@ -1,700 +1,514 @@
-extern crate num;
-
+use crate::arith;
+use crate::context::ComputeContext;
+use crate::decompose::{self, Factors, Svd};
+use crate::diff::DiffReport;
+use crate::error::LinalgError;
 use crate::matrix::Matrix;
 use crate::number::Number;
+use crate::solve;
+use crate::transform;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::ops::Neg;
-use std::sync::Arc;
 
 /// `MatrixUtilities` is a utility struct designed to perform
 ///  various algorithms or operations for `Matrix` instances, including
 ///  adding, subtracting, multiplying, and computing the row and reduced row
 ///  echelon form of `Matrix` instances
+///
+/// The algorithms now live as free functions in four modules: `arith` for element-wise
+/// arithmetic and products, `solve` for elimination, inversion, and determinants,
+/// `decompose` for factorizations, and `transform` for reshaping and changes of basis.
+/// Every method here forwards to its replacement and is deprecated, so existing code
+/// keeps compiling while it migrates
+///
+/// NaN and infinite elements are handled by one policy across the crate: solvers and
+/// decompositions that return a `Result` reject them up front with a non-finite input
+/// error, because pivoting and zero tests give meaningless answers once a NaN is involved.
+/// Element-wise operations and transforms that cannot fail, such as `add`, `multiply`,
+/// `rref`, and `determinant`, propagate them into their output instead, following IEEE 754.
+/// Use `Matrix::has_nan` and `Matrix::has_inf` to check a result
 pub struct MatrixUtilities<T: Number> {
     _marker: PhantomData<T>,
 }
 
 impl<T: Number + Neg<Output = T>> MatrixUtilities<T> {
-    /// Appends a `row` to a given `Matrix`, returning an updated `Matrix` instance with the newly
-    /// appended row
-    ///
-    /// ### Parameters
-    /// - `matrix` - The `Matrix` needed to add a `row`
-    /// - `row`: An `&[i64]` slice
-    ///
-    /// ### Returns
-    /// - An updated `Matrix` object that adds the given `row`
-    ///   to the given `Matrix`
-    pub fn append(mut matrix: Matrix<T>, row: &[T]) -> Matrix<T> {
-        matrix.mat.push(Arc::from(row));
-        matrix.rows = matrix.mat.len();
-        matrix.cols = row.len();
-
-        matrix
-    }
-
-    /// Appends multiple `rows` to a given `Matrix`, returning an updated `Matrix` instance
-    /// with the newly appended rows
-    ///
-    /// ### Parameters
-    /// - `matrix`: The `Matrix` needed to add additional `rows`
-    /// - `rows`: A slice of `&[i64]` slices representing a series of rows to add to a given `Matrix`
-    ///
-    /// ### Returns
-    /// - An updated `Matrix` object that adds all `rows` to this
-    ///   `Matrix`
-    pub fn append_multiple(mut matrix: Matrix<T>, rows: &[&[T]]) -> Matrix<T> {
-        for &row in rows {
-            matrix.mat.push(Arc::from(row));
-        }
-        matrix.rows = matrix.mat.len();
-        if !rows.is_empty() {
-            matrix.cols = rows[0].len();
-        }
-
-        matrix
-    }
-
-    /// Computes the row echelon form for the given `matrix` and returns the result as an updated
-    /// `Matrix` instance
-    ///
-    /// ### Parameters
-    /// - `matrix`: The `Matrix` needed to compute the row echelon form
-    ///
-    /// ### Returns
-    /// - A `Matrix` instance containing the given `matrix` in row echelon form
-    pub fn row_echelon_form(mut matrix: Matrix<T>) -> Matrix<T> {
-        let rows = matrix.rows;
-        let cols = matrix.cols;
-
-        for i in 0..rows {
-            let pivot = matrix.mat[i][i];
-            if pivot != T::default() {
-                for c in 0..cols {
-                    let row = Arc::make_mut(&mut matrix.mat[i]);
-                    row[c] = row[c] / pivot;
-                    if row[c] == -T::default() {
-                        row[c] = T::default();
-                    }
-                }
-            }
-
-            let pivot_row = Arc::clone(&matrix.mat[i]);
-
-            for j in (i + 1)..rows {
-                let scale_factor = matrix.mat[j][i];
-                let (_, lower) = matrix.mat.split_at_mut(j);
-                let row_j = Arc::make_mut(&mut lower[0]);
-
-                for c in 0..cols {
-                    row_j[c] = row_j[c] - scale_factor * pivot_row[c];
-                    if row_j[c] == -T::default() {
-                        row_j[c] = T::default();
-                    }
-                }
-            }
-        }
-
-        matrix
-    }
-
-    /// Computes the reduced row echelon form (RREF) for the given `matrix` and returns the result
-    /// as an updated `Matrix` instance
-    ///
-    /// ### Parameters
-    /// - `matrix`: The `Matrix` needed to compute the reduced row echelon form
-    ///
-    /// ### Returns
-    /// - A `Matrix` instance containing the given `matrix` in reduced row echelon form
-    pub fn rref(mut matrix: Matrix<T>) -> Matrix<T> {
-        let rows = matrix.rows;
-        let cols = matrix.cols;
-
-        for i in 0..rows {
-            let pivot = matrix.mat[i][i];
-            if pivot != T::default() {
-                for c in 0..cols {
-                    let row = Arc::make_mut(&mut matrix.mat[i]);
-                    row[c] = row[c] / pivot;
-                }
-            }
-
-            let pivot_row = Arc::clone(&matrix.mat[i]);
-
-            for j in (i + 1)..rows {
-                let factor = matrix.mat[j][i];
-                let pivot_row_clone = Arc::clone(&pivot_row);
-                let (_, lower) = matrix.mat.split_at_mut(j);
-                let row_j = Arc::make_mut(&mut lower[0]);
-
-                for c in 0..cols {
-                    row_j[c] = row_j[c] - factor * pivot_row_clone[c];
-                }
-            }
-        }
-
-        for i in (0..rows).rev() {
-            for j in (0..i).rev() {
-                let factor = matrix.mat[j][i];
-                let pivot_row_clone = Arc::clone(&matrix.mat[i]);
-                let (_, lower) = matrix.mat.split_at_mut(j);
-                let row_j = Arc::make_mut(&mut lower[0]);
-
-                for c in 0..cols {
-                    row_j[c] = row_j[c] - factor * pivot_row_clone[c];
-                }
-            }
-        }
-
-        matrix
-    }
-
-    /// Performs the [Gaussian Elimination](https://en.wikipedia.org/wiki/Gaussian_elimination)
-    /// technique on a given `matrix` to solve for its system of equations' missing variables
-    /// (e.g. x, y, and z)
-    ///
-    /// ### Parameters
-    /// - `matrix`: The `Matrix` to perform Gaussian Elimination on
-    ///
-    /// ### Returns
-    /// - A `Result` based on whether the matrix had a solution
-    ///     - An `Err` with an enclosed `String` representing the error state of solving the `matrix`
-    ///       using Gaussian Elimination (i.e. no solution or infinitely many solutions)
-    ///     - An `Ok` enclosed with a `HashMap` containing each variable name
-    ///       mapped to a value with its solution
-    pub fn gaussian_elimination(mut matrix: Matrix<T>) -> Result<HashMap<char, T>, String> {
-        matrix = MatrixUtilities::row_echelon_form(matrix);
-        let mut pivot_vars = HashMap::new();
-        let num_rows = matrix.rows;
-        let num_cols = matrix.cols;
-
-        let mut solutions = vec![T::default(); num_rows];
-
-        for i in (0..num_rows).rev() {
-            let mut sum = T::default();
-
-            for j in (i + 1)..num_cols - 1 {
-                sum = sum + matrix.mat[i][j] * solutions[j];
-            }
-
-            solutions[i] = matrix.mat[i][num_cols - 1] - sum;
-            solutions[i] = solutions[i] / matrix.mat[i][i];
-
-            pivot_vars.insert(('a' as u8 + i as u8) as char, solutions[i]);
-        }
-
-        for i in 0..num_rows {
-            if matrix.mat[i].iter().all(|&x| x == T::default())
-                && matrix.mat[i][num_cols - 1] == T::default()
-            {
-                return Err("Infinitely many solutions exist for the given matrix.".to_string());
-            }
-        }
-
-        for i in 0..num_rows {
-            let pivot = matrix.mat[i][i];
-            if pivot == T::default() && matrix.mat[i][num_cols - 1] != T::default() {
-                return Err("No solution exists for the given matrix.".to_string());
-            }
-        }
-
-        Ok(pivot_vars)
-    }
-
-    /// Adds two `Matrix` instances together and returns a new `Matrix` representing
-    /// their sum
-    ///
-    /// ### Parameters
-    /// - `a`: One `Matrix` operand addend
-    /// - 'b': Another 'Matrix' operand addend
-    ///
-    /// ### Returns
-    /// - A `Result` based on whether the two matrices were added or not
-    ///     - An `Err` if the two matrices are different shapes
-    ///     - An `Ok` wrapped inside a `Matrix` instance that represents the sum
-    ///       of the two matrices `a` and `b`
-    pub fn add(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, String> {
-        if (a.rows, a.cols) != (b.rows, b.cols) {
-            return Err("Cannot add the two matrices because
-                their shapes are unequal!"
-                .to_string());
-        }
-
-        let mut result = Vec::new();
-
-        for r in 0..a.rows {
-            let mut new_row = Vec::new();
-            for c in 0..a.cols {
-                new_row.push(a.mat[r][c] + b.mat[r][c]);
-            }
-            result.push(Arc::from(new_row.as_slice()));
-        }
-
-        Ok(Matrix {
-            mat: result,
-            rows: a.rows,
-            cols: a.cols,
-        })
-    }
-
-    /// Subtracts two `Matrix` instances together and returns a new `Matrix` representing
-    /// their difference
-    ///
-    /// ### Parameters
-    /// - `a`: A `Matrix` instance that will be one of the operands
-    /// - `b`: Another 'Matrix' instance that will be the second operand to subtract from
-    ///
-    /// ### Returns
-    /// - An `Result` based on whether the two matrices were added
-    ///   - An `Err` value when the two matrices have different shapes
-    ///   - An `Ok` value wrapped with a `Matrix` instance that represents the difference
-    ///     of the two matrices `a` and `b`
-    pub fn subtract(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, String> {
-        if (a.rows, a.cols) != (b.rows, b.cols) {
-            return Err("Cannot add the two matrices because
-                their shapes are unequal!"
-                .to_string());
-        }
-
-        let mut result = Vec::new();
-
-        for r in 0..a.rows {
-            let mut new_row = Vec::new();
-            for c in 0..a.cols {
-                new_row.push(a.mat[r][c] - b.mat[r][c]);
-            }
-            result.push(Arc::from(new_row.as_slice()));
-        }
-
-        Ok(Matrix {
-            mat: result,
-            rows: a.rows,
-            cols: a.cols,
-        })
-    }
-
-    /// Multiplies a given `Matrix` by a given scalar `constant`
-    ///
-    /// ### Parameters
-    /// - `matrix`: The given `Matrix` to be multiplied by a scalar constant
-    /// - `constant`: The given scalar constant to multiply the given `Matrix` by
-    ///
-    /// ### Returns
-    /// - A new `Matrix` that contains the matrix after multiplying
-    ///   by a scalar constant
-    pub fn multiply_by_scalar(mut matrix: Matrix<T>, constant: T) -> Matrix<T> {
-        for row in &mut matrix.mat {
-            for elem in Arc::make_mut(row) {
-                *elem *= constant;
-            }
-        }
-
-        matrix
-    }
-
-    /// Multiplies two `Matrix` instances together and returns their product as a
-    /// new `Matrix` object
-    ///
-    /// ### Parameters
-    /// - `a`: One `Matrix` operand to be multiplied
-    /// - 'b': Another `Matrix` operand to be multiplied
-    ///
-    /// ### Returns
-    /// - A `Result` based on whether the two matrices were multiplied
-    ///     - An `Err` if the columns of `Matrix` a does not equal the rows of `Matrix` b
-    ///     - An `Ok` wrapped inside a `Matrix` object that represents the product between two
-    ///       matrices
-    pub fn multiply(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, String> {
-        if a.cols != b.rows {
-            return Err("The columns of matrix a do not
-                equal the rows of matrix b!"
-                .to_string());
-        }
-
-        let mut new_mat = vec![];
-        for r in 0..a.rows {
-            let mut new_row = vec![];
-            for c in 0..b.cols {
-                let mut sum = T::default();
-                for k in 0..a.cols {
-                    sum += a.mat[r][k] * b.mat[k][c];
-                }
-                new_row.push(sum);
-            }
-            new_mat.push(Arc::from(new_row.as_slice()));
-        }
-
-        Ok(Matrix {
-            mat: new_mat.clone(),
-            rows: new_mat.clone().len(),
-            cols: new_mat[0].clone().len(),
-        })
-    }
-
-    /// Gets the dot product of two matrices `a` and `b`
-    ///
-    /// ### Parameters
-    /// - `a`: One of the `Matrix` instance operands
-    /// - `b`: Another `Matrix` instance operand
-    ///
-    /// ### Returns
-    /// - A `Result` based on whether there is a
-    ///   valid dot product for matrices `a` and `b`
-    ///     - An `Err` value if the columns of `Matrix` a` do not equal the
-    ///       rows of `Matrix` b`
-    ///     - An `Ok` wrapped in a T generic value, representing the
-    ///       dot product
-    pub fn dot(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, String> {
-        if a.cols != b.rows {
-            return Err("Cannot get the dot product: The number of columns in A \
-                must match the number of rows in B."
-                .to_string());
-        }
-        if !(a.rows == 1 && b.cols == 1) {
-            return Err("Dot product is only valid for a
-                row vector and a column vector."
-                .to_string());
-        }
-
-        let mut sum = T::default();
-        for i in 0..a.cols {
-            sum += a.mat[0][i] * b.mat[i][0];
-        }
-
-        Ok(sum)
-    }
-
-    /// Performs the [Gauss-Jordan Elimination](https://online.stat.psu.edu/statprogram/reviews/matrix-algebra/gauss-jordan-elimination)
-    /// technique on a given `matrix` to solve for the missing variables in a system of equations
-    /// (e.g. x, y, and z)
-    ///
-    /// ### Parameters
-    /// - `matrix`: The `Matrix` to perform Gauss-Jordan Elimination on
-    ///
-    /// ### Returns
-    /// - A `Result` based on whether the matrix had a solution
-    ///     - An `Err` with an enclosed `String` representing the error state of solving the `matrix`
-    ///       using Gaussian Elimination (i.e. no solution or infinitely many solutions)
-    ///     - An `Ok` enclosed with a `HashMap` containing each variable name
-    ///       mapped to a value with its solution
-    pub fn gauss_jordan_elimination(mut matrix: Matrix<T>) -> Result<HashMap<char, T>, String> {
-        matrix = MatrixUtilities::rref(matrix);
-        let mut pivot_vars = HashMap::new();
-
-        for i in 0..matrix.rows {
-            let pivot = matrix.mat[i][i];
-
-            if pivot != T::default() {
-                pivot_vars.insert(
-                    ('a' as u8 + i as u8) as char,
-                    matrix.mat[i][matrix.cols - 1],
-                );
-            } else if matrix.mat[i][matrix.cols - 1] != T::default() {
-                return Err("No solution exists for the given matrix.".to_string());
-            }
-        }
-
-        for i in 0..matrix.rows {
-            if matrix.mat[i].iter().all(|&x| x == T::default()) {
-                return Err("Infinitely many solutions exist for the given matrix.".to_string());
-            }
-        }
-
-        Ok(pivot_vars)
-    }
-
-    /// Generates an `n` by `n` identity matrix
-    ///
-    /// The identity `Matrix` is a matrix that when multiplied by another matrix yields that other
-    /// matrix.
-    ///
-    /// ### Returns
-    /// - An `n` by `n` identity `Matrix`
+    /// Forwards to [`transform::append`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::transform::append` instead")]
+    pub fn append(matrix: Matrix<T>, row: &[T]) -> Matrix<T> {
+        transform::append(matrix, row)
+    }
+
+    /// Forwards to [`transform::append_multiple`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::transform::append_multiple` instead"
+    )]
+    pub fn append_multiple(matrix: Matrix<T>, rows: &[&[T]]) -> Matrix<T> {
+        transform::append_multiple(matrix, rows)
+    }
+
+    /// Forwards to [`solve::row_echelon_form_owned`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::row_echelon_form_owned` instead"
+    )]
+    pub fn row_echelon_form(matrix: Matrix<T>) -> Matrix<T> {
+        solve::row_echelon_form_owned(matrix)
+    }
+
+    /// Forwards to [`solve::rref_owned`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::solve::rref_owned` instead")]
+    pub fn rref(matrix: Matrix<T>) -> Matrix<T> {
+        solve::rref_owned(matrix)
+    }
+
+    /// Forwards to [`solve::gaussian_elimination`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::gaussian_elimination` instead"
+    )]
+    pub fn gaussian_elimination(matrix: Matrix<T>) -> Result<HashMap<char, T>, LinalgError> {
+        solve::gaussian_elimination(&matrix)
+    }
+
+    /// Forwards to [`arith::add`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::add` instead")]
+    pub fn add(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::add(a, b)
+    }
+
+    /// Forwards to [`arith::subtract`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::subtract` instead")]
+    pub fn subtract(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::subtract(a, b)
+    }
+
+    /// Forwards to [`arith::multiply_by_scalar_owned`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::multiply_by_scalar_owned` instead"
+    )]
+    pub fn multiply_by_scalar(matrix: Matrix<T>, constant: T) -> Matrix<T> {
+        arith::multiply_by_scalar_owned(matrix, constant)
+    }
+
+    /// Forwards to [`arith::multiply`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::multiply` instead")]
+    pub fn multiply(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::multiply(a, b)
+    }
+
+    /// Forwards to [`arith::multiply_naive`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::multiply_naive` instead"
+    )]
+    pub fn multiply_naive(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::multiply_naive(a, b)
+    }
+
+    /// Forwards to [`arith::multiply_strassen`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::multiply_strassen` instead"
+    )]
+    pub fn multiply_strassen(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::multiply_strassen(a, b)
+    }
+
+    /// Forwards to [`arith::gram`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::gram` instead")]
+    pub fn gram(a: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::gram(a)
+    }
+
+    /// Forwards to [`arith::row_gram`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::row_gram` instead")]
+    pub fn row_gram(a: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::row_gram(a)
+    }
+
+    /// Forwards to [`arith::trace_of_product`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::trace_of_product` instead"
+    )]
+    pub fn trace_of_product(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError> {
+        arith::trace_of_product(a, b)
+    }
+
+    /// Forwards to [`arith::trace_of_transpose_product`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::trace_of_transpose_product` instead"
+    )]
+    pub fn trace_of_transpose_product(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError> {
+        arith::trace_of_transpose_product(a, b)
+    }
+
+    /// Forwards to [`arith::frobenius_inner`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::frobenius_inner` instead"
+    )]
+    pub fn frobenius_inner(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError> {
+        arith::frobenius_inner(a, b)
+    }
+
+    /// Forwards to [`arith::dot`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::dot` instead")]
+    pub fn dot(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError> {
+        arith::dot(a, b)
+    }
+
+    /// Forwards to [`arith::dot_slices`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::dot_slices` instead")]
+    pub fn dot_slices(a: &[T], b: &[T]) -> Result<T, LinalgError> {
+        arith::dot_slices(a, b)
+    }
+
+    /// Forwards to [`solve::gauss_jordan_elimination`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::gauss_jordan_elimination` instead"
+    )]
+    pub fn gauss_jordan_elimination(matrix: Matrix<T>) -> Result<HashMap<char, T>, LinalgError> {
+        solve::gauss_jordan_elimination(&matrix)
+    }
+
+    /// Forwards to [`arith::identity`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::identity` instead")]
     pub fn identity(n: usize) -> Matrix<T> {
-        let mut output: Vec<Arc<[T]>> = vec![];
-        for i in 0..n {
-            let mut zeroes = vec![T::default(); n];
-            zeroes[i] = T::one();
-            let arr = &zeroes[..];
-            output.push(Arc::from(arr));
-        }
-
-        Matrix {
-            mat: output,
-            rows: n,
-            cols: n,
-        }
-    }
-
-    /// Computes the transpose of this `Matrix`
-    ///
-    /// The transpose of a `Matrix` is the resulting matrix where the columns are
-    /// formed from the corresponding rows of the original matrix
-    ///
-    /// ### Parameters
-    /// - `x` - An existing `Matrix` to transpose
-    /// 
-    /// ### Returns
-    /// - A `Matrix` instance containing the transposed matrix
+        arith::identity(n)
+    }
+
+    /// Forwards to [`Matrix::from_diagonal`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::matrix::Matrix::from_diagonal` instead"
+    )]
+    pub fn from_diagonal(values: &[T]) -> Matrix<T> {
+        Matrix::from_diagonal(values)
+    }
+
+    /// Forwards to [`transform::transpose`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::transform::transpose` instead")]
     pub fn transpose(x: &Matrix<T>) -> Matrix<T> {
-        let mut transposed_mat: Vec<Vec<T>> = vec![vec![T::default(); x.rows]; x.cols];
-
-        for i in 0..x.rows {
-            for j in 0..x.cols {
-                transposed_mat[j][i] = x.mat[i][j];
-            }
-        }
-
-        let transposed_mat: Vec<Arc<[T]>> = transposed_mat
-            .into_iter()
-            .map(|row| Arc::from(row.into_boxed_slice()))
-            .collect();
-
-        Matrix {
-            mat: transposed_mat,
-            rows: x.cols,
-            cols: x.rows,
-        }
-    }
-
-    /// Performs the inverse of a given matrix and returns it as a `Matrix` instance
-    ///
-    /// ### Parameters
-    /// - `matrix`: The `Matrix` to perform the inverse on
-    ///
-    /// ### Returns
-    /// - A `Result` type based on whether the given `matrix` is invertible
-    ///     - An `Err` consisting of a `String` if the given `matrix` is not invertible
-    ///     - An `Ok` consisting of the inverse matrix, if the given `matrix` is invertible
-    pub fn inverse(matrix: Matrix<T>) -> Result<Matrix<T>, String> {
-        let rows = matrix.rows;
-        let cols = matrix.cols;
-
-        if rows != cols {
-            return Err("Matrix must be square to find its inverse.".to_string());
-        }
-
-        let n = rows;
-        let identity_matrix = MatrixUtilities::identity(n);
-        let mut augmented = vec![];
-        for i in 0..n {
-            let mut row: Vec<T> = matrix.mat[i].to_vec();
-            row.extend_from_slice(&identity_matrix.mat[i]);
-            augmented.push(Arc::from(row));
-        }
-
-        let mut augmented_matrix = Matrix {
-            mat: augmented,
-            rows: n,
-            cols: 2 * n,
-        };
-
-        for i in 0..n {
-            if augmented_matrix.mat[i][i] == T::default() {
-                return Err("Matrix is singular and cannot be inverted".to_string());
-            }
-
-            let pivot = augmented_matrix.mat[i][i];
-            let row = Arc::make_mut(&mut augmented_matrix.mat[i]);
-            for j in 0..augmented_matrix.cols {
-                row[j] = row[j] / pivot;
-            }
-
-            for k in 0..n {
-                if k != i {
-                    let factor = augmented_matrix.mat[k][i];
-                    let row_i = augmented_matrix.mat[i].clone();
-                    let row_k = Arc::make_mut(&mut augmented_matrix.mat[k]);
-
-                    for j in 0..augmented_matrix.cols {
-                        row_k[j] -= factor * row_i[j];
-                    }
-                }
-            }
-        }
-
-        let mut inverse_mat = vec![];
-        for i in 0..n {
-            inverse_mat.push(Arc::from(augmented_matrix.mat[i][n..].to_vec()));
-        }
-
-        Ok(Matrix {
-            mat: inverse_mat,
-            rows: n,
-            cols: n,
-        })
-    }
-
-    /// Returns the LU Decomposition of a `Matrix` in the form of a tuple
-    ///
-    /// [LU Decomposition](https://en.wikipedia.org/wiki/LU_decomposition), or factorization,
-    /// is a technique used in Linear Algebra to factor a matrix as the product of a lower
-    /// triangular matrix and an upper triangular matrix. Typically viewed as that of the
-    /// matrix form of Gaussian Elimination
-    ///
-    /// ### Parameters
-    /// - `matrix` - The matrix to perform LU decomposition on
-    ///
-    /// ### Returns
-    /// - A `Result` type based on whether or not the `matrix` is invertible
-    ///     - Returns an Ok form containing a `Matrix` tuple containing the
-    ///       `l` and `u` decomposed matrices respectively
-    ///     - Returns an error if the `matrix` is not invertible
-    pub fn lu_decomposition(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), String> {
-        let n = matrix.rows;
-        if n != matrix.cols {
-            return Err("Matrix must be square for LU decomposition.".to_string());
-        }
-
-        let mut l = Matrix {
-            mat: vec![Arc::from(vec![T::default(); n].into_boxed_slice()); n],
-            rows: n,
-            cols: n,
-        };
-        let mut u = Matrix {
-            mat: vec![Arc::from(vec![T::default(); n].into_boxed_slice()); n],
-            rows: n,
-            cols: n,
-        };
-
-        for i in 0..n {
-            for j in i..n {
-                let mut sum = matrix.mat[i][j];
-                
-                for k in 0..i {
-                    sum -= l.mat[i][k] * u.mat[k][j];
-                }
-
-                let row = Arc::make_mut(&mut u.mat[i]);
-                row[j] = sum;
-            }
-
-            for j in i..n {
-                if i == j {
-                    let row = Arc::make_mut(&mut l.mat[i]);
-                    row[i] = T::one();
-                } else {
-                    let mut sum = matrix.mat[j][i];
-
-                    for k in 0..i {
-                        sum -= l.mat[j][k] * u.mat[k][i];
-                    }
-
-                    let row = Arc::make_mut(&mut l.mat[j]);
-                    row[i] = sum / u.mat[i][i];
-                }
-            }
-        }
-
-        Ok((l, u))
-    }
-
-    /// Compute the determinant of this `Matrix`
-    ///
-    /// - In a `Matrix` with a shape of `(1, 1)`, a `Matrix`'s determinant is
-    /// simply that number itself
-    ///
-    /// - In a `Matrix` with a shape of `(2, 2)`, a `Matrix`'s determinant is
-    /// equal to `ad - bc`, which is the difference
-    /// between the left diagonal product and the right diagonal product
-    ///
-    /// - Any other `Matrix` bigger than a `(2, 2)` (i.e. `(3, 3)`, `(4, 4)`, etc.) utilizes the
-    /// [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) approach.
-    /// The [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) approach involves expanding
-    /// the determinant along a row or column breaking it down into smaller sub-matrices until reaching
-    /// 2x2 matrices, where the determinant can directly be calculated using the formula `ad - bc`
-    ///
-    /// ### Parameters
-    /// - `x` - The given matrix to compute the determinant for
-    /// 
-    /// ### Returns
-    /// - The determinant value of `x` wrapped in an (optional)`Option` 
-    ///     - A `None` if the `Matrix`'s determinant could not be calculated 
-    ///       (unequal rows and columns)
-    ///     - A `Some` with the determinant value, if this `Matrix`'s
-    ///       shape is `(2, 2)` - 2 rows and 2 columns
-    pub fn determinant(x: &mut Matrix<T>) -> Option<T> {
-        let (rows, cols) = x.shape();
-        if rows != cols {
-            return None;
-        }
-
-        match rows {
-            1 => Some(x.mat[0][0]),
-            2 => {
-                let ad = x.mat[0][0] * x.mat[1][1];
-                let bc = x.mat[0][1] * x.mat[1][0];
-
-                Some(ad - bc)
-            }
-            _ => Some(MatrixUtilities::cofactor_expansion(x)),
-        }
-    }
-    
-    /// Compute the [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) of this `Matrix`
-    /// 
-    /// # Note
-    /// 
-    /// This function is not meant to be called on its own!!! 
-    /// The `determinant` function written in `MatrixUtilities` already calls this function!
-    /// Call that one instead. This one is just there as reference!!
-    /// 
-    /// The [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) is a technique used to 
-    /// calculate the determinant of matrices that are much bigger than a `(2, 2)` 
-    /// 
-    /// This approach involves expanding the determinant along a row or column 
-    /// breaking it down into smaller sub-matrices until reaching
-    /// 2x2 matrices, where the determinant can directly be calculated using the formula `ad - bc`
-    ///
-    /// ### Parameters
-    /// - `x` - The given matrix to compute cofactor expansion upon
-    /// 
-    /// ### Returns 
-    /// - The determinant value of `x` as a generic type `T`
-    pub(crate) fn cofactor_expansion(x: &mut Matrix<T>) -> T {
-        let (_, cols) = x.shape();
-        let mut det = T::default();
-
-        for col in 0..cols {
-            let mut sub_matrix = MatrixUtilities::create_cofactor_expansion_submatrix(x, col);
-
-            let sign = if col % 2 == 0 {
-                T::default() + num::One::one()
-            } else {
-                T::default() - num::One::one()
-            };
-
-            det += sign * x.mat[0][col] * MatrixUtilities::determinant(&mut sub_matrix)
-                .unwrap_or(T::default());
-        }
-
-        det
-    }
-    fn create_cofactor_expansion_submatrix(x: &mut Matrix<T>, exclude_col: usize) -> Matrix<T> {
-        let (rows, cols) = x.shape();
-        let mut new_matrix = Vec::new();
-
-        for i in 1..rows {
-            let filtered_row: Vec<T> = x.mat[i]
-                .iter()
-                .enumerate()
-                .filter_map(|(j, &val)| 
-                    if j != exclude_col { 
-                        Some(val) 
-                    } else { 
-                        None 
-                    }
-                )
-                .collect();
-
-            new_matrix.push(Arc::from(filtered_row.as_slice()));
-        }
-
-        Matrix {
-            mat: new_matrix,
-            rows: rows - 1,
-            cols: cols - 1,
-        }
+        transform::transpose(x)
+    }
+
+    /// Forwards to [`arith::kronecker`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::kronecker` instead")]
+    pub fn kronecker(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::kronecker(a, b)
+    }
+
+    /// Forwards to [`arith::hadamard`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::hadamard` instead")]
+    pub fn hadamard(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::hadamard(a, b)
+    }
+
+    /// Forwards to [`arith::pow`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::pow` instead")]
+    pub fn pow(matrix: &Matrix<T>, exponent: u32) -> Result<Matrix<T>, LinalgError> {
+        arith::pow(matrix, exponent)
+    }
+
+    /// Forwards to [`arith::commutator`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::commutator` instead")]
+    pub fn commutator(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::commutator(a, b)
+    }
+
+    /// Forwards to [`arith::anticommutator`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::anticommutator` instead"
+    )]
+    pub fn anticommutator(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::anticommutator(a, b)
+    }
+
+    /// Forwards to [`transform::similarity_transform_with_inverse`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::transform::similarity_transform_with_inverse` instead"
+    )]
+    pub fn similarity_transform_with_inverse(
+        p: &Matrix<T>,
+        p_inv: &Matrix<T>,
+        a: &Matrix<T>,
+    ) -> Result<Matrix<T>, LinalgError> {
+        transform::similarity_transform_with_inverse(p, p_inv, a)
+    }
+
+    /// Forwards to [`transform::hstack`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::transform::hstack` instead")]
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, LinalgError> {
+        transform::hstack(matrices)
+    }
+
+    /// Forwards to [`transform::vstack`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::transform::vstack` instead")]
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, LinalgError> {
+        transform::vstack(matrices)
+    }
+
+    /// Forwards to [`transform::block`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::transform::block` instead")]
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Result<Matrix<T>, LinalgError> {
+        transform::block(blocks)
+    }
+
+    /// Forwards to [`solve::inverse`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::solve::inverse` instead")]
+    pub fn inverse(matrix: Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        solve::inverse(&matrix)
+    }
+
+    /// Forwards to [`solve::inverse_with_context`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::inverse_with_context` instead"
+    )]
+    pub fn inverse_with_context(
+        matrix: Matrix<T>,
+        ctx: &ComputeContext,
+    ) -> Result<Matrix<T>, LinalgError> {
+        solve::inverse_with_context(&matrix, ctx)
+    }
+
+    /// Forwards to [`decompose::lu_decomposition`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::lu_decomposition` instead"
+    )]
+    pub fn lu_decomposition(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        decompose::lu_decomposition(matrix)
+    }
+
+    /// Forwards to [`decompose::lu_decomposition_with_context`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::lu_decomposition_with_context` instead"
+    )]
+    pub fn lu_decomposition_with_context(
+        matrix: &Matrix<T>,
+        ctx: &ComputeContext,
+    ) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        decompose::lu_decomposition_with_context(matrix, ctx)
+    }
+
+    /// Forwards to [`decompose::ldu_decomposition`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::ldu_decomposition` instead"
+    )]
+    pub fn ldu_decomposition(matrix: &Matrix<T>) -> Result<Factors<T>, LinalgError> {
+        decompose::ldu_decomposition(matrix)
+    }
+
+    /// Forwards to [`solve::determinant`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::solve::determinant` instead")]
+    pub fn determinant(x: &Matrix<T>) -> Option<T> {
+        solve::determinant(x)
+    }
+
+    /// Forwards to [`arith::eq_up_to_row_permutation`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::eq_up_to_row_permutation` instead"
+    )]
+    pub fn eq_up_to_row_permutation(a: &Matrix<T>, b: &Matrix<T>) -> Option<Vec<usize>> {
+        arith::eq_up_to_row_permutation(a, b)
+    }
+
+    /// Forwards to [`arith::eq_up_to_col_permutation`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::eq_up_to_col_permutation` instead"
+    )]
+    pub fn eq_up_to_col_permutation(a: &Matrix<T>, b: &Matrix<T>) -> Option<Vec<usize>> {
+        arith::eq_up_to_col_permutation(a, b)
+    }
+}
+
+impl<T: Number + Neg<Output = T> + PartialOrd> MatrixUtilities<T> {
+    /// Forwards to [`arith::diff_report`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::arith::diff_report` instead")]
+    pub fn diff_report(a: &Matrix<T>, b: &Matrix<T>, tol: T) -> Result<DiffReport<T>, LinalgError> {
+        arith::diff_report(a, b, tol)
+    }
+}
+
+impl<T: Number + Neg<Output = T> + num::Float> MatrixUtilities<T> {
+    /// Forwards to [`solve::determinant_fast`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::determinant_fast` instead"
+    )]
+    pub fn determinant_fast(x: &Matrix<T>) -> Result<T, LinalgError> {
+        solve::determinant_fast(x)
+    }
+
+    /// Forwards to [`solve::determinant_of_product`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::determinant_of_product` instead"
+    )]
+    pub fn determinant_of_product(factors: &[&Matrix<T>]) -> Result<T, LinalgError> {
+        solve::determinant_of_product(factors)
+    }
+
+    /// Forwards to [`decompose::plu_decomposition`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::plu_decomposition` instead"
+    )]
+    pub fn plu_decomposition(matrix: &Matrix<T>) -> Result<Factors<T>, LinalgError> {
+        decompose::plu_decomposition(matrix)
+    }
+
+    /// Forwards to [`decompose::plu_decomposition_with_context`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::plu_decomposition_with_context` instead"
+    )]
+    pub fn plu_decomposition_with_context(
+        matrix: &Matrix<T>,
+        ctx: &ComputeContext,
+    ) -> Result<Factors<T>, LinalgError> {
+        decompose::plu_decomposition_with_context(matrix, ctx)
+    }
+
+    /// Forwards to [`decompose::cholesky_decomposition`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::cholesky_decomposition` instead"
+    )]
+    pub fn cholesky_decomposition(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        decompose::cholesky_decomposition(matrix)
+    }
+
+    /// Forwards to [`solve::solve`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::solve::solve` instead")]
+    pub fn solve(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        solve::solve(a, b)
+    }
+
+    /// Forwards to [`solve::backward_error`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::backward_error` instead"
+    )]
+    pub fn backward_error(a: &Matrix<T>, x: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError> {
+        solve::backward_error(a, x, b)
+    }
+
+    /// Forwards to [`solve::inverse_with_tol`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::solve::inverse_with_tol` instead"
+    )]
+    pub fn inverse_with_tol(matrix: &Matrix<T>, eps: T) -> Result<Matrix<T>, LinalgError> {
+        solve::inverse_with_tol(matrix, eps)
+    }
+
+    /// Forwards to [`transform::similarity_transform`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::transform::similarity_transform` instead"
+    )]
+    pub fn similarity_transform(p: &Matrix<T>, a: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        transform::similarity_transform(p, a)
+    }
+
+    /// Forwards to [`transform::change_of_basis`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::transform::change_of_basis` instead"
+    )]
+    pub fn change_of_basis(
+        from_basis: &Matrix<T>,
+        to_basis: &Matrix<T>,
+    ) -> Result<Matrix<T>, LinalgError> {
+        transform::change_of_basis(from_basis, to_basis)
+    }
+
+    /// Forwards to [`decompose::qr_decomposition`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::qr_decomposition` instead"
+    )]
+    pub fn qr_decomposition(matrix: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        decompose::qr_decomposition(matrix)
+    }
+
+    /// Forwards to [`decompose::qr_decomposition_with_context`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::qr_decomposition_with_context` instead"
+    )]
+    pub fn qr_decomposition_with_context(
+        matrix: &Matrix<T>,
+        ctx: &ComputeContext,
+    ) -> Result<(Matrix<T>, Matrix<T>), LinalgError> {
+        decompose::qr_decomposition_with_context(matrix, ctx)
+    }
+
+    /// Forwards to [`decompose::svd`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::decompose::svd` instead")]
+    pub fn svd(matrix: &Matrix<T>) -> Result<Svd<T>, LinalgError> {
+        decompose::svd(matrix)
+    }
+
+    /// Forwards to [`decompose::orthonormalize`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::orthonormalize` instead"
+    )]
+    pub fn orthonormalize(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        decompose::orthonormalize(matrix)
+    }
+
+    /// Forwards to [`solve::null_space`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::solve::null_space` instead")]
+    pub fn null_space(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        solve::null_space(matrix)
+    }
+
+    /// Forwards to [`solve::column_space`]
+    #[deprecated(since = "0.4.0", note = "use `linalgrs::solve::column_space` instead")]
+    pub fn column_space(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        solve::column_space(matrix)
+    }
+
+    /// Forwards to [`arith::matrix_cosine_similarity`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::arith::matrix_cosine_similarity` instead"
+    )]
+    pub fn matrix_cosine_similarity(a: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError> {
+        arith::matrix_cosine_similarity(a, b)
+    }
+
+    /// Forwards to [`decompose::symmetric_eigen`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::decompose::symmetric_eigen` instead"
+    )]
+    pub fn symmetric_eigen(matrix: &Matrix<T>) -> Result<(Vec<T>, Matrix<T>), LinalgError> {
+        decompose::symmetric_eigen(matrix)
+    }
+
+    /// Forwards to [`transform::matrix_exponential`]
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `linalgrs::transform::matrix_exponential` instead"
+    )]
+    pub fn matrix_exponential(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        transform::matrix_exponential(matrix)
     }
 }
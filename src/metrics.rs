@@ -0,0 +1,137 @@
+#[cfg(feature = "metrics")]
+use std::collections::BTreeMap;
+#[cfg(feature = "metrics")]
+use std::fmt::Display;
+#[cfg(feature = "metrics")]
+use std::sync::Mutex;
+#[cfg(feature = "metrics")]
+use std::time::{Duration, Instant};
+
+/// The accumulated cost of one kind of high-level call, such as `"multiply"`
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallMetrics {
+    /// How many times the call was made
+    pub calls: u64,
+    /// The nominal number of floating-point (or integer) operations performed
+    pub flops: u64,
+    /// The number of heap buffers allocated for results
+    pub allocations: u64,
+    /// The total wall-clock time spent inside the call
+    pub time: Duration,
+}
+
+/// A point-in-time copy of every counter, keyed by call name
+#[cfg(feature = "metrics")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// The counters for each call that has been made at least once
+    pub calls: BTreeMap<&'static str, CallMetrics>,
+}
+
+#[cfg(feature = "metrics")]
+static METRICS: Mutex<BTreeMap<&'static str, CallMetrics>> = Mutex::new(BTreeMap::new());
+
+/// Copies the current counters of every instrumented call
+///
+/// Counters are process-wide and accumulate across threads until `reset` is called
+///
+/// ### Returns
+/// - A `MetricsSnapshot` of the counters
+#[cfg(feature = "metrics")]
+pub fn snapshot() -> MetricsSnapshot {
+    let calls = METRICS.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    MetricsSnapshot { calls }
+}
+
+/// Clears every counter
+#[cfg(feature = "metrics")]
+pub fn reset() {
+    METRICS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+#[cfg(feature = "metrics")]
+impl MetricsSnapshot {
+    /// Gets the counters for the call called `name`, if it has been made
+    pub fn get(&self, name: &str) -> Option<&CallMetrics> {
+        self.calls.get(name)
+    }
+
+    /// Sums the counters of every call
+    pub fn total(&self) -> CallMetrics {
+        self.calls
+            .values()
+            .fold(CallMetrics::default(), |total, m| CallMetrics {
+                calls: total.calls + m.calls,
+                flops: total.flops + m.flops,
+                allocations: total.allocations + m.allocations,
+                time: total.time + m.time,
+            })
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Display for MetricsSnapshot {
+    /// Writes one line per call with its counters
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, m) in &self.calls {
+            writeln!(
+                f,
+                "{}: {} calls, {} flops, {} allocations, {:?}",
+                name, m.calls, m.flops, m.allocations, m.time
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Records the cost of one high-level call when dropped
+///
+/// Without the `metrics` feature this is a zero-sized no-op, so instrumented code pays
+/// nothing for it
+pub(crate) struct Span {
+    #[cfg(feature = "metrics")]
+    name: &'static str,
+    #[cfg(feature = "metrics")]
+    flops: u64,
+    #[cfg(feature = "metrics")]
+    allocations: u64,
+    #[cfg(feature = "metrics")]
+    start: Instant,
+}
+
+impl Span {
+    /// Starts timing a call to `name` that performs `flops` operations and allocates
+    /// `allocations` result buffers
+    #[inline]
+    #[allow(unused_variables)]
+    pub(crate) fn new(name: &'static str, flops: usize, allocations: usize) -> Span {
+        Span {
+            #[cfg(feature = "metrics")]
+            name,
+            #[cfg(feature = "metrics")]
+            flops: flops as u64,
+            #[cfg(feature = "metrics")]
+            allocations: allocations as u64,
+            #[cfg(feature = "metrics")]
+            start: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let mut metrics = METRICS.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = metrics.entry(self.name).or_default();
+        entry.calls += 1;
+        entry.flops += self.flops;
+        entry.allocations += self.allocations;
+        entry.time += elapsed;
+    }
+}
@@ -0,0 +1,441 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::transform;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// A `Matrix` whose columns (and optionally rows) carry names, DataFrame style
+///
+/// Each column is a named feature such as `"age"` or `"height"`, and each row is an
+/// observation that may carry a label. Names are kept in sync through `select`, `hconcat`,
+/// `vconcat`, and `transpose`, so callers never need to track column indices by hand
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedMatrix<T: Number> {
+    matrix: Matrix<T>,
+    columns: Vec<String>,
+    row_labels: Option<Vec<String>>,
+}
+
+/// An error produced while building or combining `NamedMatrix` values
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NamedMatrixError {
+    /// `found` column names were given for a matrix with `expected` columns
+    ColumnCount { expected: usize, found: usize },
+    /// `found` row labels were given for a matrix with `expected` rows
+    RowLabelCount { expected: usize, found: usize },
+    /// A column name appears more than once
+    DuplicateColumn(String),
+    /// A row label appears more than once
+    DuplicateRowLabel(String),
+    /// No column has this name
+    UnknownColumn(String),
+    /// No row has this label
+    UnknownRowLabel(String),
+    /// The matrices placed side by side have `left` and `right` rows
+    RowCountMismatch { left: usize, right: usize },
+    /// The matrices placed side by side have different row labels
+    RowLabelsDiffer,
+    /// The matrices stacked on top of each other have different column names
+    ColumnsDiffer,
+    /// Only one of the matrices stacked on top of each other has row labels
+    MixedRowLabels,
+    /// The operation needs row labels but the matrix has none
+    MissingRowLabels,
+}
+
+impl Display for NamedMatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NamedMatrixError::ColumnCount { expected, found } => {
+                write!(f, "Expected {} column names but got {}", expected, found)
+            }
+            NamedMatrixError::RowLabelCount { expected, found } => {
+                write!(f, "Expected {} row labels but got {}", expected, found)
+            }
+            NamedMatrixError::DuplicateColumn(name) => {
+                write!(f, "Duplicate column name `{}`", name)
+            }
+            NamedMatrixError::DuplicateRowLabel(label) => {
+                write!(f, "Duplicate row label `{}`", label)
+            }
+            NamedMatrixError::UnknownColumn(name) => write!(f, "No column named `{}`", name),
+            NamedMatrixError::UnknownRowLabel(label) => write!(f, "No row labelled `{}`", label),
+            NamedMatrixError::RowCountMismatch { left, right } => write!(
+                f,
+                "Cannot place a matrix with {} rows beside one with {} rows",
+                right, left
+            ),
+            NamedMatrixError::RowLabelsDiffer => {
+                write!(f, "Cannot concatenate matrices with different row labels")
+            }
+            NamedMatrixError::ColumnsDiffer => {
+                write!(f, "Cannot stack matrices with different columns")
+            }
+            NamedMatrixError::MixedRowLabels => {
+                write!(f, "Cannot stack a matrix with row labels onto one without")
+            }
+            NamedMatrixError::MissingRowLabels => {
+                write!(f, "Row labels are required to name the transposed columns")
+            }
+        }
+    }
+}
+
+impl Error for NamedMatrixError {}
+
+fn check_unique(
+    names: &[String],
+    duplicate: fn(String) -> NamedMatrixError,
+) -> Result<(), NamedMatrixError> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            return Err(duplicate(name.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+impl<T: Number> NamedMatrix<T> {
+    /// Creates a `NamedMatrix` by naming every column of `matrix`
+    ///
+    /// ### Parameters
+    /// - `matrix` - The `Matrix` holding the data
+    /// - `columns` - One unique name per column of `matrix`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the names fit the matrix
+    ///     - An `Ok` containing the new `NamedMatrix`
+    ///     - An `Err` of `NamedMatrixError::ColumnCount` if the number of names does not
+    ///       equal the number of columns, or `NamedMatrixError::DuplicateColumn` if a name
+    ///       is repeated
+    pub fn new(matrix: Matrix<T>, columns: &[&str]) -> Result<NamedMatrix<T>, NamedMatrixError> {
+        if columns.len() != matrix.cols {
+            return Err(NamedMatrixError::ColumnCount {
+                expected: matrix.cols,
+                found: columns.len(),
+            });
+        }
+
+        let columns: Vec<String> = columns.iter().map(|name| name.to_string()).collect();
+        check_unique(&columns, NamedMatrixError::DuplicateColumn)?;
+
+        Ok(NamedMatrix {
+            matrix,
+            columns,
+            row_labels: None,
+        })
+    }
+
+    /// Attaches a label to every row of this `NamedMatrix`
+    ///
+    /// ### Parameters
+    /// - `labels` - One unique label per row
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the labels fit the matrix
+    ///     - An `Ok` containing this `NamedMatrix` with row labels
+    ///     - An `Err` of `NamedMatrixError::RowLabelCount` if the number of labels does
+    ///       not equal the number of rows, or `NamedMatrixError::DuplicateRowLabel` if a
+    ///       label is repeated
+    pub fn with_row_labels(mut self, labels: &[&str]) -> Result<NamedMatrix<T>, NamedMatrixError> {
+        if labels.len() != self.matrix.rows {
+            return Err(NamedMatrixError::RowLabelCount {
+                expected: self.matrix.rows,
+                found: labels.len(),
+            });
+        }
+
+        let labels: Vec<String> = labels.iter().map(|label| label.to_string()).collect();
+        check_unique(&labels, NamedMatrixError::DuplicateRowLabel)?;
+        self.row_labels = Some(labels);
+
+        Ok(self)
+    }
+
+    /// Gets the underlying `Matrix`
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Consumes this `NamedMatrix` and returns the underlying `Matrix`
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Gets the column names, in column order
+    pub fn column_names(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Gets the row labels, if any were attached
+    pub fn row_labels(&self) -> Option<&[String]> {
+        self.row_labels.as_deref()
+    }
+
+    /// Finds the index of the column called `name`
+    ///
+    /// ### Parameters
+    /// - `name` - The column name to look up
+    ///
+    /// ### Returns
+    /// - `Some` with the column index, or `None` if no column has that name
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column == name)
+    }
+
+    /// Finds the index of the row labelled `label`
+    ///
+    /// ### Parameters
+    /// - `label` - The row label to look up
+    ///
+    /// ### Returns
+    /// - `Some` with the row index, or `None` if there are no row labels or no row has
+    ///   that label
+    pub fn row_index(&self, label: &str) -> Option<usize> {
+        self.row_labels
+            .as_ref()?
+            .iter()
+            .position(|row| row == label)
+    }
+
+    /// Gets the values of the column called `name`
+    ///
+    /// ### Parameters
+    /// - `name` - The column name to fetch
+    ///
+    /// ### Returns
+    /// - A `Result` containing the column values top to bottom, or an `Err` of
+    ///   `NamedMatrixError::UnknownColumn` if no column has that name
+    pub fn col_by_name(&self, name: &str) -> Result<Vec<T>, NamedMatrixError> {
+        let c = self
+            .column_index(name)
+            .ok_or_else(|| NamedMatrixError::UnknownColumn(name.to_string()))?;
+
+        Ok(self.matrix.mat.iter().map(|row| row[c]).collect())
+    }
+
+    /// Gets the values of the row labelled `label`
+    ///
+    /// ### Parameters
+    /// - `label` - The row label to fetch
+    ///
+    /// ### Returns
+    /// - A `Result` containing the row values, or an `Err` of
+    ///   `NamedMatrixError::UnknownRowLabel` if no row has that label
+    pub fn row_by_label(&self, label: &str) -> Result<Vec<T>, NamedMatrixError> {
+        let r = self
+            .row_index(label)
+            .ok_or_else(|| NamedMatrixError::UnknownRowLabel(label.to_string()))?;
+
+        Ok(self.matrix.mat[r].to_vec())
+    }
+
+    /// Builds a new `NamedMatrix` containing only the named columns, in the given order
+    ///
+    /// ### Parameters
+    /// - `names` - The columns to keep
+    ///
+    /// ### Returns
+    /// - A `Result` containing the selected columns with their names and the original row
+    ///   labels, or an `Err` of `NamedMatrixError::UnknownColumn` or
+    ///   `NamedMatrixError::DuplicateColumn` if a name is unknown or repeated
+    pub fn select(&self, names: &[&str]) -> Result<NamedMatrix<T>, NamedMatrixError> {
+        let indices = names
+            .iter()
+            .map(|name| {
+                self.column_index(name)
+                    .ok_or_else(|| NamedMatrixError::UnknownColumn(name.to_string()))
+            })
+            .collect::<Result<Vec<usize>, NamedMatrixError>>()?;
+
+        let mat = self
+            .matrix
+            .mat
+            .iter()
+            .map(|row| {
+                let selected: Vec<T> = indices.iter().map(|&c| row[c]).collect();
+                Arc::from(selected)
+            })
+            .collect();
+        let matrix = Matrix {
+            mat,
+            rows: self.matrix.rows,
+            cols: indices.len(),
+        };
+
+        let mut selected = NamedMatrix::new(matrix, names)?;
+        selected.row_labels = self.row_labels.clone();
+
+        Ok(selected)
+    }
+
+    /// Places the columns of `other` to the right of this `NamedMatrix`
+    ///
+    /// ### Parameters
+    /// - `other` - The `NamedMatrix` whose columns are appended
+    ///
+    /// ### Returns
+    /// - A `Result` containing the combined `NamedMatrix`, or an `Err` of
+    ///   `NamedMatrixError::RowCountMismatch` if the row counts differ,
+    ///   `NamedMatrixError::DuplicateColumn` if a column name appears in both, or
+    ///   `NamedMatrixError::RowLabelsDiffer` if both have different row labels
+    pub fn hconcat(&self, other: &NamedMatrix<T>) -> Result<NamedMatrix<T>, NamedMatrixError> {
+        if self.matrix.rows != other.matrix.rows {
+            return Err(NamedMatrixError::RowCountMismatch {
+                left: self.matrix.rows,
+                right: other.matrix.rows,
+            });
+        }
+
+        let row_labels = match (&self.row_labels, &other.row_labels) {
+            (Some(a), Some(b)) if a != b => return Err(NamedMatrixError::RowLabelsDiffer),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+
+        let mut columns = self.columns.clone();
+        columns.extend(other.columns.iter().cloned());
+        check_unique(&columns, NamedMatrixError::DuplicateColumn)?;
+
+        let mat = self
+            .matrix
+            .mat
+            .iter()
+            .zip(&other.matrix.mat)
+            .map(|(left, right)| {
+                let mut row = left.to_vec();
+                row.extend_from_slice(right);
+                Arc::from(row)
+            })
+            .collect();
+
+        Ok(NamedMatrix {
+            matrix: Matrix {
+                mat,
+                rows: self.matrix.rows,
+                cols: columns.len(),
+            },
+            columns,
+            row_labels,
+        })
+    }
+
+    /// Places the rows of `other` below this `NamedMatrix`
+    ///
+    /// `other` must have the same column names, though not necessarily in the same order;
+    /// its columns are reordered to match this `NamedMatrix`
+    ///
+    /// ### Parameters
+    /// - `other` - The `NamedMatrix` whose rows are appended
+    ///
+    /// ### Returns
+    /// - A `Result` containing the combined `NamedMatrix`, or an `Err` of
+    ///   `NamedMatrixError::ColumnsDiffer` if the column names differ,
+    ///   `NamedMatrixError::MixedRowLabels` if only one side has row labels, or
+    ///   `NamedMatrixError::DuplicateRowLabel` if a row label appears in both
+    pub fn vconcat(&self, other: &NamedMatrix<T>) -> Result<NamedMatrix<T>, NamedMatrixError> {
+        let names: Vec<&str> = self.columns.iter().map(String::as_str).collect();
+        if other.columns.len() != names.len() {
+            return Err(NamedMatrixError::ColumnsDiffer);
+        }
+        let reordered = other
+            .select(&names)
+            .map_err(|_| NamedMatrixError::ColumnsDiffer)?;
+
+        let row_labels = match (&self.row_labels, &reordered.row_labels) {
+            (Some(a), Some(b)) => {
+                let mut labels = a.clone();
+                labels.extend(b.iter().cloned());
+                check_unique(&labels, NamedMatrixError::DuplicateRowLabel)?;
+                Some(labels)
+            }
+            (None, None) => None,
+            _ => return Err(NamedMatrixError::MixedRowLabels),
+        };
+
+        let mut mat = self.matrix.mat.clone();
+        mat.extend(reordered.matrix.mat);
+
+        Ok(NamedMatrix {
+            matrix: Matrix {
+                rows: mat.len(),
+                cols: self.matrix.cols,
+                mat,
+            },
+            columns: self.columns.clone(),
+            row_labels,
+        })
+    }
+}
+
+impl<T: Number + Neg<Output = T>> NamedMatrix<T> {
+    /// Computes the transpose of this `NamedMatrix`, swapping the column names and the
+    /// row labels
+    ///
+    /// ### Returns
+    /// - A `Result` containing the transposed `NamedMatrix`, or an `Err` of
+    ///   `NamedMatrixError::MissingRowLabels` if this matrix has no row labels to become
+    ///   the new column names
+    pub fn transpose(&self) -> Result<NamedMatrix<T>, NamedMatrixError> {
+        let row_labels = self
+            .row_labels
+            .clone()
+            .ok_or(NamedMatrixError::MissingRowLabels)?;
+
+        Ok(NamedMatrix {
+            matrix: transform::transpose(&self.matrix),
+            columns: row_labels,
+            row_labels: Some(self.columns.clone()),
+        })
+    }
+}
+
+impl<T: Number> Display for NamedMatrix<T> {
+    /// Writes a `NamedMatrix` as a table with a header row of column names
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label_width = self.row_labels.as_ref().map_or(0, |labels| {
+            labels.iter().map(String::len).max().unwrap_or(0)
+        });
+
+        let cells: Vec<Vec<String>> = self
+            .matrix
+            .mat
+            .iter()
+            .map(|row| row.iter().map(|x| format!("{}", x)).collect())
+            .collect();
+        let widths: Vec<usize> = (0..self.columns.len())
+            .map(|c| {
+                cells
+                    .iter()
+                    .map(|row| row[c].len())
+                    .chain(std::iter::once(self.columns[c].len()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        write!(f, "{:label_width$}", "")?;
+        for (name, width) in self.columns.iter().zip(&widths) {
+            write!(f, " {:>width$}", name, width = *width)?;
+        }
+        writeln!(f)?;
+
+        for (r, row) in cells.iter().enumerate() {
+            let label = self.row_labels.as_ref().map_or("", |labels| &labels[r]);
+            write!(f, "{:label_width$}", label)?;
+            for (cell, width) in row.iter().zip(&widths) {
+                write!(f, " {:>width$}", cell, width = *width)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
 extern crate num;
 
 use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 /// A Number trait to restrict a `Matrix`'s `T` generic to only signed numeric types
+///
+/// Numbers are `Send + Sync` so that, with the `rayon` feature, rows can be processed on
+/// several threads at once
 pub trait Number:
     Add<Output = Self>
     + Sub<Output = Self>
@@ -18,15 +22,140 @@ pub trait Number:
     + Debug
     + Display
     + PartialEq
+    + Send
+    + Sync
     + num::One
 {
+    /// Checks whether this value is NaN; always `false` for integer types
+    fn is_nan_value(self) -> bool {
+        false
+    }
+
+    /// Checks whether this value is positive or negative infinity; always `false` for
+    /// integer types
+    fn is_infinite_value(self) -> bool {
+        false
+    }
+
+    /// Widens `scale` to cover the magnitude of this value
+    ///
+    /// Elimination keeps such a scale for every element, the largest magnitude it or the
+    /// terms subtracted from it have held, and `is_approx_zero` measures rounding residue
+    /// against it. Integer arithmetic is exact and needs no scale, so the default leaves
+    /// `scale` unchanged
+    fn rounding_scale(self, scale: Self) -> Self {
+        scale
+    }
+
+    /// Checks whether this value is zero up to rounding, for an element computed from
+    /// values as large as `scale`
+    ///
+    /// Elimination uses this to flush the residue of a cancellation such as
+    /// `0.3 − 3 × 0.1` to an exact zero, so it is never divided by as a pivot. Integer
+    /// arithmetic is exact, so the default only accepts an exact zero
+    fn is_approx_zero(self, scale: Self) -> bool {
+        let _ = scale;
+        self == Self::default()
+    }
+
+    /// Feeds this value into `state` so that values which compare equal hash equally
+    ///
+    /// The default hashes the `Debug` output; the built-in types override it with their
+    /// exact representation
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        format!("{:?}", self).hash(state);
+    }
+}
+
+impl Number for i8 {
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        self.hash(state);
+    }
+}
+impl Number for i16 {
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        self.hash(state);
+    }
+}
+impl Number for i32 {
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        self.hash(state);
+    }
+}
+impl Number for i64 {
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        self.hash(state);
+    }
+}
+impl Number for i128 {
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        self.hash(state);
+    }
 }
+impl Number for isize {
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        self.hash(state);
+    }
+}
+impl Number for f32 {
+    fn is_nan_value(self) -> bool {
+        self.is_nan()
+    }
+
+    fn is_infinite_value(self) -> bool {
+        self.is_infinite()
+    }
+
+    fn rounding_scale(self, scale: Self) -> Self {
+        scale.max(self.abs())
+    }
+
+    /// Accepts anything within four units of rounding of `scale`
+    fn is_approx_zero(self, scale: Self) -> bool {
+        self.abs() <= 4.0 * f32::EPSILON * scale
+    }
+
+    /// Hashes the bits of the value, with `-0.0` folded into `0.0` because they compare
+    /// equal and every NaN folded into one
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        let canonical = if self == 0.0 {
+            0.0
+        } else if self.is_nan() {
+            f32::NAN
+        } else {
+            self
+        };
+        canonical.to_bits().hash(state);
+    }
+}
+impl Number for f64 {
+    fn is_nan_value(self) -> bool {
+        self.is_nan()
+    }
+
+    fn is_infinite_value(self) -> bool {
+        self.is_infinite()
+    }
+
+    fn rounding_scale(self, scale: Self) -> Self {
+        scale.max(self.abs())
+    }
 
-impl Number for i8 {}
-impl Number for i16 {}
-impl Number for i32 {}
-impl Number for i64 {}
-impl Number for i128 {}
-impl Number for isize {}
-impl Number for f32 {}
-impl Number for f64 {}
+    /// Accepts anything within four units of rounding of `scale`
+    fn is_approx_zero(self, scale: Self) -> bool {
+        self.abs() <= 4.0 * f64::EPSILON * scale
+    }
+
+    /// Hashes the bits of the value, with `-0.0` folded into `0.0` because they compare
+    /// equal and every NaN folded into one
+    fn hash_value<H: Hasher>(self, state: &mut H) {
+        let canonical = if self == 0.0 {
+            0.0
+        } else if self.is_nan() {
+            f64::NAN
+        } else {
+            self
+        };
+        canonical.to_bits().hash(state);
+    }
+}
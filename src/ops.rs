@@ -0,0 +1,153 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::ops::{Add, Mul, Neg, Sub};
+
+impl<T: Number + Neg<Output = T>> Matrix<T> {
+    /// Adds `other` to this `Matrix` without panicking; the checked form of `&a + &b`
+    ///
+    /// ### Parameters
+    /// - `other` - The `Matrix` to add
+    ///
+    /// ### Returns
+    /// - A `Result` containing the sum, or an `Err` if the shapes differ
+    pub fn checked_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::add(self, other)
+    }
+
+    /// Subtracts `other` from this `Matrix` without panicking; the checked form of `&a - &b`
+    ///
+    /// ### Parameters
+    /// - `other` - The `Matrix` to subtract
+    ///
+    /// ### Returns
+    /// - A `Result` containing the difference, or an `Err` if the shapes differ
+    pub fn checked_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::subtract(self, other)
+    }
+
+    /// Multiplies this `Matrix` by `other` without panicking; the checked form of `&a * &b`
+    ///
+    /// ### Parameters
+    /// - `other` - The right-hand `Matrix`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the product, or an `Err` if the columns of this `Matrix`
+    ///   do not equal the rows of `other`
+    pub fn checked_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        arith::multiply(self, other)
+    }
+}
+
+/// Implements a binary matrix operator for every combination of owned and borrowed operands,
+/// panicking with the checked method's error message on a shape mismatch
+///
+/// Given an `$owned` function, an owned left operand is updated in place by it instead of
+/// being copied
+macro_rules! matrix_op {
+    ($op:ident, $method:ident, $checked:ident, $owned:path) => {
+        matrix_op!(@borrowed $op, $method, $checked);
+
+        impl<T: Number + Neg<Output = T>> $op<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<T: Number + Neg<Output = T>> $op<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                $owned(self, rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+    };
+    ($op:ident, $method:ident, $checked:ident) => {
+        matrix_op!(@borrowed $op, $method, $checked);
+
+        impl<T: Number + Neg<Output = T>> $op<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T: Number + Neg<Output = T>> $op<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                (&self).$method(rhs)
+            }
+        }
+    };
+    (@borrowed $op:ident, $method:ident, $checked:ident) => {
+        impl<T: Number + Neg<Output = T>> $op<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            /// # Panics
+            /// Panics if the shapes are incompatible; use the `checked_` method to get a
+            /// `Result` instead
+            fn $method(self, rhs: &Matrix<T>) -> Matrix<T> {
+                self.$checked(rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl<T: Number + Neg<Output = T>> $op<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+matrix_op!(Add, add, checked_add, arith::add_owned);
+matrix_op!(Sub, sub, checked_sub, arith::subtract_owned);
+matrix_op!(Mul, mul, checked_mul);
+
+impl<T: Number + Neg<Output = T>> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Multiplies every element by the scalar `rhs`
+    fn mul(self, rhs: T) -> Matrix<T> {
+        arith::multiply_by_scalar(self, rhs)
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Multiplies every element by the scalar `rhs`
+    fn mul(self, rhs: T) -> Matrix<T> {
+        arith::multiply_by_scalar_owned(self, rhs)
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Negates every element
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Negates every element
+    fn neg(mut self) -> Matrix<T> {
+        for row in &mut self.mat {
+            for elem in std::sync::Arc::make_mut(row) {
+                *elem = -*elem;
+            }
+        }
+
+        self
+    }
+}
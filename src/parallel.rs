@@ -0,0 +1,47 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// The fewest multiply-adds a loop must perform before the `rayon` feature splits it across
+/// threads; below this, scheduling costs more than the arithmetic
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1 << 15;
+
+/// Collects `f(i)` for every `i` in `0..len`, in order
+///
+/// With the `rayon` feature enabled and `work` at least `PARALLEL_THRESHOLD`, the calls are
+/// spread across the rayon thread pool
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+pub(crate) fn map_range<R: Send>(
+    len: usize,
+    work: usize,
+    f: impl Fn(usize) -> R + Send + Sync,
+) -> Vec<R> {
+    #[cfg(feature = "rayon")]
+    if work >= PARALLEL_THRESHOLD {
+        return (0..len).into_par_iter().map(f).collect();
+    }
+
+    (0..len).map(f).collect()
+}
+
+/// Calls `f(i, &mut items[i])` for every index of `items`
+///
+/// With the `rayon` feature enabled and `work` at least `PARALLEL_THRESHOLD`, the calls are
+/// spread across the rayon thread pool
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+pub(crate) fn for_each_mut<I: Send>(
+    items: &mut [I],
+    work: usize,
+    f: impl Fn(usize, &mut I) + Send + Sync,
+) {
+    #[cfg(feature = "rayon")]
+    if work >= PARALLEL_THRESHOLD {
+        items
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, item)| f(i, item));
+        return;
+    }
+
+    items.iter_mut().enumerate().for_each(|(i, item)| f(i, item));
+}
@@ -0,0 +1,134 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An error produced while parsing a `Matrix` literal from a string
+///
+/// `position` is the byte offset into the input where the problem was found, so front-ends
+/// can point users at the offending character
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseMatrixError {
+    /// The byte offset into the input where the error was detected
+    pub position: usize,
+
+    /// A human-readable description of the error
+    pub message: String,
+}
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl Error for ParseMatrixError {}
+
+impl<T: Number + FromStr> Matrix<T> {
+    /// Parses a `Matrix` from a MATLAB-style literal such as `"1 2 3; 4 5 6"`
+    ///
+    /// Elements are separated by whitespace and/or commas and rows are separated by `;`
+    /// or newlines. The whole literal may optionally be wrapped in `[` and `]`
+    ///
+    /// ### Parameters
+    /// - `input` - The matrix literal to parse
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether `input` is a valid matrix literal
+    ///     - An `Ok` containing the parsed `Matrix`
+    ///     - An `Err` with a `ParseMatrixError` pointing at the invalid element, the first
+    ///       row whose length differs from the first row, or a misplaced bracket
+    pub fn parse(input: &str) -> Result<Matrix<T>, ParseMatrixError> {
+        let (body, offset) = strip_brackets(input)?;
+
+        let mut mat: Vec<Arc<[T]>> = Vec::new();
+        let mut cols = None;
+        let mut row: Vec<T> = Vec::new();
+        let mut row_start = offset;
+        let mut token_start = None;
+
+        let bytes = body.as_bytes();
+        for i in 0..=bytes.len() {
+            let c = bytes.get(i).copied();
+            let is_separator = matches!(c, None | Some(b' ' | b'\t' | b'\r' | b',' | b';' | b'\n'));
+
+            if is_separator {
+                if let Some(start) = token_start.take() {
+                    let token = &body[start..i];
+                    let value = token.parse::<T>().map_err(|_| ParseMatrixError {
+                        position: offset + start,
+                        message: format!("invalid number `{}`", token),
+                    })?;
+                    row.push(value);
+                }
+            } else if token_start.is_none() {
+                if row.is_empty() {
+                    row_start = offset + i;
+                }
+                token_start = Some(i);
+            }
+
+            if matches!(c, None | Some(b';' | b'\n')) && !row.is_empty() {
+                let expected = *cols.get_or_insert(row.len());
+                if row.len() != expected {
+                    return Err(ParseMatrixError {
+                        position: row_start,
+                        message: format!(
+                            "row {} has {} elements but the first row has {}",
+                            mat.len(),
+                            row.len(),
+                            expected
+                        ),
+                    });
+                }
+                mat.push(Arc::from(std::mem::take(&mut row)));
+            }
+        }
+
+        Ok(Matrix {
+            rows: mat.len(),
+            cols: cols.unwrap_or(0),
+            mat,
+        })
+    }
+}
+
+impl<T: Number + FromStr> FromStr for Matrix<T> {
+    type Err = ParseMatrixError;
+
+    /// Parses a `Matrix` from a MATLAB-style literal; equivalent to `Matrix::parse`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Matrix::parse(s)
+    }
+}
+
+/// Removes an optional pair of surrounding brackets, returning the inner text and its
+/// byte offset in `input`
+fn strip_brackets(input: &str) -> Result<(&str, usize), ParseMatrixError> {
+    let start = input.len() - input.trim_start().len();
+    let trimmed = input.trim();
+
+    let (body, offset) = match trimmed.strip_prefix('[') {
+        Some(rest) => match rest.strip_suffix(']') {
+            Some(inner) => (inner, start + 1),
+            None => {
+                return Err(ParseMatrixError {
+                    position: start + trimmed.len(),
+                    message: "expected a closing `]`".to_string(),
+                })
+            }
+        },
+        None => (trimmed, start),
+    };
+
+    if let Some(i) = body.find(['[', ']']) {
+        return Err(ParseMatrixError {
+            position: offset + i,
+            message: format!("unexpected `{}`", &body[i..i + 1]),
+        });
+    }
+
+    Ok((body, offset))
+}
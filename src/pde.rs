@@ -0,0 +1,279 @@
+use crate::error::LinalgError;
+use crate::iterative;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::sparse::SparseMatrix;
+use crate::system::System;
+use std::sync::Arc;
+
+/// A finite-difference discretization of the Poisson equation `−∇²u = f` on the unit
+/// square, assembled by `poisson_2d`
+///
+/// The unknowns are the values of `u` at the `nx x ny` interior grid points
+/// `(x_i, y_j) = ((i + 1) hx, (j + 1) hy)` with `hx = 1 / (nx + 1)` and
+/// `hy = 1 / (ny + 1)`, numbered row by row so that point `(i, j)` is unknown
+/// `j * nx + i`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoissonSystem<T: Number> {
+    /// The symmetric positive definite 5-point stencil matrix, with one row per unknown
+    pub matrix: SparseMatrix<T>,
+
+    /// The right-hand side: the source term plus the known boundary values
+    pub rhs: Vec<T>,
+
+    /// The number of interior points along `x`
+    pub nx: usize,
+
+    /// The number of interior points along `y`
+    pub ny: usize,
+}
+
+/// Assembles the 5-point finite-difference system for Laplace's equation `∇²u = 0` on the
+/// unit square with Dirichlet boundary values
+///
+/// Every interior point `(i, j)` contributes the equation
+/// `(2 / hx² + 2 / hy²) u(i, j) − (u(i − 1, j) + u(i + 1, j)) / hx² −
+/// (u(i, j − 1) + u(i, j + 1)) / hy² = f(x_i, y_j)`, with neighbours on the boundary moved
+/// to the right-hand side. Call `PoissonSystem::with_source` to add a source term `f`, and
+/// `PoissonSystem::solve` to solve the system by conjugate gradients
+///
+/// ### Parameters
+/// - `nx` - The number of interior grid points along `x`
+/// - `ny` - The number of interior grid points along `y`
+/// - `boundary` - The prescribed value `u(x, y)` at a point `(x, y)` on the boundary of
+///   the unit square
+///
+/// ### Returns
+/// - A `Result` containing the assembled `PoissonSystem`, or an `Err` of
+///   `LinalgError::InvalidArgument` if `nx` or `ny` is `0`
+pub fn poisson_2d<T, B>(nx: usize, ny: usize, boundary: B) -> Result<PoissonSystem<T>, LinalgError>
+where
+    T: Number + num::Float,
+    B: Fn(T, T) -> T,
+{
+    if nx == 0 || ny == 0 {
+        return Err(LinalgError::InvalidArgument(format!(
+            "A {}x{} grid has no interior points!",
+            nx, ny
+        )));
+    }
+
+    let wx = coordinate::<T>(nx + 1, 1).powi(2);
+    let wy = coordinate::<T>(ny + 1, 1).powi(2);
+    let centre = wx + wx + wy + wy;
+    let mut triplets = Vec::with_capacity(5 * nx * ny);
+    let mut rhs = vec![T::zero(); nx * ny];
+    for j in 0..ny {
+        for i in 0..nx {
+            let k = j * nx + i;
+            let (x, y) = (coordinate(i + 1, nx + 1), coordinate(j + 1, ny + 1));
+            triplets.push((k, k, centre));
+
+            // Each neighbour is either another unknown or a known boundary value
+            let neighbours = [
+                (i > 0, k.wrapping_sub(1), (T::zero(), y), wx),
+                (i + 1 < nx, k + 1, (T::one(), y), wx),
+                (j > 0, k.wrapping_sub(nx), (x, T::zero()), wy),
+                (j + 1 < ny, k + nx, (x, T::one()), wy),
+            ];
+            for (interior, neighbour, (bx, by), weight) in neighbours {
+                if interior {
+                    triplets.push((k, neighbour, -weight));
+                } else {
+                    rhs[k] += weight * boundary(bx, by);
+                }
+            }
+        }
+    }
+
+    Ok(PoissonSystem {
+        matrix: SparseMatrix::from_triplets(nx * ny, nx * ny, &triplets)?,
+        rhs,
+        nx,
+        ny,
+    })
+}
+
+impl<T: Number + num::Float> PoissonSystem<T> {
+    /// Adds the source term `f` of the Poisson equation `−∇²u = f` to this system
+    ///
+    /// ### Parameters
+    /// - `source` - The value `f(x, y)` at an interior point `(x, y)`
+    ///
+    /// ### Returns
+    /// - This `PoissonSystem` with `f` added to its right-hand side
+    pub fn with_source<F: Fn(T, T) -> T>(mut self, source: F) -> PoissonSystem<T> {
+        for j in 0..self.ny {
+            for i in 0..self.nx {
+                let (x, y) = (
+                    coordinate(i + 1, self.nx + 1),
+                    coordinate(j + 1, self.ny + 1),
+                );
+                self.rhs[j * self.nx + i] += source(x, y);
+            }
+        }
+        self
+    }
+
+    /// Solves this system by `iterative::conjugate_gradient`
+    ///
+    /// ### Parameters
+    /// - `tol` - The relative residual to reach, greater than `0`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the solver converged
+    ///     - An `Ok` containing the `ny x nx` solution grid, see `to_grid`
+    ///     - An `Err` of `LinalgError::InvalidArgument` if `tol` is not positive, or
+    ///       `LinalgError::NotConverged` if `tol` is too small to reach in twice as many
+    ///       iterations as there are unknowns
+    pub fn solve(&self, tol: T) -> Result<Matrix<T>, LinalgError> {
+        let max_iter = 2 * self.rhs.len();
+        let result = iterative::conjugate_gradient(&self.matrix, &self.rhs, tol, max_iter)?;
+
+        self.to_grid(&result.solution)
+    }
+
+    /// Arranges a vector of unknowns as the grid it describes
+    ///
+    /// ### Parameters
+    /// - `u` - The value at every interior point, in the numbering of `PoissonSystem`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `ny x nx` grid whose row `j`, column `i` holds the value
+    ///   at `(x_i, y_j)`, or an `Err` of `LinalgError::LengthMismatch` if `u` does not
+    ///   have `nx * ny` elements
+    pub fn to_grid(&self, u: &[T]) -> Result<Matrix<T>, LinalgError> {
+        if u.len() != self.nx * self.ny {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.nx * self.ny,
+                found: u.len(),
+            });
+        }
+
+        Ok(Matrix {
+            mat: u.chunks(self.nx).map(Arc::from).collect(),
+            rows: self.ny,
+            cols: self.nx,
+        })
+    }
+}
+
+/// Converts grid index `i` of `n` intervals to its coordinate `i / n` in the unit interval
+fn coordinate<T: num::Float>(i: usize, n: usize) -> T {
+    T::from(i).unwrap_or_else(T::nan) / T::from(n).unwrap_or_else(T::nan)
+}
+
+/// Imposes the Dirichlet condition `u[node] = value` on an assembled `System`
+///
+/// The row of `node` is replaced by the equation `u[node] = value`, and the known value is
+/// moved out of every other equation into its right-hand side, clearing the column of
+/// `node`. Unlike overwriting the row alone, this keeps a symmetric system symmetric, so
+/// Cholesky and conjugate gradients still apply. Every right-hand side column gets the
+/// same condition
+///
+/// ### Parameters
+/// - `system` - The `System` to modify, with an `n x n` coefficient matrix
+/// - `node` - The unknown to fix
+/// - `value` - The value it is fixed to
+///
+/// ### Returns
+/// - A `Result` based on whether the condition could be applied
+///     - An `Ok` once `system` has been modified
+///     - An `Err` of `LinalgError::OutOfBounds` if `node` is not below `n`,
+///       `LinalgError::NonSquare` if the coefficient matrix is not square, or
+///       `LinalgError::ShapeMismatch` if the right-hand side does not have `n` rows
+pub fn apply_dirichlet<T>(system: &mut System<T>, node: usize, value: T) -> Result<(), LinalgError>
+where
+    T: Number + num::One,
+{
+    let n = check_system(system, node)?;
+    let System {
+        coefficients,
+        constants,
+        ..
+    } = system;
+
+    for i in (0..n).filter(|&i| i != node) {
+        let coupling = coefficients.mat[i][node];
+        if coupling != T::default() {
+            constants.with_row_mut(i, |b| b.iter_mut().for_each(|x| *x -= coupling * value))?;
+            coefficients.with_row_mut(i, |row| row[node] = T::default())?;
+        }
+    }
+    coefficients.with_row_mut(node, |row| {
+        row.fill(T::default());
+        row[node] = T::one();
+    })?;
+    constants.with_row_mut(node, |b| b.fill(value))?;
+
+    Ok(())
+}
+
+/// Imposes a Neumann (flux) condition on an assembled `System`
+///
+/// In a finite-difference or finite-volume discretization a prescribed flux through the
+/// boundary enters the balance equation of the adjacent unknown as a source, so `flux`
+/// is added to the right-hand side of `node`. It should already be multiplied by the
+/// length or area of the boundary that `node` owns, and is positive for flow into the
+/// domain
+///
+/// ### Parameters
+/// - `system` - The `System` to modify, with an `n x n` coefficient matrix
+/// - `node` - The unknown on the boundary
+/// - `flux` - The integrated inflow through the boundary of `node`
+///
+/// ### Returns
+/// - A `Result` based on whether the condition could be applied
+///     - An `Ok` once `system` has been modified
+///     - An `Err` of `LinalgError::OutOfBounds` if `node` is not below `n`,
+///       `LinalgError::NonSquare` if the coefficient matrix is not square, or
+///       `LinalgError::ShapeMismatch` if the right-hand side does not have `n` rows
+pub fn apply_neumann<T>(system: &mut System<T>, node: usize, flux: T) -> Result<(), LinalgError>
+where
+    T: Number + num::One,
+{
+    check_system(system, node)?;
+
+    system
+        .constants
+        .with_row_mut(node, |b| b.iter_mut().for_each(|x| *x += flux))
+}
+
+/// Checks that `system` is a square system with `node` among its unknowns
+///
+/// ### Returns
+/// - A `Result` containing the number of unknowns, or an `Err` of
+///   `LinalgError::NonSquare` or `LinalgError::ShapeMismatch` if the shapes disagree
+fn check_system<T: Number + num::One>(
+    system: &System<T>,
+    node: usize,
+) -> Result<usize, LinalgError> {
+    let System {
+        coefficients,
+        constants,
+        ..
+    } = system;
+    coefficients.validate()?;
+    constants.validate()?;
+    let n = coefficients.rows;
+    if n != coefficients.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: coefficients.cols,
+        });
+    }
+    if constants.rows != n {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (n, constants.cols),
+            found: (constants.rows, constants.cols),
+        });
+    }
+    if node >= n {
+        return Err(LinalgError::OutOfBounds {
+            index: node,
+            len: n,
+        });
+    }
+
+    Ok(n)
+}
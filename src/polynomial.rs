@@ -0,0 +1,60 @@
+use crate::eigen;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use num::Complex;
+use std::ops::Neg;
+
+/// Finds every root of a polynomial as the eigenvalues of its companion matrix
+///
+/// This is the method of MATLAB's and NumPy's `roots`: `Matrix::companion` turns the
+/// polynomial into a matrix and `eigen::eigenvalues` finds its eigenvalues. Leading zero
+/// coefficients are dropped, and trailing zeros give exact roots at `0` without being
+/// passed to the eigenvalue solver. The roots are backward stable, so they are the exact
+/// roots of a polynomial whose coefficients differ from `coeffs` by a few rounding
+/// errors; multiple roots are only accurate to about `ε^(1/k)` for multiplicity `k`
+///
+/// ### Parameters
+/// - `coeffs` - The coefficients `c₀, c₁, …, cₙ` of `c₀ xⁿ + c₁ xⁿ⁻¹ + … + cₙ`, highest
+///   degree first
+///
+/// ### Returns
+/// - A `Result` containing the roots sorted by real part and then imaginary part, with
+///   complex roots in conjugate pairs and none for a constant polynomial, or an `Err` of
+///   `LinalgError::InvalidArgument` if every coefficient is zero or one is NaN or
+///   infinite, or `LinalgError::NotConverged` as described by `eigen::eigenvalues`
+pub fn roots<T>(coeffs: &[T]) -> Result<Vec<Complex<T>>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    if coeffs.iter().any(|c| !c.is_finite()) {
+        return Err(LinalgError::InvalidArgument(
+            "Every coefficient must be finite".to_string(),
+        ));
+    }
+    let start = coeffs.iter().position(|&c| c != T::zero()).ok_or_else(|| {
+        LinalgError::InvalidArgument("The zero polynomial has no finite roots".to_string())
+    })?;
+    let end = coeffs
+        .iter()
+        .rposition(|&c| c != T::zero())
+        .unwrap_or(start)
+        + 1;
+
+    let zeros = coeffs.len() - end;
+    let mut roots = match end - start {
+        1 => Vec::new(),
+        _ => eigen::eigenvalues(&Matrix::companion(&coeffs[start..end])?)?,
+    };
+    roots.extend(std::iter::repeat_n(
+        Complex::new(T::zero(), T::zero()),
+        zeros,
+    ));
+    roots.sort_by(|p, q| {
+        (p.re, p.im)
+            .partial_cmp(&(q.re, q.im))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(roots)
+}
@@ -0,0 +1,27 @@
+//! Re-exports the types, traits, macros, and free-function modules most programs need, so
+//! that a single `use linalgrs::prelude::*;` is enough. Items are only ever added, so glob
+//! imports keep compiling across releases. `Arc` is included because `matrix!` expands to
+//! `Arc::from`, and the operator and iterator traits because generic code over `Matrix`
+//! needs them in its bounds, e.g. `T: Number + Neg<Output = T>`
+//!
+//! The deprecated `MatrixUtilities` facade stays exported so existing glob imports keep
+//! working; new code should call the `arith`, `decompose`, `solve`, and `transform`
+//! functions it forwards to instead
+
+pub use crate::dense::DenseMatrix;
+pub use crate::error::LinalgError;
+pub use crate::iter::Iter;
+pub use crate::matrix::{Matrix, NormKind};
+#[allow(deprecated)]
+pub use crate::matrix_utilities::MatrixUtilities;
+pub use crate::number::Number;
+pub use crate::solver_registry::Solver;
+pub use crate::sparse::SparseMatrix;
+pub use crate::system::System;
+pub use crate::vector::Vector;
+pub use crate::view::MatrixView;
+pub use crate::{arith, decompose, solve, transform};
+pub use crate::{matrix, vector};
+pub use std::iter::{Product, Sum};
+pub use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+pub use std::sync::Arc;
@@ -0,0 +1,110 @@
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A thread-safe, reference-counted handle to a `Matrix` with copy-on-write semantics
+///
+/// Cloning a `SharedMatrix` is cheap: every clone points at the same underlying `Matrix`
+/// and no element is copied. Reads go straight through to the shared data via `Deref`.
+/// Writes go through `SharedMatrix::make_mut`, which clones the `Matrix` first if any
+/// other handle still refers to it, so an edit is never visible through another handle
+///
+/// This is distinct from `Matrix` itself, which has value semantics: a cloned `Matrix`
+/// may share row storage internally, but it always behaves as an independent copy
+#[derive(Debug)]
+pub struct SharedMatrix<T: Number> {
+    inner: Arc<Matrix<T>>,
+}
+
+impl<T: Number> SharedMatrix<T> {
+    /// Wraps a `Matrix` in a new `SharedMatrix` handle
+    ///
+    /// ### Parameters
+    /// - `matrix` - The `Matrix` to share
+    ///
+    /// ### Returns
+    /// - A `SharedMatrix` that is currently the only handle to `matrix`
+    pub fn new(matrix: Matrix<T>) -> SharedMatrix<T> {
+        SharedMatrix {
+            inner: Arc::new(matrix),
+        }
+    }
+
+    /// Gets mutable access to the underlying `Matrix`, cloning it first if it is shared
+    ///
+    /// After this call the handle is guaranteed to be unique, so edits made through the
+    /// returned reference are never observed by other `SharedMatrix` clones
+    ///
+    /// ### Returns
+    /// - A mutable reference to a `Matrix` owned solely by this handle
+    pub fn make_mut(&mut self) -> &mut Matrix<T> {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Checks whether this handle is the only one referring to its `Matrix`
+    ///
+    /// ### Returns
+    /// - `true` if no other `SharedMatrix` shares the data, `false` otherwise
+    pub fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.inner) == 1
+    }
+
+    /// Checks whether two handles share the same underlying `Matrix`
+    ///
+    /// ### Parameters
+    /// - `a` - One `SharedMatrix` handle
+    /// - `b` - Another `SharedMatrix` handle
+    ///
+    /// ### Returns
+    /// - `true` if both handles point at the same allocation, `false` otherwise
+    pub fn ptr_eq(a: &SharedMatrix<T>, b: &SharedMatrix<T>) -> bool {
+        Arc::ptr_eq(&a.inner, &b.inner)
+    }
+
+    /// Gets the number of `SharedMatrix` handles referring to this `Matrix`
+    ///
+    /// ### Returns
+    /// - The strong reference count of the shared data
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    /// Consumes this handle and returns the underlying `Matrix`, cloning it only if
+    /// other handles still share it
+    ///
+    /// ### Returns
+    /// - The `Matrix` that this handle referred to
+    pub fn into_matrix(self) -> Matrix<T> {
+        Arc::try_unwrap(self.inner).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl<T: Number> Clone for SharedMatrix<T> {
+    /// Creates a new handle to the same `Matrix` without copying any elements
+    fn clone(&self) -> Self {
+        SharedMatrix {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T: Number> Deref for SharedMatrix<T> {
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Matrix<T> {
+        &self.inner
+    }
+}
+
+impl<T: Number> From<Matrix<T>> for SharedMatrix<T> {
+    fn from(matrix: Matrix<T>) -> Self {
+        SharedMatrix::new(matrix)
+    }
+}
+
+impl<T: Number> PartialEq for SharedMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.inner == *other.inner
+    }
+}
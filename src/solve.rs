@@ -0,0 +1,1597 @@
+use crate::arith;
+use crate::context::ComputeContext;
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::{Matrix, NormKind};
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::parallel;
+use crate::transform;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// The most gradient steps `condition_estimate_1norm` takes before settling
+const MAX_ESTIMATE_STEPS: usize = 5;
+
+/// Checks that `matrix` is an augmented `n x (n + 1)` system with at most 26 variables,
+/// one per letter of the alphabet
+fn check_augmented<T>(matrix: &Matrix<T>) -> Result<(), LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    if matrix.cols != matrix.rows + 1 {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (matrix.rows, matrix.rows + 1),
+            found: (matrix.rows, matrix.cols),
+        });
+    }
+    if matrix.rows > 26 {
+        return Err(LinalgError::InvalidArgument(
+            "At most 26 variables (a to z) can be solved for".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Computes `x − factor × y` for one step of elimination, flushing a result that is only
+/// rounding residue, by `Number::is_approx_zero`, to an exact zero
+///
+/// `scale` is the largest magnitude the element has held so far, and is widened to cover
+/// both operands. Without this a float matrix that is singular can leave a pivot like
+/// `1e-17` where exact arithmetic gives `0`, and dividing by it fills the result with huge
+/// values. Each element is measured against its own history rather than the whole
+/// matrix, so small elements of a badly scaled matrix are never flushed
+fn eliminate<T: Number>(x: T, factor: T, y: T, scale: &mut T) -> T {
+    let product = factor * y;
+    *scale = product.rounding_scale(x.rounding_scale(*scale));
+    let difference = x - product;
+    if difference.is_approx_zero(*scale) {
+        T::default()
+    } else {
+        difference
+    }
+}
+
+/// Gets the starting `scale` that `eliminate` keeps for every element of `rows`
+fn element_scales<T: Number, R: AsRef<[T]>>(rows: &[R]) -> Vec<Vec<T>> {
+    rows.iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|&x| x.rounding_scale(T::default()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Divides the `scale`s of a pivot row as the row itself is divided by `pivot`
+fn divide_scales<T: Number>(scales: &mut [T], pivot: T) {
+    for scale in scales {
+        *scale = (*scale / pivot).rounding_scale(T::default());
+    }
+}
+
+/// Computes the row echelon form for the given `matrix` and returns the result as a new
+/// `Matrix` instance
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` needed to compute the row echelon form
+///
+/// ### Returns
+/// - A `Matrix` instance containing the given `matrix` in row echelon form
+pub fn row_echelon_form<T>(matrix: &Matrix<T>) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    row_echelon_form_owned(matrix.clone())
+}
+
+/// Computes the row echelon form like `row_echelon_form`, reusing the rows of `matrix`
+///
+/// Rows that no other `Matrix` shares are updated in place, so a `matrix` that is not
+/// needed afterwards is reduced without copying any elements
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` needed to compute the row echelon form
+///
+/// ### Returns
+/// - `matrix` in row echelon form
+pub fn row_echelon_form_owned<T>(mut matrix: Matrix<T>) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let rows = matrix.rows;
+    let cols = matrix.cols;
+    let mut scales = element_scales(&matrix.mat);
+
+    for i in 0..rows.min(cols) {
+        let pivot = matrix.mat[i][i];
+        if pivot != T::default() {
+            for c in 0..cols {
+                let row = Arc::make_mut(&mut matrix.mat[i]);
+                row[c] /= pivot;
+                if row[c] == -T::default() {
+                    row[c] = T::default();
+                }
+            }
+            divide_scales(&mut scales[i], pivot);
+        }
+
+        let pivot_row = Arc::clone(&matrix.mat[i]);
+        let work = (rows - i - 1) * cols;
+        let mut below: Vec<_> = matrix.mat[(i + 1)..]
+            .iter_mut()
+            .zip(&mut scales[(i + 1)..])
+            .collect();
+
+        parallel::for_each_mut(&mut below, work, |_, (row, scale)| {
+            let scale_factor = row[i];
+            let row_j = Arc::make_mut(row);
+
+            for c in 0..cols {
+                row_j[c] = eliminate(row_j[c], scale_factor, pivot_row[c], &mut scale[c]);
+            }
+        });
+    }
+
+    matrix
+}
+
+/// Computes the reduced row echelon form (RREF) for the given `matrix` and returns the result
+/// as a new `Matrix` instance
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` needed to compute the reduced row echelon form
+///
+/// ### Returns
+/// - A `Matrix` instance containing the given `matrix` in reduced row echelon form
+pub fn rref<T>(matrix: &Matrix<T>) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    rref_owned(matrix.clone())
+}
+
+/// Computes the reduced row echelon form like `rref`, reusing the rows of `matrix`
+///
+/// Rows that no other `Matrix` shares are updated in place, so a `matrix` that is not
+/// needed afterwards is reduced without copying any elements
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` needed to compute the reduced row echelon form
+///
+/// ### Returns
+/// - `matrix` in reduced row echelon form
+pub fn rref_owned<T>(mut matrix: Matrix<T>) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let rows = matrix.rows;
+    let cols = matrix.cols;
+    let mut scales = element_scales(&matrix.mat);
+
+    for i in 0..rows.min(cols) {
+        let pivot = matrix.mat[i][i];
+        if pivot != T::default() {
+            for c in 0..cols {
+                let row = Arc::make_mut(&mut matrix.mat[i]);
+                row[c] /= pivot;
+            }
+            divide_scales(&mut scales[i], pivot);
+        }
+
+        let pivot_row = Arc::clone(&matrix.mat[i]);
+        let work = (rows - i - 1) * cols;
+        let mut below: Vec<_> = matrix.mat[(i + 1)..]
+            .iter_mut()
+            .zip(&mut scales[(i + 1)..])
+            .collect();
+
+        parallel::for_each_mut(&mut below, work, |_, (row, scale)| {
+            let factor = row[i];
+            let row_j = Arc::make_mut(row);
+
+            for c in 0..cols {
+                row_j[c] = eliminate(row_j[c], factor, pivot_row[c], &mut scale[c]);
+            }
+        });
+    }
+
+    for i in (0..rows.min(cols)).rev() {
+        let pivot_row = Arc::clone(&matrix.mat[i]);
+        let mut above: Vec<_> = matrix.mat[..i].iter_mut().zip(&mut scales[..i]).collect();
+
+        parallel::for_each_mut(&mut above, i * cols, |_, (row, scale)| {
+            let factor = row[i];
+            let row_j = Arc::make_mut(row);
+
+            for c in 0..cols {
+                row_j[c] = eliminate(row_j[c], factor, pivot_row[c], &mut scale[c]);
+            }
+        });
+    }
+
+    matrix
+}
+
+/// Performs the [Gaussian Elimination](https://en.wikipedia.org/wiki/Gaussian_elimination)
+/// technique on a given `matrix` to solve for its system of equations' missing variables
+/// (e.g. x, y, and z)
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` to perform Gaussian Elimination on
+///
+/// ### Returns
+/// - A `Result` based on whether the matrix had a solution
+///     - An `Err` with an enclosed `LinalgError` representing the error state of solving the `matrix`
+///       using Gaussian Elimination (i.e. no solution or infinitely many solutions)
+///     - An `Ok` enclosed with a `HashMap` containing each variable name
+///       mapped to a value with its solution
+#[allow(clippy::needless_range_loop)]
+pub fn gaussian_elimination<T>(matrix: &Matrix<T>) -> Result<HashMap<char, T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    check_augmented(matrix)?;
+    let matrix = row_echelon_form(matrix);
+    let mut pivot_vars = HashMap::new();
+    let num_rows = matrix.rows;
+    let num_cols = matrix.cols;
+
+    let mut solutions = vec![T::default(); num_rows];
+
+    for i in 0..num_rows {
+        if matrix.mat[i].iter().all(|&x| x == T::default())
+            && matrix.mat[i][num_cols - 1] == T::default()
+        {
+            return Err(LinalgError::InfiniteSolutions);
+        }
+    }
+
+    for i in 0..num_rows {
+        let pivot = matrix.mat[i][i];
+        if pivot == T::default() && matrix.mat[i][num_cols - 1] != T::default() {
+            return Err(LinalgError::NoSolution);
+        }
+    }
+
+    for i in (0..num_rows).rev() {
+        if matrix.mat[i][i] == T::default() {
+            return Err(LinalgError::ZeroPivot);
+        }
+
+        let mut sum = T::default();
+
+        for j in (i + 1)..num_cols - 1 {
+            sum += matrix.mat[i][j] * solutions[j];
+        }
+
+        solutions[i] = matrix.mat[i][num_cols - 1] - sum;
+        solutions[i] /= matrix.mat[i][i];
+
+        pivot_vars.insert((b'a' + i as u8) as char, solutions[i]);
+    }
+
+    Ok(pivot_vars)
+}
+
+/// Performs the [Gauss-Jordan Elimination](https://online.stat.psu.edu/statprogram/reviews/matrix-algebra/gauss-jordan-elimination)
+/// technique on a given `matrix` to solve for the missing variables in a system of equations
+/// (e.g. x, y, and z)
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` to perform Gauss-Jordan Elimination on
+///
+/// ### Returns
+/// - A `Result` based on whether the matrix had a solution
+///     - An `Err` with an enclosed `LinalgError` representing the error state of solving the `matrix`
+///       using Gaussian Elimination (i.e. no solution or infinitely many solutions)
+///     - An `Ok` enclosed with a `HashMap` containing each variable name
+///       mapped to a value with its solution
+pub fn gauss_jordan_elimination<T>(matrix: &Matrix<T>) -> Result<HashMap<char, T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    check_augmented(matrix)?;
+    let matrix = rref(matrix);
+    let mut pivot_vars = HashMap::new();
+
+    for i in 0..matrix.rows {
+        let pivot = matrix.mat[i][i];
+
+        if pivot != T::default() {
+            pivot_vars.insert((b'a' + i as u8) as char, matrix.mat[i][matrix.cols - 1]);
+        } else if matrix.mat[i][matrix.cols - 1] != T::default() {
+            return Err(LinalgError::NoSolution);
+        }
+    }
+
+    for i in 0..matrix.rows {
+        if matrix.mat[i].iter().all(|&x| x == T::default()) {
+            return Err(LinalgError::InfiniteSolutions);
+        }
+    }
+
+    Ok(pivot_vars)
+}
+
+/// Performs the inverse of a given matrix and returns it as a `Matrix` instance
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` to perform the inverse on
+///
+/// ### Returns
+/// - A `Result` type based on whether the given `matrix` is invertible
+///     - An `Err` consisting of a `LinalgError` if the given `matrix` is not invertible
+///     - An `Ok` consisting of the inverse matrix, if the given `matrix` is invertible
+pub fn inverse<T>(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    inverse_with_context(matrix, &ComputeContext::new())
+}
+
+/// Performs the inverse of a given matrix and returns it as a `Matrix` instance
+///
+/// Checks `ctx` once per pivot and stops early if it is cancelled or its deadline passes
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` to perform the inverse on
+/// - `ctx` - The `ComputeContext` that can interrupt the computation
+///
+/// ### Returns
+/// - A `Result` type based on whether the given `matrix` is invertible
+///     - An `Err` consisting of a `LinalgError` if the given `matrix` is not invertible
+///     - An `Ok` consisting of the inverse matrix, if the given `matrix` is invertible
+#[allow(clippy::needless_range_loop)]
+pub fn inverse_with_context<T>(
+    matrix: &Matrix<T>,
+    ctx: &ComputeContext,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let rows = matrix.rows;
+    let cols = matrix.cols;
+
+    if rows != cols {
+        return Err(LinalgError::NonSquare { rows, cols });
+    }
+
+    let n = rows;
+    let _span = Span::new("inverse", 2 * n * n * n, 2 * n + 2);
+    let identity_matrix = arith::identity(n);
+    let mut augmented = vec![];
+    for i in 0..n {
+        let mut row: Vec<T> = matrix.mat[i].to_vec();
+        row.extend_from_slice(&identity_matrix.mat[i]);
+        augmented.push(Arc::from(row));
+    }
+
+    let mut augmented_matrix = Matrix {
+        mat: augmented,
+        rows: n,
+        cols: 2 * n,
+    };
+
+    let mut scales = element_scales(&augmented_matrix.mat);
+    for i in 0..n {
+        ctx.check()?;
+        if augmented_matrix.mat[i][i] == T::default() {
+            return Err(LinalgError::Singular);
+        }
+
+        let pivot = augmented_matrix.mat[i][i];
+        let row = Arc::make_mut(&mut augmented_matrix.mat[i]);
+        for j in 0..augmented_matrix.cols {
+            row[j] /= pivot;
+        }
+        divide_scales(&mut scales[i], pivot);
+
+        let row_i = augmented_matrix.mat[i].clone();
+        let cols = augmented_matrix.cols;
+        let mut rows: Vec<_> = augmented_matrix.mat.iter_mut().zip(&mut scales).collect();
+        parallel::for_each_mut(&mut rows, n * cols, |k, (row, scale)| {
+            if k != i {
+                let factor = row[i];
+                let row_k = Arc::make_mut(row);
+
+                for j in 0..cols {
+                    row_k[j] = eliminate(row_k[j], factor, row_i[j], &mut scale[j]);
+                }
+            }
+        });
+    }
+
+    let mut inverse_mat = vec![];
+    for i in 0..n {
+        inverse_mat.push(Arc::from(augmented_matrix.mat[i][n..].to_vec()));
+    }
+
+    Ok(Matrix {
+        mat: inverse_mat,
+        rows: n,
+        cols: n,
+    })
+}
+
+/// Compute the determinant of this `Matrix`
+///
+/// - An empty `Matrix` with a shape of `(0, 0)` has a determinant of `1`, the empty product
+///
+/// - In a `Matrix` with a shape of `(1, 1)`, a `Matrix`'s determinant is
+///   simply that number itself
+///
+/// - In a `Matrix` with a shape of `(2, 2)`, a `Matrix`'s determinant is
+///   equal to `ad - bc`, which is the difference
+///   between the left diagonal product and the right diagonal product
+///
+/// - Any other `Matrix` bigger than a `(2, 2)` (i.e. `(3, 3)`, `(4, 4)`, etc.) utilizes the
+///   [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) approach.
+///   The [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) approach involves expanding
+///   the determinant along a row or column breaking it down into smaller sub-matrices until reaching
+///   2x2 matrices, where the determinant can directly be calculated using the formula `ad - bc`
+///
+/// Cofactor expansion takes O(n!) time, so prefer `determinant_fast` for floating-point
+/// matrices larger than about 8 x 8
+///
+/// ### Parameters
+/// - `x` - The given matrix to compute the determinant for
+///
+/// ### Returns
+/// - The determinant value of `x` wrapped in an (optional)`Option`
+///     - A `None` if the `Matrix`'s determinant could not be calculated
+///       (unequal rows and columns)
+///     - A `Some` with the determinant value, if this `Matrix`'s
+///       shape is `(2, 2)` - 2 rows and 2 columns
+pub fn determinant<T>(x: &Matrix<T>) -> Option<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    x.validate().ok()?;
+    let (rows, cols) = x.shape();
+    if rows != cols {
+        return None;
+    }
+
+    match rows {
+        0 => Some(T::one()),
+        1 => Some(x.mat[0][0]),
+        2 => {
+            let ad = x.mat[0][0] * x.mat[1][1];
+            let bc = x.mat[0][1] * x.mat[1][0];
+
+            Some(ad - bc)
+        }
+        _ => Some(cofactor_expansion(x)),
+    }
+}
+
+/// Compute the [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) of this `Matrix`
+///
+/// # Note
+///
+/// This function is not meant to be called on its own!!!
+/// The `determinant` function in this module already calls this function!
+/// Call that one instead. This one is just there as reference!!
+///
+/// The [Cofactor/Laplace Expansion](https://en.wikipedia.org/wiki/Laplace_expansion) is a technique used to
+/// calculate the determinant of matrices that are much bigger than a `(2, 2)`
+///
+/// This approach involves expanding the determinant along a row or column
+/// breaking it down into smaller sub-matrices until reaching
+/// 2x2 matrices, where the determinant can directly be calculated using the formula `ad - bc`
+///
+/// ### Parameters
+/// - `x` - The given matrix to compute cofactor expansion upon
+///
+/// ### Returns
+/// - The determinant value of `x` as a generic type `T`
+pub(crate) fn cofactor_expansion<T>(x: &Matrix<T>) -> T
+where
+    T: Number + Neg<Output = T>,
+{
+    let (_, cols) = x.shape();
+    let mut det = T::default();
+
+    for col in 0..cols {
+        let sub_matrix = create_cofactor_expansion_submatrix(x, col);
+
+        let sign = if col % 2 == 0 {
+            T::default() + num::One::one()
+        } else {
+            T::default() - num::One::one()
+        };
+
+        det += sign * x.mat[0][col] * determinant(&sub_matrix).unwrap_or(T::default());
+    }
+
+    det
+}
+
+fn create_cofactor_expansion_submatrix<T>(x: &Matrix<T>, exclude_col: usize) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let (rows, cols) = x.shape();
+    let mut new_matrix = Vec::new();
+
+    for i in 1..rows {
+        let filtered_row: Vec<T> = x.mat[i]
+            .iter()
+            .enumerate()
+            .filter_map(|(j, &val)| if j != exclude_col { Some(val) } else { None })
+            .collect();
+
+        new_matrix.push(Arc::from(filtered_row.as_slice()));
+    }
+
+    Matrix {
+        mat: new_matrix,
+        rows: rows - 1,
+        cols: cols - 1,
+    }
+}
+
+/// Computes the determinant of a square `Matrix` from its LU decomposition
+///
+/// Gaussian elimination with partial pivoting reduces `x` to an upper triangular
+/// matrix whose diagonal product, negated once per row exchange, is the determinant.
+/// This takes O(n^3) time, where `determinant`'s cofactor expansion takes O(n!), so a
+/// 100 x 100 determinant takes milliseconds. A triangular `x` skips the elimination and
+/// multiplies its diagonal directly, in O(n^2)
+///
+/// ### Parameters
+/// - `x` - The square `Matrix` to compute the determinant of
+///
+/// ### Returns
+/// - A `Result` based on whether the determinant could be computed
+///     - An `Ok` containing the determinant, which is `1` for an empty matrix and `0`
+///       for a singular one
+///     - An `Err` of `LinalgError::NonSquare` if `x` is not square, or of
+///       `LinalgError::NonFinite` if it holds a NaN or infinity
+pub fn determinant_fast<T>(x: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (sign, pivots) = lu_pivots(x)?;
+
+    Ok(pivots.into_iter().fold(sign, |det, pivot| det * pivot))
+}
+
+/// Computes the pivots of the LU factorization of `x` with partial pivoting, whose
+/// product is its determinant
+///
+/// A triangular `x` skips the elimination and uses its diagonal. Keeping the pivots
+/// separate lets callers combine them without forming a product that could overflow or
+/// underflow
+///
+/// ### Returns
+/// - A `Result` containing `(sign, pivots)`, where `sign` is `1` or `-1` by the parity of
+///   the row exchanges and `pivots` stops at the first zero pivot, since the determinant
+///   is then zero, or an `Err` as described in `determinant_fast`
+pub(crate) fn lu_pivots<T>(x: &Matrix<T>) -> Result<(T, Vec<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    x.validate()?;
+    x.check_finite()?;
+    let n = x.rows;
+    if n != x.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: x.cols,
+        });
+    }
+    if x.is_upper_triangular() || x.is_lower_triangular() {
+        return Ok((T::one(), x.diagonal()));
+    }
+    let _span = Span::new("determinant_fast", 2 * n * n * n / 3, n + 1);
+
+    let mut a: Vec<Vec<T>> = x.mat.iter().map(|row| row.to_vec()).collect();
+    let mut scales = element_scales(&a);
+    let mut sign = T::one();
+    let mut pivots = Vec::with_capacity(n);
+    for k in 0..n {
+        let mut pivot = k;
+        for i in (k + 1)..n {
+            if a[i][k].abs() > a[pivot][k].abs() {
+                pivot = i;
+            }
+        }
+        if a[pivot][k] == T::zero() {
+            pivots.push(T::zero());
+            break;
+        }
+        if pivot != k {
+            a.swap(pivot, k);
+            scales.swap(pivot, k);
+            sign = -sign;
+        }
+
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for (row, scale) in lower.iter_mut().zip(&mut scales[(k + 1)..]) {
+            let factor = row[k] / pivot_row[k];
+            for j in (k + 1)..n {
+                row[j] = eliminate(row[j], factor, pivot_row[j], &mut scale[j]);
+            }
+        }
+        pivots.push(pivot_row[k]);
+    }
+
+    Ok((sign, pivots))
+}
+
+/// Computes the determinant of a square integer `Matrix` exactly, failing instead of
+/// wrapping on overflow
+///
+/// Uses [Bareiss elimination](https://en.wikipedia.org/wiki/Bareiss_algorithm), which
+/// keeps every intermediate value a minor of `x` by dividing each step exactly by the
+/// previous pivot. It takes O(n^3) time, where `determinant`'s cofactor expansion takes
+/// O(n!) and silently wraps. Each intermediate product is checked as well, so a
+/// determinant that fits in `T` is still reported as an overflow when one of those
+/// products, about the square of a minor, does not
+///
+/// ### Parameters
+/// - `x` - The square `Matrix` to compute the determinant of
+///
+/// ### Returns
+/// - A `Result` based on whether the determinant could be computed
+///     - An `Ok` containing the determinant, which is `1` for an empty matrix
+///     - An `Err` of `LinalgError::NonSquare` if `x` is not square, or
+///       `LinalgError::Overflow` if any step overflows `T`
+pub fn checked_determinant<T>(x: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + num::CheckedSub + num::CheckedMul + num::CheckedDiv,
+{
+    x.validate()?;
+    let n = x.rows;
+    if n != x.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: x.cols,
+        });
+    }
+    let _span = Span::new("checked_determinant", 2 * n * n * n / 3, n + 1);
+
+    let overflow = |value: Option<T>| value.ok_or(LinalgError::Overflow);
+    bareiss(
+        x.mat.iter().map(|row| row.to_vec()).collect(),
+        T::default(),
+        T::one(),
+        |a_ij, a_kk, a_ik, a_kj, previous| {
+            let kept = overflow(a_ij.checked_mul(a_kk))?;
+            let removed = overflow(a_ik.checked_mul(a_kj))?;
+            let minor = overflow(kept.checked_sub(&removed))?;
+            overflow(minor.checked_div(previous))
+        },
+        |det| overflow(T::default().checked_sub(&det)),
+    )
+}
+
+/// Computes the determinant of the square matrix `a` by Bareiss elimination
+///
+/// Shared by `checked_determinant` and `ExactMatrix::determinant`, which differ
+/// only in their arithmetic. `cross(a_ij, a_kk, a_ik, a_kj, previous)` computes the next
+/// minor `(a_ij·a_kk − a_ik·a_kj) / previous`, and `negate` flips the sign of the result
+/// after an odd number of row exchanges; either may fail, e.g. on overflow
+pub(crate) fn bareiss<T, E>(
+    mut a: Vec<Vec<T>>,
+    zero: T,
+    one: T,
+    cross: impl Fn(&T, &T, &T, &T, &T) -> Result<T, E>,
+    negate: impl Fn(T) -> Result<T, E>,
+) -> Result<T, E>
+where
+    T: Clone + PartialEq,
+{
+    let n = a.len();
+    if n == 0 {
+        return Ok(one);
+    }
+
+    let mut negated = false;
+    let mut previous = one;
+    for k in 0..(n - 1) {
+        if a[k][k] == zero {
+            match ((k + 1)..n).find(|&i| a[i][k] != zero) {
+                Some(i) => {
+                    a.swap(i, k);
+                    negated = !negated;
+                }
+                None => return Ok(zero),
+            }
+        }
+
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower {
+            for j in (k + 1)..n {
+                row[j] = cross(&row[j], &pivot_row[k], &row[k], &pivot_row[j], &previous)?;
+            }
+        }
+        previous = pivot_row[k].clone();
+    }
+
+    let det = a.swap_remove(n - 1).swap_remove(n - 1);
+    if negated {
+        negate(det)
+    } else {
+        Ok(det)
+    }
+}
+
+/// Computes the determinant of the product of several square matrices without forming
+/// the product
+///
+/// Uses `det(A₁A₂…Aₖ) = det(A₁)det(A₂)…det(Aₖ)`, taking each factor's determinant from
+/// its own LU decomposition. This saves the O(kn^3) matrix products and the rounding
+/// they would accumulate
+///
+/// ### Parameters
+/// - `factors` - The n x n matrices of the product, in order
+///
+/// ### Returns
+/// - A `Result` based on whether the determinant could be computed
+///     - An `Ok` containing the determinant of the product, which is `1` when `factors`
+///       is empty
+///     - An `Err` of `LinalgError::NonSquare` if the first factor is not square,
+///       `LinalgError::ShapeMismatch` if a later factor has a different shape, or
+///       `LinalgError::NonFinite` if a factor holds a NaN or infinity
+pub fn determinant_of_product<T>(factors: &[&Matrix<T>]) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let Some(first) = factors.first() else {
+        return Ok(T::one());
+    };
+    let n = first.rows;
+    if first.cols != n {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: first.cols,
+        });
+    }
+
+    let mut det = T::one();
+    for factor in factors {
+        if (factor.rows, factor.cols) != (n, n) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, n),
+                found: (factor.rows, factor.cols),
+            });
+        }
+        det *= determinant_fast(factor)?;
+    }
+
+    Ok(det)
+}
+
+/// Solves the linear system `AX = B` for one or more right-hand sides
+///
+/// The coefficient matrix is factored once and every column of `b` is solved against
+/// that factorization by forward and back substitution. A symmetric positive definite
+/// `a` is factored by `cholesky_decomposition`; any other square `a` falls back to
+/// `plu_decomposition`
+///
+/// ### Parameters
+/// - `a` - The n x n coefficient `Matrix`
+/// - `b` - An n x k `Matrix` holding one right-hand side per column
+///
+/// ### Returns
+/// - A `Result` based on whether the system has a unique solution
+///     - An `Ok` containing the n x k solution `X`, whose columns solve the columns of `b`
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::ShapeMismatch` if `b` does not have n rows,
+///       `LinalgError::NonFinite` if either input holds a NaN or infinity, or
+///       `LinalgError::Singular` if `a` is singular
+pub fn solve<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    solve_factored(a, b).map(|(x, _)| x)
+}
+
+/// Solves `AX = B` as `solve` does, also returning the factors `PA = LU` it used
+fn solve_factored<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+) -> Result<(Matrix<T>, decompose::Factors<T>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    a.validate()?;
+    b.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if b.rows != n {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (n, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+    a.check_finite()?;
+    b.check_finite()?;
+    let _span = Span::new("solve", 2 * n * n * (n / 3 + b.cols), 3);
+
+    let factors = factor(a)?;
+    let x = solve_with_factors(&factors, b)?;
+    Ok((x, factors))
+}
+
+/// Solves `AX = B` from factors `PA = LU` of a square `A`, for a finite `b` with as many
+/// rows as `A`, by forward and back substitution on every column of `b`
+///
+/// ### Returns
+/// - A `Result` containing the solution, or an `Err` of `LinalgError::Singular` if `U`
+///   has a zero on its diagonal
+pub(crate) fn solve_with_factors<T>(
+    factors: &decompose::Factors<T>,
+    b: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let n = b.rows;
+    if (0..n).any(|i| factors.2.mat[i][i] == T::zero()) {
+        return Err(LinalgError::Singular);
+    }
+
+    let mut x = vec![vec![T::zero(); b.cols]; n];
+    for c in 0..b.cols {
+        let column = substitute(factors, b.mat.iter().map(|row| row[c]).collect(), false);
+        for (row, value) in x.iter_mut().zip(column) {
+            row[c] = value;
+        }
+    }
+
+    Ok(Matrix {
+        mat: x.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: b.cols,
+    })
+}
+
+/// Factors a square, finite `a` as `PA = LU` for `solve`, by `cholesky_decomposition`
+/// when `a` is symmetric positive definite (with `P = I` and `U = Lᵀ`) and by
+/// `plu_decomposition` otherwise
+fn factor<T>(a: &Matrix<T>) -> Result<decompose::Factors<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    match decompose::cholesky_decomposition(a) {
+        Ok(l) => {
+            let u = transform::transpose(&l);
+            Ok((arith::identity(a.rows), l, u))
+        }
+        Err(LinalgError::NotPositiveDefinite) => decompose::plu_decomposition(a),
+        Err(e) => Err(e),
+    }
+}
+
+/// Solves `Ax = v` from factors `PA = LU`, or `Aᵀx = v` when `transpose` is set, by
+/// forward and back substitution. Every diagonal entry of `U` must be nonzero
+pub(crate) fn substitute<T>(factors: &decompose::Factors<T>, v: Vec<T>, transpose: bool) -> Vec<T>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (p, l, u) = factors;
+    let n = v.len();
+    let permute = |x: &[T], inverse: bool| -> Vec<T> {
+        let mut out = vec![T::zero(); n];
+        for (i, row) in p.mat.iter().enumerate() {
+            let j = row.iter().position(|&e| e != T::zero()).unwrap_or(i);
+            if inverse {
+                out[j] = x[i];
+            } else {
+                out[i] = x[j];
+            }
+        }
+        out
+    };
+
+    if !transpose {
+        // L(Ux) = Pv
+        let mut x = permute(&v, false);
+        for i in 0..n {
+            for j in 0..i {
+                let update = l.mat[i][j] * x[j];
+                x[i] -= update;
+            }
+            x[i] /= l.mat[i][i];
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let update = u.mat[i][j] * x[j];
+                x[i] -= update;
+            }
+            x[i] /= u.mat[i][i];
+        }
+        x
+    } else {
+        // Aᵀ = UᵀLᵀP, so Uᵀ(Lᵀ(Px)) = v
+        let mut x = v;
+        for i in 0..n {
+            for j in 0..i {
+                let update = u.mat[j][i] * x[j];
+                x[i] -= update;
+            }
+            x[i] /= u.mat[i][i];
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let update = l.mat[j][i] * x[j];
+                x[i] -= update;
+            }
+            x[i] /= l.mat[i][i];
+        }
+        permute(&x, true)
+    }
+}
+
+/// Estimates `‖A⁻¹‖₁` from factors `PA = LU` by Hager's method with Higham's
+/// refinements, or `‖A⁻ᵀ‖₁ = ‖A⁻¹‖∞` when `transpose` is set
+///
+/// Each step solves once with `A` and once with `Aᵀ`, so the estimate costs O(n²) per
+/// step on top of the factorization. The result is a lower bound that is almost always
+/// exact or within a small factor
+fn estimate_inverse_norm<T>(factors: &decompose::Factors<T>, transpose: bool) -> T
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let n = factors.2.rows;
+    let n_t = T::from(n).unwrap();
+    let one_norm = |y: &[T]| y.iter().fold(T::zero(), |sum, x| sum + x.abs());
+
+    let mut x = vec![T::one() / n_t; n];
+    let mut y = substitute(factors, x.clone(), transpose);
+    let mut estimate = one_norm(&y);
+    if n == 1 {
+        return estimate;
+    }
+
+    for step in 0..MAX_ESTIMATE_STEPS {
+        let signs: Vec<T> = y
+            .iter()
+            .map(|&e| if e < T::zero() { -T::one() } else { T::one() })
+            .collect();
+        let z = substitute(factors, signs, !transpose);
+        let (j, largest) = z
+            .iter()
+            .enumerate()
+            .fold((0, T::zero()), |(j, max), (i, e)| {
+                if e.abs() > max {
+                    (i, e.abs())
+                } else {
+                    (j, max)
+                }
+            });
+        let slope = z
+            .iter()
+            .zip(&x)
+            .fold(T::zero(), |sum, (&a, &b)| sum + a * b);
+        if step > 0 && largest <= slope {
+            break;
+        }
+
+        x = vec![T::zero(); n];
+        x[j] = T::one();
+        y = substitute(factors, x.clone(), transpose);
+        let next = one_norm(&y);
+        if next <= estimate {
+            break;
+        }
+        estimate = next;
+    }
+
+    // Higham's alternating-sign vector catches matrices that fool the gradient steps
+    let alternating: Vec<T> = (0..n)
+        .map(|i| {
+            let magnitude = T::one() + T::from(i).unwrap() / T::from(n - 1).unwrap();
+            if i % 2 == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        })
+        .collect();
+    let y = substitute(factors, alternating, transpose);
+    let three = T::from(3).unwrap();
+    estimate.max((one_norm(&y) + one_norm(&y)) / (three * n_t))
+}
+
+/// Estimates the 1-norm [condition number](https://en.wikipedia.org/wiki/Condition_number)
+/// `κ₁(A) = ‖A‖₁ ‖A⁻¹‖₁` from an existing factorization of `A`
+///
+/// `‖A⁻¹‖₁` is estimated by the Hager–Higham method, which needs a handful of
+/// triangular solves instead of the explicit inverse, so the estimate costs O(n²) once
+/// `A` is factored. It never exceeds the true `κ₁(A)` and is rarely more than a small
+/// factor below it
+///
+/// ### Parameters
+/// - `factors` - The `p`, `l`, and `u` factors of `A`, as returned by `plu_decomposition`
+/// - `a_norm` - The 1-norm of `A`, as returned by `a.norm(NormKind::One)`
+///
+/// ### Returns
+/// - A `Result` based on whether the factors are usable
+///     - An `Ok` containing the estimate, which is infinite when `u` has a zero on its
+///       diagonal
+///     - An `Err` of `LinalgError::Empty` if the factors are empty,
+///       `LinalgError::ShapeMismatch` if they are not all the same square shape, or
+///       `LinalgError::InvalidArgument` if `a_norm` is negative or not finite
+pub fn condition_estimate_1norm<T>(
+    factors: &decompose::Factors<T>,
+    a_norm: T,
+) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (p, l, u) = factors;
+    let n = u.rows;
+    for m in [p, l, u] {
+        m.validate()?;
+        if (m.rows, m.cols) != (n, n) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, n),
+                found: (m.rows, m.cols),
+            });
+        }
+    }
+    if n == 0 {
+        return Err(LinalgError::Empty);
+    }
+    if !a_norm.is_finite() || a_norm < T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The norm of A must be finite and non-negative!".to_string(),
+        ));
+    }
+    if (0..n).any(|i| u.mat[i][i] == T::zero()) {
+        return Ok(T::infinity());
+    }
+    let _span = Span::new(
+        "condition_estimate_1norm",
+        2 * n * n * (MAX_ESTIMATE_STEPS + 2),
+        3,
+    );
+
+    Ok(a_norm * estimate_inverse_norm(factors, false))
+}
+
+/// The result of `solve_with_report`: the solution plus estimates of how accurate it is
+///
+/// `backward_error` says how well the problem was solved, `forward_error` how close the
+/// answer is. A backward error near `T::epsilon()` means the solve was as good as the
+/// arithmetic allows; the forward error can still be large if `A` is ill-conditioned
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolveReport<T: Number> {
+    /// The n x k solution `X`
+    pub solution: Matrix<T>,
+
+    /// The normwise backward error of `solution`, as computed by `backward_error`
+    pub backward_error: T,
+
+    /// An estimate of the condition number `κ∞(A) = ‖A‖∞ ‖A⁻¹‖∞`, which is a lower bound
+    /// and rarely more than a small factor below it
+    pub condition_number: T,
+
+    /// The bound on `‖X − X_exact‖∞ / ‖X‖∞` over every right-hand side, or infinity when
+    /// the backward error is too large for `A` to be trusted as nonsingular
+    pub forward_error: T,
+}
+
+/// Computes the normwise [backward error](https://en.wikipedia.org/wiki/Numerical_stability#Forward,_backward,_and_mixed_stability)
+/// of an approximate solution `x` to `AX = B`
+///
+/// For each right-hand side this is `‖b − Ax‖∞ / (‖A‖∞ ‖x‖∞ + ‖b‖∞)`, the smallest
+/// relative change to `A` and `b` that makes `x` an exact solution (Rigal and Gaches).
+/// The largest over all columns is returned
+///
+/// ### Parameters
+/// - `a` - The n x n coefficient `Matrix`
+/// - `x` - The n x k approximate solution
+/// - `b` - The n x k right-hand sides
+///
+/// ### Returns
+/// - A `Result` containing the backward error, which is `0` for an exact solution, or an
+///   `Err` of `LinalgError::ShapeMismatch` if the shapes do not fit together or
+///   `LinalgError::NonFinite` if any input holds a NaN or infinity
+pub fn backward_error<T>(a: &Matrix<T>, x: &Matrix<T>, b: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let ax = arith::multiply(a, x)?;
+    if (b.rows, b.cols) != (ax.rows, ax.cols) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (ax.rows, ax.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+    for m in [a, x, b] {
+        m.check_finite()?;
+    }
+
+    let a_norm = a.norm(NormKind::Infinity)?;
+    let column_norm = |m: &Matrix<T>, c: usize| {
+        m.mat
+            .iter()
+            .fold(T::zero(), |max, row| max.max(row[c].abs()))
+    };
+    Ok((0..b.cols).fold(T::zero(), |worst, c| {
+        let residual = b
+            .mat
+            .iter()
+            .zip(&ax.mat)
+            .fold(T::zero(), |max, (b_row, ax_row)| {
+                max.max((b_row[c] - ax_row[c]).abs())
+            });
+        if residual == T::zero() {
+            return worst;
+        }
+        worst.max(residual / (a_norm * column_norm(x, c) + column_norm(b, c)))
+    }))
+}
+
+/// Solves the linear system `AX = B` as `solve` does, and reports how accurate the
+/// solution is
+///
+/// The forward error is bounded to first order by `2 κ η / (1 − κ η)`, where `κ` is the
+/// condition number and `η` the backward error. `κ` is estimated from the factorization
+/// by the Hager–Higham method, as `condition_estimate_1norm` does, so the report costs
+/// little more than the solve itself
+///
+/// ### Parameters
+/// - `a` - The n x n coefficient `Matrix`
+/// - `b` - An n x k `Matrix` holding one right-hand side per column
+///
+/// ### Returns
+/// - A `Result` based on whether the system has a unique solution
+///     - An `Ok` containing the `SolveReport`
+///     - An `Err` as described by `solve`
+pub fn solve_with_report<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<SolveReport<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (solution, factors) = solve_factored(a, b)?;
+    let condition_number = a.norm(NormKind::Infinity)? * estimate_inverse_norm(&factors, true);
+    let backward_error = backward_error(a, &solution, b)?;
+
+    let amplification = condition_number * backward_error;
+    let forward_error = if amplification < T::one() {
+        (amplification + amplification) / (T::one() - amplification)
+    } else {
+        T::infinity()
+    };
+
+    Ok(SolveReport {
+        solution,
+        backward_error,
+        condition_number,
+        forward_error,
+    })
+}
+
+/// Solves `LX = B` for a lower triangular `l` by
+/// [forward substitution](https://en.wikipedia.org/wiki/Triangular_matrix#Forward_and_back_substitution)
+///
+/// Takes O(n²) per right-hand side. With `solve_upper_triangular` this solves `AX = B`
+/// from the factors `A = LU` of `decompose::lu_decomposition` without forming `A⁻¹`
+///
+/// ### Parameters
+/// - `l` - The n x n lower triangular `Matrix`
+/// - `b` - An n x k `Matrix` holding one right-hand side per column
+///
+/// ### Returns
+/// - A `Result` based on whether the system has a unique solution
+///     - An `Ok` containing the n x k solution `X`
+///     - An `Err` of `LinalgError::InvalidArgument` if `l` has a nonzero element above
+///       its diagonal, `LinalgError::Singular` if its diagonal holds a zero, or any error
+///       of `solve` for mismatched shapes and non-finite elements
+#[allow(clippy::needless_range_loop)]
+pub fn solve_lower_triangular<T>(l: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_triangular(l, b, l.is_lower_triangular(), "lower")?;
+    let n = l.rows;
+    let _span = Span::new("solve_lower_triangular", n * n * b.cols, 1);
+
+    let mut x: Vec<Vec<T>> = b.mat.iter().map(|row| row.to_vec()).collect();
+    for i in 0..n {
+        for j in 0..i {
+            for c in 0..b.cols {
+                let update = l.mat[i][j] * x[j][c];
+                x[i][c] -= update;
+            }
+        }
+        x[i].iter_mut().for_each(|v| *v /= l.mat[i][i]);
+    }
+
+    Ok(Matrix {
+        mat: x.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: b.cols,
+    })
+}
+
+/// Solves `UX = B` for an upper triangular `u` by back substitution
+///
+/// Takes O(n²) per right-hand side
+///
+/// ### Parameters
+/// - `u` - The n x n upper triangular `Matrix`
+/// - `b` - An n x k `Matrix` holding one right-hand side per column
+///
+/// ### Returns
+/// - A `Result` based on whether the system has a unique solution
+///     - An `Ok` containing the n x k solution `X`
+///     - An `Err` of `LinalgError::InvalidArgument` if `u` has a nonzero element below
+///       its diagonal, `LinalgError::Singular` if its diagonal holds a zero, or any error
+///       of `solve` for mismatched shapes and non-finite elements
+#[allow(clippy::needless_range_loop)]
+pub fn solve_upper_triangular<T>(u: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_triangular(u, b, u.is_upper_triangular(), "upper")?;
+    let n = u.rows;
+    let _span = Span::new("solve_upper_triangular", n * n * b.cols, 1);
+
+    let mut x: Vec<Vec<T>> = b.mat.iter().map(|row| row.to_vec()).collect();
+    for i in (0..n).rev() {
+        for j in (i + 1)..n {
+            for c in 0..b.cols {
+                let update = u.mat[i][j] * x[j][c];
+                x[i][c] -= update;
+            }
+        }
+        x[i].iter_mut().for_each(|v| *v /= u.mat[i][i]);
+    }
+
+    Ok(Matrix {
+        mat: x.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: b.cols,
+    })
+}
+
+/// Checks the inputs of the triangular solvers, where `triangular` says whether `a` has
+/// the expected `side` zero
+fn check_triangular<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    triangular: bool,
+    side: &str,
+) -> Result<(), LinalgError>
+where
+    T: Number + num::Float,
+{
+    check_square_system(a, b)?;
+    if !triangular {
+        return Err(LinalgError::InvalidArgument(format!(
+            "The coefficient matrix must be {} triangular!",
+            side
+        )));
+    }
+    if a.diagonal().contains(&T::zero()) {
+        return Err(LinalgError::Singular);
+    }
+
+    Ok(())
+}
+
+/// Checks that `a` is square, `b` has one row per row of `a`, and both are finite
+fn check_square_system<T: Number>(a: &Matrix<T>, b: &Matrix<T>) -> Result<(), LinalgError> {
+    a.validate()?;
+    b.validate()?;
+    if a.rows != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    if b.rows != a.rows {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+    a.check_finite()?;
+    b.check_finite()
+}
+
+/// Solves `AX = B` for a [banded](https://en.wikipedia.org/wiki/Band_matrix) `a`
+///
+/// Only the `lower` subdiagonals, the diagonal, and the `upper` superdiagonals of `a` are
+/// read, copied into a compact array of width `2 * lower + upper + 1`, and eliminated with
+/// partial pivoting inside the band, as LAPACK's `gbsv` does. That takes
+/// O(n · lower · (lower + upper)) rather than the O(n³) of `solve`; only the check that
+/// `a` is zero outside the band reads all n² elements
+///
+/// ### Parameters
+/// - `a` - The n x n coefficient `Matrix`, zero outside its band
+/// - `lower` - The number of subdiagonals that may be nonzero
+/// - `upper` - The number of superdiagonals that may be nonzero
+/// - `b` - An n x k `Matrix` holding one right-hand side per column
+///
+/// ### Returns
+/// - A `Result` based on whether the system has a unique solution
+///     - An `Ok` containing the n x k solution `X`
+///     - An `Err` of `LinalgError::InvalidArgument` if `a` has a nonzero element outside
+///       the band, `LinalgError::Singular` if it is singular, or any error of `solve` for
+///       mismatched shapes and non-finite elements
+#[allow(clippy::needless_range_loop)]
+pub fn solve_banded<T>(
+    a: &Matrix<T>,
+    lower: usize,
+    upper: usize,
+    b: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    check_square_system(a, b)?;
+    let n = a.rows;
+    if let Some((i, j)) = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .find(|&(i, j)| (i > j + lower || j > i + upper) && a.mat[i][j] != T::zero())
+    {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Element ({}, {}) lies outside the band of {} lower and {} upper diagonals!",
+            i, j, lower, upper
+        )));
+    }
+    let width = 2 * lower + upper + 1;
+    let _span = Span::new("solve_banded", 2 * n * lower * (lower + upper + 1), 2);
+
+    // Row i keeps columns i − lower ..= i + lower + upper; the extra `lower` columns on
+    // the right hold the fill-in that row exchanges bring in
+    let mut band = vec![vec![T::zero(); width]; n];
+    for (i, row) in band.iter_mut().enumerate() {
+        for j in i.saturating_sub(lower)..n.min(i + upper + 1) {
+            row[j + lower - i] = a.mat[i][j];
+        }
+    }
+    let at = |i: usize, j: usize| j + lower - i;
+    let mut x: Vec<Vec<T>> = b.mat.iter().map(|row| row.to_vec()).collect();
+
+    for k in 0..n {
+        let last_row = n.min(k + lower + 1);
+        let last_col = n.min(k + lower + upper + 1);
+        let pivot = (k..last_row)
+            .max_by(|&p, &q| {
+                band[p][at(p, k)]
+                    .abs()
+                    .partial_cmp(&band[q][at(q, k)].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(k);
+        if band[pivot][at(pivot, k)] == T::zero() {
+            return Err(LinalgError::Singular);
+        }
+        if pivot != k {
+            for j in k..last_col {
+                let tmp = band[k][at(k, j)];
+                band[k][at(k, j)] = band[pivot][at(pivot, j)];
+                band[pivot][at(pivot, j)] = tmp;
+            }
+            x.swap(k, pivot);
+        }
+
+        for i in (k + 1)..last_row {
+            let factor = band[i][at(i, k)] / band[k][at(k, k)];
+            if factor == T::zero() {
+                continue;
+            }
+            band[i][at(i, k)] = T::zero();
+            for j in (k + 1)..last_col {
+                let update = factor * band[k][at(k, j)];
+                band[i][at(i, j)] -= update;
+            }
+            for c in 0..b.cols {
+                let update = factor * x[k][c];
+                x[i][c] -= update;
+            }
+        }
+    }
+
+    for i in (0..n).rev() {
+        for j in (i + 1)..n.min(i + lower + upper + 1) {
+            for c in 0..b.cols {
+                let update = band[i][at(i, j)] * x[j][c];
+                x[i][c] -= update;
+            }
+        }
+        let diagonal = band[i][at(i, i)];
+        x[i].iter_mut().for_each(|v| *v /= diagonal);
+    }
+
+    Ok(Matrix {
+        mat: x.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: b.cols,
+    })
+}
+
+/// Inverts a square `Matrix`, refusing when it is too ill-conditioned for the inverse to
+/// be trusted
+///
+/// `inverse` only fails on an exactly-zero pivot, so a nearly singular float matrix
+/// yields an inverse made mostly of rounding error. This instead computes the reciprocal
+/// condition number `σ_min / σ_max` and refuses when it is below `eps`; otherwise the
+/// inverse is found by solving against the identity with partial pivoting. An `eps` of
+/// `T::epsilon()` rejects only matrices whose inverse has no correct digits left
+///
+/// ### Parameters
+/// - `matrix` - The square `Matrix` to invert
+/// - `eps` - The smallest reciprocal condition number accepted
+///
+/// ### Returns
+/// - A `Result` based on whether the `matrix` can be inverted reliably
+///     - An `Ok` containing the inverse
+///     - An `Err` of `LinalgError::Singular` if the `matrix` is singular,
+///       `LinalgError::IllConditioned` if its reciprocal condition number is below `eps`,
+///       `LinalgError::InvalidArgument` if `eps` is negative or NaN, or any error of
+///       `Matrix::condition_number`
+pub fn inverse_with_tol<T>(matrix: &Matrix<T>, eps: T) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    if eps.is_nan() || eps < T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The tolerance must be a non-negative number".to_string(),
+        ));
+    }
+    let condition = matrix.condition_number()?;
+    if condition.is_infinite() {
+        return Err(LinalgError::Singular);
+    }
+    if condition.recip() < eps {
+        return Err(LinalgError::IllConditioned);
+    }
+
+    solve(matrix, &arith::identity(matrix.rows))
+}
+
+/// Computes a basis for the [null space](https://en.wikipedia.org/wiki/Kernel_(linear_algebra))
+/// of a `Matrix`, the solutions of `Ax = 0`
+///
+/// `matrix` is reduced to row echelon form with partial pivoting, and each column without
+/// a pivot gives one basis vector: its free variable is set to `1`, the other free
+/// variables to `0`, and the pivot variables are read off the reduced rows. Elements
+/// within `max(m, n)` rounding errors of the largest element are treated as zero, so a
+/// matrix that is singular up to rounding is reported as such
+///
+/// ### Parameters
+/// - `matrix` - The `m x n` `Matrix` `A`
+///
+/// ### Returns
+/// - A `Result` containing the `n x (n - rank)` `Matrix` whose columns are the basis
+///   vectors, with no columns when `A` has full column rank, or an `Err` of
+///   `LinalgError::NonFinite` if `matrix` holds a NaN or infinity
+pub fn null_space<T>(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (reduced, pivots) = pivoted_rref(matrix)?;
+    let n = matrix.cols;
+    let free: Vec<usize> = (0..n).filter(|c| !pivots.contains(c)).collect();
+
+    let mut basis = vec![vec![T::zero(); free.len()]; n];
+    for (k, &f) in free.iter().enumerate() {
+        basis[f][k] = T::one();
+        for (row, &p) in pivots.iter().enumerate() {
+            basis[p][k] = -reduced[row][f];
+        }
+    }
+
+    Ok(Matrix {
+        mat: basis.into_iter().map(Arc::from).collect(),
+        rows: n,
+        cols: free.len(),
+    })
+}
+
+/// Computes a basis for the [column space](https://en.wikipedia.org/wiki/Row_and_column_spaces)
+/// of a `Matrix`, the vectors `Ax` can reach
+///
+/// The basis is the columns of `matrix` that hold a pivot once it is reduced to row
+/// echelon form with partial pivoting, taken unchanged and in order, so it is not
+/// orthonormal; pass it to `decompose::orthonormalize` for that. Elements are compared
+/// against zero with the same tolerance as `null_space`
+///
+/// ### Parameters
+/// - `matrix` - The `m x n` `Matrix` `A`
+///
+/// ### Returns
+/// - A `Result` containing the `m x rank` `Matrix` of pivot columns, or an `Err` of
+///   `LinalgError::NonFinite` if `matrix` holds a NaN or infinity
+pub fn column_space<T>(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    let (_, pivots) = pivoted_rref(matrix)?;
+
+    Ok(Matrix {
+        mat: matrix
+            .mat
+            .iter()
+            .map(|row| pivots.iter().map(|&c| row[c]).collect())
+            .collect(),
+        rows: matrix.rows,
+        cols: pivots.len(),
+    })
+}
+
+/// Reduces `matrix` to reduced row echelon form with partial pivoting, treating elements
+/// within `max(m, n)` rounding errors of the largest as zero
+///
+/// ### Returns
+/// - A `Result` containing the nonzero rows of the reduced matrix and, for each of them,
+///   the column of its pivot
+fn pivoted_rref<T>(matrix: &Matrix<T>) -> Result<(Vec<Vec<T>>, Vec<usize>), LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let (m, n) = (matrix.rows, matrix.cols);
+    let _span = Span::new("pivoted_rref", 2 * m * m * n, 1);
+
+    let mut a: Vec<Vec<T>> = matrix.mat.iter().map(|row| row.to_vec()).collect();
+    let largest = a
+        .iter()
+        .flatten()
+        .fold(T::zero(), |max, x| max.max(x.abs()));
+    let tol = T::from(m.max(n)).unwrap_or_else(T::one) * T::epsilon() * largest;
+
+    let mut pivots = Vec::new();
+    for c in 0..n {
+        let r = pivots.len();
+        if r == m {
+            break;
+        }
+        let pivot = (r..m)
+            .max_by(|&i, &j| {
+                a[i][c]
+                    .abs()
+                    .partial_cmp(&a[j][c].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(r);
+        if a[pivot][c].abs() <= tol {
+            continue;
+        }
+        a.swap(r, pivot);
+
+        let scale = a[r][c];
+        for x in &mut a[r] {
+            *x /= scale;
+        }
+        let pivot_row = a[r].clone();
+        for (i, row) in a.iter_mut().enumerate() {
+            if i != r && row[c] != T::zero() {
+                let factor = row[c];
+                for (x, &p) in row.iter_mut().zip(&pivot_row) {
+                    *x -= factor * p;
+                }
+            }
+        }
+        pivots.push(c);
+    }
+    a.truncate(pivots.len());
+
+    Ok((a, pivots))
+}
@@ -0,0 +1,249 @@
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::solve;
+use std::any::{Any, TypeId};
+use std::ops::Neg;
+use std::sync::{Arc, Mutex};
+
+/// The structure of a coefficient matrix, as seen by `Solver::accepts` when `solve_auto`
+/// picks a solver
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatrixProperties {
+    /// The number of rows
+    pub rows: usize,
+
+    /// The number of columns
+    pub cols: usize,
+
+    /// Whether the matrix is square and equal to its transpose, compared exactly
+    pub symmetric: bool,
+
+    /// The largest `i − j` of any nonzero element `(i, j)`, so `0` for an upper
+    /// triangular matrix
+    pub lower_bandwidth: usize,
+
+    /// The largest `j − i` of any nonzero element `(i, j)`, so `0` for a lower
+    /// triangular matrix
+    pub upper_bandwidth: usize,
+
+    /// The number of nonzero elements
+    pub nonzeros: usize,
+}
+
+impl MatrixProperties {
+    /// Inspects `matrix` in one pass over its elements
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `MatrixProperties` of `matrix`, or an `Err` if its rows
+    ///   are not all `cols` long
+    pub fn of<T: Number>(matrix: &Matrix<T>) -> Result<MatrixProperties, LinalgError> {
+        matrix.validate()?;
+        let mut properties = MatrixProperties {
+            rows: matrix.rows,
+            cols: matrix.cols,
+            symmetric: matrix.is_symmetric(),
+            lower_bandwidth: 0,
+            upper_bandwidth: 0,
+            nonzeros: 0,
+        };
+        for (i, row) in matrix.mat.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                if x != T::default() {
+                    properties.nonzeros += 1;
+                    properties.lower_bandwidth =
+                        properties.lower_bandwidth.max(i.saturating_sub(j));
+                    properties.upper_bandwidth =
+                        properties.upper_bandwidth.max(j.saturating_sub(i));
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Checks whether the matrix has as many rows as columns
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Checks whether every element below the main diagonal is zero
+    pub fn is_upper_triangular(&self) -> bool {
+        self.lower_bandwidth == 0
+    }
+
+    /// Checks whether every element above the main diagonal is zero
+    pub fn is_lower_triangular(&self) -> bool {
+        self.upper_bandwidth == 0
+    }
+}
+
+/// A solver for `AX = B` that can be registered with `register_solver`, such as one
+/// backed by a GPU or a vendor library
+///
+/// Implementations are shared between threads, so any state they keep must be
+/// synchronized
+pub trait Solver<T: Number>: Send + Sync {
+    /// Gets the name of this solver, unique among the solvers registered for `T`
+    fn name(&self) -> &str;
+
+    /// Checks whether this solver should handle a coefficient matrix with `properties`
+    fn accepts(&self, properties: &MatrixProperties) -> bool;
+
+    /// Solves `AX = B`
+    ///
+    /// ### Parameters
+    /// - `a` - The n x n coefficient `Matrix`, already checked to be square and valid
+    /// - `b` - An n x k `Matrix` holding one right-hand side per column, already checked to
+    ///   have n rows
+    ///
+    /// ### Returns
+    /// - A `Result` containing the n x k solution, or an `Err` that `solve_auto` returns
+    ///   as is
+    fn solve(&self, a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>;
+}
+
+/// A registered solver, boxed as an `Arc<dyn Solver<T>>` for the element type it was
+/// registered for
+struct Registered {
+    type_id: TypeId,
+    name: String,
+    solver: Box<dyn Any + Send + Sync>,
+}
+
+static SOLVERS: Mutex<Vec<Registered>> = Mutex::new(Vec::new());
+
+/// Registers `solver` for `solve_auto` on matrices of `T`
+///
+/// Registered solvers are tried newest first, so a solver registered later takes
+/// precedence over an earlier one accepting the same matrices. Registering a solver with
+/// the name of one already registered for `T` replaces it
+///
+/// ### Returns
+/// - `true` if a solver of the same name was replaced
+pub fn register_solver<T: Number + 'static>(solver: impl Solver<T> + 'static) -> bool {
+    let name = solver.name().to_string();
+    let solver: Arc<dyn Solver<T>> = Arc::new(solver);
+    let mut solvers = SOLVERS.lock().unwrap_or_else(|e| e.into_inner());
+    let replaced = remove(&mut solvers, TypeId::of::<T>(), &name);
+    solvers.push(Registered {
+        type_id: TypeId::of::<T>(),
+        name,
+        solver: Box::new(solver),
+    });
+
+    replaced
+}
+
+/// Removes the solver called `name` registered for `T`
+///
+/// ### Returns
+/// - `true` if such a solver was registered
+pub fn unregister_solver<T: Number + 'static>(name: &str) -> bool {
+    let mut solvers = SOLVERS.lock().unwrap_or_else(|e| e.into_inner());
+    remove(&mut solvers, TypeId::of::<T>(), name)
+}
+
+/// Lists the names of the solvers registered for `T`, in the order `solve_auto` tries them
+pub fn registered_solvers<T: Number + 'static>() -> Vec<String> {
+    let solvers = SOLVERS.lock().unwrap_or_else(|e| e.into_inner());
+    solvers
+        .iter()
+        .rev()
+        .filter(|r| r.type_id == TypeId::of::<T>())
+        .map(|r| r.name.clone())
+        .collect()
+}
+
+/// Gets the name of the solver `solve_auto` would use for a coefficient matrix with
+/// `properties`
+///
+/// ### Returns
+/// - The name of the newest registered solver for `T` that accepts `properties`, or else
+///   the built-in choice: `"lower_triangular"`, `"upper_triangular"`, or `"solve"`
+pub fn solver_for<T: Number + 'static>(properties: &MatrixProperties) -> String {
+    match find::<T>(properties) {
+        Some(solver) => solver.name().to_string(),
+        None => builtin(properties).to_string(),
+    }
+}
+
+/// Solves `AX = B` with the solver best suited to `a`
+///
+/// The newest registered solver for `T` whose `accepts` holds for the
+/// `MatrixProperties` of `a` is used. Without one, a triangular `a` is solved by
+/// substitution with `solve::solve_lower_triangular` or `solve::solve_upper_triangular`,
+/// and any other `a` by `solve::solve`. A registered solver is called without the
+/// registry locked, so it may itself call `solve_auto`
+///
+/// ### Parameters
+/// - `a` - The n x n coefficient `Matrix`
+/// - `b` - An n x k `Matrix` holding one right-hand side per column
+///
+/// ### Returns
+/// - A `Result` based on whether the system was solved
+///     - An `Ok` containing the n x k solution `X`
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::ShapeMismatch` if `b` does not have n rows, or whatever error the
+///       chosen solver returns, without trying any other
+pub fn solve_auto<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float + 'static,
+{
+    let properties = MatrixProperties::of(a)?;
+    b.validate()?;
+    if !properties.is_square() {
+        return Err(LinalgError::NonSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    if b.rows != a.rows {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (a.rows, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+
+    if let Some(solver) = find::<T>(&properties) {
+        return solver.solve(a, b);
+    }
+    match builtin(&properties) {
+        "lower_triangular" => solve::solve_lower_triangular(a, b),
+        "upper_triangular" => solve::solve_upper_triangular(a, b),
+        _ => solve::solve(a, b),
+    }
+}
+
+/// Finds the newest registered solver for `T` accepting `properties`
+///
+/// The registry stays locked while `accepts` runs, but not while the solver returned is
+/// used
+fn find<T: Number + 'static>(properties: &MatrixProperties) -> Option<Arc<dyn Solver<T>>> {
+    let solvers = SOLVERS.lock().unwrap_or_else(|e| e.into_inner());
+    solvers
+        .iter()
+        .rev()
+        .filter_map(|r| r.solver.downcast_ref::<Arc<dyn Solver<T>>>())
+        .find(|solver| solver.accepts(properties))
+        .cloned()
+}
+
+/// Names the built-in solver `solve_auto` falls back to for `properties`
+fn builtin(properties: &MatrixProperties) -> &'static str {
+    if properties.is_lower_triangular() {
+        "lower_triangular"
+    } else if properties.is_upper_triangular() {
+        "upper_triangular"
+    } else {
+        "solve"
+    }
+}
+
+/// Removes the solver called `name` registered for the type `type_id`, if any
+fn remove(solvers: &mut Vec<Registered>, type_id: TypeId, name: &str) -> bool {
+    let before = solvers.len();
+    solvers.retain(|r| r.type_id != type_id || r.name != name);
+
+    solvers.len() != before
+}
@@ -0,0 +1,170 @@
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+
+/// The result of a stationary iterative solver: the solution plus how it converged
+#[derive(Clone, Debug, PartialEq)]
+pub struct StationarySolution<T: Number> {
+    /// The solution `x` of `Ax = b`
+    pub solution: Vec<T>,
+
+    /// The number of iterations taken
+    pub iterations: usize,
+
+    /// The final relative residual `‖b − Ax‖ / ‖b‖`
+    pub residual: T,
+
+    /// The relative residual after each iteration, in order
+    ///
+    /// The ratio of successive entries approaches the spectral radius of the iteration
+    /// matrix, so it shows how fast the method is converging on this system
+    pub history: Vec<T>,
+}
+
+/// Solves `Ax = b` by the [Jacobi method](https://en.wikipedia.org/wiki/Jacobi_method)
+///
+/// Every unknown is updated from the previous iterate only, as
+/// `x_i ← (b_i − Σ_{j≠i} a_ij x_j) / a_ii`. The iteration converges for any starting point
+/// when `A` is strictly diagonally dominant. Starts from `x = 0` and stops once the
+/// relative residual is at most `tol`
+///
+/// ### Parameters
+/// - `a` - The `n x n` coefficient `Matrix`, with no zero on its diagonal
+/// - `b` - The right-hand side, with `n` elements
+/// - `tol` - The relative residual `‖b − Ax‖ / ‖b‖` to reach, greater than `0`
+/// - `max_iter` - The most iterations to take
+///
+/// ### Returns
+/// - A `Result` based on whether the solver converged
+///     - An `Ok` containing the `StationarySolution`
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::LengthMismatch` if `b` does not have `n` elements,
+///       `LinalgError::InvalidArgument` if `tol` is not positive,
+///       `LinalgError::ZeroPivot` if a diagonal element of `a` is zero, or
+///       `LinalgError::NotConverged` if `max_iter` iterations were not enough or the
+///       iterates diverged
+pub fn jacobi<T>(
+    a: &Matrix<T>,
+    b: &[T],
+    tol: T,
+    max_iter: usize,
+) -> Result<StationarySolution<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    stationary(a, b, tol, max_iter, false)
+}
+
+/// Solves `Ax = b` by the [Gauss–Seidel method](https://en.wikipedia.org/wiki/Gauss%E2%80%93Seidel_method)
+///
+/// Like `jacobi`, but each unknown is updated in place, so later updates in a sweep
+/// already see the new values of earlier ones. This typically takes about half as many
+/// iterations as `jacobi`, and also converges for any symmetric positive definite `A`
+///
+/// ### Parameters
+/// - `a` - The `n x n` coefficient `Matrix`, with no zero on its diagonal
+/// - `b` - The right-hand side, with `n` elements
+/// - `tol` - The relative residual `‖b − Ax‖ / ‖b‖` to reach, greater than `0`
+/// - `max_iter` - The most iterations to take
+///
+/// ### Returns
+/// - A `Result` containing the `StationarySolution`, or an `Err` with one of the failures
+///   described in `jacobi`
+pub fn gauss_seidel<T>(
+    a: &Matrix<T>,
+    b: &[T],
+    tol: T,
+    max_iter: usize,
+) -> Result<StationarySolution<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    stationary(a, b, tol, max_iter, true)
+}
+
+/// Runs `jacobi`, or `gauss_seidel` when `in_place` is set
+fn stationary<T>(
+    a: &Matrix<T>,
+    b: &[T],
+    tol: T,
+    max_iter: usize,
+    in_place: bool,
+) -> Result<StationarySolution<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if b.len() != n {
+        return Err(LinalgError::LengthMismatch {
+            expected: n,
+            found: b.len(),
+        });
+    }
+    if tol.is_nan() || tol <= T::zero() {
+        return Err(LinalgError::InvalidArgument(
+            "The tolerance must be positive!".to_string(),
+        ));
+    }
+    if (0..n).any(|i| a.mat[i][i] == T::zero()) {
+        return Err(LinalgError::ZeroPivot);
+    }
+
+    let norm = |v: &[T]| v.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt();
+    let b_norm = norm(b);
+    let mut x = vec![T::zero(); n];
+    let mut history = Vec::new();
+    if b_norm == T::zero() {
+        return Ok(StationarySolution {
+            solution: x,
+            iterations: 0,
+            residual: T::zero(),
+            history,
+        });
+    }
+
+    for iteration in 1..=max_iter {
+        let previous = if in_place { Vec::new() } else { x.clone() };
+        for (i, row) in a.mat.iter().enumerate() {
+            let source = |j: usize| if in_place { x[j] } else { previous[j] };
+            let sum = (0..n)
+                .filter(|&j| j != i)
+                .fold(b[i], |sum, j| sum - row[j] * source(j));
+            x[i] = sum / row[i];
+        }
+
+        let r: Vec<T> = a
+            .mat
+            .iter()
+            .zip(b)
+            .map(|(row, &b_i)| {
+                row.iter()
+                    .zip(&x)
+                    .fold(b_i, |r_i, (&a_ij, &x_j)| r_i - a_ij * x_j)
+            })
+            .collect();
+        let residual = norm(&r) / b_norm;
+        history.push(residual);
+        if residual <= tol {
+            return Ok(StationarySolution {
+                solution: x,
+                iterations: iteration,
+                residual,
+                history,
+            });
+        }
+        if !residual.is_finite() {
+            break;
+        }
+    }
+
+    Err(LinalgError::NotConverged {
+        iterations: history.len(),
+    })
+}
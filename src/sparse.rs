@@ -0,0 +1,445 @@
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::sync::Arc;
+
+/// A sparse matrix stored in [compressed sparse row](https://en.wikipedia.org/wiki/Sparse_matrix)
+/// (CSR) form
+///
+/// Only the stored entries are kept: row `r` owns the entries at positions
+/// `row_ptr[r]..row_ptr[r + 1]` of `col_indices` and `values`, sorted by column. A matrix
+/// with `nnz` stored entries takes O(rows + nnz) memory, and `mul_vec` takes O(nnz) time,
+/// so systems with millions of unknowns but a few entries per row stay tractable
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix<T: Number> {
+    /// Stores the number of rows in the matrix
+    pub rows: usize,
+
+    /// Stores the number of columns in the matrix
+    pub cols: usize,
+
+    /// The offsets into `col_indices` and `values` at which each row starts, plus a final
+    /// entry equal to the number of stored entries
+    pub row_ptr: Vec<usize>,
+
+    /// The column of each stored entry
+    pub col_indices: Vec<usize>,
+
+    /// The value of each stored entry
+    pub values: Vec<T>,
+}
+
+impl<T: Number> SparseMatrix<T> {
+    /// Creates a `rows` x `cols` `SparseMatrix` with no stored entries
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    ///
+    /// ### Returns
+    /// - An all-zero `SparseMatrix`
+    pub fn new(rows: usize, cols: usize) -> SparseMatrix<T> {
+        SparseMatrix {
+            rows,
+            cols,
+            row_ptr: vec![0; rows + 1],
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Creates a `SparseMatrix` from `(row, col, value)` triplets, in any order
+    ///
+    /// Triplets at the same position are summed, which is how finite element and stencil
+    /// assembly naturally produces them
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    /// - `triplets` - The entries to store
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `SparseMatrix`, or an `Err` of `LinalgError::OutOfBounds`
+    ///   if a triplet lies outside the matrix
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<SparseMatrix<T>, LinalgError> {
+        for &(r, c, _) in triplets {
+            if r >= rows {
+                return Err(LinalgError::OutOfBounds {
+                    index: r,
+                    len: rows,
+                });
+            }
+            if c >= cols {
+                return Err(LinalgError::OutOfBounds {
+                    index: c,
+                    len: cols,
+                });
+            }
+        }
+
+        let mut sorted = triplets.to_vec();
+        sorted.sort_by_key(|&(r, c, _)| (r, c));
+        let mut matrix = SparseMatrix::new(rows, cols);
+        let mut previous = None;
+        for (r, c, value) in sorted {
+            match matrix.values.last_mut() {
+                Some(last) if previous == Some((r, c)) => *last += value,
+                _ => {
+                    matrix.col_indices.push(c);
+                    matrix.values.push(value);
+                    matrix.row_ptr[r + 1] = matrix.values.len();
+                    previous = Some((r, c));
+                }
+            }
+        }
+        // Rows without entries start where the previous row ended
+        for r in 0..rows {
+            matrix.row_ptr[r + 1] = matrix.row_ptr[r + 1].max(matrix.row_ptr[r]);
+        }
+
+        Ok(matrix)
+    }
+
+    /// Creates a `SparseMatrix` holding the nonzero elements of a dense `Matrix`
+    ///
+    /// ### Parameters
+    /// - `matrix` - The dense `Matrix` to compress
+    ///
+    /// ### Returns
+    /// - A `Result` containing the `SparseMatrix`, or an `Err` if `matrix` is malformed
+    pub fn from_dense(matrix: &Matrix<T>) -> Result<SparseMatrix<T>, LinalgError> {
+        matrix.validate()?;
+        let mut sparse = SparseMatrix::new(matrix.rows, matrix.cols);
+        for (r, row) in matrix.mat.iter().enumerate() {
+            for (c, &value) in row.iter().enumerate() {
+                if value != T::default() {
+                    sparse.col_indices.push(c);
+                    sparse.values.push(value);
+                }
+            }
+            sparse.row_ptr[r + 1] = sparse.values.len();
+        }
+
+        Ok(sparse)
+    }
+
+    /// Expands this `SparseMatrix` into a dense `Matrix`
+    ///
+    /// ### Returns
+    /// - A `Matrix` with the stored entries in place and zeros everywhere else
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mat = (0..self.rows)
+            .map(|r| {
+                let mut row = vec![T::default(); self.cols];
+                for (c, value) in self.row(r) {
+                    row[c] = value;
+                }
+                Arc::from(row)
+            })
+            .collect();
+
+        Matrix {
+            mat,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Counts the stored entries of this `SparseMatrix`
+    ///
+    /// ### Returns
+    /// - The number of stored entries, which may include explicitly stored zeros
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Computes the fraction of positions that hold a stored entry
+    ///
+    /// ### Returns
+    /// - `nnz / (rows * cols)`, or `0` for an empty matrix
+    pub fn density(&self) -> f64 {
+        let size = self.rows * self.cols;
+        if size == 0 {
+            0.0
+        } else {
+            self.nnz() as f64 / size as f64
+        }
+    }
+
+    /// Iterates over the stored entries of row `r`, in column order
+    ///
+    /// ### Parameters
+    /// - `r` - The row index, which must be less than `rows`
+    ///
+    /// ### Returns
+    /// - An iterator yielding `(col, value)` for each stored entry of the row
+    pub fn row(&self, r: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+        let range = self.row_ptr[r]..self.row_ptr[r + 1];
+        self.col_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Gets the element at `(row, col)`, whether or not it is stored
+    ///
+    /// ### Parameters
+    /// - `row` - The row index
+    /// - `col` - The column index
+    ///
+    /// ### Returns
+    /// - `Some` with the element, which is zero if no entry is stored there, or `None` if
+    ///   the position is out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let range = self.row_ptr[row]..self.row_ptr[row + 1];
+
+        Some(match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(i) => self.values[range.start + i],
+            Err(_) => T::default(),
+        })
+    }
+
+    /// Multiplies this `SparseMatrix` by the vector `x` in O(nnz) time
+    ///
+    /// ### Parameters
+    /// - `x` - A vector with one element per column
+    ///
+    /// ### Returns
+    /// - A `Result` containing the product, with one element per row, or an `Err` of
+    ///   `LinalgError::LengthMismatch` if `x` does not have `cols` elements
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, LinalgError> {
+        if x.len() != self.cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.cols,
+                found: x.len(),
+            });
+        }
+
+        Ok((0..self.rows)
+            .map(|r| {
+                self.row(r)
+                    .fold(T::default(), |sum, (c, value)| sum + value * x[c])
+            })
+            .collect())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: Number> SparseMatrix<T> {
+    /// Creates a `rows` x `cols` `SparseMatrix` whose entries sit at uniformly random
+    /// positions, using the thread-local random number generator
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    /// - `density` - The fraction of positions to fill, between `0` and `1`
+    /// - `distribution` - The distribution each stored value is drawn from, such as
+    ///   `rand::distr::Uniform::new(-1.0, 1.0)?`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the random `SparseMatrix` with `round(density * rows * cols)`
+    ///   stored entries, or an `Err` of `LinalgError::InvalidArgument` if `density` is not
+    ///   between `0` and `1`
+    pub fn random<D>(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        distribution: D,
+    ) -> Result<SparseMatrix<T>, LinalgError>
+    where
+        D: rand::distr::Distribution<T>,
+    {
+        SparseMatrix::random_with_rng(rows, cols, density, distribution, &mut rand::rng())
+    }
+
+    /// Creates a random `SparseMatrix` like `random`, drawing from `rng`
+    ///
+    /// Pass a seeded generator, such as `rand::rngs::StdRng::seed_from_u64`, for
+    /// reproducible matrices
+    ///
+    /// ### Parameters
+    /// - `rows` - The number of rows
+    /// - `cols` - The number of columns
+    /// - `density` - The fraction of positions to fill, between `0` and `1`
+    /// - `distribution` - The distribution each stored value is drawn from
+    /// - `rng` - The random number generator to draw from
+    ///
+    /// ### Returns
+    /// - A `Result` containing the random `SparseMatrix`, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if `density` is not between `0` and `1`
+    pub fn random_with_rng<D, G>(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        distribution: D,
+        rng: &mut G,
+    ) -> Result<SparseMatrix<T>, LinalgError>
+    where
+        D: rand::distr::Distribution<T>,
+        G: rand::Rng + ?Sized,
+    {
+        let count = fill_count(rows * cols, density)?;
+        let triplets: Vec<(usize, usize, T)> = rand::seq::index::sample(rng, rows * cols, count)
+            .into_iter()
+            .map(|i| (i / cols, i % cols, distribution.sample(rng)))
+            .collect();
+
+        SparseMatrix::from_triplets(rows, cols, &triplets)
+    }
+
+    /// Creates an `n x n` banded `SparseMatrix` with every entry inside the band filled,
+    /// using the thread-local random number generator
+    ///
+    /// ### Parameters
+    /// - `n` - The number of rows and columns
+    /// - `lower` - The number of diagonals filled below the main diagonal
+    /// - `upper` - The number of diagonals filled above the main diagonal
+    /// - `distribution` - The distribution each stored value is drawn from
+    ///
+    /// ### Returns
+    /// - The random banded `SparseMatrix`, whose `(i, j)` entry is stored exactly when
+    ///   `i - lower <= j <= i + upper`
+    pub fn random_banded<D>(
+        n: usize,
+        lower: usize,
+        upper: usize,
+        distribution: D,
+    ) -> SparseMatrix<T>
+    where
+        D: rand::distr::Distribution<T>,
+    {
+        SparseMatrix::random_banded_with_rng(n, lower, upper, distribution, &mut rand::rng())
+    }
+
+    /// Creates a random banded `SparseMatrix` like `random_banded`, drawing from `rng`
+    ///
+    /// ### Parameters
+    /// - `n` - The number of rows and columns
+    /// - `lower` - The number of diagonals filled below the main diagonal
+    /// - `upper` - The number of diagonals filled above the main diagonal
+    /// - `distribution` - The distribution each stored value is drawn from
+    /// - `rng` - The random number generator to draw from
+    ///
+    /// ### Returns
+    /// - The random banded `SparseMatrix`
+    pub fn random_banded_with_rng<D, G>(
+        n: usize,
+        lower: usize,
+        upper: usize,
+        distribution: D,
+        rng: &mut G,
+    ) -> SparseMatrix<T>
+    where
+        D: rand::distr::Distribution<T>,
+        G: rand::Rng + ?Sized,
+    {
+        let mut matrix = SparseMatrix::new(n, n);
+        for r in 0..n {
+            for c in r.saturating_sub(lower)..n.min(r.saturating_add(upper).saturating_add(1)) {
+                matrix.col_indices.push(c);
+                matrix.values.push(distribution.sample(rng));
+            }
+            matrix.row_ptr[r + 1] = matrix.values.len();
+        }
+
+        matrix
+    }
+
+    /// Creates a block-sparse `SparseMatrix` made of dense `block_size x block_size` blocks
+    /// at random positions of a `block_rows x block_cols` grid, using the thread-local random
+    /// number generator
+    ///
+    /// ### Parameters
+    /// - `block_rows` - The number of block rows
+    /// - `block_cols` - The number of block columns
+    /// - `block_size` - The side length of each block
+    /// - `density` - The fraction of blocks to fill, between `0` and `1`
+    /// - `distribution` - The distribution each stored value is drawn from
+    ///
+    /// ### Returns
+    /// - A `Result` containing the random `SparseMatrix`, which is
+    ///   `block_rows * block_size` x `block_cols * block_size`, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if `density` is not between `0` and `1`
+    pub fn random_block_sparse<D>(
+        block_rows: usize,
+        block_cols: usize,
+        block_size: usize,
+        density: f64,
+        distribution: D,
+    ) -> Result<SparseMatrix<T>, LinalgError>
+    where
+        D: rand::distr::Distribution<T>,
+    {
+        SparseMatrix::random_block_sparse_with_rng(
+            block_rows,
+            block_cols,
+            block_size,
+            density,
+            distribution,
+            &mut rand::rng(),
+        )
+    }
+
+    /// Creates a random block-sparse `SparseMatrix` like `random_block_sparse`, drawing
+    /// from `rng`
+    ///
+    /// ### Parameters
+    /// - `block_rows` - The number of block rows
+    /// - `block_cols` - The number of block columns
+    /// - `block_size` - The side length of each block
+    /// - `density` - The fraction of blocks to fill, between `0` and `1`
+    /// - `distribution` - The distribution each stored value is drawn from
+    /// - `rng` - The random number generator to draw from
+    ///
+    /// ### Returns
+    /// - A `Result` containing the random `SparseMatrix`, or an `Err` of
+    ///   `LinalgError::InvalidArgument` if `density` is not between `0` and `1`
+    pub fn random_block_sparse_with_rng<D, G>(
+        block_rows: usize,
+        block_cols: usize,
+        block_size: usize,
+        density: f64,
+        distribution: D,
+        rng: &mut G,
+    ) -> Result<SparseMatrix<T>, LinalgError>
+    where
+        D: rand::distr::Distribution<T>,
+        G: rand::Rng + ?Sized,
+    {
+        let count = fill_count(block_rows * block_cols, density)?;
+        let mut triplets = Vec::with_capacity(count * block_size * block_size);
+        for block in rand::seq::index::sample(rng, block_rows * block_cols, count) {
+            let (top, left) = (
+                block / block_cols * block_size,
+                block % block_cols * block_size,
+            );
+            for r in top..top + block_size {
+                for c in left..left + block_size {
+                    triplets.push((r, c, distribution.sample(rng)));
+                }
+            }
+        }
+
+        SparseMatrix::from_triplets(block_rows * block_size, block_cols * block_size, &triplets)
+    }
+}
+
+/// The number of the `total` positions a random generator fills at `density`
+#[cfg(feature = "rand")]
+fn fill_count(total: usize, density: f64) -> Result<usize, LinalgError> {
+    if !(0.0..=1.0).contains(&density) {
+        return Err(LinalgError::InvalidArgument(format!(
+            "The density must be between 0 and 1, but got {}!",
+            density
+        )));
+    }
+
+    Ok(((total as f64 * density).round() as usize).min(total))
+}
@@ -0,0 +1,212 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// A positive semi-definite kernel `k(x, y)` comparing two samples
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kernel<T: Number> {
+    /// The plain dot product `x · y`
+    Linear,
+
+    /// The Gaussian radial basis function `exp(-gamma * |x - y|^2)`
+    RBF {
+        /// How quickly similarity decays with distance; must be positive
+        gamma: T,
+    },
+
+    /// The polynomial kernel `(x · y + coef)^deg`
+    Polynomial {
+        /// The degree of the polynomial
+        deg: i32,
+
+        /// The constant added before raising to `deg`; usually non-negative
+        coef: T,
+    },
+}
+
+impl<T: Number + num::Float> Kernel<T> {
+    /// Evaluates this kernel on two samples of equal length
+    ///
+    /// ### Parameters
+    /// - `x` - One sample
+    /// - `y` - Another sample
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the samples could be compared
+    ///     - An `Ok` containing the kernel value `k(x, y)`
+    ///     - An `Err` of `LinalgError::LengthMismatch` if `x` and `y` differ in length
+    pub fn apply(&self, x: &[T], y: &[T]) -> Result<T, LinalgError> {
+        if x.len() != y.len() {
+            return Err(LinalgError::LengthMismatch {
+                expected: x.len(),
+                found: y.len(),
+            });
+        }
+
+        Ok(self.evaluate(x, y))
+    }
+
+    /// Evaluates this kernel on two samples already known to have equal length
+    fn evaluate(&self, x: &[T], y: &[T]) -> T {
+        let dot = || x.iter().zip(y).fold(T::zero(), |sum, (&a, &b)| sum + a * b);
+
+        match *self {
+            Kernel::Linear => dot(),
+            Kernel::RBF { gamma } => {
+                let distance = x
+                    .iter()
+                    .zip(y)
+                    .fold(T::zero(), |sum, (&a, &b)| sum + (a - b) * (a - b));
+                (-gamma * distance).exp()
+            }
+            Kernel::Polynomial { deg, coef } => (dot() + coef).powi(deg),
+        }
+    }
+}
+
+/// Computes the kernel (Gram) matrix of a dataset
+///
+/// Each row of `x` is one sample. The result is symmetric, so only its upper triangle is
+/// evaluated
+///
+/// ### Parameters
+/// - `x` - An `n x d` `Matrix` of `n` samples with `d` features each
+/// - `kernel` - The `Kernel` to compare samples with
+///
+/// ### Returns
+/// - A `Result` based on whether the kernel matrix could be computed
+///     - An `Ok` containing the `n x n` `Matrix` whose `(i, j)` element is
+///       `kernel(row i, row j)`
+///     - An `Err` if `x` is malformed or an RBF `gamma` is not positive
+pub fn kernel_matrix<T>(x: &Matrix<T>, kernel: Kernel<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    x.validate()?;
+    if let Kernel::RBF { gamma } = kernel {
+        if gamma.is_nan() || gamma <= T::zero() {
+            return Err(LinalgError::InvalidArgument(
+                "The RBF kernel needs a positive gamma!".to_string(),
+            ));
+        }
+    }
+    let n = x.rows;
+    let _span = Span::new("kernel_matrix", n * (n + 1) * x.cols, n + 1);
+
+    Ok(arith::symmetric(n, |i, j| {
+        kernel.evaluate(&x.mat[i], &x.mat[j])
+    }))
+}
+
+/// The result of `kmeans`: a cluster label for every sample plus the cluster centres
+#[derive(Clone, Debug, PartialEq)]
+pub struct KMeans<T: Number> {
+    /// The cluster of each sample, in `0..k`, in the order of the rows of the data
+    pub labels: Vec<usize>,
+
+    /// A `k x d` `Matrix` whose rows are the cluster centroids
+    pub centroids: Matrix<T>,
+
+    /// The number of assignment passes taken before the labels stopped changing
+    pub iterations: usize,
+}
+
+/// Groups the rows of `x` into `k` clusters with [k-means](https://en.wikipedia.org/wiki/K-means_clustering)
+///
+/// The centroids are seeded deterministically by farthest-point traversal, starting from
+/// the first sample, so the same data always gives the same clustering. Lloyd iterations
+/// then alternate assigning each sample to its nearest centroid and moving each centroid
+/// to the mean of its samples until no label changes
+///
+/// ### Parameters
+/// - `x` - An `n x d` `Matrix` of `n` samples with `d` features each
+/// - `k` - The number of clusters, between `1` and `n`
+/// - `max_iter` - The most assignment passes to take; at least one is always made
+///
+/// ### Returns
+/// - A `Result` based on whether the clustering could be computed
+///     - An `Ok` containing the `KMeans` labels and centroids
+///     - An `Err` if `x` is malformed or holds a NaN or infinity, or of
+///       `LinalgError::InvalidArgument` if `k` is not between `1` and `n`
+pub fn kmeans<T>(x: &Matrix<T>, k: usize, max_iter: usize) -> Result<KMeans<T>, LinalgError>
+where
+    T: Number + num::Float,
+{
+    x.validate()?;
+    x.check_finite()?;
+    let (n, d) = (x.rows, x.cols);
+    if k == 0 || k > n {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Cannot form {} clusters from {} samples!",
+            k, n
+        )));
+    }
+    let _span = Span::new("kmeans", 3 * n * k * d * max_iter.max(1), k + 1);
+    let distance = |a: &[T], b: &[T]| {
+        a.iter()
+            .zip(b)
+            .fold(T::zero(), |sum, (&p, &q)| sum + (p - q) * (p - q))
+    };
+    let nearest = |sample: &[T], centroids: &[Vec<T>]| {
+        (0..centroids.len()).fold(0, |best, c| {
+            if distance(sample, &centroids[c]) < distance(sample, &centroids[best]) {
+                c
+            } else {
+                best
+            }
+        })
+    };
+
+    let mut centroids = vec![x.mat[0].to_vec()];
+    let mut closest: Vec<T> = x.mat.iter().map(|row| distance(row, &x.mat[0])).collect();
+    while centroids.len() < k {
+        let farthest = (0..n).fold(
+            0,
+            |best, i| if closest[i] > closest[best] { i } else { best },
+        );
+        centroids.push(x.mat[farthest].to_vec());
+        for (i, row) in x.mat.iter().enumerate() {
+            closest[i] = closest[i].min(distance(row, &x.mat[farthest]));
+        }
+    }
+
+    let mut labels = vec![usize::MAX; n];
+    let mut iterations = 0;
+    while iterations < max_iter.max(1) {
+        iterations += 1;
+        let assigned: Vec<usize> = x.mat.iter().map(|row| nearest(row, &centroids)).collect();
+        if assigned == labels {
+            break;
+        }
+        labels = assigned;
+
+        let mut sums = vec![vec![T::zero(); d]; k];
+        let mut counts = vec![0usize; k];
+        for (row, &label) in x.mat.iter().zip(&labels) {
+            counts[label] += 1;
+            for (sum, &value) in sums[label].iter_mut().zip(row.iter()) {
+                *sum += value;
+            }
+        }
+        // A cluster that lost all its samples keeps its previous centroid
+        for c in 0..k {
+            if let Some(count) = T::from(counts[c]).filter(|_| counts[c] > 0) {
+                centroids[c] = sums[c].iter().map(|&sum| sum / count).collect();
+            }
+        }
+    }
+
+    Ok(KMeans {
+        labels,
+        centroids: Matrix {
+            mat: centroids.into_iter().map(Arc::from).collect(),
+            rows: k,
+            cols: d,
+        },
+        iterations,
+    })
+}
@@ -0,0 +1,373 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::{Matrix, NormKind};
+use crate::number::Number;
+use crate::solve;
+use crate::transform;
+use std::collections::HashMap;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// A system of linear equations `AX = B`
+///
+/// Bundles a coefficient matrix with its right-hand sides so that builders such as
+/// `graph::kirchhoff_system` can hand back a complete problem ready to `solve`
+///
+/// Build one with `System::new` or `System::from_augmented`, which check the shapes, and
+/// name its unknowns with `System::with_variables`
+///
+/// With the `serde` feature a `System` serializes as its `coefficients`, `constants`, and
+/// `variables`; a missing `variables` field reads as `None`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct System<T: Number> {
+    /// The `n x n` coefficient matrix `A`
+    pub coefficients: Matrix<T>,
+
+    /// The `n x k` matrix `B` holding one right-hand side per column
+    pub constants: Matrix<T>,
+
+    /// The name of each unknown, in column order, or `None` to call them `a`, `b`, `c`, …
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub variables: Option<Vec<String>>,
+}
+
+/// The result of `System::solve_equilibrated`: the solution plus the scaling applied
+///
+/// The scaled system `(R A C) y = R B` was solved, with `R` and `C` the diagonal matrices
+/// of `row_scale` and `col_scale`, and the solution unscaled as `X = C y`. Every scale
+/// factor is a power of two, so scaling itself introduces no rounding error
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquilibratedSolution<T: Number> {
+    /// The solution `X` of the original, unscaled system
+    pub solution: Matrix<T>,
+
+    /// The factor each equation was multiplied by
+    pub row_scale: Vec<T>,
+
+    /// The factor each unknown was divided by
+    pub col_scale: Vec<T>,
+}
+
+/// Bounds on the relative size of the errors in a `System`, measured in the ∞-norm
+///
+/// A bound of `0.01` on the coefficients allows any `δA` with `‖δA‖∞ ≤ 0.01 ‖A‖∞`, which
+/// holds whenever every coefficient is known to within 1%
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Perturbation<T: Number> {
+    /// The bound on `‖δA‖∞ / ‖A‖∞`
+    pub coefficients: T,
+
+    /// The bound on `‖δb‖∞ / ‖b‖∞`, applied to each right-hand side
+    pub constants: T,
+}
+
+/// The result of `System::sensitivity`: the solution plus first-order error bars
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sensitivity<T: Number> {
+    /// The solution `X` of the unperturbed system
+    pub solution: Matrix<T>,
+
+    /// The condition number `κ∞(A) = ‖A‖∞ ‖A⁻¹‖∞`
+    pub condition_number: T,
+
+    /// The bound on `‖δx‖∞ / ‖x‖∞` for every right-hand side, or infinity when the
+    /// perturbation may make `A` singular
+    pub relative_error: T,
+
+    /// The bound on `‖δx‖∞` for each right-hand side, in column order
+    pub error_bounds: Vec<T>,
+}
+
+impl<T: Number> System<T> {
+    /// Creates a `System` from its coefficient matrix and right-hand sides
+    ///
+    /// ### Parameters
+    /// - `coefficients` - The `n x n` coefficient matrix `A`
+    /// - `constants` - The `n x k` matrix `B` holding one right-hand side per column
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the shapes fit together
+    ///     - An `Ok` containing the new `System`, with unnamed variables
+    ///     - An `Err` of `LinalgError::NonSquare` if `coefficients` is not square,
+    ///       `LinalgError::ShapeMismatch` if `constants` does not have `n` rows, or the
+    ///       error from `Matrix::validate` for a malformed input
+    pub fn new(coefficients: Matrix<T>, constants: Matrix<T>) -> Result<System<T>, LinalgError> {
+        coefficients.validate()?;
+        constants.validate()?;
+        let n = coefficients.rows;
+        if coefficients.cols != n {
+            return Err(LinalgError::NonSquare {
+                rows: n,
+                cols: coefficients.cols,
+            });
+        }
+        if constants.rows != n {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, constants.cols),
+                found: (constants.rows, constants.cols),
+            });
+        }
+
+        Ok(System {
+            coefficients,
+            constants,
+            variables: None,
+        })
+    }
+
+    /// Creates a `System` from an augmented matrix `[A | B]`
+    ///
+    /// The first `n` columns of an `n`-row `matrix` become `A` and the rest become `B`, so
+    /// an `n x (n + k)` matrix holds `k` right-hand sides
+    ///
+    /// ### Parameters
+    /// - `matrix` - The augmented matrix, with at least one more column than rows
+    ///
+    /// ### Returns
+    /// - A `Result` containing the new `System`, or an `Err` of
+    ///   `LinalgError::ShapeMismatch` if `matrix` has no column left over for `B`
+    pub fn from_augmented(matrix: Matrix<T>) -> Result<System<T>, LinalgError> {
+        matrix.validate()?;
+        let n = matrix.rows;
+        if matrix.cols <= n {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, n + 1),
+                found: (n, matrix.cols),
+            });
+        }
+
+        let split = |cols: std::ops::Range<usize>| Matrix {
+            mat: matrix
+                .mat
+                .iter()
+                .map(|row| Arc::from(&row[cols.clone()]))
+                .collect(),
+            rows: n,
+            cols: cols.len(),
+        };
+        System::new(split(0..n), split(n..matrix.cols))
+    }
+
+    /// Names the unknowns of this `System`
+    ///
+    /// ### Parameters
+    /// - `names` - One unique name per unknown, in column order
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the names fit the system
+    ///     - An `Ok` containing this `System` with named variables
+    ///     - An `Err` of `LinalgError::LengthMismatch` if there is not one name per
+    ///       column of `coefficients`, or `LinalgError::InvalidArgument` if a name is
+    ///       repeated
+    pub fn with_variables(mut self, names: &[&str]) -> Result<System<T>, LinalgError> {
+        if names.len() != self.coefficients.cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.coefficients.cols,
+                found: names.len(),
+            });
+        }
+        if let Some((i, name)) = names
+            .iter()
+            .enumerate()
+            .find(|(i, name)| names[..*i].contains(name))
+        {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Variable name \"{}\" is repeated at position {}",
+                name, i
+            )));
+        }
+
+        self.variables = Some(names.iter().map(|name| name.to_string()).collect());
+        Ok(self)
+    }
+
+    /// Gets the name of every unknown, in column order
+    ///
+    /// ### Returns
+    /// - The names given to `with_variables`, or `a`, `b`, `c`, … if none were given
+    pub fn variable_names(&self) -> Vec<String> {
+        match &self.variables {
+            Some(names) => names.clone(),
+            None => (0..self.coefficients.cols)
+                .map(|i| ((b'a' + i as u8) as char).to_string())
+                .collect(),
+        }
+    }
+}
+
+impl<T: Number + Neg<Output = T>> System<T> {
+    /// Solves this `System` by Gaussian elimination, naming each unknown in the result
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the system has a unique solution
+    ///     - An `Ok` containing a `HashMap` from each name in `variable_names` to its value
+    ///     - An `Err` of `LinalgError::ShapeMismatch` if there is not exactly one
+    ///       right-hand side, or one of the failures described by
+    ///       `solve::gaussian_elimination`
+    pub fn gaussian_elimination(&self) -> Result<HashMap<String, T>, LinalgError> {
+        let n = self.coefficients.rows;
+        if self.constants.cols != 1 {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, 1),
+                found: (self.constants.rows, self.constants.cols),
+            });
+        }
+        let augmented = transform::hstack(&[&self.coefficients, &self.constants])?;
+        let solution = solve::gaussian_elimination(&augmented)?;
+
+        let names = self.variable_names();
+        Ok(solution
+            .into_iter()
+            .map(|(letter, value)| (names[(letter as u8 - b'a') as usize].clone(), value))
+            .collect())
+    }
+}
+
+impl<T: Number + Neg<Output = T> + num::Float> System<T> {
+    /// Solves this `System` for its unknowns
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the system has a unique solution
+    ///     - An `Ok` containing the `n x k` solution `X`
+    ///     - An `Err` as described by `solve::solve`
+    pub fn solve(&self) -> Result<Matrix<T>, LinalgError> {
+        solve::solve(&self.coefficients, &self.constants)
+    }
+
+    /// Solves this `System` after [equilibrating](https://en.wikipedia.org/wiki/Preconditioner)
+    /// its coefficient matrix
+    ///
+    /// Each equation is first scaled so that its largest coefficient has magnitude near
+    /// `1`, then each unknown likewise. A system whose coefficients span many orders of
+    /// magnitude, such as one mixing volts with megaohms, then has pivots chosen by their
+    /// true significance rather than by the units they happen to carry, and its solution
+    /// loses far less accuracy
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the system has a unique solution
+    ///     - An `Ok` containing the `EquilibratedSolution`, with the solution already
+    ///       unscaled
+    ///     - An `Err` of `LinalgError::Singular` if an equation or unknown has only zero
+    ///       coefficients, or one of the failures described by `solve::solve`
+    pub fn solve_equilibrated(&self) -> Result<EquilibratedSolution<T>, LinalgError> {
+        let a = &self.coefficients;
+        a.validate()?;
+        a.check_finite()?;
+        if self.constants.rows != a.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (a.rows, self.constants.cols),
+                found: (self.constants.rows, self.constants.cols),
+            });
+        }
+        let power_of_two = |largest: T| {
+            if largest > T::zero() {
+                Ok((-largest.log2().round()).exp2())
+            } else {
+                Err(LinalgError::Singular)
+            }
+        };
+
+        let row_scale = a
+            .mat
+            .iter()
+            .map(|row| power_of_two(row.iter().fold(T::zero(), |max, x| max.max(x.abs()))))
+            .collect::<Result<Vec<T>, LinalgError>>()?;
+        let col_scale = (0..a.cols)
+            .map(|j| {
+                power_of_two((0..a.rows).fold(T::zero(), |max, i| {
+                    max.max((a.mat[i][j] * row_scale[i]).abs())
+                }))
+            })
+            .collect::<Result<Vec<T>, LinalgError>>()?;
+
+        let scaled = |m: &Matrix<T>, col: &dyn Fn(usize) -> T| Matrix {
+            mat: m
+                .mat
+                .iter()
+                .zip(&row_scale)
+                .map(|(row, &r)| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(j, &x)| x * r * col(j))
+                        .collect()
+                })
+                .collect(),
+            rows: m.rows,
+            cols: m.cols,
+        };
+        let y = solve::solve(
+            &scaled(a, &|j| col_scale[j]),
+            &scaled(&self.constants, &|_| T::one()),
+        )?;
+        let solution = Matrix {
+            mat: y
+                .mat
+                .iter()
+                .zip(&col_scale)
+                .map(|(row, &c)| row.iter().map(|&x| x * c).collect())
+                .collect(),
+            rows: y.rows,
+            cols: y.cols,
+        };
+
+        Ok(EquilibratedSolution {
+            solution,
+            row_scale,
+            col_scale,
+        })
+    }
+
+    /// Bounds how far the solution of this `System` can move when its coefficients and
+    /// constants are only known to within `perturbation`
+    ///
+    /// Uses the standard first-order bound: when `κ ε_A < 1`, every solution of
+    /// `(A + δA)(x + δx) = b + δb` satisfies
+    /// `‖δx‖ / ‖x‖ ≤ κ (ε_A + ε_b) / (1 − κ ε_A)`, where `κ` is the condition number and
+    /// `ε_A`, `ε_b` are the relative bounds. Once `κ ε_A ≥ 1` the perturbed matrix may be
+    /// singular and the bound is infinite. `A⁻¹` is formed explicitly to find `κ`, which
+    /// costs about three times as much as `solve`
+    ///
+    /// ### Parameters
+    /// - `perturbation` - The relative bounds on the errors in `A` and `b`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the system has a unique solution
+    ///     - An `Ok` containing the `Sensitivity`, whose `error_bounds` are the error bars
+    ///       to report with each solution
+    ///     - An `Err` of `LinalgError::InvalidArgument` if either bound is negative or NaN,
+    ///       or one of the failures described by `solve::solve`
+    pub fn sensitivity(
+        &self,
+        perturbation: Perturbation<T>,
+    ) -> Result<Sensitivity<T>, LinalgError> {
+        let (eps_a, eps_b) = (perturbation.coefficients, perturbation.constants);
+        if [eps_a, eps_b].iter().any(|e| e.is_nan() || *e < T::zero()) {
+            return Err(LinalgError::InvalidArgument(
+                "Perturbation bounds must be non-negative numbers".to_string(),
+            ));
+        }
+
+        let solution = self.solve()?;
+        let inverse = solve::solve(&self.coefficients, &arith::identity(self.coefficients.rows))?;
+        let condition_number =
+            self.coefficients.norm(NormKind::Infinity)? * inverse.norm(NormKind::Infinity)?;
+        let amplification = condition_number * eps_a;
+        let relative_error = if amplification < T::one() {
+            condition_number * (eps_a + eps_b) / (T::one() - amplification)
+        } else {
+            T::infinity()
+        };
+        let error_bounds = solution
+            .columns()
+            .map(|x| relative_error * x.iter().fold(T::zero(), |max, v| max.max(v.abs())))
+            .collect();
+
+        Ok(Sensitivity {
+            solution,
+            condition_number,
+            relative_error,
+            error_bounds,
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use crate::adaptive::{solve_adaptive, AdaptiveSolution};
+use crate::arith;
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::solve;
+use std::future::Future;
+use std::ops::Neg;
+
+/// Runs `f` on Tokio's blocking thread pool and resolves with its result
+///
+/// The work starts as soon as this is called, not when the future is first polled, so
+/// several operations can be started and then awaited together. Must be called from
+/// within a Tokio runtime
+///
+/// ### Parameters
+/// - `f` - The computation to run
+///
+/// ### Returns
+/// - A future resolving to the result of `f`, or to `LinalgError::TaskFailed` if `f`
+///   panicked or the runtime shut down before it finished
+pub fn spawn<R, F>(f: F) -> impl Future<Output = Result<R, LinalgError>>
+where
+    F: FnOnce() -> Result<R, LinalgError> + Send + 'static,
+    R: Send + 'static,
+{
+    let handle = tokio::task::spawn_blocking(f);
+    async move {
+        handle
+            .await
+            .map_err(|e| LinalgError::TaskFailed(e.to_string()))?
+    }
+}
+
+/// Multiplies `a` by `b` on the blocking pool; see `arith::multiply`
+pub fn spawn_multiply<T>(
+    a: Matrix<T>,
+    b: Matrix<T>,
+) -> impl Future<Output = Result<Matrix<T>, LinalgError>>
+where
+    T: Number + Neg<Output = T> + Send + Sync + 'static,
+{
+    spawn(move || arith::multiply(&a, &b))
+}
+
+/// Inverts `matrix` on the blocking pool; see `solve::inverse`
+pub fn spawn_inverse<T>(matrix: Matrix<T>) -> impl Future<Output = Result<Matrix<T>, LinalgError>>
+where
+    T: Number + Neg<Output = T> + Send + Sync + 'static,
+{
+    spawn(move || solve::inverse(&matrix))
+}
+
+/// Computes the determinant of `matrix` on the blocking pool; see
+/// `solve::determinant_fast`
+///
+/// ### Returns
+/// - A future resolving to the determinant, or to `LinalgError::NonSquare` if `matrix` is
+///   not square or `LinalgError::NonFinite` if it holds a NaN or infinity
+pub fn spawn_determinant<T>(matrix: Matrix<T>) -> impl Future<Output = Result<T, LinalgError>>
+where
+    T: Number + Neg<Output = T> + num::Float + Send + Sync + 'static,
+{
+    spawn(move || solve::determinant_fast(&matrix))
+}
+
+/// Computes the LU decomposition of `matrix` on the blocking pool; see
+/// `decompose::lu_decomposition`
+pub fn spawn_lu_decomposition<T>(
+    matrix: Matrix<T>,
+) -> impl Future<Output = Result<(Matrix<T>, Matrix<T>), LinalgError>>
+where
+    T: Number + Neg<Output = T> + Send + Sync + 'static,
+{
+    spawn(move || decompose::lu_decomposition(&matrix))
+}
+
+/// Computes the QR decomposition of `matrix` on the blocking pool; see
+/// `decompose::qr_decomposition`
+pub fn spawn_qr_decomposition<T>(
+    matrix: Matrix<T>,
+) -> impl Future<Output = Result<(Matrix<T>, Matrix<T>), LinalgError>>
+where
+    T: Number + Neg<Output = T> + num::Float + Send + Sync + 'static,
+{
+    spawn(move || decompose::qr_decomposition(&matrix))
+}
+
+/// Solves `Ax = b` on the blocking pool; see `adaptive::solve_adaptive`
+pub fn spawn_solve_adaptive(
+    a: Matrix<f64>,
+    b: Matrix<f64>,
+) -> impl Future<Output = Result<AdaptiveSolution, LinalgError>> {
+    spawn(move || solve_adaptive(&a, &b))
+}
@@ -0,0 +1,323 @@
+use crate::adaptive::solve_adaptive;
+use crate::arith;
+use crate::decompose;
+use crate::matrix::{self, Matrix};
+use crate::number::Number;
+use crate::solve;
+use crate::transform;
+use std::fs;
+use std::ops::Neg;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that, when set to `1`, makes snapshot assertions overwrite
+/// their stored snapshots instead of comparing against them
+pub const UPDATE_SNAPSHOTS_ENV: &str = "LINALGRS_UPDATE_SNAPSHOTS";
+
+/// The tolerance used by `assert_matrix_snapshot!` when none is given
+pub const DEFAULT_SNAPSHOT_TOLERANCE: f64 = 1e-9;
+
+const SNAPSHOT_HEADER: &str = "# linalgrs matrix snapshot";
+
+/// Serializes a `Matrix` into the readable text format used for snapshots
+///
+/// The format is a header line, a `shape: RxC` line, and then one line per row with
+/// the elements separated by single spaces
+///
+/// ### Parameters
+/// - `matrix` - The `Matrix` to serialize
+///
+/// ### Returns
+/// - The snapshot text for `matrix`
+pub fn to_snapshot_string<T: Number>(matrix: &Matrix<T>) -> String {
+    let mut out = format!(
+        "{}\nshape: {}x{}\n",
+        SNAPSHOT_HEADER, matrix.rows, matrix.cols
+    );
+    for row in &matrix.mat {
+        let line: Vec<String> = row.iter().map(|x| format!("{}", x)).collect();
+        out.push_str(&line.join(" "));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Builds matrices from arbitrary bytes and runs every fallible operation on them
+///
+/// This is the body of the `solvers` fuzz target in `fuzz/`, exposed so it can also run in
+/// ordinary tests but hidden from the documentation, since it is not part of the supported
+/// API. It never checks results; it only requires that nothing panics. The first byte
+/// picks the row count, the second the column count (both at most 6), and the third
+/// whether to corrupt the matrix so that its fields disagree. The remaining bytes are read
+/// as `f64` values, so NaN, infinities, and subnormals all appear. Infallible
+/// operations such as `transpose` are only run once `Matrix::validate` has passed
+///
+/// ### Parameters
+/// - `bytes` - The raw fuzzer input
+#[doc(hidden)]
+pub fn exercise_operations(bytes: &[u8]) {
+    let (header, data) = bytes.split_at(bytes.len().min(3));
+    let byte = |i: usize| header.get(i).copied().unwrap_or(0);
+    let (rows, cols, corrupt) = (byte(0) as usize % 7, byte(1) as usize % 7, byte(2));
+
+    let mut values = data.chunks(8).map(|chunk| {
+        let mut raw = [0; 8];
+        raw[..chunk.len()].copy_from_slice(chunk);
+        f64::from_le_bytes(raw)
+    });
+    let mut m = Matrix {
+        mat: (0..rows)
+            .map(|_| {
+                let row: Vec<f64> = (0..cols).map(|_| values.next().unwrap_or(0.0)).collect();
+                std::sync::Arc::from(row)
+            })
+            .collect(),
+        rows,
+        cols,
+    };
+    match corrupt % 4 {
+        1 if rows > 0 => m.mat[rows - 1] = std::sync::Arc::from(vec![0.0; cols + 1]),
+        2 => m.rows += 1,
+        3 => m.cols += 1,
+        _ => {}
+    }
+    let column = Matrix {
+        mat: (0..m.rows)
+            .map(|_| std::sync::Arc::from([values.next().unwrap_or(1.0)]))
+            .collect(),
+        rows: m.rows,
+        cols: 1,
+    };
+
+    let _ = arith::add(&m, &m);
+    let _ = arith::subtract(&m, &m);
+    let _ = arith::dot(&m, &column);
+    let _ = arith::diff_report(&m, &m, 0.0);
+    let _ = solve::inverse(&m);
+    let _ = decompose::lu_decomposition(&m);
+    let _ = decompose::qr_decomposition(&m);
+    let _ = solve::determinant(&m);
+    let _ = solve::gaussian_elimination(&m);
+    let _ = solve::gauss_jordan_elimination(&m);
+    let _ = solve_adaptive(&m, &column);
+    let _ = m.trace();
+
+    if m.validate().is_ok() {
+        let t = transform::transpose(&m);
+        let _ = arith::multiply(&m, &t);
+        let _ = solve::row_echelon_form(&m);
+        let _ = solve::rref(&m);
+        let _ = arith::multiply_by_scalar(&m, f64::NAN);
+        let _ = (m.sum(), m.min(), m.max(), m.mean());
+        let _ = to_snapshot_string(&m);
+    }
+}
+
+/// Compares two matrices element by element, as `Matrix::approx_eq` does
+///
+/// ### Parameters
+/// - `left` - The `Matrix` produced by the code under test
+/// - `right` - The expected `Matrix`
+/// - `epsilon` - The largest absolute difference allowed between two elements
+///
+/// ### Returns
+/// - A `Result` based on whether the matrices are approximately equal
+///     - An `Ok` if the shapes match and every element is within `epsilon`
+///     - An `Err` with a `String` listing the shapes or every mismatched element
+pub fn check_matrix_eq<T>(left: &Matrix<T>, right: &Matrix<T>, epsilon: T) -> Result<(), String>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    if left.shape() != right.shape() {
+        return Err(format!(
+            "matrices differ in shape: left is {}x{} but right is {}x{}",
+            left.rows, left.cols, right.rows, right.cols
+        ));
+    }
+
+    let mut mismatches = Vec::new();
+    for (r, (a, b)) in left.mat.iter().zip(&right.mat).enumerate() {
+        for (c, (&x, &y)) in a.iter().zip(b.iter()).enumerate() {
+            if !matrix::within(x, y, epsilon) {
+                mismatches.push(format!("  ({}, {}): left {}, right {}", r, c, x, y));
+            }
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "matrices differ by more than {}:\n{}\n\nleft:\n{}\nright:\n{}",
+            epsilon,
+            mismatches.join("\n"),
+            left,
+            right
+        ))
+    }
+}
+
+/// Gets the path of the snapshot called `name` inside `dir`
+///
+/// ### Parameters
+/// - `dir` - The directory holding the snapshots
+/// - `name` - The snapshot name
+///
+/// ### Returns
+/// - The path `dir/name.snap`
+pub fn snapshot_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{}.snap", name))
+}
+
+/// Compares a `Matrix` against the snapshot stored at `path`
+///
+/// If the snapshot does not exist yet, or `LINALGRS_UPDATE_SNAPSHOTS=1` is set, the
+/// snapshot is (re)written from `matrix` and the check passes
+///
+/// ### Parameters
+/// - `matrix` - The `Matrix` to check
+/// - `path` - The snapshot file to compare against
+/// - `tolerance` - The largest absolute difference allowed between two elements
+///
+/// ### Returns
+/// - A `Result` based on whether `matrix` matches the snapshot
+///     - An `Ok` if the shapes match and every element is within `tolerance`
+///     - An `Err` with a `String` describing the mismatch, or an I/O or parse failure
+pub fn check_snapshot<T: Number>(
+    matrix: &Matrix<T>,
+    path: &Path,
+    tolerance: f64,
+) -> Result<(), String> {
+    let actual = to_snapshot_string(matrix);
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1");
+
+    if update || !path.exists() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        return fs::write(path, actual).map_err(|e| e.to_string());
+    }
+
+    let expected = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let (expected_shape, expected_values) = parse_snapshot(&expected)?;
+    let (_, actual_values) = parse_snapshot(&actual)?;
+
+    if expected_shape != (matrix.rows, matrix.cols) {
+        return Err(format!(
+            "snapshot {} has shape {}x{} but the matrix is {}x{}",
+            path.display(),
+            expected_shape.0,
+            expected_shape.1,
+            matrix.rows,
+            matrix.cols
+        ));
+    }
+
+    let mut mismatches = Vec::new();
+    for (i, (e, a)) in expected_values.iter().zip(&actual_values).enumerate() {
+        let equal = e == a || (e - a).abs() <= tolerance;
+        if !equal {
+            let (r, c) = (i / matrix.cols, i % matrix.cols);
+            mismatches.push(format!("  ({}, {}): expected {}, got {}", r, c, e, a));
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "matrix does not match snapshot {} (tolerance {}):\n{}\n\nactual:\n{}",
+            path.display(),
+            tolerance,
+            mismatches.join("\n"),
+            actual
+        ))
+    }
+}
+
+fn parse_snapshot(text: &str) -> Result<((usize, usize), Vec<f64>), String> {
+    let mut lines = text.lines().filter(|line| !line.starts_with('#'));
+    let shape = lines
+        .next()
+        .and_then(|line| line.strip_prefix("shape: "))
+        .and_then(|shape| shape.split_once('x'))
+        .and_then(|(r, c)| Some((r.trim().parse().ok()?, c.trim().parse().ok()?)))
+        .ok_or_else(|| "snapshot is missing its `shape: RxC` line".to_string())?;
+
+    let mut values = Vec::new();
+    for (r, line) in lines.enumerate() {
+        let row: Vec<f64> = line
+            .split_whitespace()
+            .map(|token| {
+                token
+                    .parse::<f64>()
+                    .map_err(|_| format!("invalid number `{}` in snapshot row {}", token, r))
+            })
+            .collect::<Result<_, _>>()?;
+        if row.len() != shape.1 {
+            return Err(format!(
+                "snapshot row {} has {} values but the shape has {} columns",
+                r,
+                row.len(),
+                shape.1
+            ));
+        }
+        values.extend(row);
+    }
+
+    if values.len() != shape.0 * shape.1 {
+        return Err(format!(
+            "snapshot has {} rows but the shape has {} rows",
+            values.len() / shape.1.max(1),
+            shape.0
+        ));
+    }
+
+    Ok((shape, values))
+}
+
+/// Asserts that a `Matrix` matches a golden-file snapshot
+///
+/// Snapshots are stored as readable text in `tests/snapshots/<name>.snap` under the calling
+/// crate's manifest directory. A missing snapshot is written on first run; set
+/// `LINALGRS_UPDATE_SNAPSHOTS=1` to accept new output for existing snapshots
+///
+/// ### Parameters
+/// - `$matrix`: The `Matrix` to check
+/// - `$name`: The snapshot name
+/// - `$tol` (optional): The largest absolute difference allowed between two elements,
+///   defaulting to `DEFAULT_SNAPSHOT_TOLERANCE`
+#[macro_export]
+macro_rules! assert_matrix_snapshot {
+    ($matrix:expr, $name:expr $(,)?) => {
+        $crate::assert_matrix_snapshot!($matrix, $name, $crate::testing::DEFAULT_SNAPSHOT_TOLERANCE)
+    };
+    ($matrix:expr, $name:expr, $tol:expr $(,)?) => {{
+        let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("snapshots");
+        let path = $crate::testing::snapshot_path(&dir, $name);
+        if let Err(message) = $crate::testing::check_snapshot(&$matrix, &path, $tol) {
+            panic!("{}", message);
+        }
+    }};
+}
+
+/// Asserts that two matrices have the same shape and are equal element by element to
+/// within a tolerance
+///
+/// On failure the panic message lists every element that differs by more than the
+/// tolerance, followed by both matrices
+///
+/// ### Parameters
+/// - `$left`: The `Matrix` produced by the code under test
+/// - `$right`: The expected `Matrix`
+/// - `$epsilon`: The largest absolute difference allowed between two elements
+#[macro_export]
+macro_rules! assert_matrix_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        if let Err(message) = $crate::testing::check_matrix_eq(&$left, &$right, $epsilon) {
+            panic!("{}", message);
+        }
+    };
+}
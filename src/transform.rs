@@ -0,0 +1,410 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::metrics::Span;
+use crate::number::Number;
+use crate::solve;
+use std::ops::Neg;
+use std::sync::Arc;
+
+/// The degree of the Padé approximant `matrix_exponential` applies after scaling
+const PADE_DEGREE: usize = 6;
+
+/// Appends a `row` to a given `Matrix`, returning an updated `Matrix` instance with the newly
+/// appended row
+///
+/// ### Parameters
+/// - `matrix` - The `Matrix` needed to add a `row`
+/// - `row`: An `&[i64]` slice
+///
+/// ### Returns
+/// - An updated `Matrix` object that adds the given `row`
+///   to the given `Matrix`
+pub fn append<T>(mut matrix: Matrix<T>, row: &[T]) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    matrix.mat.push(Arc::from(row));
+    matrix.rows = matrix.mat.len();
+    matrix.cols = row.len();
+
+    matrix
+}
+
+/// Appends multiple `rows` to a given `Matrix`, returning an updated `Matrix` instance
+/// with the newly appended rows
+///
+/// ### Parameters
+/// - `matrix`: The `Matrix` needed to add additional `rows`
+/// - `rows`: A slice of `&[i64]` slices representing a series of rows to add to a given `Matrix`
+///
+/// ### Returns
+/// - An updated `Matrix` object that adds all `rows` to this
+///   `Matrix`
+pub fn append_multiple<T>(mut matrix: Matrix<T>, rows: &[&[T]]) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    for &row in rows {
+        matrix.mat.push(Arc::from(row));
+    }
+    matrix.rows = matrix.mat.len();
+    if !rows.is_empty() {
+        matrix.cols = rows[0].len();
+    }
+
+    matrix
+}
+
+/// Computes the transpose of this `Matrix`
+///
+/// The transpose of a `Matrix` is the resulting matrix where the columns are
+/// formed from the corresponding rows of the original matrix
+///
+/// ### Parameters
+/// - `x` - An existing `Matrix` to transpose
+///
+/// ### Returns
+/// - A `Matrix` instance containing the transposed matrix
+#[allow(clippy::needless_range_loop)]
+pub fn transpose<T>(x: &Matrix<T>) -> Matrix<T>
+where
+    T: Number + Neg<Output = T>,
+{
+    let _span = Span::new("transpose", 0, x.cols + 1);
+    let mut transposed_mat: Vec<Vec<T>> = vec![vec![T::default(); x.rows]; x.cols];
+
+    for i in 0..x.rows {
+        for j in 0..x.cols {
+            transposed_mat[j][i] = x.mat[i][j];
+        }
+    }
+
+    let transposed_mat: Vec<Arc<[T]>> = transposed_mat
+        .into_iter()
+        .map(|row| Arc::from(row.into_boxed_slice()))
+        .collect();
+
+    Matrix {
+        mat: transposed_mat,
+        rows: x.cols,
+        cols: x.rows,
+    }
+}
+
+/// Computes the [similarity transform](https://en.wikipedia.org/wiki/Matrix_similarity)
+/// `P⁻¹AP` from an already known inverse of `P`
+///
+/// Use this when `P⁻¹` is available, for instance when `P` is orthogonal and `P⁻¹` is
+/// its transpose; `similarity_transform` computes it otherwise
+///
+/// ### Parameters
+/// - `p` - The n x n change-of-basis `Matrix` `P`
+/// - `p_inv` - The inverse of `p`, which is trusted rather than checked
+/// - `a` - The n x n `Matrix` `A` to transform
+///
+/// ### Returns
+/// - A `Result` containing `P⁻¹AP`, or an `Err` of `LinalgError::NonSquare` if `a` is
+///   not square or `LinalgError::ShapeMismatch` if `p` or `p_inv` does not have the
+///   shape of `a`
+pub fn similarity_transform_with_inverse<T>(
+    p: &Matrix<T>,
+    p_inv: &Matrix<T>,
+    a: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    for m in [p, p_inv] {
+        if (m.rows, m.cols) != (n, n) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n, n),
+                found: (m.rows, m.cols),
+            });
+        }
+    }
+
+    arith::multiply(p_inv, &arith::multiply(a, p)?)
+}
+
+/// Joins matrices side by side, left to right
+///
+/// ### Parameters
+/// - `matrices` - The matrices to join, which must all have the same number of rows
+///
+/// ### Returns
+/// - A `Result` based on whether the matrices could be joined
+///     - An `Ok` containing a `Matrix` whose columns are those of every input in turn
+///     - An `Err` of `LinalgError::Empty` if `matrices` is empty, or
+///       `LinalgError::ShapeMismatch` naming the first input whose row count differs
+///       from the first
+pub fn hstack<T>(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let rows = check_stack(matrices, |m| m.rows, |m, rows| (rows, m.cols))?;
+    let cols = matrices.iter().map(|m| m.cols).sum();
+
+    let _span = Span::new("hstack", 0, rows + 1);
+    let mat = (0..rows)
+        .map(|i| {
+            let mut row = Vec::with_capacity(cols);
+            for m in matrices {
+                row.extend_from_slice(&m.mat[i]);
+            }
+            Arc::from(row)
+        })
+        .collect();
+
+    Ok(Matrix { mat, rows, cols })
+}
+
+/// Joins matrices one above another, top to bottom
+///
+/// The rows of the result share their storage with the inputs and are copied only when
+/// one side is written to
+///
+/// ### Parameters
+/// - `matrices` - The matrices to join, which must all have the same number of columns
+///
+/// ### Returns
+/// - A `Result` based on whether the matrices could be joined
+///     - An `Ok` containing a `Matrix` whose rows are those of every input in turn
+///     - An `Err` of `LinalgError::Empty` if `matrices` is empty, or
+///       `LinalgError::ShapeMismatch` naming the first input whose column count differs
+///       from the first
+pub fn vstack<T>(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let cols = check_stack(matrices, |m| m.cols, |m, cols| (m.rows, cols))?;
+
+    Ok(Matrix {
+        mat: matrices
+            .iter()
+            .flat_map(|m| m.mat.iter().cloned())
+            .collect(),
+        rows: matrices.iter().map(|m| m.rows).sum(),
+        cols,
+    })
+}
+
+/// Composes a `Matrix` from a grid of blocks, such as the augmented system `[A b]` or
+/// the saddle point matrix `[[A, Bᵀ], [B, 0]]`
+///
+/// ### Parameters
+/// - `blocks` - The block rows, top to bottom, each listing its blocks left to right.
+///   Blocks in the same block row must have the same number of rows, and every block
+///   row must add up to the same number of columns
+///
+/// ### Returns
+/// - A `Result` based on whether the blocks fit together
+///     - An `Ok` containing the composed `Matrix`
+///     - An `Err` of `LinalgError::Empty` if `blocks` or one of its block rows is
+///       empty, or `LinalgError::ShapeMismatch` if the blocks do not line up
+pub fn block<T>(blocks: &[&[&Matrix<T>]]) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let block_rows = blocks
+        .iter()
+        .map(|row| hstack(row))
+        .collect::<Result<Vec<Matrix<T>>, LinalgError>>()?;
+
+    vstack(&block_rows.iter().collect::<Vec<&Matrix<T>>>())
+}
+
+/// Validates the inputs of `hstack` or `vstack`
+///
+/// ### Returns
+/// - A `Result` containing the extent `axis` shared by every matrix, or an `Err` whose
+///   expected shape is built by `expected` from the offending matrix and that extent
+fn check_stack<T>(
+    matrices: &[&Matrix<T>],
+    axis: impl Fn(&Matrix<T>) -> usize,
+    expected: impl Fn(&Matrix<T>, usize) -> (usize, usize),
+) -> Result<usize, LinalgError>
+where
+    T: Number + Neg<Output = T>,
+{
+    let first = matrices.first().ok_or(LinalgError::Empty)?;
+    let extent = axis(first);
+    for m in matrices {
+        m.validate()?;
+        if axis(m) != extent {
+            return Err(LinalgError::ShapeMismatch {
+                expected: expected(m, extent),
+                found: (m.rows, m.cols),
+            });
+        }
+    }
+
+    Ok(extent)
+}
+
+/// Computes the [similarity transform](https://en.wikipedia.org/wiki/Matrix_similarity)
+/// `P⁻¹AP`, the matrix of the map `A` in the basis formed by the columns of `P`
+///
+/// `P⁻¹` is never formed; `P⁻¹(AP)` is found by solving `PX = AP` with `solve`, which
+/// pivots, so a permutation `P` works too
+///
+/// ### Parameters
+/// - `p` - The invertible n x n change-of-basis `Matrix` `P`
+/// - `a` - The n x n `Matrix` `A` to transform
+///
+/// ### Returns
+/// - A `Result` based on whether `p` is invertible
+///     - An `Ok` containing `P⁻¹AP`
+///     - An `Err` of `LinalgError::NonSquare` if `a` is not square,
+///       `LinalgError::ShapeMismatch` if `p` does not have the shape of `a`,
+///       `LinalgError::Singular` if `p` is singular, or `LinalgError::NonFinite` if
+///       either input holds a NaN or infinity
+pub fn similarity_transform<T>(p: &Matrix<T>, a: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    a.validate()?;
+    let n = a.rows;
+    if n != a.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: a.cols,
+        });
+    }
+    if (p.rows, p.cols) != (n, n) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (n, n),
+            found: (p.rows, p.cols),
+        });
+    }
+
+    solve::solve(p, &arith::multiply(a, p)?)
+}
+
+/// Computes the [change-of-basis matrix](https://en.wikipedia.org/wiki/Change_of_basis)
+/// that converts coordinates relative to `from_basis` into coordinates relative to
+/// `to_basis`
+///
+/// With the basis vectors stored as columns `B` and `C`, a vector with coordinates `x`
+/// in `B` is `Bx = Cy`, so the result is `C⁻¹B`
+///
+/// ### Parameters
+/// - `from_basis` - An n x n `Matrix` whose columns are the source basis vectors
+/// - `to_basis` - An n x n `Matrix` whose columns are the target basis vectors
+///
+/// ### Returns
+/// - A `Result` based on whether both arguments are bases
+///     - An `Ok` containing the n x n matrix `C⁻¹B`
+///     - An `Err` of `LinalgError::NonSquare` if `from_basis` is not square,
+///       `LinalgError::ShapeMismatch` if `to_basis` does not have its shape,
+///       `LinalgError::Singular` if either set of columns is linearly dependent, or
+///       `LinalgError::NonFinite` if either input holds a NaN or infinity
+pub fn change_of_basis<T>(
+    from_basis: &Matrix<T>,
+    to_basis: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    from_basis.validate()?;
+    let n = from_basis.rows;
+    if n != from_basis.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: from_basis.cols,
+        });
+    }
+    if (to_basis.rows, to_basis.cols) != (n, n) {
+        return Err(LinalgError::ShapeMismatch {
+            expected: (n, n),
+            found: (to_basis.rows, to_basis.cols),
+        });
+    }
+    if solve::determinant_fast(from_basis)? == T::zero() {
+        return Err(LinalgError::Singular);
+    }
+
+    solve::solve(to_basis, from_basis)
+}
+
+/// Computes the [matrix exponential](https://en.wikipedia.org/wiki/Matrix_exponential)
+/// `e^A`, which solves the linear ODE `x' = Ax` as `x(t) = e^(At) x(0)` and turns a
+/// Markov generator into transition probabilities
+///
+/// Uses scaling and squaring: `A` is divided by a power of two `2^s` until its
+/// infinity norm is at most `1/2`, the exponential of the scaled matrix is approximated
+/// by the diagonal Padé approximant of degree 6, and the result is squared `s` times.
+/// The approximant is accurate to about double precision on the scaled matrix
+///
+/// ### Parameters
+/// - `matrix` - The square `Matrix` `A`
+///
+/// ### Returns
+/// - A `Result` containing `e^A`, or an `Err` of `LinalgError::NonSquare` if `matrix`
+///   is not square or `LinalgError::NonFinite` if it holds a NaN or infinity
+pub fn matrix_exponential<T>(matrix: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+where
+    T: Number + Neg<Output = T> + num::Float,
+{
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let n = matrix.rows;
+    if n != matrix.cols {
+        return Err(LinalgError::NonSquare {
+            rows: n,
+            cols: matrix.cols,
+        });
+    }
+
+    let norm = matrix
+        .mat
+        .iter()
+        .map(|row| row.iter().fold(T::zero(), |sum, &x| sum + x.abs()))
+        .fold(T::zero(), T::max);
+    let squarings = if norm > T::zero() {
+        norm.log2().floor().to_i32().map_or(0, |e| (e + 2).max(0))
+    } else {
+        0
+    };
+    let _span = Span::new(
+        "matrix_exponential",
+        2 * n * n * n * (PADE_DEGREE + squarings as usize),
+        PADE_DEGREE + squarings as usize,
+    );
+    let constant = |x: f64| T::from(x).unwrap_or_else(T::nan);
+    let a = arith::multiply_by_scalar(matrix, constant(0.5f64.powi(squarings)));
+
+    // Accumulate the numerator N(A) = Σ c_k A^k and the denominator N(−A) together
+    let identity = arith::identity(n);
+    let mut coefficient = 0.5;
+    let half_a = arith::multiply_by_scalar(&a, constant(coefficient));
+    let mut numerator = arith::add(&identity, &half_a)?;
+    let mut denominator = arith::subtract(&identity, &half_a)?;
+    let mut power = a.clone();
+    for k in 2..=PADE_DEGREE {
+        coefficient *= (PADE_DEGREE - k + 1) as f64 / (k * (2 * PADE_DEGREE - k + 1)) as f64;
+        power = arith::multiply(&a, &power)?;
+        let term = arith::multiply_by_scalar(&power, constant(coefficient));
+        numerator = arith::add(&numerator, &term)?;
+        denominator = if k % 2 == 0 {
+            arith::add(&denominator, &term)?
+        } else {
+            arith::subtract(&denominator, &term)?
+        };
+    }
+
+    let mut result = solve::solve(&denominator, &numerator)?;
+    for _ in 0..squarings {
+        result = arith::multiply(&result, &result)?;
+    }
+
+    Ok(result)
+}
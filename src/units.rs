@@ -0,0 +1,280 @@
+use crate::arith;
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use crate::transform;
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Div, Mul, Neg};
+
+const SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// A physical unit, stored as the exponent of each of the seven SI base units
+///
+/// Units multiply and divide by adding and subtracting exponents, so `meter() / second()`
+/// is a velocity and `meter() * meter()` is an area. A `Unit` with every exponent zero is
+/// dimensionless
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Unit {
+    /// Exponents of meter, kilogram, second, ampere, kelvin, mole and candela, in that order
+    pub exponents: [i8; 7],
+}
+
+impl Unit {
+    /// Creates a dimensionless `Unit`
+    pub fn dimensionless() -> Unit {
+        Unit::default()
+    }
+
+    fn base(index: usize) -> Unit {
+        let mut exponents = [0; 7];
+        exponents[index] = 1;
+        Unit { exponents }
+    }
+
+    /// Creates the SI base unit of length, the meter (`m`)
+    pub fn meter() -> Unit {
+        Unit::base(0)
+    }
+
+    /// Creates the SI base unit of mass, the kilogram (`kg`)
+    pub fn kilogram() -> Unit {
+        Unit::base(1)
+    }
+
+    /// Creates the SI base unit of time, the second (`s`)
+    pub fn second() -> Unit {
+        Unit::base(2)
+    }
+
+    /// Creates the SI base unit of electric current, the ampere (`A`)
+    pub fn ampere() -> Unit {
+        Unit::base(3)
+    }
+
+    /// Creates the SI base unit of temperature, the kelvin (`K`)
+    pub fn kelvin() -> Unit {
+        Unit::base(4)
+    }
+
+    /// Creates the SI base unit of amount of substance, the mole (`mol`)
+    pub fn mole() -> Unit {
+        Unit::base(5)
+    }
+
+    /// Creates the SI base unit of luminous intensity, the candela (`cd`)
+    pub fn candela() -> Unit {
+        Unit::base(6)
+    }
+
+    /// Raises this `Unit` to an integer power
+    ///
+    /// ### Parameters
+    /// - `n` - The exponent
+    ///
+    /// ### Returns
+    /// - A `Unit` with every base exponent multiplied by `n`
+    pub fn powi(self, n: i8) -> Unit {
+        let mut exponents = self.exponents;
+        for e in exponents.iter_mut() {
+            *e *= n;
+        }
+        Unit { exponents }
+    }
+
+    /// Checks whether this `Unit` has no dimensions
+    pub fn is_dimensionless(&self) -> bool {
+        self.exponents.iter().all(|&e| e == 0)
+    }
+}
+
+impl Mul for Unit {
+    type Output = Unit;
+
+    /// Multiplies two units by adding their base exponents
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[allow(clippy::needless_range_loop)]
+    fn mul(self, rhs: Unit) -> Unit {
+        let mut exponents = self.exponents;
+        for i in 0..exponents.len() {
+            exponents[i] += rhs.exponents[i];
+        }
+        Unit { exponents }
+    }
+}
+
+impl Div for Unit {
+    type Output = Unit;
+
+    fn div(self, rhs: Unit) -> Unit {
+        self * rhs.powi(-1)
+    }
+}
+
+impl Display for Unit {
+    /// Writes a `Unit` such as `kg m s^-2`, or `1` when dimensionless
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_dimensionless() {
+            return write!(f, "1");
+        }
+
+        let parts: Vec<String> = self
+            .exponents
+            .iter()
+            .zip(SYMBOLS)
+            .filter(|(&e, _)| e != 0)
+            .map(|(&e, symbol)| match e {
+                1 => symbol.to_string(),
+                _ => format!("{}^{}", symbol, e),
+            })
+            .collect();
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// An error produced by arithmetic on `UnitMatrix` values
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnitError {
+    /// The operands of an addition or subtraction have different units
+    UnitMismatch { left: Unit, right: Unit },
+    /// The underlying `Matrix` operation failed, e.g. because the shapes disagree
+    Linalg(LinalgError),
+}
+
+impl Display for UnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnitError::UnitMismatch { left, right } => write!(
+                f,
+                "Cannot combine matrices with units `{}` and `{}`",
+                left, right
+            ),
+            UnitError::Linalg(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for UnitError {}
+
+impl From<LinalgError> for UnitError {
+    fn from(error: LinalgError) -> UnitError {
+        UnitError::Linalg(error)
+    }
+}
+
+/// A `Matrix` whose every element carries the same physical `Unit`
+///
+/// Arithmetic on a `UnitMatrix` checks units at runtime: adding or subtracting matrices in
+/// different units is an error, while multiplying them produces the derived unit
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitMatrix<T: Number> {
+    /// The numeric values
+    pub matrix: Matrix<T>,
+    /// The unit shared by every element of `matrix`
+    pub unit: Unit,
+}
+
+impl<T: Number + Neg<Output = T>> UnitMatrix<T> {
+    /// Tags `matrix` with `unit`
+    ///
+    /// ### Parameters
+    /// - `matrix` - The numeric values
+    /// - `unit` - The unit of every element
+    ///
+    /// ### Returns
+    /// - A new `UnitMatrix`
+    pub fn new(matrix: Matrix<T>, unit: Unit) -> UnitMatrix<T> {
+        UnitMatrix { matrix, unit }
+    }
+
+    fn check_same_unit(&self, other: &UnitMatrix<T>) -> Result<(), UnitError> {
+        if self.unit != other.unit {
+            return Err(UnitError::UnitMismatch {
+                left: self.unit,
+                right: other.unit,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Adds two `UnitMatrix` instances with the same unit
+    ///
+    /// ### Parameters
+    /// - `other` - The `UnitMatrix` to add
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the units and dimensions agree
+    ///     - An `Ok` containing the sum, in the shared unit
+    ///     - An `Err` of `UnitError::UnitMismatch` if the units differ, or
+    ///       `UnitError::Linalg` if the matrices cannot be added
+    pub fn add(&self, other: &UnitMatrix<T>) -> Result<UnitMatrix<T>, UnitError> {
+        self.check_same_unit(other)?;
+        let matrix = arith::add(&self.matrix, &other.matrix)?;
+
+        Ok(UnitMatrix::new(matrix, self.unit))
+    }
+
+    /// Subtracts `other` from this `UnitMatrix`, which must have the same unit
+    ///
+    /// ### Parameters
+    /// - `other` - The `UnitMatrix` to subtract
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the units and dimensions agree
+    ///     - An `Ok` containing the difference, in the shared unit
+    ///     - An `Err` of `UnitError::UnitMismatch` if the units differ, or
+    ///       `UnitError::Linalg` if the matrices cannot be subtracted
+    pub fn subtract(&self, other: &UnitMatrix<T>) -> Result<UnitMatrix<T>, UnitError> {
+        self.check_same_unit(other)?;
+        let matrix = arith::subtract(&self.matrix, &other.matrix)?;
+
+        Ok(UnitMatrix::new(matrix, self.unit))
+    }
+
+    /// Multiplies this `UnitMatrix` by `other`, deriving the unit of the product
+    ///
+    /// ### Parameters
+    /// - `other` - The right-hand `UnitMatrix`
+    ///
+    /// ### Returns
+    /// - A `Result` containing the product in unit `self.unit * other.unit`, or an `Err`
+    ///   of `UnitError::Linalg` if the dimensions are incompatible
+    pub fn multiply(&self, other: &UnitMatrix<T>) -> Result<UnitMatrix<T>, UnitError> {
+        let matrix = arith::multiply(&self.matrix, &other.matrix)?;
+
+        Ok(UnitMatrix::new(matrix, self.unit * other.unit))
+    }
+
+    /// Multiplies every element by a scalar quantity
+    ///
+    /// ### Parameters
+    /// - `constant` - The scalar value
+    /// - `unit` - The unit of `constant`, or `Unit::dimensionless()` for a plain number
+    ///
+    /// ### Returns
+    /// - The scaled `UnitMatrix` in unit `self.unit * unit`
+    pub fn multiply_by_scalar(&self, constant: T, unit: Unit) -> UnitMatrix<T> {
+        let matrix = arith::multiply_by_scalar(&self.matrix, constant);
+
+        UnitMatrix::new(matrix, self.unit * unit)
+    }
+
+    /// Computes the transpose, keeping the unit
+    pub fn transpose(&self) -> UnitMatrix<T> {
+        UnitMatrix::new(transform::transpose(&self.matrix), self.unit)
+    }
+}
+
+impl<T: Number> Display for UnitMatrix<T> {
+    /// Writes the matrix followed by its unit in square brackets
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.matrix, self.unit)
+    }
+}
@@ -0,0 +1,399 @@
+extern crate num;
+
+use crate::error::LinalgError;
+use crate::matrix::Matrix;
+use crate::number::Number;
+use std::fmt::Display;
+use std::sync::Arc;
+
+/// A struct representing a `Vector` in linear algebra: an ordered list of numbers
+///
+/// Vectors can be treated as 1 x n row matrices or n x 1 column matrices, but working with
+/// them directly avoids having to track which orientation a piece of 1-D data is stored in.
+/// Use `Vector::to_row_matrix` / `Vector::to_column_matrix` to move into `Matrix` operations
+///
+/// With the `serde` feature a `Vector` serializes as a plain array of its elements
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Vector<T: Number> {
+    /// The elements of the `Vector`, in order
+    pub elements: Vec<T>,
+}
+
+/// A macro to create a `Vector` from a list of elements
+///
+/// ### Parameters
+/// - `$($elem:expr),*`: The elements of the vector
+///
+/// ### Returns
+/// - A `Vector` instance containing the specified elements
+#[macro_export]
+macro_rules! vector {
+    ($($elem:expr),* $(,)?) => {
+        $crate::vector::Vector::new(vec![$($elem),*])
+    };
+}
+
+impl<T: Number> Vector<T> {
+    /// Creates a new `Vector` from its elements
+    ///
+    /// ### Parameters
+    /// - `elements` - The elements of the vector
+    ///
+    /// ### Returns
+    /// - A `Vector` holding `elements`
+    pub fn new(elements: Vec<T>) -> Vector<T> {
+        Vector { elements }
+    }
+
+    /// Creates a `Vector` of `n` zeroes
+    ///
+    /// ### Parameters
+    /// - `n` - The length of the vector
+    ///
+    /// ### Returns
+    /// - A `Vector` of length `n` filled with zeroes
+    pub fn zeros(n: usize) -> Vector<T> {
+        Vector {
+            elements: vec![T::default(); n],
+        }
+    }
+
+    /// Gets the number of elements in this `Vector`
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Checks whether this `Vector` has no elements
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Computes the dot product of this `Vector` and `other`
+    ///
+    /// ### Parameters
+    /// - `other` - The other `Vector` operand
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the vectors have the same length
+    ///     - An `Ok` containing the sum of the element-wise products
+    ///     - An `Err` if the two vectors have different lengths
+    pub fn dot(&self, other: &Vector<T>) -> Result<T, LinalgError> {
+        self.check_len(other)?;
+
+        let mut sum = T::default();
+        for i in 0..self.len() {
+            sum += self.elements[i] * other.elements[i];
+        }
+
+        Ok(sum)
+    }
+
+    /// Computes the cross product of this 3-D `Vector` and `other`
+    ///
+    /// ### Parameters
+    /// - `other` - The other 3-D `Vector` operand
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether both vectors are 3-D
+    ///     - An `Ok` containing the `Vector` perpendicular to both operands
+    ///     - An `Err` if either vector does not have exactly 3 elements
+    pub fn cross(&self, other: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        if let Some(v) = [self, other].into_iter().find(|v| v.len() != 3) {
+            return Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: v.len(),
+            });
+        }
+
+        let (a, b) = (&self.elements, &other.elements);
+        Ok(Vector::new(vec![
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]))
+    }
+
+    /// Adds this `Vector` and `other` element by element
+    ///
+    /// ### Parameters
+    /// - `other` - The `Vector` to add
+    ///
+    /// ### Returns
+    /// - A `Result` containing the sum, or an `Err` if the lengths differ
+    pub fn add(&self, other: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        self.check_len(other)?;
+
+        Ok(self
+            .elements
+            .iter()
+            .zip(&other.elements)
+            .map(|(&x, &y)| x + y)
+            .collect())
+    }
+
+    /// Subtracts `other` from this `Vector` element by element
+    ///
+    /// ### Parameters
+    /// - `other` - The `Vector` to subtract
+    ///
+    /// ### Returns
+    /// - A `Result` containing the difference, or an `Err` if the lengths differ
+    pub fn subtract(&self, other: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        self.check_len(other)?;
+
+        Ok(self
+            .elements
+            .iter()
+            .zip(&other.elements)
+            .map(|(&x, &y)| x - y)
+            .collect())
+    }
+
+    /// Multiplies every element of this `Vector` by a scalar `constant`
+    ///
+    /// ### Parameters
+    /// - `constant` - The scalar to multiply by
+    ///
+    /// ### Returns
+    /// - A new, scaled `Vector`
+    pub fn multiply_by_scalar(&self, constant: T) -> Vector<T> {
+        self.elements.iter().map(|&x| x * constant).collect()
+    }
+
+    /// Computes the squared Euclidean norm `v · v` of this `Vector`
+    ///
+    /// Unlike `norm`, this is exact for integer vectors
+    ///
+    /// ### Returns
+    /// - The sum of the squares of the elements
+    pub fn norm_squared(&self) -> T {
+        self.elements
+            .iter()
+            .fold(T::default(), |sum, &x| sum + x * x)
+    }
+
+    /// Converts this `Vector` into a 1 x n row `Matrix`
+    pub fn to_row_matrix(&self) -> Matrix<T> {
+        Matrix {
+            mat: vec![Arc::from(self.elements.as_slice())],
+            rows: 1,
+            cols: self.len(),
+        }
+    }
+
+    /// Converts this `Vector` into an n x 1 column `Matrix`
+    pub fn to_column_matrix(&self) -> Matrix<T> {
+        Matrix {
+            mat: self.elements.iter().map(|&x| Arc::from([x])).collect(),
+            rows: self.len(),
+            cols: 1,
+        }
+    }
+
+    /// Creates a `Vector` from a row (1 x n) or column (n x 1) `Matrix`
+    ///
+    /// ### Parameters
+    /// - `matrix` - The row or column `Matrix` to convert
+    ///
+    /// ### Returns
+    /// - A `Result` containing the elements of `matrix` in order, or an `Err` if `matrix`
+    ///   has more than one row and more than one column
+    pub fn from_matrix(matrix: &Matrix<T>) -> Result<Vector<T>, LinalgError> {
+        if matrix.rows == 1 {
+            Ok(Vector::new(matrix.mat[0].to_vec()))
+        } else if matrix.cols == 1 || matrix.rows == 0 {
+            Ok(matrix.mat.iter().map(|row| row[0]).collect())
+        } else {
+            Err(LinalgError::InvalidArgument(
+                "Only a row or column matrix can be converted into a vector!".to_string(),
+            ))
+        }
+    }
+
+    fn check_len(&self, other: &Vector<T>) -> Result<(), LinalgError> {
+        if self.len() != other.len() {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.len(),
+                found: other.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Number + num::Float> Vector<T> {
+    /// Computes the Euclidean (L2) norm, or length, of this `Vector`
+    ///
+    /// ### Returns
+    /// - The square root of the sum of the squares of the elements
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Scales this `Vector` to unit length
+    ///
+    /// ### Returns
+    /// - A `Result` containing the unit `Vector` pointing in the same direction, or an
+    ///   `Err` if this is the zero vector
+    pub fn normalize(&self) -> Result<Vector<T>, LinalgError> {
+        let norm = self.norm();
+        if norm == T::zero() {
+            return Err(LinalgError::InvalidArgument(
+                "Cannot normalize the zero vector!".to_string(),
+            ));
+        }
+
+        Ok(self.multiply_by_scalar(T::one() / norm))
+    }
+
+    /// Computes the cosine similarity of this `Vector` and `other`; see `cosine_similarity`
+    pub fn cosine_similarity(&self, other: &Vector<T>) -> Result<T, LinalgError> {
+        cosine_similarity(self, other)
+    }
+
+    /// Computes the angle between this `Vector` and `other`
+    ///
+    /// ### Parameters
+    /// - `other` - The other `Vector`
+    ///
+    /// ### Returns
+    /// - A `Result` based on whether the angle is defined
+    ///     - An `Ok` containing the angle in radians, between `0` and `pi`
+    ///     - An `Err` if the lengths differ, either vector is the zero vector, or either
+    ///       holds a NaN or infinity
+    pub fn angle(&self, other: &Vector<T>) -> Result<T, LinalgError> {
+        Ok(cosine_similarity(self, other)?.acos())
+    }
+}
+
+/// Computes the cosine similarity of `a` and `b`: the cosine of the angle between them
+///
+/// ### Parameters
+/// - `a` - One `Vector` operand
+/// - `b` - Another `Vector` operand
+///
+/// ### Returns
+/// - A `Result` based on whether the similarity is defined
+///     - An `Ok` containing `a · b / (|a| |b|)`, clamped to `[-1, 1]` to absorb rounding
+///     - An `Err` if the lengths differ or either vector is the zero vector, or
+///       `LinalgError::NonFinite` locating the first NaN or infinity, with `row` `0` for
+///       `a` and `1` for `b`
+pub fn cosine_similarity<T: Number + num::Float>(
+    a: &Vector<T>,
+    b: &Vector<T>,
+) -> Result<T, LinalgError> {
+    a.check_len(b)?;
+    for (row, v) in [a, b].into_iter().enumerate() {
+        if let Some(col) = v.elements.iter().position(|x| !x.is_finite()) {
+            return Err(LinalgError::NonFinite { row, col });
+        }
+    }
+
+    cosine(a.elements.iter().copied().zip(b.elements.iter().copied())).ok_or_else(|| {
+        LinalgError::InvalidArgument(
+            "Cosine similarity is undefined for the zero vector!".to_string(),
+        )
+    })
+}
+
+/// Computes `x · y / (|x| |y|)` over the finite pairs `(x, y)`, clamped to `[-1, 1]`
+///
+/// Each side is first divided by its largest magnitude, so the sums of squares can
+/// neither overflow nor underflow however large or small the elements are
+///
+/// ### Returns
+/// - The cosine similarity, or `None` if either side is all zeros
+pub(crate) fn cosine<T, I>(pairs: I) -> Option<T>
+where
+    T: Number + num::Float,
+    I: Iterator<Item = (T, T)> + Clone,
+{
+    let (scale_x, scale_y) = pairs
+        .clone()
+        .fold((T::zero(), T::zero()), |(sx, sy), (x, y)| {
+            (sx.max(x.abs()), sy.max(y.abs()))
+        });
+    if scale_x == T::zero() || scale_y == T::zero() {
+        return None;
+    }
+
+    let (mut dot, mut norm_x, mut norm_y) = (T::zero(), T::zero(), T::zero());
+    for (x, y) in pairs {
+        let (x, y) = (x / scale_x, y / scale_y);
+        dot += x * y;
+        norm_x += x * x;
+        norm_y += y * y;
+    }
+
+    let similarity = dot / (norm_x.sqrt() * norm_y.sqrt());
+    Some(similarity.max(-T::one()).min(T::one()))
+}
+
+/// Computes the cosine similarity of every pair of rows of `matrix`
+///
+/// ### Parameters
+/// - `matrix` - The `Matrix` whose rows are compared, e.g. one embedding per row
+///
+/// ### Returns
+/// - A `Result` based on whether every similarity is defined
+///     - An `Ok` containing a symmetric `rows x rows` `Matrix` whose `(i, j)` element is
+///       the cosine similarity of rows `i` and `j`
+///     - An `Err` if `matrix` is malformed or any row is all zeros, or
+///       `LinalgError::NonFinite` if it holds a NaN or infinity
+pub fn cosine_similarity_matrix<T: Number + num::Float>(
+    matrix: &Matrix<T>,
+) -> Result<Matrix<T>, LinalgError> {
+    matrix.validate()?;
+    matrix.check_finite()?;
+    let rows: Vec<Vector<T>> = matrix.mat.iter().map(|row| row.to_vec().into()).collect();
+
+    let mut result = vec![vec![T::one(); rows.len()]; rows.len()];
+    for i in 0..rows.len() {
+        if rows[i].elements.iter().all(|&x| x == T::zero()) {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Cosine similarity is undefined for row {}, which is all zeros!",
+                i
+            )));
+        }
+        for j in 0..i {
+            let similarity = cosine_similarity(&rows[i], &rows[j])?;
+            result[i][j] = similarity;
+            result[j][i] = similarity;
+        }
+    }
+
+    Ok(Matrix {
+        mat: result.into_iter().map(Arc::from).collect(),
+        rows: matrix.rows,
+        cols: matrix.rows,
+    })
+}
+
+impl<T: Number> From<Vec<T>> for Vector<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Vector::new(elements)
+    }
+}
+
+impl<T: Number> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: Number> Display for Vector<T> {
+    /// Writes a `Vector` as a pretty-printable string
+    ///
+    /// ### Returns
+    /// - Unit result of the write operation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let elements: Vec<String> = self.elements.iter().map(|x| format!("{}", x)).collect();
+        write!(f, "[{}]", elements.join(", "))
+    }
+}
@@ -0,0 +1,224 @@
+use crate::decompose;
+use crate::error::LinalgError;
+use crate::matrix::{Matrix, NormKind};
+use crate::number::Number;
+use std::ops::{Index, Neg, Range};
+use std::sync::Arc;
+
+/// A read-only window onto a rectangular block of a `Matrix`, created by `Matrix::view`
+///
+/// A view borrows the rows of its `Matrix` instead of copying them, so taking one costs
+/// O(1) however large the block is. Use `to_matrix` when an owned copy is needed
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixView<'a, T: Number> {
+    rows: &'a [Arc<[T]>],
+    col_start: usize,
+    cols: usize,
+}
+
+impl<'a, T: Number> MatrixView<'a, T> {
+    /// Creates a view of the columns `col_range` of `rows`, which the caller has checked
+    pub(crate) fn new(rows: &'a [Arc<[T]>], col_range: Range<usize>) -> MatrixView<'a, T> {
+        MatrixView {
+            rows,
+            col_start: col_range.start,
+            cols: col_range.len(),
+        }
+    }
+
+    /// Gets the shape of this `MatrixView`
+    ///
+    /// ### Returns
+    /// - A tuple of the number of rows and columns, in that order
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows.len(), self.cols)
+    }
+
+    /// Gets the number of rows in this `MatrixView`
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Gets the number of columns in this `MatrixView`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Borrows row `r` of this `MatrixView` as a slice
+    ///
+    /// ### Returns
+    /// - `Some` with the `cols` elements of the row, or `None` if `r` is out of bounds
+    pub fn row(&self, r: usize) -> Option<&'a [T]> {
+        let rows = self.rows;
+        rows.get(r)
+            .map(|row| &row[self.col_start..self.col_start + self.cols])
+    }
+
+    /// Gets the element at `(row, col)` of this `MatrixView`
+    ///
+    /// ### Returns
+    /// - `Some` with the element, or `None` if the position is out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if col >= self.cols {
+            return None;
+        }
+        self.row(row).map(|r| r[col])
+    }
+
+    /// Narrows this `MatrixView` to a smaller block, still without copying
+    ///
+    /// ### Parameters
+    /// - `row_range` - The rows to keep, relative to this view
+    /// - `col_range` - The columns to keep, relative to this view
+    ///
+    /// ### Returns
+    /// - A `Result` containing the narrower `MatrixView`, or an `Err` of
+    ///   `LinalgError::OutOfBounds` if either range ends past this view or
+    ///   `LinalgError::InvalidArgument` if a range starts after it ends
+    pub fn view(
+        &self,
+        row_range: Range<usize>,
+        col_range: Range<usize>,
+    ) -> Result<MatrixView<'a, T>, LinalgError> {
+        check_ranges(self.rows(), self.cols, &row_range, &col_range)?;
+
+        Ok(MatrixView {
+            rows: &self.rows[row_range],
+            col_start: self.col_start + col_range.start,
+            cols: col_range.len(),
+        })
+    }
+
+    /// Copies the block this `MatrixView` covers into a new `Matrix`
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            mat: self
+                .rows
+                .iter()
+                .map(|row| Arc::from(&row[self.col_start..self.col_start + self.cols]))
+                .collect(),
+            rows: self.rows(),
+            cols: self.cols,
+        }
+    }
+
+    /// Multiplies this `MatrixView` by another, reading both in place
+    ///
+    /// ### Parameters
+    /// - `other` - The right operand, with as many rows as this view has columns
+    ///
+    /// ### Returns
+    /// - A `Result` containing the product as a new `Matrix`, or an `Err` of
+    ///   `LinalgError::ShapeMismatch` if the inner dimensions differ
+    pub fn multiply(&self, other: &MatrixView<'_, T>) -> Result<Matrix<T>, LinalgError> {
+        if self.cols != other.rows() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.cols, other.cols),
+                found: other.shape(),
+            });
+        }
+
+        let mat = (0..self.rows())
+            .map(|r| {
+                let a_row = &self.rows[r][self.col_start..self.col_start + self.cols];
+                let mut out = vec![T::default(); other.cols];
+                for (k, &a) in a_row.iter().enumerate() {
+                    let b_row = &other.rows[k][other.col_start..other.col_start + other.cols];
+                    for (sum, &b) in out.iter_mut().zip(b_row) {
+                        *sum += a * b;
+                    }
+                }
+                Arc::from(out)
+            })
+            .collect();
+
+        Ok(Matrix {
+            mat,
+            rows: self.rows(),
+            cols: other.cols,
+        })
+    }
+}
+
+impl<T: Number + Neg<Output = T> + num::Float> MatrixView<'_, T> {
+    /// Computes a norm of this `MatrixView`, as `Matrix::norm` does
+    ///
+    /// The Frobenius, 1-, and ∞-norms are read in place; `NormKind::Spectral` needs a
+    /// singular value decomposition and copies the block first
+    ///
+    /// ### Parameters
+    /// - `kind` - Which `NormKind` to compute
+    ///
+    /// ### Returns
+    /// - A `Result` containing the norm, which is `0` for an empty view, or an `Err` if the
+    ///   view holds a NaN or infinity and `kind` is `NormKind::Spectral`
+    pub fn norm(&self, kind: NormKind) -> Result<T, LinalgError> {
+        let rows = || (0..self.rows()).filter_map(|r| self.row(r));
+
+        Ok(match kind {
+            NormKind::Frobenius => rows()
+                .flatten()
+                .fold(T::zero(), |sum, &x| sum + x * x)
+                .sqrt(),
+            NormKind::One => (0..self.cols)
+                .map(|j| rows().fold(T::zero(), |sum, row| sum + row[j].abs()))
+                .fold(T::zero(), T::max),
+            NormKind::Infinity => rows()
+                .map(|row| row.iter().fold(T::zero(), |sum, x| sum + x.abs()))
+                .fold(T::zero(), T::max),
+            NormKind::Spectral => decompose::svd(&self.to_matrix())?
+                .1
+                .first()
+                .copied()
+                .unwrap_or_else(T::zero),
+        })
+    }
+}
+
+impl<T: Number> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    /// Gets a reference to the element at `(row, col)` of this `MatrixView`
+    ///
+    /// # Panics
+    /// Panics if `row` or `col` is out of bounds; use `MatrixView::get` for a checked read
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.cols, "Index out of bounds");
+        &self.rows[row][self.col_start + col]
+    }
+}
+
+impl<T: Number + PartialEq> PartialEq for MatrixView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && (0..self.rows()).all(|r| self.row(r) == other.row(r))
+    }
+}
+
+/// Checks that `row_range` and `col_range` are ordered and end within a `rows x cols`
+/// block
+pub(crate) fn check_ranges(
+    rows: usize,
+    cols: usize,
+    row_range: &Range<usize>,
+    col_range: &Range<usize>,
+) -> Result<(), LinalgError> {
+    if row_range.start > row_range.end || col_range.start > col_range.end {
+        return Err(LinalgError::InvalidArgument(
+            "A range must not start after it ends!".to_string(),
+        ));
+    }
+    if row_range.end > rows {
+        return Err(LinalgError::OutOfBounds {
+            index: row_range.end,
+            len: rows,
+        });
+    }
+    if col_range.end > cols {
+        return Err(LinalgError::OutOfBounds {
+            index: col_range.end,
+            len: cols,
+        });
+    }
+
+    Ok(())
+}
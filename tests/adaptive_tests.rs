@@ -0,0 +1,103 @@
+mod adaptive_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::adaptive::{
+        solve_adaptive, solve_adaptive_with_context, solve_adaptive_with_tol, SolvePath,
+    };
+    use linalgrs::context::ComputeContext;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::{Arc, Mutex};
+
+    fn hilbert(n: usize) -> Matrix<f64> {
+        Matrix {
+            mat: (0..n)
+                .map(|i| Arc::from((0..n).map(|j| 1.0 / (i + j + 1) as f64).collect::<Vec<_>>()))
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    #[test]
+    fn test_well_conditioned_stays_single() {
+        let a = matrix!([4.0, 1.0], [2.0, 3.0]);
+        let b = matrix!([1.0], [2.0]);
+
+        let result = solve_adaptive_with_tol(&a, &b, 1e-5).unwrap();
+        assert_eq!(result.path, SolvePath::Single);
+        assert!(approx_eq!(
+            f64,
+            result.solution.mat[0][0],
+            0.1,
+            epsilon = 1e-5
+        ));
+        assert!(approx_eq!(
+            f64,
+            result.solution.mat[1][0],
+            0.6,
+            epsilon = 1e-5
+        ));
+    }
+
+    #[test]
+    fn test_tight_tolerance_refines_in_single() {
+        let a = matrix!([4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 3.0]);
+        let b = matrix!([1.0, 0.0], [2.0, 1.0], [3.0, 0.0]);
+
+        let result = solve_adaptive(&a, &b).unwrap();
+        assert!(matches!(result.path, SolvePath::SingleRefined { .. }));
+        assert!(result.error_estimate <= 1e-8);
+        assert_eq!(result.solution.cols, 2);
+    }
+
+    #[test]
+    fn test_ill_conditioned_escalates_to_double() {
+        let a = hilbert(8);
+        let ones: Vec<f64> = vec![1.0; 8];
+        let b = Matrix {
+            mat: a
+                .mat
+                .iter()
+                .map(|row| Arc::from([row.iter().zip(&ones).map(|(x, y)| x * y).sum::<f64>()]))
+                .collect(),
+            rows: 8,
+            cols: 1,
+        };
+
+        let result = solve_adaptive_with_tol(&a, &b, 1e-3).unwrap();
+        assert!(matches!(
+            result.path,
+            SolvePath::Double | SolvePath::DoubleRefined { .. }
+        ));
+        assert!(result.condition > 1e8);
+        for row in &result.solution.mat {
+            assert!(approx_eq!(f64, row[0], 1.0, epsilon = 1e-3));
+        }
+    }
+
+    #[test]
+    fn test_refinement_counts_only_kept_steps() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        let ctx = ComputeContext::new().with_progress(move |i, r| log.lock().unwrap().push((i, r)));
+        let a = hilbert(4);
+        let b = matrix!([1.0], [-2.0], [3.0], [0.5]);
+
+        // No tolerance is reachable, so f32 refinement gives up and the f64 solution is
+        // returned as is, since its first refinement step does not lower the error
+        let result = solve_adaptive_with_context(&a, &b, 0.0, &ctx).unwrap();
+        assert_eq!(result.path, SolvePath::Double);
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.iter().map(|&(i, _)| i).collect::<Vec<_>>(), [1, 2]);
+        assert!(seen[1].1 < seen[0].1);
+    }
+
+    #[test]
+    fn test_invalid_systems() {
+        let b = matrix!([1.0], [2.0]);
+
+        assert!(solve_adaptive(&matrix!([1.0, 2.0]), &b).is_err());
+        assert!(solve_adaptive(&matrix!([1.0, 2.0], [2.0, 4.0]), &b).is_err());
+        assert!(solve_adaptive(&matrix!([1.0, 0.0], [0.0, 1.0]), &matrix!([1.0])).is_err());
+    }
+}
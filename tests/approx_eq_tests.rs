@@ -0,0 +1,43 @@
+mod approx_eq_tests {
+    use linalgrs::matrix::Matrix;
+    use linalgrs::testing::check_matrix_eq;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_approx_eq() {
+        let a = matrix!([1.0, 2.0], [3.0, f64::INFINITY]);
+
+        assert!(a.approx_eq(&matrix!([1.0 + 1e-10, 2.0], [3.0, f64::INFINITY]), 1e-9));
+        assert!(!a.approx_eq(&matrix!([1.0 + 1e-8, 2.0], [3.0, f64::INFINITY]), 1e-9));
+        assert!(!a.approx_eq(&matrix!([1.0, 2.0], [3.0, f64::NEG_INFINITY]), 1e-9));
+        assert!(!a.approx_eq(&matrix!([1.0, 2.0, 0.0], [3.0, 4.0, 0.0]), 1e-9));
+        assert!(!matrix!([f64::NAN]).approx_eq(&matrix!([f64::NAN]), 1.0));
+        assert!(Matrix::<f64>::zeros(0, 3).approx_eq(&Matrix::zeros(0, 3), 0.0));
+    }
+
+    #[test]
+    fn test_check_matrix_eq_reports_mismatches() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        assert_eq!(check_matrix_eq(&a, &a, 0.0), Ok(()));
+        let message = check_matrix_eq(&a, &matrix!([1.0, 2.5], [3.0, 4.0]), 0.1).unwrap_err();
+        assert!(message.contains("(0, 1): left 2, right 2.5"));
+        assert!(!message.contains("(1, 1)"));
+        assert_eq!(
+            check_matrix_eq(&a, &matrix!([1.0, 2.0]), 0.1),
+            Err("matrices differ in shape: left is 2x2 but right is 1x2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_assert_matrix_eq() {
+        assert_matrix_eq!(matrix!([0.1 + 0.2]), matrix!([0.3]), 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 0): left 1, right 2")]
+    fn test_assert_matrix_eq_panics() {
+        assert_matrix_eq!(matrix!([1.0]), matrix!([2.0]), 0.5);
+    }
+}
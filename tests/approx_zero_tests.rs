@@ -0,0 +1,76 @@
+mod approx_zero_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::number::Number;
+    use linalgrs::{assert_matrix_eq, solve};
+    use std::sync::Arc;
+
+    fn singular() -> Matrix<f64> {
+        matrix!([0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9])
+    }
+
+    #[test]
+    fn test_number_tolerances() {
+        assert!((0.1 + 0.2 - 0.3f64).is_approx_zero(0.3));
+        assert!(!1e-15f64.is_approx_zero(0.3));
+        assert!(!1e-300f64.is_approx_zero(1e-300));
+        assert!(1e-7f32.is_approx_zero(1.0));
+        assert_eq!((-3.0f64).rounding_scale(2.0), 3.0);
+        assert_eq!(1.0f32.rounding_scale(2.0), 2.0);
+
+        assert!(0i32.is_approx_zero(100));
+        assert!(!1i64.is_approx_zero(i64::MAX));
+        assert_eq!((-5i32).rounding_scale(0), 0);
+    }
+
+    #[test]
+    fn test_singular_up_to_rounding() {
+        let a = singular();
+        assert_eq!(solve::inverse(&a), Err(LinalgError::Singular));
+        assert_eq!(solve::determinant_fast(&a), Ok(0.0));
+        assert_eq!(solve::rref(&a).mat[2].to_vec(), vec![0.0; 3]);
+        assert_eq!(solve::row_echelon_form(&a).mat[2].to_vec(), vec![0.0; 3]);
+
+        let a32 = matrix!([0.1f32, 0.2], [0.3, 0.6]);
+        assert_eq!(solve::inverse(&a32), Err(LinalgError::Singular));
+        assert_eq!(
+            solve::gaussian_elimination(&matrix!(
+                [0.1, 0.2, 0.3, 1.0],
+                [0.4, 0.5, 0.6, 2.0],
+                [0.7, 0.8, 0.9, 3.0]
+            )),
+            Err(LinalgError::InfiniteSolutions)
+        );
+    }
+
+    #[test]
+    fn test_small_elements_are_kept() {
+        let a = matrix!([1e-20, 0.0], [0.0, 1e20]);
+        assert_matrix_eq!(
+            solve::inverse(&a).unwrap(),
+            matrix!([1e20, 0.0], [0.0, 1e-20]),
+            0.0
+        );
+        assert_eq!(solve::determinant_fast(&a), Ok(1.0));
+
+        let b = matrix!([1.0, 1e-12], [1e6, 1.0]);
+        let inverse = solve::inverse(&b).unwrap();
+        assert_matrix_eq!(
+            inverse,
+            solve::solve(&b, &linalgrs::arith::identity(2)).unwrap(),
+            1e-9
+        );
+        assert_eq!(solve::row_echelon_form(&b).mat[1].to_vec(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_integers_are_exact() {
+        let a = matrix!([2, 4, 6], [1, 3, 5], [1, 1, 1]);
+        assert_eq!(solve::determinant(&a), Some(0));
+        assert_eq!(
+            solve::rref(&matrix!([2, 4], [1, 3])),
+            matrix!([1, 0], [0, 1])
+        );
+    }
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "arrow")]
+
+mod arrow_tests {
+    use arrow_array::{Array, Float64Array, Int32Array, RecordBatch};
+    use linalgrs::io::arrow;
+    use linalgrs::io::arrow::ArrowError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_matrix_to_record_batch() {
+        let mat = matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+
+        let batch = arrow::to_record_batch(&mat, Some(&["x", "y"])).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.schema().field(1).name(), "y");
+
+        let y = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(y.values().to_vec(), vec![2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_record_batch_round_trip() {
+        let mat = matrix!([1.5, -2.0, 0.0], [3.0, 4.0, 1e9]);
+
+        let batch = arrow::to_record_batch(&mat, None).unwrap();
+        assert_eq!(batch.schema().field(2).name(), "c2");
+        assert_eq!(arrow::from_record_batch(&batch).unwrap(), mat);
+    }
+
+    #[test]
+    fn test_record_batches_are_stacked() {
+        let a = arrow::to_record_batch(&matrix!([1.0, 2.0]), None).unwrap();
+        let b = arrow::to_record_batch(&matrix!([3.0, 4.0], [5.0, 6.0]), None).unwrap();
+
+        let stacked = arrow::from_record_batches(&[a, b]).unwrap();
+        assert_eq!(stacked, matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]));
+    }
+
+    #[test]
+    fn test_invalid_batches() {
+        let mat = matrix!([1.0, 2.0]);
+        assert_eq!(
+            arrow::to_record_batch(&mat, Some(&["only_one"])).unwrap_err(),
+            ArrowError::ColumnNames {
+                expected: 2,
+                found: 1
+            }
+        );
+
+        let ints = RecordBatch::try_from_iter(vec![(
+            "n",
+            Arc::new(Int32Array::from(vec![1, 2])) as Arc<dyn Array>,
+        )])
+        .unwrap();
+        assert_eq!(
+            arrow::from_record_batch(&ints),
+            Err(ArrowError::NotFloat64 {
+                column: "n".to_string(),
+                data_type: "Int32".to_string()
+            })
+        );
+
+        let nulls = RecordBatch::try_from_iter(vec![(
+            "n",
+            Arc::new(Float64Array::from(vec![Some(1.0), None])) as Arc<dyn Array>,
+        )])
+        .unwrap();
+        assert_eq!(
+            arrow::from_record_batch(&nulls),
+            Err(ArrowError::Nulls("n".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_zero_column_matrix_is_rejected() {
+        let empty = Matrix::<f64>::zeros(3, 0);
+
+        assert_eq!(
+            arrow::to_record_batch(&empty, None).unwrap_err(),
+            ArrowError::NoColumns
+        );
+        assert_eq!(
+            arrow::to_record_batch(&empty, Some(&[])).unwrap_err(),
+            ArrowError::NoColumns
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_round_trip() {
+        let path = std::env::temp_dir().join("linalgrs_arrow_test.parquet");
+        let mat = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+
+        arrow::write_parquet(&path, &mat, Some(&["a", "b", "c"])).unwrap();
+        assert_eq!(arrow::read_parquet(&path).unwrap(), mat);
+    }
+}
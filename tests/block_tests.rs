@@ -0,0 +1,106 @@
+#![allow(deprecated)]
+
+mod block_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_hstack_builds_augmented_matrix() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([5], [6]);
+
+        assert_eq!(
+            MatrixUtilities::hstack(&[&a, &b]).unwrap(),
+            matrix!([1, 2, 5], [3, 4, 6])
+        );
+        assert_eq!(MatrixUtilities::hstack(&[&a]).unwrap(), a);
+    }
+
+    #[test]
+    fn test_vstack_shares_rows() {
+        let a = matrix!([1, 2]);
+        let b = matrix!([3, 4], [5, 6]);
+        let stacked = MatrixUtilities::vstack(&[&a, &b]).unwrap();
+
+        assert_eq!(stacked, matrix!([1, 2], [3, 4], [5, 6]));
+        assert!(Arc::ptr_eq(&stacked.mat[0], &a.mat[0]));
+    }
+
+    #[test]
+    fn test_block_composes_saddle_point_matrix() {
+        let a = matrix!([2, 0], [0, 3]);
+        let b = matrix!([1, 1]);
+        let bt = MatrixUtilities::transpose(&b);
+        let zero = Matrix::zeros(1, 1);
+
+        assert_eq!(
+            MatrixUtilities::block(&[&[&a, &bt], &[&b, &zero]]).unwrap(),
+            matrix!([2, 0, 1], [0, 3, 1], [1, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_stack_errors() {
+        let a = matrix!([1, 2], [3, 4]);
+        let row = matrix!([5, 6, 7]);
+
+        assert_eq!(
+            MatrixUtilities::hstack(&[&a, &row]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (1, 3)
+            })
+        );
+        assert_eq!(
+            MatrixUtilities::vstack(&[&a, &row]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 2),
+                found: (1, 3)
+            })
+        );
+        assert_eq!(MatrixUtilities::<i32>::hstack(&[]), Err(LinalgError::Empty));
+        assert_eq!(
+            MatrixUtilities::block(&[&[&a], &[&row]]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 2),
+                found: (1, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_split_round_trips() {
+        let m = matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+
+        let (top, bottom) = m.split_at_row(1).unwrap();
+        assert_eq!(top, matrix!([1, 2, 3]));
+        assert_eq!(bottom, matrix!([4, 5, 6], [7, 8, 9]));
+        assert_eq!(MatrixUtilities::vstack(&[&top, &bottom]).unwrap(), m);
+
+        let (left, right) = m.split_at_col(2).unwrap();
+        assert_eq!(left, matrix!([1, 2], [4, 5], [7, 8]));
+        assert_eq!(right, matrix!([3], [6], [9]));
+        assert_eq!(MatrixUtilities::hstack(&[&left, &right]).unwrap(), m);
+
+        let (empty, all) = m.split_at_col(0).unwrap();
+        assert_eq!((empty.rows, empty.cols), (3, 0));
+        assert_eq!(all, m);
+    }
+
+    #[test]
+    fn test_split_out_of_bounds() {
+        let m = matrix!([1, 2], [3, 4]);
+
+        assert_eq!(
+            m.split_at_row(3),
+            Err(LinalgError::OutOfBounds { index: 3, len: 2 })
+        );
+        assert_eq!(
+            m.split_at_col(5),
+            Err(LinalgError::OutOfBounds { index: 5, len: 2 })
+        );
+    }
+}
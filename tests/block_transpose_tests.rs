@@ -0,0 +1,76 @@
+#![allow(deprecated)]
+
+mod block_transpose_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_block_transpose_moves_blocks() {
+        let m = matrix!([1, 2, 3, 4], [5, 6, 7, 8]);
+
+        // Two 2x2 blocks side by side become two stacked blocks
+        assert_eq!(
+            m.block_transpose(2, 2).unwrap(),
+            matrix!([1, 2], [5, 6], [3, 4], [7, 8])
+        );
+        assert_eq!(
+            m.block_transpose(1, 1).unwrap(),
+            MatrixUtilities::transpose(&m)
+        );
+        assert_eq!(m.block_transpose(2, 4).unwrap(), m);
+    }
+
+    #[test]
+    fn test_partial_transpose_transposes_blocks() {
+        let m = matrix!([1, 2, 3, 4], [5, 6, 7, 8]);
+
+        assert_eq!(
+            m.partial_transpose(2, 2).unwrap(),
+            matrix!([1, 5, 3, 7], [2, 6, 4, 8])
+        );
+        assert_eq!(m.partial_transpose(1, 1).unwrap(), m);
+        assert_eq!(
+            m.partial_transpose(2, 4).unwrap(),
+            MatrixUtilities::transpose(&m)
+        );
+    }
+
+    #[test]
+    fn test_partial_transposes_of_kronecker_product() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([5, 6, 7], [8, 9, 10]);
+        let ab = MatrixUtilities::kronecker(&a, &b).unwrap();
+        let (at, bt) = (
+            MatrixUtilities::transpose(&a),
+            MatrixUtilities::transpose(&b),
+        );
+
+        let first = ab.block_transpose(b.rows, b.cols).unwrap();
+        assert_eq!(first, MatrixUtilities::kronecker(&at, &b).unwrap());
+        let second = ab.partial_transpose(b.rows, b.cols).unwrap();
+        assert_eq!(second, MatrixUtilities::kronecker(&a, &bt).unwrap());
+        assert_eq!(
+            first.partial_transpose(b.rows, b.cols).unwrap(),
+            MatrixUtilities::transpose(&ab)
+        );
+    }
+
+    #[test]
+    fn test_blocks_must_tile_matrix() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+
+        for (block_rows, block_cols) in [(2, 2), (0, 1), (3, 3)] {
+            assert!(matches!(
+                m.block_transpose(block_rows, block_cols),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+            assert!(matches!(
+                m.partial_transpose(block_rows, block_cols),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+    }
+}
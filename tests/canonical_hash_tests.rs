@@ -0,0 +1,58 @@
+mod canonical_hash_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_reordered_rows_hash_equally() {
+        let system = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]);
+        let reordered = matrix!([7.0, 8.0, 9.0], [1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+
+        assert_eq!(system.canonical_hash(), reordered.canonical_hash());
+        assert_eq!(system.canonical_hash(), system.clone().canonical_hash());
+    }
+
+    #[test]
+    fn test_different_matrices_hash_differently() {
+        let a = matrix!([1, 2], [3, 4]);
+
+        // Reordering within a row changes the equation
+        assert_ne!(a.canonical_hash(), matrix!([2, 1], [3, 4]).canonical_hash());
+        assert_ne!(a.canonical_hash(), matrix!([1, 2], [3, 5]).canonical_hash());
+        assert_ne!(a.canonical_hash(), matrix!([1, 2, 3, 4]).canonical_hash());
+        assert_ne!(
+            Matrix::<i32>::zeros(0, 2).canonical_hash(),
+            Matrix::<i32>::zeros(0, 3).canonical_hash()
+        );
+        // Repeated rows count as often as they occur
+        assert_ne!(
+            matrix!([1, 1], [1, 1], [2, 2]).canonical_hash(),
+            matrix!([1, 1], [2, 2], [2, 2]).canonical_hash()
+        );
+    }
+
+    #[test]
+    fn test_equal_floats_hash_equally() {
+        assert_eq!(
+            matrix!([0.0, 1.5]).canonical_hash(),
+            matrix!([-0.0, 1.5]).canonical_hash()
+        );
+        assert_eq!(
+            matrix!([f64::NAN]).canonical_hash(),
+            matrix!([-f64::NAN]).canonical_hash()
+        );
+    }
+
+    #[test]
+    fn test_deduplicates_systems() {
+        let systems = [
+            matrix!([1.0, 1.0, 2.0], [1.0, -1.0, 0.0]),
+            matrix!([1.0, -1.0, 0.0], [1.0, 1.0, 2.0]),
+            matrix!([2.0, 2.0, 4.0], [1.0, -1.0, 0.0]),
+        ];
+
+        let unique: HashSet<u64> = systems.iter().map(Matrix::canonical_hash).collect();
+        assert_eq!(unique.len(), 2);
+    }
+}
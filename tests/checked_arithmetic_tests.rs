@@ -0,0 +1,101 @@
+mod checked_arithmetic_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{arith, solve};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_checked_add() {
+        let a = matrix!([1i8, -2], [3, 100]);
+        let b = matrix!([4i8, 5], [-6, 27]);
+        assert_eq!(arith::checked_add(&a, &b), arith::add(&a, &b));
+
+        assert_eq!(
+            arith::checked_add(&a, &matrix!([0i8, 0], [0, 28])),
+            Err(LinalgError::Overflow)
+        );
+        assert_eq!(
+            arith::checked_add(&a, &matrix!([1i8, 2])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_multiply() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+        let b = matrix!([7, 8], [9, 10], [11, 12]);
+        assert_eq!(arith::checked_multiply(&a, &b), arith::multiply(&a, &b));
+
+        let big = matrix!([65_536i32, 1], [1, 1]);
+        assert_eq!(
+            arith::checked_multiply(&big, &big),
+            Err(LinalgError::Overflow)
+        );
+        let sums = matrix!([i64::MAX / 2 + 1, i64::MAX / 2 + 1]);
+        assert_eq!(
+            arith::checked_multiply(&sums, &matrix!([1i64], [1])),
+            Err(LinalgError::Overflow)
+        );
+        assert_eq!(
+            arith::checked_multiply(&a, &a),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_determinant_matches_cofactor_expansion() {
+        for a in [
+            matrix!([7]),
+            matrix!([2, 3], [1, 4]),
+            matrix!([0, 2, 1], [1, -1, 4], [3, 5, -2]),
+            matrix!([0, 0, 1], [0, 1, 0], [1, 0, 0]),
+            matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]),
+            matrix!([2, -1, 0, 3], [1, 0, 4, -2], [0, 3, -1, 1], [5, 2, 2, 0]),
+        ] {
+            assert_eq!(
+                solve::checked_determinant(&a),
+                Ok(solve::determinant(&a).unwrap())
+            );
+        }
+        assert_eq!(
+            solve::checked_determinant(&Matrix::<i32>::zeros(0, 0)),
+            Ok(1)
+        );
+        assert_eq!(
+            solve::checked_determinant(&matrix!([1, 2])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn test_checked_determinant_overflow() {
+        assert_eq!(
+            solve::checked_determinant(&matrix!([100i8, 0], [0, 100])),
+            Err(LinalgError::Overflow)
+        );
+        assert_eq!(
+            solve::checked_determinant(&matrix!([0, 1], [i32::MIN, 0])),
+            Err(LinalgError::Overflow)
+        );
+
+        // Every minor of this Vandermonde matrix fits in i64, so the exact value comes back
+        let vandermonde: Matrix<i64> = Matrix {
+            mat: (1..=8i64)
+                .map(|x| (0..8).map(|p| x.pow(p)).collect())
+                .collect(),
+            rows: 8,
+            cols: 8,
+        };
+        let expected: i64 = (1..=8i64)
+            .flat_map(|i| ((i + 1)..=8).map(move |j| j - i))
+            .product();
+        assert_eq!(solve::checked_determinant(&vandermonde), Ok(expected));
+    }
+}
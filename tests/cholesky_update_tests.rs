@@ -0,0 +1,99 @@
+mod cholesky_update_tests {
+    use linalgrs::decompose::{self, CholeskyFactorization};
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{arith, assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    fn outer(x: &[f64]) -> Matrix<f64> {
+        Matrix {
+            mat: x
+                .iter()
+                .map(|&a| x.iter().map(|&b| a * b).collect())
+                .collect(),
+            rows: x.len(),
+            cols: x.len(),
+        }
+    }
+
+    fn spd() -> Matrix<f64> {
+        matrix!([4.0, 2.0, 0.6], [2.0, 5.0, 1.0], [0.6, 1.0, 3.0])
+    }
+
+    #[test]
+    fn test_update_matches_refactorization() {
+        let x = [1.0, -2.0, 0.5];
+        let mut chol = CholeskyFactorization::new(&spd()).unwrap();
+
+        chol.update(&x).unwrap();
+        let updated = arith::add(&spd(), &outer(&x)).unwrap();
+        assert_matrix_eq!(
+            chol.l(),
+            decompose::cholesky_decomposition(&updated).unwrap(),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_downdate_undoes_update() {
+        let mut chol = CholeskyFactorization::new(&spd()).unwrap();
+        let original = chol.l();
+
+        for x in [[0.3, 1.0, -0.7], [2.0, 0.0, 1.0], [-1.0, 0.5, 0.25]] {
+            chol.update(&x).unwrap();
+        }
+        for x in [[2.0, 0.0, 1.0], [-1.0, 0.5, 0.25], [0.3, 1.0, -0.7]] {
+            chol.downdate(&x).unwrap();
+        }
+        assert_matrix_eq!(chol.l(), original, 1e-12);
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut chol = CholeskyFactorization::new(&spd()).unwrap();
+        chol.update(&[1.0, 1.0, 1.0]).unwrap();
+
+        let a = arith::add(&spd(), &outer(&[1.0, 1.0, 1.0])).unwrap();
+        let b = matrix!([1.0, 0.0], [2.0, 1.0], [3.0, 0.0]);
+        let x = chol.solve(&b).unwrap();
+        assert_matrix_eq!(arith::multiply(&a, &x).unwrap(), b, 1e-12);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut chol = CholeskyFactorization::new(&matrix!([1.0, 0.0], [0.0, 1.0])).unwrap();
+        let before = chol.clone();
+
+        assert_eq!(
+            chol.downdate(&[1.0, 0.0]),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            chol.downdate(&[2.0, 0.0]),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        assert_eq!(chol, before);
+        assert_eq!(
+            chol.update(&[1.0]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            chol.update(&[0.0, f64::INFINITY]),
+            Err(LinalgError::NonFinite { row: 0, col: 1 })
+        );
+        assert_eq!(
+            chol.solve(&matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        assert_eq!(
+            CholeskyFactorization::new(&matrix!([1.0, 2.0], [2.0, 1.0])),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+    }
+}
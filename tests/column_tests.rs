@@ -0,0 +1,55 @@
+mod column_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_column() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+
+        assert_eq!(m.column(1), Ok(vec![2, 5]));
+        assert_eq!(
+            m.column(3),
+            Err(LinalgError::OutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        let m = matrix!([1, 2], [3, 4], [5, 6]);
+        let columns: Vec<Vec<i32>> = m.columns().collect();
+
+        assert_eq!(columns, vec![vec![1, 3, 5], vec![2, 4, 6]]);
+        assert_eq!(Matrix::<i32>::default().columns().count(), 0);
+    }
+
+    #[test]
+    fn test_set_column_copies_on_write() {
+        let a = matrix!([1, 2], [3, 4]);
+        let mut b = a.clone();
+
+        assert!(b.set_column(0, &[7, 8]).is_ok());
+        assert_eq!(b, matrix!([7, 2], [8, 4]));
+        assert_eq!(a, matrix!([1, 2], [3, 4]));
+        assert_eq!(
+            b.set_column(1, &[1]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(b.set_column(2, &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_swap_columns() {
+        let mut m = matrix!([1, 2, 3], [4, 5, 6]);
+
+        assert!(m.swap_columns(0, 2).is_ok());
+        assert_eq!(m, matrix!([3, 2, 1], [6, 5, 4]));
+        assert!(m.swap_columns(1, 1).is_ok());
+        assert_eq!(m, matrix!([3, 2, 1], [6, 5, 4]));
+        assert!(m.swap_columns(0, 3).is_err());
+    }
+}
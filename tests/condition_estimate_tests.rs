@@ -0,0 +1,87 @@
+mod condition_estimate_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::{Matrix, NormKind};
+    use linalgrs::{arith, decompose, solve};
+    use std::sync::Arc;
+
+    fn hilbert(n: usize) -> Matrix<f64> {
+        Matrix {
+            mat: (0..n)
+                .map(|i| (0..n).map(|j| 1.0 / (i + j + 1) as f64).collect())
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    fn exact(a: &Matrix<f64>) -> f64 {
+        a.norm(NormKind::One).unwrap()
+            * solve::solve(a, &arith::identity(a.rows))
+                .unwrap()
+                .norm(NormKind::One)
+                .unwrap()
+    }
+
+    fn estimate(a: &Matrix<f64>) -> f64 {
+        let factors = decompose::plu_decomposition(a).unwrap();
+        solve::condition_estimate_1norm(&factors, a.norm(NormKind::One).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_estimate_matches_exact_on_small_matrices() {
+        for a in [
+            matrix!([4.0]),
+            matrix!([2.0, 1.0], [1.0, 3.0]),
+            matrix!([0.0, 2.0, 1.0], [1.0, -1.0, 4.0], [3.0, 5.0, -2.0]),
+            matrix!(
+                [10.0, -7.0, 0.0, 1.0],
+                [-3.0, 2.0, 6.0, 0.5],
+                [5.0, -1.0, 5.0, 2.0],
+                [2.0, 1.0, 0.0, 3.0]
+            ),
+        ] {
+            let (estimate, exact) = (estimate(&a), exact(&a));
+            assert!(estimate <= exact * (1.0 + 1e-12));
+            assert!((estimate - exact).abs() <= 1e-10 * exact);
+        }
+    }
+
+    #[test]
+    fn test_estimate_on_hilbert() {
+        let a = hilbert(6);
+
+        let (estimate, exact) = (estimate(&a), exact(&a));
+        assert!(estimate > 1e7);
+        assert!(estimate <= exact * (1.0 + 1e-6));
+        assert!(estimate >= exact / 3.0);
+    }
+
+    #[test]
+    fn test_singular_and_errors() {
+        let singular = matrix!([1.0, 2.0], [2.0, 4.0]);
+        let factors = decompose::plu_decomposition(&singular).unwrap();
+        assert_eq!(
+            solve::condition_estimate_1norm(&factors, 6.0),
+            Ok(f64::INFINITY)
+        );
+        assert!(matches!(
+            solve::condition_estimate_1norm(&factors, -1.0),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+
+        let (p, l, _) = factors;
+        assert_eq!(
+            solve::condition_estimate_1norm(&(p, l, matrix!([1.0, 2.0])), 1.0),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 1),
+                found: (2, 2)
+            })
+        );
+        let empty = Matrix::<f64>::zeros(0, 0);
+        assert_eq!(
+            solve::condition_estimate_1norm(&(empty.clone(), empty.clone(), empty), 0.0),
+            Err(LinalgError::Empty)
+        );
+    }
+}
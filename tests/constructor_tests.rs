@@ -0,0 +1,107 @@
+mod constructor_tests {
+    use linalgrs::arith;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::transform;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_zeros_ones_full() {
+        assert_eq!(Matrix::<i32>::zeros(2, 3), matrix!([0, 0, 0], [0, 0, 0]));
+        assert_eq!(Matrix::<f64>::ones(1, 2), matrix!([1.0, 1.0]));
+        assert_eq!(Matrix::full(2, 1, 7), matrix!([7], [7]));
+
+        let empty = Matrix::<i32>::zeros(0, 4);
+        assert_eq!((empty.rows, empty.cols), (0, 4));
+        assert!(empty.validate().is_ok());
+    }
+
+    #[test]
+    fn test_full_rows_copy_on_write() {
+        let mut m = Matrix::full(2, 2, 1);
+        m[(0, 0)] = 5;
+
+        assert_eq!(m, matrix!([5, 1], [1, 1]));
+    }
+
+    #[test]
+    fn test_hadamard() {
+        assert_eq!(Matrix::<i32>::hadamard(1).unwrap(), matrix!([1]));
+        assert_eq!(
+            Matrix::<i32>::hadamard(4).unwrap(),
+            matrix!([1, 1, 1, 1], [1, -1, 1, -1], [1, 1, -1, -1], [1, -1, -1, 1])
+        );
+
+        let h = Matrix::<f64>::hadamard(16).unwrap();
+        let gram = arith::multiply(&h, &transform::transpose(&h)).unwrap();
+        assert_eq!(gram, arith::multiply_by_scalar(&arith::identity(16), 16.0));
+    }
+
+    #[test]
+    fn test_walsh_sequency_order() {
+        assert_eq!(
+            Matrix::<i32>::walsh(4).unwrap(),
+            matrix!([1, 1, 1, 1], [1, 1, -1, -1], [1, -1, -1, 1], [1, -1, 1, -1])
+        );
+
+        let w = Matrix::<i64>::walsh(32).unwrap();
+        let mut rows: Vec<_> = w.mat.to_vec();
+        for (k, row) in w.mat.iter().enumerate() {
+            assert_eq!(row.windows(2).filter(|p| p[0] != p[1]).count(), k);
+        }
+        rows.sort();
+        let mut hadamard_rows = Matrix::<i64>::hadamard(32).unwrap().mat;
+        hadamard_rows.sort();
+        assert_eq!(rows, hadamard_rows);
+    }
+
+    #[test]
+    fn test_hadamard_order_errors() {
+        for n in [0, 3, 12] {
+            assert!(matches!(
+                Matrix::<f64>::hadamard(n),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+            assert!(matches!(
+                Matrix::<i32>::walsh(n),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let m = Matrix::random(3, 4, -1.0..1.0).unwrap();
+        assert_eq!((m.rows, m.cols), (3, 4));
+        assert!(m.validate().is_ok());
+        assert!(m
+            .mat
+            .iter()
+            .flat_map(|r| r.iter())
+            .all(|&x| (-1.0..1.0).contains(&x)));
+
+        let a = Matrix::random_with_rng(2, 2, 0..=9, &mut StdRng::seed_from_u64(7)).unwrap();
+        let b = Matrix::random_with_rng(2, 2, 0..=9, &mut StdRng::seed_from_u64(7)).unwrap();
+        assert_eq!(a, b);
+
+        assert!(Matrix::<i32>::random(1, 1, 5..5).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_rejects_non_finite_width() {
+        assert!(matches!(
+            Matrix::random(2, 2, f64::MIN..f64::MAX),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            Matrix::random(2, 2, 0.0..f64::INFINITY),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+}
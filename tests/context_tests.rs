@@ -0,0 +1,114 @@
+#![allow(deprecated)]
+
+mod context_tests {
+    use linalgrs::adaptive::solve_adaptive_with_context;
+    use linalgrs::context::{CancellationToken, ComputeContext, Progress};
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::mpsc;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_default_context_never_interrupts() {
+        let ctx = ComputeContext::new();
+        let a = matrix!([4.0, 3.0], [6.0, 3.0]);
+
+        assert!(ctx.check().is_ok());
+        assert!(MatrixUtilities::lu_decomposition_with_context(&a, &ctx).is_ok());
+        assert!(MatrixUtilities::qr_decomposition_with_context(&a, &ctx).is_ok());
+        assert_eq!(
+            MatrixUtilities::inverse_with_context(a.clone(), &ctx).unwrap(),
+            MatrixUtilities::inverse(a).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cancelled_token() {
+        let token = CancellationToken::new();
+        let ctx = ComputeContext::new().with_cancellation(token.clone());
+        let a = matrix!([4.0, 3.0], [6.0, 3.0]);
+
+        token.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(
+            MatrixUtilities::inverse_with_context(a.clone(), &ctx),
+            Err(LinalgError::Cancelled)
+        );
+        assert_eq!(
+            MatrixUtilities::lu_decomposition_with_context(&a, &ctx),
+            Err(LinalgError::Cancelled)
+        );
+        assert_eq!(
+            solve_adaptive_with_context(&a, &matrix!([1.0], [2.0]), 1e-8, &ctx),
+            Err(LinalgError::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_deadline_exceeded() {
+        let ctx = ComputeContext::new().with_deadline(Instant::now());
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        assert_eq!(
+            MatrixUtilities::qr_decomposition_with_context(&a, &ctx),
+            Err(LinalgError::DeadlineExceeded)
+        );
+        let generous = ComputeContext::new().with_timeout(Duration::from_secs(60));
+        assert!(MatrixUtilities::qr_decomposition_with_context(&a, &generous).is_ok());
+    }
+
+    #[test]
+    fn test_algorithm_errors_pass_through() {
+        let ctx = ComputeContext::new();
+        let err =
+            MatrixUtilities::lu_decomposition_with_context(&matrix!([1.0, 2.0]), &ctx).unwrap_err();
+
+        assert_eq!(err, LinalgError::NonSquare { rows: 1, cols: 2 });
+        assert_eq!(
+            MatrixUtilities::lu_decomposition(&matrix!([1.0, 2.0])).unwrap_err(),
+            err
+        );
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        let ctx = ComputeContext::new().with_progress(move |i, r| log.lock().unwrap().push((i, r)));
+        let a = matrix!([4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 3.0]);
+        let b = matrix!([1.0], [2.0], [3.0]);
+
+        solve_adaptive_with_context(&a, &b, 1e-12, &ctx).unwrap();
+        let seen = seen.lock().unwrap();
+        assert!(!seen.is_empty());
+        assert_eq!(seen[0].0, 1);
+        assert!(seen.windows(2).all(|w| w[1].1 < w[0].1));
+    }
+
+    #[test]
+    fn test_progress_channel() {
+        let (tx, rx) = mpsc::channel();
+        let ctx = ComputeContext::new().with_progress_channel(tx);
+
+        ctx.report(1, 0.5);
+        ctx.report(2, 0.25);
+        drop(ctx);
+        let updates: Vec<Progress> = rx.iter().collect();
+        assert_eq!(
+            updates,
+            vec![
+                Progress {
+                    iteration: 1,
+                    residual: 0.5
+                },
+                Progress {
+                    iteration: 2,
+                    residual: 0.25
+                }
+            ]
+        );
+    }
+}
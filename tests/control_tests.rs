@@ -0,0 +1,82 @@
+#![allow(deprecated)]
+
+mod control_tests {
+    use linalgrs::control::{lqr, solve_care};
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_solve_care_scalar() {
+        // 0·x + x·0 − x² + 1 = 0 has the stabilizing root x = 1
+        let x = solve_care(
+            &matrix!([0.0]),
+            &matrix!([1.0]),
+            &matrix!([1.0]),
+            &matrix!([1.0]),
+        );
+
+        assert_matrix_eq!(x.unwrap(), matrix!([1.0]), 1e-9);
+    }
+
+    #[test]
+    fn test_lqr_double_integrator() {
+        let a = matrix!([0.0, 1.0], [0.0, 0.0]);
+        let b = matrix!([0.0], [1.0]);
+        let q = matrix!([1.0, 0.0], [0.0, 1.0]);
+        let r = matrix!([1.0]);
+        let root3 = 3f64.sqrt();
+
+        let (gain, x) = lqr(&a, &b, &q, &r).unwrap();
+        assert_matrix_eq!(x, matrix!([root3, 1.0], [1.0, root3]), 1e-9);
+        assert_matrix_eq!(gain, matrix!([1.0, root3]), 1e-9);
+
+        // The residual AᵀX + XA − XBR⁻¹BᵀX + Q vanishes
+        let at_x = MatrixUtilities::multiply(&MatrixUtilities::transpose(&a), &x).unwrap();
+        let x_a = MatrixUtilities::multiply(&x, &a).unwrap();
+        let x_b = MatrixUtilities::multiply(&x, &b).unwrap();
+        let quadratic = MatrixUtilities::multiply(&x_b, &MatrixUtilities::transpose(&x_b)).unwrap();
+        let residual = MatrixUtilities::add(
+            &MatrixUtilities::subtract(&MatrixUtilities::add(&at_x, &x_a).unwrap(), &quadratic)
+                .unwrap(),
+            &q,
+        )
+        .unwrap();
+        assert_matrix_eq!(residual, Matrix::zeros(2, 2), 1e-9);
+    }
+
+    #[test]
+    fn test_solve_care_errors() {
+        let a = matrix!([0.0, 1.0], [0.0, 0.0]);
+        let q = matrix!([1.0, 0.0], [0.0, 1.0]);
+
+        assert_eq!(
+            solve_care(
+                &a,
+                &matrix!([0.0], [1.0]),
+                &q,
+                &matrix!([1.0, 0.0], [0.0, 1.0])
+            ),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 1),
+                found: (2, 2)
+            })
+        );
+        assert_eq!(
+            solve_care(&matrix!([1.0, 0.0]), &a, &q, &q),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        // An unstable mode that the input cannot reach has no stabilizing solution
+        assert_eq!(
+            solve_care(
+                &matrix!([1.0]),
+                &matrix!([0.0]),
+                &matrix!([1.0]),
+                &matrix!([1.0])
+            ),
+            Err(LinalgError::RankDeficient)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+mod csv_tests {
+    use linalgrs::io::csv::CsvError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_from_csv() {
+        let data = "1, 2, 3\r\n4,5,6\n\n-7,8.5,9\n";
+        let m: Matrix<f64> = Matrix::from_csv(data.as_bytes(), ',').unwrap();
+
+        assert_eq!(
+            m,
+            matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [-7.0, 8.5, 9.0])
+        );
+    }
+
+    #[test]
+    fn test_from_csv_other_delimiters() {
+        let tabs: Matrix<i32> = Matrix::from_csv("1\t2\n3\t4".as_bytes(), '\t').unwrap();
+        assert_eq!(tabs, matrix!([1, 2], [3, 4]));
+
+        let semicolons: Matrix<i32> = Matrix::from_csv("1;2\n3;4".as_bytes(), ';').unwrap();
+        assert_eq!(semicolons, tabs);
+
+        let empty: Matrix<i32> = Matrix::from_csv("".as_bytes(), ',').unwrap();
+        assert_eq!((empty.rows, empty.cols), (0, 0));
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        assert_eq!(
+            Matrix::<f64>::from_csv("1,2,3\n\n4,5\n".as_bytes(), ','),
+            Err(CsvError::Ragged {
+                line: 3,
+                expected: 3,
+                found: 2
+            })
+        );
+        let err = Matrix::<f64>::from_csv("1,2\n3,x\n".as_bytes(), ',').unwrap_err();
+        assert_eq!(
+            err,
+            CsvError::InvalidNumber {
+                line: 2,
+                field: 2,
+                value: "x".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "Invalid number `x` in field 2 of line 2");
+        assert!(matches!(
+            Matrix::<f64>::from_csv_file("/nonexistent/linalgrs.csv", ','),
+            Err(CsvError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_csv_round_trip() {
+        let m = matrix!([0.1, -2.5], [1e-20, 3.0]);
+        let mut out = Vec::new();
+        m.to_csv(&mut out, ',').unwrap();
+
+        assert_eq!(
+            String::from_utf8(out.clone()).unwrap(),
+            "0.1,-2.5\n0.00000000000000000001,3\n"
+        );
+        assert_eq!(Matrix::from_csv(out.as_slice(), ',').unwrap(), m);
+
+        let path = std::env::temp_dir().join("linalgrs_csv_test.csv");
+        m.to_csv_file(&path, ';').unwrap();
+        assert_eq!(Matrix::from_csv_file(&path, ';').unwrap(), m);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(deprecated)]
+
+mod dense_tests {
+    use linalgrs::dense::DenseMatrix;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_new_and_access() {
+        let mut m = DenseMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        assert_eq!(m.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(m.row(2), None);
+        assert_eq!(m.get(0, 2), Some(3));
+        assert_eq!(m.get(0, 3), None);
+        m[(1, 0)] = 9;
+        assert_eq!(m[(1, 0)], 9);
+        assert_eq!(
+            DenseMatrix::new(2, 2, vec![1, 2, 3]),
+            Err(LinalgError::LengthMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip_with_matrix() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+
+        let dense = DenseMatrix::from(&m);
+        assert_eq!(dense.data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(Matrix::try_from(&dense), Ok(m.clone()));
+        assert_eq!(
+            Matrix::try_from(&DenseMatrix::<i32>::zeros(0, 3)),
+            Ok(Matrix::zeros(0, 3))
+        );
+
+        let truncated = DenseMatrix {
+            data: vec![1, 2, 3],
+            rows: 2,
+            cols: 2,
+        };
+        assert_eq!(
+            Matrix::try_from(&truncated),
+            Err(LinalgError::LengthMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_transpose_matches_matrix() {
+        // Spans several transpose blocks with ragged edges
+        let m = Matrix {
+            mat: (0..45)
+                .map(|i| (0..70).map(|j| i * 100 + j).collect())
+                .collect(),
+            rows: 45,
+            cols: 70,
+        };
+
+        let transposed = DenseMatrix::from(&m).transpose();
+        assert_eq!((transposed.rows, transposed.cols), (70, 45));
+        assert_eq!(
+            Matrix::try_from(&transposed),
+            Ok(MatrixUtilities::transpose(&m))
+        );
+    }
+
+    #[test]
+    fn test_multiply_matches_matrix() {
+        let a = matrix!([1.5, -2.0, 0.25], [3.0, 0.5, -1.0]);
+        let b = matrix!([2.0, 1.0], [0.0, -4.0], [8.0, 0.5]);
+
+        let product = DenseMatrix::from(&a)
+            .multiply(&DenseMatrix::from(&b))
+            .unwrap();
+        assert_eq!(
+            Matrix::try_from(&product),
+            MatrixUtilities::multiply(&a, &b)
+        );
+        assert_eq!(
+            DenseMatrix::from(&a).multiply(&DenseMatrix::from(&a)),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            })
+        );
+        let empty = DenseMatrix::<f64>::zeros(2, 0).multiply(&DenseMatrix::zeros(0, 3));
+        assert_eq!(empty.unwrap(), DenseMatrix::zeros(2, 3));
+    }
+}
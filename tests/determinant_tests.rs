@@ -1,33 +1,37 @@
+#![allow(deprecated)]
+
 mod determinant_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
     use linalgrs::matrix::Matrix;
     use linalgrs::matrix_utilities::MatrixUtilities;
     use std::sync::Arc;
 
     #[test]
     fn test_determinant_1x1() {
-        let mut matrix = Matrix {
+        let matrix = Matrix {
             mat: vec![Arc::new([1])],
             rows: 1,
             cols: 1,
         };
 
-        assert_eq!(MatrixUtilities::determinant(&mut matrix).unwrap(), 1);
+        assert_eq!(MatrixUtilities::determinant(&matrix).unwrap(), 1);
     }
 
     #[test]
     fn test_determinant_2x2() {
-        let mut matrix = Matrix {
+        let matrix = Matrix {
             mat: vec![Arc::new([1, 2]), Arc::new([3, 4])],
             rows: 2,
             cols: 2,
         };
 
-        assert_eq!(MatrixUtilities::determinant(&mut matrix).unwrap(), -2);
+        assert_eq!(MatrixUtilities::determinant(&matrix).unwrap(), -2);
     }
 
     #[test]
     fn test_determinant_3x3() {
-        let mut matrix = Matrix {
+        let matrix = Matrix {
             mat: vec![
                 Arc::new([1, 2, 3]),
                 Arc::new([0, 1, 4]),
@@ -37,13 +41,13 @@ mod determinant_tests {
             cols: 3,
         };
 
-        let result = MatrixUtilities::determinant(&mut matrix);
+        let result = MatrixUtilities::determinant(&matrix);
         assert_eq!(result.unwrap(), 1);
     }
 
     #[test]
     fn test_determinant_4x4() {
-        let mut matrix = Matrix {
+        let matrix = Matrix {
             mat: vec![
                 Arc::new([1, 0, 2, -1]),
                 Arc::new([3, 0, 0, 5]),
@@ -53,18 +57,122 @@ mod determinant_tests {
             rows: 4,
             cols: 4,
         };
-        assert_eq!(MatrixUtilities::determinant(&mut matrix).unwrap(), 30);
+        assert_eq!(MatrixUtilities::determinant(&matrix).unwrap(), 30);
     }
 
     #[test]
     fn test_non_square_matrix() {
-        let mut matrix = Matrix {
+        let matrix = Matrix {
             mat: vec![Arc::new([1, 2, 3]), Arc::new([4, 5, 6])],
             rows: 2,
             cols: 3,
         };
 
-        let result = MatrixUtilities::determinant(&mut matrix);
+        let result = MatrixUtilities::determinant(&matrix);
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_determinant_fast_matches_cofactor_expansion() {
+        let matrix = Matrix {
+            mat: vec![
+                Arc::from([2.0, -3.0, 1.0, 5.0]),
+                Arc::from([4.0, 0.5, -2.0, 1.0]),
+                Arc::from([0.0, 7.0, 3.0, -1.0]),
+                Arc::from([1.0, 1.0, 1.0, 1.0]),
+            ],
+            rows: 4,
+            cols: 4,
+        };
+
+        let expected: f64 = MatrixUtilities::determinant(&matrix).unwrap();
+        let fast = MatrixUtilities::determinant_fast(&matrix).unwrap();
+        assert!((fast - expected).abs() < 1e-9 * expected.abs());
+    }
+
+    #[test]
+    fn test_determinant_fast_edge_cases() {
+        let singular = Matrix {
+            mat: vec![Arc::from([1.0, 2.0]), Arc::from([2.0, 4.0])],
+            rows: 2,
+            cols: 2,
+        };
+        let swapped = Matrix {
+            mat: vec![Arc::from([0.0, 1.0]), Arc::from([1.0, 0.0])],
+            rows: 2,
+            cols: 2,
+        };
+
+        assert_eq!(MatrixUtilities::determinant_fast(&singular), Ok(0.0));
+        assert_eq!(MatrixUtilities::determinant_fast(&swapped), Ok(-1.0));
+        assert_eq!(
+            MatrixUtilities::<f64>::determinant_fast(&Matrix::default()),
+            Ok(1.0)
+        );
+        assert!(MatrixUtilities::determinant_fast(&Matrix {
+            mat: vec![Arc::from([1.0, 2.0])],
+            rows: 1,
+            cols: 2,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_determinant_fast_large_matrix() {
+        // A tridiagonal matrix with 2 on the diagonal and -1 beside it has determinant n + 1
+        let n: usize = 100;
+        let mat = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| match i.abs_diff(j) {
+                        0 => 2.0,
+                        1 => -1.0,
+                        _ => 0.0,
+                    })
+                    .collect::<Vec<f64>>()
+                    .into()
+            })
+            .collect();
+        let matrix = Matrix {
+            mat,
+            rows: n,
+            cols: n,
+        };
+
+        let det = MatrixUtilities::determinant_fast(&matrix).unwrap();
+        assert!((det - 101.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_determinant_of_product() {
+        // det(a) = 18, det(b) = -5 and det(c) = 1
+        let a = matrix!([2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]);
+        let b = matrix!([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 5.0]);
+        let c = matrix!([1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]);
+        let ab = MatrixUtilities::multiply(&a, &b).unwrap();
+        let abc = MatrixUtilities::multiply(&ab, &c).unwrap();
+
+        let det: f64 = MatrixUtilities::determinant_of_product(&[&a, &b, &c]).unwrap();
+        assert!((det - MatrixUtilities::determinant_fast(&abc).unwrap()).abs() < 1e-9);
+        assert!((det + 90.0).abs() < 1e-9);
+        assert_eq!(MatrixUtilities::<f64>::determinant_of_product(&[]), Ok(1.0));
+    }
+
+    #[test]
+    fn test_determinant_of_product_errors() {
+        let square = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let wide = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+
+        assert_eq!(
+            MatrixUtilities::determinant_of_product(&[&wide, &square]),
+            Err(LinalgError::NonSquare { rows: 2, cols: 3 })
+        );
+        assert_eq!(
+            MatrixUtilities::determinant_of_product(&[&square, &wide]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+    }
 }
@@ -0,0 +1,81 @@
+#![allow(deprecated)]
+
+mod diff_report_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_identical_matrices() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        let report = MatrixUtilities::diff_report(&a, &a, 0.0).unwrap();
+        assert!(report.is_match());
+        assert_eq!(report.differing, 0);
+        assert_eq!(report.max_abs_diff, 0.0);
+        assert!(report.worst.is_empty());
+    }
+
+    #[test]
+    fn test_differences_sorted_worst_first() {
+        let a = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+        let b = matrix!([1.0, 2.5, 3.0], [1.0, 5.0, 6.0001]);
+
+        let report = MatrixUtilities::diff_report(&a, &b, 1e-3).unwrap();
+        assert!(!report.is_match());
+        assert_eq!(report.differing, 2);
+        assert_eq!(report.max_abs_diff, 3.0);
+        assert_eq!((report.worst[0].row, report.worst[0].col), (1, 0));
+        assert_eq!((report.worst[1].row, report.worst[1].col), (0, 1));
+        assert_eq!(report.worst[1].abs_diff, 0.5);
+    }
+
+    #[test]
+    fn test_integer_matrices_and_truncation() {
+        let a = matrix!([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let b = matrix!([1, -2, 3, -4, 5, -6, 7, -8, 9, -10, 11, -12]);
+
+        let report = MatrixUtilities::diff_report(&a, &b, 0).unwrap();
+        assert_eq!(report.differing, 12);
+        assert_eq!(report.max_abs_diff, 12);
+        assert_eq!(report.worst.len(), 10);
+        assert_eq!(report.worst[0].abs_diff, 12);
+
+        let text = format!("{}", report);
+        assert!(text.starts_with("12 of 12 cells differ by more than 0"));
+        assert!(text.contains("... and 2 more"));
+    }
+
+    #[test]
+    fn test_nan_is_reported() {
+        let a = matrix!([1.0, f64::NAN]);
+        let b = matrix!([1.0, 2.0]);
+
+        let report = MatrixUtilities::diff_report(&a, &b, 1.0).unwrap();
+        assert_eq!(report.differing, 1);
+        assert_eq!(report.worst[0].col, 1);
+    }
+
+    #[test]
+    fn test_nan_ranks_as_worst() {
+        let a = matrix!([1.0, f64::NAN, 50.0], [f64::NAN, 7.0, 1.0]);
+        let b = matrix!([2.0, 0.0, 0.0], [0.0, 0.0, 1.0]);
+
+        let report = MatrixUtilities::diff_report(&a, &b, 0.5).unwrap();
+        assert_eq!(report.differing, 5);
+        assert!(report.max_abs_diff.is_nan());
+        assert!(report.worst[0].abs_diff.is_nan());
+        assert!(report.worst[1].abs_diff.is_nan());
+        let finite: Vec<f64> = report.worst[2..].iter().map(|d| d.abs_diff).collect();
+        assert_eq!(finite, vec![50.0, 7.0, 1.0]);
+    }
+
+    #[test]
+    fn test_shape_mismatch() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([1, 2]);
+
+        assert!(MatrixUtilities::diff_report(&a, &b, 0).is_err());
+    }
+}
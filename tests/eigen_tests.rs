@@ -0,0 +1,456 @@
+#![allow(deprecated)]
+
+mod eigen_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::eigen::{
+        balance, eigenvalue_bounds, eigenvalues, gershgorin_discs, orthogonal_iteration,
+        rayleigh_quotient, rayleigh_quotient_iteration, top_k, GershgorinDisc,
+    };
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::{Matrix, NormKind};
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::vector;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_symmetric_eigen_reconstructs_matrix() {
+        let a = matrix!(
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0]
+        );
+        let (values, v) = MatrixUtilities::symmetric_eigen(&a).unwrap();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut d = Matrix::zeros(4, 4);
+        for (i, &x) in values.iter().enumerate() {
+            d.mat[i] = (0..4).map(|j| if i == j { x } else { 0.0 }).collect();
+        }
+        let vd = MatrixUtilities::multiply(&v, &d).unwrap();
+        let vt = MatrixUtilities::transpose(&v);
+        assert_matrix_eq!(MatrixUtilities::multiply(&vd, &vt).unwrap(), a, 1e-10);
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&vt, &v).unwrap(),
+            MatrixUtilities::identity(4),
+            1e-10,
+        );
+        let trace: f64 = values.iter().sum();
+        assert!(approx_eq!(f64, trace, 8.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_symmetric_eigen_known_values() {
+        let (values, _) =
+            MatrixUtilities::symmetric_eigen(&matrix!([2.0, 1.0], [1.0, 2.0])).unwrap();
+        assert!(approx_eq!(f64, values[0], 1.0, epsilon = 1e-12));
+        assert!(approx_eq!(f64, values[1], 3.0, epsilon = 1e-12));
+
+        let (values, v) =
+            MatrixUtilities::symmetric_eigen(&matrix!([5.0, 0.0], [0.0, -1.0])).unwrap();
+        assert_eq!(values, vec![-1.0, 5.0]);
+        assert_eq!(v, matrix!([0.0, 1.0], [1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_symmetric_eigen_errors() {
+        assert!(matches!(
+            MatrixUtilities::symmetric_eigen(&matrix!([1.0, 2.0], [0.0, 1.0])),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            MatrixUtilities::symmetric_eigen(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_rayleigh_quotient() {
+        let a = matrix!([2.0, 1.0], [1.0, 2.0]);
+
+        assert_eq!(rayleigh_quotient(&a, &vector![1.0, 1.0]).unwrap(), 3.0);
+        assert_eq!(rayleigh_quotient(&a, &vector![1.0, -1.0]).unwrap(), 1.0);
+        assert_eq!(rayleigh_quotient(&a, &vector![1.0, 0.0]).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_errors() {
+        let a = matrix!([2.0, 1.0], [1.0, 2.0]);
+
+        assert!(matches!(
+            rayleigh_quotient(&a, &vector![0.0, 0.0]),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            rayleigh_quotient(&a, &vector![1.0]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            rayleigh_quotient(&matrix!([1.0, 2.0]), &vector![1.0, 1.0]),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_iteration_refines_guess() {
+        let a = matrix!([4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]);
+        let (values, _) = MatrixUtilities::symmetric_eigen(&a).unwrap();
+
+        let pair = rayleigh_quotient_iteration(&a, &vector![1.0, 0.5, 0.1], 1e-12, 20).unwrap();
+        assert!(pair.iterations <= 5);
+        assert!(approx_eq!(f64, pair.value, values[2], epsilon = 1e-10));
+        assert!(approx_eq!(f64, pair.vector.norm(), 1.0, epsilon = 1e-12));
+        let ax = MatrixUtilities::multiply(&a, &pair.vector.to_column_matrix()).unwrap();
+        for i in 0..3 {
+            assert!(approx_eq!(
+                f64,
+                ax.mat[i][0],
+                pair.value * pair.vector.elements[i],
+                epsilon = 1e-10
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_iteration_exact_eigenvector() {
+        let a = matrix!([2.0, 0.0], [0.0, 5.0]);
+
+        let pair = rayleigh_quotient_iteration(&a, &vector![0.0, 3.0], 1e-12, 10).unwrap();
+        assert_eq!(pair.value, 5.0);
+        assert_eq!(pair.vector, vector![0.0, 1.0]);
+        assert_eq!(pair.iterations, 0);
+    }
+
+    #[test]
+    fn test_rayleigh_quotient_iteration_errors() {
+        let a = matrix!([2.0, 1.0], [1.0, 2.0]);
+
+        assert!(matches!(
+            rayleigh_quotient_iteration(&a, &vector![1.0, 0.0], 0.0, 10),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            rayleigh_quotient_iteration(&a, &vector![1.0, 0.0], 1e-12, 0),
+            Err(LinalgError::NotConverged { iterations: 0 })
+        );
+        assert!(matches!(
+            rayleigh_quotient_iteration(
+                &matrix!([f64::NAN, 0.0], [0.0, 1.0]),
+                &vector![1.0, 0.0],
+                1e-12,
+                10
+            ),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_gershgorin_discs() {
+        let a = matrix!([10.0, -1.0, 2.0], [0.5, 4.0, -0.5], [1.0, 1.0, -3.0]);
+
+        assert_eq!(
+            gershgorin_discs(&a).unwrap(),
+            vec![
+                GershgorinDisc {
+                    center: 10.0,
+                    radius: 3.0
+                },
+                GershgorinDisc {
+                    center: 4.0,
+                    radius: 1.0
+                },
+                GershgorinDisc {
+                    center: -3.0,
+                    radius: 2.0
+                },
+            ]
+        );
+        assert_eq!(eigenvalue_bounds(&a).unwrap(), (-5.0, 13.0));
+    }
+
+    #[test]
+    fn test_eigenvalue_bounds_contain_spectrum() {
+        let a = matrix!([4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]);
+        let (values, _) = MatrixUtilities::symmetric_eigen(&a).unwrap();
+
+        let (lower, upper) = eigenvalue_bounds(&a).unwrap();
+        assert!(values.iter().all(|&v| lower <= v && v <= upper));
+    }
+
+    #[test]
+    fn test_gershgorin_errors() {
+        assert_eq!(
+            gershgorin_discs(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(gershgorin_discs::<f64>(&Matrix::zeros(0, 0)), Ok(vec![]));
+        assert_eq!(
+            eigenvalue_bounds::<f64>(&Matrix::zeros(0, 0)),
+            Err(LinalgError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_top_k_matches_full_spectrum() {
+        let a = matrix!(
+            [6.0, 2.0, 1.0, 0.0],
+            [2.0, 5.0, 0.0, 1.0],
+            [1.0, 0.0, -9.0, 0.5],
+            [0.0, 1.0, 0.5, 1.0]
+        );
+        let (mut values, _) = MatrixUtilities::symmetric_eigen(&a).unwrap();
+        values.sort_by(|p: &f64, q| q.abs().total_cmp(&p.abs()));
+
+        let pairs = top_k(&a, 2, 1e-10, 1000).unwrap();
+        assert_eq!(pairs.len(), 2);
+        for (pair, &expected) in pairs.iter().zip(&values) {
+            assert!(approx_eq!(f64, pair.value, expected, epsilon = 1e-8));
+            let ax = MatrixUtilities::multiply(&a, &pair.vector.to_column_matrix()).unwrap();
+            for i in 0..4 {
+                assert!(approx_eq!(
+                    f64,
+                    ax.mat[i][0],
+                    pair.value * pair.vector.elements[i],
+                    epsilon = 1e-8
+                ));
+            }
+        }
+        assert!(pairs[0].vector.dot(&pairs[1].vector).unwrap().abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_top_k_edge_cases() {
+        let a = matrix!([2.0, 0.0], [0.0, 0.0]);
+
+        assert_eq!(top_k(&a, 0, 1e-10, 10).unwrap(), vec![]);
+        let pairs = top_k(&a, 2, 1e-10, 100).unwrap();
+        assert!(approx_eq!(f64, pairs[0].value, 2.0, epsilon = 1e-10));
+        assert!(approx_eq!(f64, pairs[1].value, 0.0, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn test_top_k_errors() {
+        let a = matrix!([2.0, 1.0], [1.0, 2.0]);
+
+        assert!(matches!(
+            top_k(&a, 3, 1e-10, 10),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            top_k(&matrix!([1.0, 2.0], [0.0, 1.0]), 1, 1e-10, 10),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            top_k(&matrix!([1.0, 2.0]), 1, 1e-10, 10),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        // Eigenvalues 3 and -3 share a magnitude, so power iteration cannot settle
+        assert_eq!(
+            top_k(&matrix!([3.0, 0.0], [0.0, -3.0]), 1, 1e-10, 50),
+            Err(LinalgError::NotConverged { iterations: 50 })
+        );
+    }
+
+    #[test]
+    fn test_eigenvalues_real_and_complex() {
+        // Upper triangular, so the eigenvalues are the diagonal
+        let triangular = matrix!([3.0, 1.0, 4.0], [0.0, -2.0, 5.0], [0.0, 0.0, 1.0]);
+        let values = eigenvalues(&triangular).unwrap();
+        for (value, expected) in values.iter().zip([-2.0, 1.0, 3.0]) {
+            assert!(approx_eq!(f64, value.re, expected, epsilon = 1e-12));
+            assert_eq!(value.im, 0.0);
+        }
+
+        // A rotation by 90° has eigenvalues ±i; the block adds a real eigenvalue 2
+        let rotation = matrix!([0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 2.0]);
+        let values = eigenvalues(&rotation).unwrap();
+        assert_eq!(values.len(), 3);
+        assert!(approx_eq!(f64, values[0].re, 0.0, epsilon = 1e-12));
+        assert!(approx_eq!(f64, values[0].im, -1.0, epsilon = 1e-12));
+        assert!(approx_eq!(f64, values[1].im, 1.0, epsilon = 1e-12));
+        assert!(approx_eq!(f64, values[2].re, 2.0, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_eigenvalues_match_invariants() {
+        let a = matrix!(
+            [4.0, -2.0, 1.0, 3.0, 0.5],
+            [1.0, 5.0, -3.0, 0.0, 2.0],
+            [2.0, 1.0, -1.0, 4.0, 1.0],
+            [-3.0, 0.0, 2.0, 2.0, -1.0],
+            [1.0, 2.0, 0.0, -2.0, 3.0]
+        );
+
+        let values = eigenvalues(&a).unwrap();
+        let sum = values
+            .iter()
+            .fold(num::Complex::new(0.0, 0.0), |s, v| s + v);
+        let product = values
+            .iter()
+            .fold(num::Complex::new(1.0, 0.0), |p, v| p * v);
+        let det = linalgrs::solve::determinant_fast(&a).unwrap();
+        assert!(approx_eq!(f64, sum.re, a.trace().unwrap(), epsilon = 1e-10));
+        assert!(sum.im.abs() < 1e-10);
+        assert!(approx_eq!(f64, product.re, det, epsilon = 1e-8 * det.abs()));
+        assert!(product.im.abs() < 1e-8 * det.abs());
+    }
+
+    #[test]
+    fn test_eigenvalues_errors() {
+        assert_eq!(
+            eigenvalues(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            eigenvalues(&matrix!([f64::NAN])),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+        assert_eq!(eigenvalues(&Matrix::<f64>::zeros(0, 0)), Ok(vec![]));
+    }
+
+    /// `D M D⁻¹` for `D = diag(2⁻²⁰, 1, 2²⁰)`, computed exactly because every scale
+    /// factor is a power of two
+    fn badly_scaled(m: &Matrix<f64>) -> Matrix<f64> {
+        let d = [2f64.powi(-20), 1.0, 2f64.powi(20)];
+        Matrix {
+            mat: (0..3)
+                .map(|i| (0..3).map(|j| d[i] * m.mat[i][j] / d[j]).collect())
+                .collect(),
+            rows: 3,
+            cols: 3,
+        }
+    }
+
+    #[test]
+    fn test_balance() {
+        let m = matrix!([1.0, 2.0, 0.5], [3.0, 4.0, 5.0], [0.25, 6.0, 7.0]);
+        let a = badly_scaled(&m);
+
+        let balanced = balance(&a).unwrap();
+        let norm = |x: &Matrix<f64>| x.norm(NormKind::One).unwrap();
+        assert!(norm(&balanced.matrix) < 1e-4 * norm(&a));
+        assert!(balanced.scale.iter().all(|s| s.log2().fract() == 0.0));
+        for i in 0..3 {
+            for j in 0..3 {
+                let scaled = a.mat[i][j] * balanced.scale[j] / balanced.scale[i];
+                assert_eq!(balanced.matrix.mat[i][j], scaled);
+            }
+        }
+
+        // An already balanced matrix is left alone
+        let symmetric = matrix!([2.0, 1.0], [1.0, 3.0]);
+        assert_eq!(balance(&symmetric).unwrap().scale, vec![1.0, 1.0]);
+        assert_eq!(
+            balance(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn test_balance_back_transform() {
+        // x = [1, 1] is an eigenvector of A for λ = 3
+        let a = matrix!([1.0, 2e6], [1e-6, 2.0]);
+        let x = [1.0, 1e-6];
+        let balanced = balance(&a).unwrap();
+
+        // y = D⁻¹x is the matching eigenvector of the balanced matrix
+        let y = matrix!([x[0] / balanced.scale[0]], [x[1] / balanced.scale[1]]);
+        let by = MatrixUtilities::multiply(&balanced.matrix, &y).unwrap();
+        for i in 0..2 {
+            assert!(approx_eq!(f64, by.mat[i][0], 3.0 * y.mat[i][0], ulps = 4));
+        }
+        assert_eq!(
+            balanced.back_transform(&y).unwrap(),
+            matrix!([x[0]], [x[1]])
+        );
+        assert_eq!(
+            balanced.back_transform(&matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_eigenvalues_of_badly_scaled_matrix() {
+        let m = matrix!([1.0, 2.0, 0.5], [3.0, 4.0, 5.0], [0.25, 6.0, 7.0]);
+
+        let expected = eigenvalues(&m).unwrap();
+        let found = eigenvalues(&badly_scaled(&m)).unwrap();
+        for (f, e) in found.iter().zip(&expected) {
+            assert!((f - e).norm() <= 1e-12 * e.norm());
+        }
+    }
+
+    #[test]
+    fn test_orthogonal_iteration_diagonal() {
+        let a = matrix!(
+            [5.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -3.0, 0.0],
+            [0.0, 0.0, 0.0, 0.2]
+        );
+
+        let q: Matrix<f64> = orthogonal_iteration(&a, 2, 100).unwrap();
+        let qt = MatrixUtilities::transpose(&q);
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&qt, &q).unwrap(),
+            MatrixUtilities::identity(2),
+            1e-12
+        );
+        // Only the eigenvectors of 5 and -3 remain
+        assert!(q.mat[1]
+            .iter()
+            .chain(q.mat[3].iter())
+            .all(|x| x.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_orthogonal_iteration_invariant_subspace() {
+        // Not symmetric, with eigenvalues 6, 4, 1, and 0.5
+        let a = matrix!(
+            [6.0, 2.0, 1.0, 0.0],
+            [0.0, 4.0, 3.0, 1.0],
+            [0.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 0.0, 0.5]
+        );
+
+        let q: Matrix<f64> = orthogonal_iteration(&a, 2, 200).unwrap();
+        let aq = MatrixUtilities::multiply(&a, &q).unwrap();
+        let projected = MatrixUtilities::multiply(
+            &q,
+            &MatrixUtilities::multiply(&MatrixUtilities::transpose(&q), &aq).unwrap(),
+        )
+        .unwrap();
+        assert_matrix_eq!(aq, projected, 1e-10);
+        // The leading subspace of an upper triangular matrix is spanned by e₁ and e₂
+        assert!(q.mat[2]
+            .iter()
+            .chain(q.mat[3].iter())
+            .all(|x| x.abs() < 1e-10));
+    }
+
+    #[test]
+    fn test_orthogonal_iteration_errors() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        assert_eq!(orthogonal_iteration(&a, 0, 10), Ok(Matrix::zeros(2, 0)));
+        assert!(matches!(
+            orthogonal_iteration(&a, 3, 10),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            orthogonal_iteration(&matrix!([1.0, 2.0]), 1, 10),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            orthogonal_iteration(&matrix!([f64::INFINITY]), 1, 10),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+    }
+}
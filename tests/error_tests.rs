@@ -0,0 +1,64 @@
+#![allow(deprecated)]
+
+mod error_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_shape_mismatch_reports_shapes() {
+        let a = matrix!([1, 2, 3]);
+        let b = matrix!([1, 2]);
+
+        assert_eq!(
+            MatrixUtilities::add(&a, &b).unwrap_err(),
+            LinalgError::ShapeMismatch {
+                expected: (1, 3),
+                found: (1, 2)
+            }
+        );
+        assert_eq!(
+            MatrixUtilities::multiply(&a, &b).unwrap_err(),
+            LinalgError::ShapeMismatch {
+                expected: (3, 2),
+                found: (1, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_structured_variants() {
+        let m = matrix!([1.0, 2.0], [2.0, 4.0]);
+
+        assert_eq!(
+            m.sub_matrix(0..3, 0..1).unwrap_err(),
+            LinalgError::OutOfBounds { index: 3, len: 2 }
+        );
+        assert_eq!(
+            MatrixUtilities::inverse(m.clone()).unwrap_err(),
+            LinalgError::Singular
+        );
+        assert_eq!(
+            MatrixUtilities::inverse(matrix!([1.0, 2.0])).unwrap_err(),
+            LinalgError::NonSquare { rows: 1, cols: 2 }
+        );
+        assert_eq!(
+            MatrixUtilities::<f64>::qr_decomposition(&Matrix::default()).unwrap_err(),
+            LinalgError::Empty
+        );
+    }
+
+    #[test]
+    fn test_display_and_string_conversion() {
+        let err = LinalgError::NonSquare { rows: 2, cols: 3 };
+        let message: String = err.clone().into();
+
+        assert_eq!(message, "Expected a square matrix but got 2x3");
+        assert_eq!(message, err.to_string());
+
+        let boxed: Box<dyn std::error::Error> = Box::new(LinalgError::NoSolution);
+        assert_eq!(boxed.to_string(), "No solution exists for the given matrix");
+    }
+}
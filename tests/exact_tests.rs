@@ -0,0 +1,94 @@
+mod exact_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::exact::ExactMatrix;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use num::{BigInt, BigRational};
+    use std::sync::Arc;
+
+    fn rational(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(numer.into(), denom.into())
+    }
+
+    fn rationals(rows: &[&[(i64, i64)]]) -> ExactMatrix<BigRational> {
+        ExactMatrix::from_rows(
+            rows.iter()
+                .map(|row| row.iter().map(|&(n, d)| rational(n, d)).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_determinant_of_integers() {
+        let m: ExactMatrix<BigInt> =
+            ExactMatrix::from(&matrix!([2, -1, 0], [-1, 2, -1], [0, -1, 2]));
+        assert_eq!(m.determinant(), Ok(BigInt::from(4)));
+
+        // A zero in the corner forces a row exchange
+        let swapped: ExactMatrix<BigInt> = ExactMatrix::from(&matrix!([0, 1], [1, 0]));
+        assert_eq!(swapped.determinant(), Ok(BigInt::from(-1)));
+
+        let singular: ExactMatrix<BigInt> = ExactMatrix::from(&matrix!([1, 2], [2, 4]));
+        assert_eq!(singular.determinant(), Ok(BigInt::from(0)));
+    }
+
+    #[test]
+    fn test_determinant_of_hilbert_matrix() {
+        // The Hilbert matrix has det 1/(product of large binomials); for n = 8 it is about
+        // 2.7e-33, far below what elimination in f64 recovers accurately
+        let n = 8;
+        let hilbert = ExactMatrix::from_rows(
+            (0..n)
+                .map(|i| (0..n).map(|j| rational(1, i + j + 1)).collect())
+                .collect(),
+        )
+        .unwrap();
+        let det = hilbert.determinant().unwrap();
+
+        let expected: BigInt = "365356847125734485878112256000000".parse().unwrap();
+        assert_eq!(det, BigRational::new(1.into(), expected));
+    }
+
+    #[test]
+    fn test_rref_is_exact() {
+        let m = rationals(&[&[(1, 3), (1, 1), (2, 1)], &[(2, 3), (1, 1), (5, 1)]]);
+
+        assert_eq!(
+            m.rref(),
+            rationals(&[&[(1, 1), (0, 1), (9, 1)], &[(0, 1), (1, 1), (-1, 1)]])
+        );
+    }
+
+    #[test]
+    fn test_rref_with_row_exchange_and_free_column() {
+        let m: ExactMatrix<BigRational> =
+            ExactMatrix::<BigInt>::from(&matrix!([0, 0, 1], [2, 4, 6], [1, 2, 5]))
+                .map(BigRational::from_integer);
+
+        assert_eq!(
+            m.rref(),
+            rationals(&[
+                &[(1, 1), (2, 1), (0, 1)],
+                &[(0, 1), (0, 1), (1, 1)],
+                &[(0, 1), (0, 1), (0, 1)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_exact_matrix_errors_and_display() {
+        assert!(matches!(
+            ExactMatrix::from_rows(vec![vec![BigInt::from(1)], vec![]]),
+            Err(LinalgError::Malformed(_))
+        ));
+        let wide: ExactMatrix<BigInt> = ExactMatrix::from(&matrix!([1, 2, 3]));
+        assert_eq!(
+            wide.determinant(),
+            Err(LinalgError::NonSquare { rows: 1, cols: 3 })
+        );
+
+        let half = rationals(&[&[(1, 2), (-3, 1)]]);
+        assert_eq!(half.to_string(), "| 1/2 -3 |\n");
+    }
+}
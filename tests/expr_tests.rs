@@ -0,0 +1,270 @@
+mod expr_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::expr::{Expr, ShapeIssue};
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{arith, assert_matrix_eq, matrix, solve, transform};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_eval_normal_equations() {
+        let a_matrix = matrix!([1.0, 0.0], [1.0, 1.0], [1.0, 2.0]);
+        let b_matrix = matrix!([1.0], [2.0], [4.0]);
+        let a = Expr::input("A", a_matrix.clone());
+        let b = Expr::input("b", b_matrix.clone());
+
+        let at = a.transpose();
+        let x = at.multiply(&a).named("gram").solve(&at.multiply(&b));
+        assert_eq!(x.shape(), Ok((2, 1)));
+
+        let at_matrix = transform::transpose(&a_matrix);
+        let expected = solve::solve(
+            &arith::multiply(&at_matrix, &a_matrix).unwrap(),
+            &arith::multiply(&at_matrix, &b_matrix).unwrap(),
+        )
+        .unwrap();
+        assert_matrix_eq!(x.eval().unwrap(), expected, 1e-12);
+        assert_matrix_eq!(
+            a.scale(2.0).subtract(&a).add(&a).eval().unwrap(),
+            arith::multiply_by_scalar(&a_matrix, 2.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_display_marks_the_failing_node() {
+        let a = Expr::input("A", matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]));
+        let b = Expr::input("B", matrix!([1.0, 2.0, 3.0]));
+
+        let bad = a.multiply(&a).named("square").add(&b);
+        assert_eq!(
+            bad.shape(),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (3, 2)
+            })
+        );
+        assert_eq!(
+            bad.to_string(),
+            "add: ?\n\
+             \x20 square = multiply: error: Expected a 2x2 matrix but got 3x2\n\
+             \x20   A: 3x2\n\
+             \x20   A: 3x2\n\
+             \x20 B: 1x3\n"
+        );
+        assert_eq!(
+            bad.eval(),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_shared_nodes() {
+        let a = Expr::input("A", matrix!([1.0, 2.0], [3.0, 4.0]));
+        let gram = a.transpose().multiply(&a);
+        let sum = gram.add(&gram);
+
+        assert_eq!(
+            sum.to_string(),
+            "add: 2x2\n\
+             \x20 multiply: 2x2\n\
+             \x20   transpose: 2x2\n\
+             \x20     A: 2x2\n\
+             \x20   A: 2x2\n\
+             \x20 multiply: (shown above)\n"
+        );
+        assert_eq!(
+            sum.to_dot(),
+            "digraph expr {\n\
+             \x20   n0 [label=\"A\\n2x2\"];\n\
+             \x20   n1 [label=\"transpose\\n2x2\"];\n\
+             \x20   n0 -> n1;\n\
+             \x20   n2 [label=\"multiply\\n2x2\"];\n\
+             \x20   n1 -> n2;\n\
+             \x20   n0 -> n2;\n\
+             \x20   n3 [label=\"add\\n2x2\"];\n\
+             \x20   n2 -> n3;\n\
+             \x20   n2 -> n3;\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_dot_marks_errors_in_red() {
+        let a = Expr::input("A", matrix!([1.0, 2.0]));
+        let dot = a.solve(&a).to_dot();
+
+        assert!(dot.contains(
+            "n1 [label=\"solve\\nerror: Expected a square matrix but got 1x2\", color=red];"
+        ));
+        assert_eq!(a.name(), Some("A"));
+        assert_eq!(a.transpose().name(), None);
+    }
+
+    #[test]
+    fn test_validate_reports_every_mismatch() {
+        let a = Expr::input("A", matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]));
+        let b = Expr::input("b", matrix!([1.0], [2.0]));
+        let c = Expr::input("C", matrix!([1.0, 2.0]));
+
+        let fitted = a.multiply(&a).named("bad_gram");
+        let residual = b.subtract(&c).named("residual");
+        let total = fitted.add(&residual).solve(&b);
+        let issues = total.validate().unwrap_err();
+
+        assert_eq!(
+            issues,
+            vec![
+                ShapeIssue {
+                    node: 1,
+                    label: "bad_gram = multiply".to_string(),
+                    error: LinalgError::ShapeMismatch {
+                        expected: (2, 2),
+                        found: (3, 2)
+                    }
+                },
+                ShapeIssue {
+                    node: 4,
+                    label: "residual = subtract".to_string(),
+                    error: LinalgError::ShapeMismatch {
+                        expected: (2, 1),
+                        found: (1, 2)
+                    }
+                },
+            ]
+        );
+        assert_eq!(
+            issues[1].to_string(),
+            "n4 (residual = subtract): Expected a 2x1 matrix but got 1x2"
+        );
+        assert!(total
+            .to_dot()
+            .contains("n4 [label=\"residual = subtract\\nerror:"));
+    }
+
+    #[test]
+    fn test_validate_solve() {
+        let a = Expr::input("A", matrix!([2.0, 1.0], [1.0, 3.0]));
+        let b = Expr::input("b", matrix!([1.0], [2.0]));
+
+        assert_eq!(a.multiply(&a).solve(&b).validate(), Ok((2, 1)));
+        assert_eq!(
+            a.solve(&b.transpose()).validate(),
+            Err(vec![ShapeIssue {
+                node: 3,
+                label: "solve".to_string(),
+                error: LinalgError::ShapeMismatch {
+                    expected: (2, 2),
+                    found: (1, 2)
+                }
+            }])
+        );
+    }
+
+    #[test]
+    fn test_eval_inverse() {
+        let a_matrix = matrix!([1.0, 0.0], [1.0, 1.0], [1.0, 2.0]);
+        let b_matrix = matrix!([1.0], [2.0], [4.0]);
+        let a = Expr::input("A", a_matrix.clone());
+        let b = Expr::input("b", b_matrix.clone());
+
+        let at = a.transpose();
+        let x = at.multiply(&a).inverse().multiply(&at).multiply(&b);
+        let solved = at.multiply(&a).solve(&at.multiply(&b));
+        assert_matrix_eq!(x.eval().unwrap(), solved.eval().unwrap(), 1e-12);
+
+        let gram = arith::multiply(&transform::transpose(&a_matrix), &a_matrix).unwrap();
+        assert_matrix_eq!(
+            at.multiply(&a).inverse().eval().unwrap(),
+            solve::solve(&gram, &arith::identity(2)).unwrap(),
+            1e-12
+        );
+        assert_eq!(
+            a.inverse().shape(),
+            Err(LinalgError::NonSquare { rows: 3, cols: 2 })
+        );
+        assert_eq!(
+            at.multiply(&a).inverse().multiply(&a).eval(),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (3, 2)
+            })
+        );
+        let singular = Expr::input("S", matrix!([1.0, 2.0], [2.0, 4.0]));
+        assert!(matches!(
+            singular.inverse().multiply(&singular).eval(),
+            Err(LinalgError::Singular)
+        ));
+    }
+
+    #[test]
+    fn test_eval_folds_scale_and_transpose() {
+        let a_matrix = matrix!([1.0, -2.0, 0.5], [3.0, 4.0, -1.0]);
+        let c_matrix = matrix!([2.0, 1.0], [0.0, -3.0], [1.5, 2.5]);
+        let a = Expr::input("A", a_matrix.clone());
+        let c = Expr::input("C", c_matrix.clone());
+        let (at_matrix, ct_matrix) = (
+            transform::transpose(&a_matrix),
+            transform::transpose(&c_matrix),
+        );
+
+        let product = |x: &Matrix<f64>, y: &Matrix<f64>| arith::multiply(x, y).unwrap();
+        let cases = [
+            (a.multiply(&c), product(&a_matrix, &c_matrix)),
+            (
+                a.scale(2.0).multiply(&c.scale(-0.5)),
+                arith::multiply_by_scalar(&product(&a_matrix, &c_matrix), -1.0),
+            ),
+            (
+                a.transpose().multiply(&c.transpose()),
+                product(&at_matrix, &ct_matrix),
+            ),
+            (
+                a.multiply(&c).transpose().scale(3.0),
+                arith::multiply_by_scalar(&product(&ct_matrix, &at_matrix), 3.0),
+            ),
+            (
+                c.transpose()
+                    .transpose()
+                    .multiply(&a.scale(0.5).transpose().transpose()),
+                arith::multiply_by_scalar(&product(&c_matrix, &a_matrix), 0.5),
+            ),
+            (
+                a.transpose().scale(-1.0),
+                arith::multiply_by_scalar(&at_matrix, -1.0),
+            ),
+        ];
+        for (expr, expected) in cases {
+            assert_matrix_eq!(expr.eval().unwrap(), expected, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_eval_repeated_subexpressions() {
+        let a_matrix = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let a = Expr::input("A", a_matrix.clone());
+        let other = Expr::input("A", a_matrix.clone());
+
+        let square = arith::multiply(&a_matrix, &a_matrix).unwrap();
+        assert_matrix_eq!(
+            a.multiply(&a).add(&a.multiply(&a)).eval().unwrap(),
+            arith::multiply_by_scalar(&square, 2.0),
+            0.0
+        );
+        assert_matrix_eq!(
+            a.scale(2.0).add(&a.scale(3.0)).eval().unwrap(),
+            arith::multiply_by_scalar(&a_matrix, 5.0),
+            0.0
+        );
+        assert_matrix_eq!(
+            a.multiply(&other)
+                .subtract(&other.multiply(&a))
+                .eval()
+                .unwrap(),
+            Matrix::zeros(2, 2),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,124 @@
+mod factorization_cache_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::factorization_cache::{Factorization, FactorizationCache, FactorizationKind};
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{assert_matrix_eq, decompose, solve};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_repeated_solves_factor_once() {
+        let spd = matrix!([4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]);
+        let general = matrix!([0.0, 2.0, 1.0], [1.0, -1.0, 4.0], [3.0, 5.0, -2.0]);
+        let b = matrix!([1.0, 0.0], [2.0, 1.0], [3.0, -1.0]);
+        let mut cache = FactorizationCache::new(8);
+
+        for a in [&spd, &general] {
+            let expected = solve::solve(a, &b).unwrap();
+            assert_matrix_eq!(cache.solve(a, &b).unwrap(), expected, 1e-12);
+            assert_matrix_eq!(cache.solve(&a.clone(), &b).unwrap(), expected, 1e-12);
+        }
+        // The general matrix caches its failed Cholesky attempt beside its LU factors
+        assert_eq!(cache.len(), 3);
+        assert_eq!((cache.hits(), cache.misses()), (3, 3));
+
+        assert_eq!(
+            cache.lu(&general).unwrap(),
+            decompose::plu_decomposition(&general).unwrap()
+        );
+        assert_eq!(
+            cache.get(&spd, FactorizationKind::Cholesky).unwrap(),
+            Factorization::Cholesky {
+                l: decompose::cholesky_decomposition(&spd).unwrap()
+            }
+        );
+        assert_eq!(cache.hits(), 5);
+
+        let (q, r) = cache.qr(&general).unwrap();
+        assert_eq!((q, r), decompose::qr_decomposition(&general).unwrap());
+        assert_eq!(
+            cache.get(&general, FactorizationKind::Qr).unwrap().kind(),
+            FactorizationKind::Qr
+        );
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (6, 4, 4));
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let a = matrix!([2.0, 0.0], [0.0, 2.0]);
+        let b = matrix!([3.0, 1.0], [1.0, 3.0]);
+        let c = matrix!([5.0, 2.0], [2.0, 5.0]);
+        let mut cache = FactorizationCache::new(2);
+
+        cache.cholesky(&a).unwrap();
+        cache.cholesky(&b).unwrap();
+        cache.cholesky(&a).unwrap();
+        cache.cholesky(&c).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.misses(), 3);
+
+        cache.cholesky(&a).unwrap();
+        assert_eq!(cache.misses(), 3);
+        cache.cholesky(&b).unwrap();
+        assert_eq!(cache.misses(), 4);
+
+        let mut uncached = FactorizationCache::new(0);
+        uncached.cholesky(&a).unwrap();
+        uncached.cholesky(&a).unwrap();
+        assert!(uncached.is_empty());
+        assert_eq!(uncached.misses(), 2);
+    }
+
+    #[test]
+    fn test_invalidation() {
+        let mut a = matrix!([4.0, 1.0], [1.0, 3.0]);
+        let mut cache = FactorizationCache::new(4);
+        cache.lu(&a).unwrap();
+        cache.cholesky(&a).unwrap();
+
+        let fingerprint = a.fingerprint();
+        a[(0, 0)] = 5.0;
+        // An edited matrix is a different key, so it is factored afresh
+        assert_eq!(
+            cache.cholesky(&a).unwrap(),
+            decompose::cholesky_decomposition(&a).unwrap()
+        );
+        assert_eq!(cache.misses(), 3);
+
+        assert_eq!(cache.invalidate_fingerprint(fingerprint), 2);
+        assert_eq!(cache.invalidate(&a), 1);
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 4);
+
+        cache.lu(&a).unwrap();
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut cache = FactorizationCache::new(4);
+        let singular = matrix!([1.0, 2.0], [2.0, 4.0]);
+        let b = matrix!([1.0], [1.0]);
+
+        assert_eq!(cache.solve(&singular, &b), Err(LinalgError::Singular));
+        assert_eq!(cache.solve(&singular, &b), Err(LinalgError::Singular));
+        assert_eq!(cache.misses(), 2);
+        assert_eq!(
+            cache.cholesky(&singular),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            cache.solve(&matrix!([1.0, 2.0]), &b),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            cache.solve(&matrix!([2.0, 0.0], [0.0, 2.0]), &matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        let _: Matrix<f64> = cache.solve(&matrix!([2.0]), &matrix!([4.0])).unwrap();
+    }
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "ffi")]
+
+mod ffi_tests {
+    use linalgrs::ffi::*;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    fn create(rows: usize, cols: usize, data: &[f64]) -> *mut LinalgrsMatrix {
+        let mut m = ptr::null_mut();
+        let status = unsafe { linalgrs_matrix_create(rows, cols, data.as_ptr(), &mut m) };
+        assert_eq!(status, LinalgrsStatus::Ok);
+        m
+    }
+
+    fn data(m: *const LinalgrsMatrix) -> Vec<f64> {
+        unsafe {
+            let len = linalgrs_matrix_rows(m) * linalgrs_matrix_cols(m);
+            let mut out = vec![0.0; len];
+            assert_eq!(
+                linalgrs_matrix_copy_data(m, out.as_mut_ptr(), len),
+                LinalgrsStatus::Ok
+            );
+            out
+        }
+    }
+
+    #[test]
+    fn test_multiply_and_free() {
+        let a = create(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        let b = create(2, 1, &[1.0, 1.0]);
+        let mut product = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(
+                linalgrs_matrix_multiply(a, b, &mut product),
+                LinalgrsStatus::Ok
+            );
+            assert_eq!(linalgrs_matrix_rows(product), 2);
+            assert_eq!(linalgrs_matrix_cols(product), 1);
+            assert_eq!(data(product), vec![3.0, 7.0]);
+
+            linalgrs_matrix_free(product);
+            linalgrs_matrix_free(a);
+            linalgrs_matrix_free(b);
+            linalgrs_matrix_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_solve_and_determinant() {
+        let a = create(2, 2, &[2.0, 0.0, 0.0, 4.0]);
+        let b = create(2, 1, &[2.0, 8.0]);
+        let mut x = ptr::null_mut();
+        let mut det = 0.0;
+
+        unsafe {
+            assert_eq!(linalgrs_matrix_solve(a, b, &mut x), LinalgrsStatus::Ok);
+            assert_eq!(data(x), vec![1.0, 2.0]);
+            assert_eq!(linalgrs_matrix_determinant(a, &mut det), LinalgrsStatus::Ok);
+            assert_eq!(det, 8.0);
+
+            for m in [a, b, x] {
+                linalgrs_matrix_free(m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinant_of_large_matrix() {
+        // Cofactor expansion would take 20! steps here
+        let n = 20;
+        let values: Vec<f64> = (0..n * n)
+            .map(|i| if i % (n + 1) == 0 { 2.0 } else { 0.0 })
+            .collect();
+        let a = create(n, n, &values);
+        let mut det = 0.0;
+
+        unsafe {
+            assert_eq!(linalgrs_matrix_determinant(a, &mut det), LinalgrsStatus::Ok);
+            assert_eq!(det, 1048576.0);
+            linalgrs_matrix_free(a);
+        }
+    }
+
+    #[test]
+    fn test_errors_are_reported() {
+        let a = create(1, 2, &[1.0, 2.0]);
+        let mut out = ptr::null_mut();
+        let mut det = 0.0;
+
+        unsafe {
+            assert_eq!(
+                linalgrs_matrix_multiply(a, a, &mut out),
+                LinalgrsStatus::Failed
+            );
+            assert!(out.is_null());
+            let message = CStr::from_ptr(linalgrs_last_error_message());
+            assert!(!message.to_str().unwrap().is_empty());
+
+            assert_eq!(
+                linalgrs_matrix_determinant(a, &mut det),
+                LinalgrsStatus::Failed
+            );
+            assert_eq!(
+                linalgrs_matrix_multiply(ptr::null(), a, &mut out),
+                LinalgrsStatus::NullPointer
+            );
+            let mut small = [0.0; 1];
+            assert_eq!(
+                linalgrs_matrix_copy_data(a, small.as_mut_ptr(), 1),
+                LinalgrsStatus::InvalidArgument
+            );
+            linalgrs_matrix_free(a);
+        }
+    }
+
+    #[test]
+    fn test_create_zeros() {
+        let mut m = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                linalgrs_matrix_create(2, 3, ptr::null(), &mut m),
+                LinalgrsStatus::Ok
+            );
+            assert_eq!(data(m), vec![0.0; 6]);
+            linalgrs_matrix_free(m);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+mod fingerprint_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::{Fingerprint, Matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_equal_matrices_match() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let b = matrix!([1.0, 2.0], [3.0, 4.0]);
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_eq!(a.fingerprint(), a.clone().fingerprint());
+        assert_eq!(
+            matrix!([0.0, f64::NAN]).fingerprint(),
+            matrix!([-0.0, -f64::NAN]).fingerprint()
+        );
+
+        let fingerprint = a.fingerprint();
+        assert_eq!((fingerprint.rows, fingerprint.cols), (2, 2));
+        assert_eq!(
+            fingerprint.to_string(),
+            format!("2x2:{:016x}", fingerprint.hash)
+        );
+    }
+
+    #[test]
+    fn test_changes_are_detected() {
+        let a = matrix!([1, 2], [3, 4]);
+        let mut edited = a.clone();
+        edited[(1, 0)] = 5;
+
+        assert_ne!(a.fingerprint(), edited.fingerprint());
+        assert_ne!(a.fingerprint(), matrix!([3, 4], [1, 2]).fingerprint());
+        assert_ne!(a.fingerprint(), matrix!([2, 1], [3, 4]).fingerprint());
+        assert_ne!(
+            a.fingerprint().hash,
+            matrix!([1, 2, 3, 4]).fingerprint().hash
+        );
+        assert_ne!(
+            Matrix::<i32>::zeros(0, 2).fingerprint(),
+            Matrix::<i32>::zeros(0, 3).fingerprint()
+        );
+        assert_ne!(
+            matrix!([1.0, 2.0]).fingerprint(),
+            matrix!([1.0, 2.0 + f64::EPSILON * 2.0]).fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        // Pinned, since changing the hash would invalidate every stored fingerprint
+        assert_eq!(
+            matrix!([1.0, 2.0], [3.0, 4.0]).fingerprint(),
+            Fingerprint {
+                rows: 2,
+                cols: 2,
+                hash: 1585691063213640032,
+            }
+        );
+    }
+}
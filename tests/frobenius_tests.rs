@@ -0,0 +1,85 @@
+#![allow(deprecated)]
+
+mod frobenius_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_frobenius_inner() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([5, 6], [7, 8]);
+
+        assert_eq!(MatrixUtilities::frobenius_inner(&a, &b), Ok(70));
+        assert_eq!(MatrixUtilities::frobenius_inner(&a, &a), Ok(30));
+        assert!(matches!(
+            MatrixUtilities::frobenius_inner(&a, &matrix!([1, 2])),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_matrix_cosine_similarity() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let rotation = matrix!([0.0, -1.0], [1.0, 0.0]);
+        let scaled = MatrixUtilities::multiply_by_scalar(a.clone(), 2.5);
+
+        let similarity = |x: &Matrix<f64>, y: &Matrix<f64>| {
+            MatrixUtilities::matrix_cosine_similarity(x, y).unwrap()
+        };
+        assert!(approx_eq!(
+            f64,
+            similarity(&a, &scaled),
+            1.0,
+            epsilon = 1e-12
+        ));
+        assert!(approx_eq!(
+            f64,
+            similarity(&a, &MatrixUtilities::multiply_by_scalar(a.clone(), -1.0)),
+            -1.0,
+            epsilon = 1e-12
+        ));
+        // A rotation is orthogonal to the identity, since its trace is zero
+        assert_eq!(similarity(&rotation, &MatrixUtilities::identity(2)), 0.0);
+        assert!(approx_eq!(
+            f64,
+            similarity(&a, &matrix!([1.0, 0.0], [0.0, 1.0])),
+            5.0 / (30.0_f64.sqrt() * 2.0_f64.sqrt()),
+            epsilon = 1e-12
+        ));
+    }
+
+    #[test]
+    fn test_matrix_cosine_similarity_errors() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        assert!(matches!(
+            MatrixUtilities::matrix_cosine_similarity(&a, &Matrix::zeros(2, 2)),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            MatrixUtilities::matrix_cosine_similarity(&a, &Matrix::zeros(2, 3)),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+        assert_eq!(
+            MatrixUtilities::matrix_cosine_similarity(&a, &matrix!([1.0, 2.0], [f64::NAN, 4.0])),
+            Err(LinalgError::NonFinite { row: 1, col: 0 })
+        );
+    }
+
+    #[test]
+    fn test_matrix_cosine_similarity_extreme_magnitudes() {
+        let huge = matrix!([1e200, 1e200], [1e200, -1e200]);
+        let tiny = matrix!([1e-200, 0.0], [0.0, 1e-200]);
+
+        let similarity = |x: &Matrix<f64>, y: &Matrix<f64>| {
+            MatrixUtilities::matrix_cosine_similarity(x, y).unwrap()
+        };
+        assert!(approx_eq!(f64, similarity(&huge, &huge), 1.0, ulps = 4));
+        assert!(approx_eq!(f64, similarity(&tiny, &tiny), 1.0, ulps = 4));
+        assert_eq!(similarity(&huge, &tiny), 0.0);
+    }
+}
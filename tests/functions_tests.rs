@@ -0,0 +1,87 @@
+#![allow(deprecated)]
+
+mod functions_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::functions::{sign, spectral_projectors};
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_sign_of_diagonal() {
+        let a = matrix!([3.0, 0.0, 0.0], [0.0, -0.25, 0.0], [0.0, 0.0, 40.0]);
+
+        assert_matrix_eq!(
+            sign(&a).unwrap(),
+            matrix!([1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn test_sign_of_non_normal_matrix() {
+        // Eigenvalues 2 and -1 with eigenvectors (1, 0) and (1, -1)
+        let a = matrix!([2.0, 3.0], [0.0, -1.0]);
+
+        let s = sign(&a).unwrap();
+        assert_matrix_eq!(s, matrix!([1.0, 2.0], [0.0, -1.0]), 1e-10);
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&s, &s).unwrap(),
+            MatrixUtilities::identity(2),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn test_sign_errors() {
+        assert_eq!(
+            sign(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        // The eigenvalues ±i lie on the imaginary axis
+        assert!(sign(&matrix!([0.0, 1.0], [-1.0, 0.0])).is_err());
+        assert_eq!(
+            sign(&matrix!([0.0, 1.0], [0.0, 0.0])),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_spectral_projectors() {
+        let a = matrix!([1.0, 2.0, 0.0], [0.0, -3.0, 1.0], [0.0, 0.0, 2.0]);
+
+        let (positive, negative) = spectral_projectors(&a, 0.0).unwrap();
+        assert_matrix_eq!(
+            MatrixUtilities::add(&positive, &negative).unwrap(),
+            MatrixUtilities::identity(3),
+            1e-10,
+        );
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&positive, &positive).unwrap(),
+            positive,
+            1e-10,
+        );
+        assert!(approx_eq!(
+            f64,
+            positive.trace().unwrap(),
+            2.0,
+            epsilon = 1e-10
+        ));
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&a, &negative).unwrap(),
+            MatrixUtilities::multiply(&negative, &a).unwrap(),
+            1e-10,
+        );
+
+        // Moving the line past 1 moves that eigenvalue to the left group
+        let (positive, _) = spectral_projectors(&a, 1.5).unwrap();
+        assert!(approx_eq!(
+            f64,
+            positive.trace().unwrap(),
+            1.0,
+            epsilon = 1e-10
+        ));
+    }
+}
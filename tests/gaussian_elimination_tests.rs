@@ -1,4 +1,7 @@
+#![allow(deprecated)]
+
 mod gaussian_elimination_tests {
+    use linalgrs::error::LinalgError;
     use linalgrs::matrix::Matrix;
     use linalgrs::matrix_utilities::MatrixUtilities;
     use std::sync::Arc;
@@ -81,10 +84,7 @@ mod gaussian_elimination_tests {
 
         let result = MatrixUtilities::gaussian_elimination(matrix);
         assert!(result.is_err());
-        assert_eq!(
-            result.err(),
-            Some("No solution exists for the given matrix.".to_string())
-        );
+        assert_eq!(result.err(), Some(LinalgError::NoSolution));
     }
     #[test]
     fn test_gaussian_elimination_infinitely_many_solutions() {
@@ -100,9 +100,6 @@ mod gaussian_elimination_tests {
 
         let result = MatrixUtilities::gaussian_elimination(matrix);
         assert!(result.is_err());
-        assert_eq!(
-            result.err(),
-            Some("Infinitely many solutions exist for the given matrix.".to_string())
-        );
+        assert_eq!(result.err(), Some(LinalgError::InfiniteSolutions));
     }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+#![cfg(feature = "gpu")]
+
+mod gpu_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::gpu::{GpuError, GpuMap, GpuMatrix};
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    // These tests need a usable GPU adapter, which headless CI does not have, so they are
+    // ignored by default; run them with `cargo test --features gpu -- --ignored`
+    fn upload(matrix: &Matrix<f32>) -> GpuMatrix {
+        GpuMatrix::upload(matrix).expect("no usable GPU adapter")
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_round_trip() {
+        let mat = matrix!([1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]);
+        let gpu = upload(&mat);
+
+        assert_eq!(gpu.shape(), (2, 3));
+        assert_eq!(gpu.download().unwrap(), mat);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_multiply() {
+        let a = matrix!([1.0f32, 2.0], [3.0, 4.0], [5.0, 6.0]);
+        let b = matrix!([7.0f32, 8.0, 9.0], [10.0, 11.0, 12.0]);
+        let (gpu_a, gpu_b) = (upload(&a), upload(&b));
+
+        let product = gpu_a.multiply(&gpu_b).unwrap().download().unwrap();
+        let expected = matrix!(
+            [27.0f32, 30.0, 33.0],
+            [61.0, 68.0, 75.0],
+            [95.0, 106.0, 117.0]
+        );
+        assert_eq!(product, expected);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_multiply_shape_mismatch() {
+        let a = matrix!([1.0f32, 2.0]);
+        let gpu_a = upload(&a);
+
+        assert_eq!(
+            gpu_a.multiply(&gpu_a).err(),
+            Some(GpuError::Linalg(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            }))
+        );
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn test_gpu_add_and_map() {
+        let a = matrix!([1.0f32, -2.0], [3.0, -4.0]);
+        let gpu_a = upload(&a);
+
+        let sum = gpu_a.add(&gpu_a).unwrap().download().unwrap();
+        assert_eq!(sum, matrix!([2.0f32, -4.0], [6.0, -8.0]));
+
+        let relu = gpu_a.map(GpuMap::Relu).unwrap().download().unwrap();
+        assert_eq!(relu, matrix!([1.0f32, 0.0], [3.0, 0.0]));
+
+        let scaled = gpu_a.map(GpuMap::Scale(0.5)).unwrap().download().unwrap();
+        assert_eq!(scaled, matrix!([0.5f32, -1.0], [1.5, -2.0]));
+    }
+}
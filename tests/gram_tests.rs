@@ -0,0 +1,46 @@
+#![allow(deprecated)]
+
+mod gram_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_gram_matches_transpose_product() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+        let at = MatrixUtilities::transpose(&a);
+
+        assert_eq!(
+            MatrixUtilities::gram(&a).unwrap(),
+            MatrixUtilities::multiply(&at, &a).unwrap()
+        );
+        assert_eq!(
+            MatrixUtilities::row_gram(&a).unwrap(),
+            MatrixUtilities::multiply(&a, &at).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_gram_shapes() {
+        let a = matrix!([1.0, 0.0], [0.0, 2.0], [3.0, 0.0]);
+
+        assert_eq!(
+            MatrixUtilities::gram(&a).unwrap(),
+            matrix!([10.0, 0.0], [0.0, 4.0])
+        );
+        assert_eq!(MatrixUtilities::row_gram(&a).unwrap().rows, 3);
+
+        let empty = MatrixUtilities::<f64>::gram(&Matrix::default()).unwrap();
+        assert_eq!((empty.rows, empty.cols), (0, 0));
+    }
+
+    #[test]
+    fn test_gram_rejects_malformed_input() {
+        let mut a = matrix!([1, 2], [3, 4]);
+        a.cols = 3;
+
+        assert!(MatrixUtilities::gram(&a).is_err());
+        assert!(MatrixUtilities::row_gram(&a).is_err());
+    }
+}
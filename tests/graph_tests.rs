@@ -0,0 +1,160 @@
+#![allow(deprecated)]
+
+mod graph_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::graph::{
+        incidence_matrix, kirchhoff_system, laplacian, normalized_laplacian, spectral_clustering,
+    };
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    /// Two triangles {0, 1, 2} and {3, 4, 5} joined by a weak edge between 2 and 3
+    fn two_cliques() -> Matrix<f64> {
+        matrix!(
+            [0.0, 1.0, 1.0, 0.0, 0.0, 0.0],
+            [1.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0, 0.1, 0.0, 0.0],
+            [0.0, 0.0, 0.1, 0.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 0.0]
+        )
+    }
+
+    #[test]
+    fn test_laplacian() {
+        let a = matrix!([0, 2, 1], [2, 0, 0], [1, 0, 0]);
+
+        assert_eq!(
+            laplacian(&a).unwrap(),
+            matrix!([3, -2, -1], [-2, 2, 0], [-1, 0, 1])
+        );
+    }
+
+    #[test]
+    fn test_normalized_laplacian() {
+        let a = matrix!([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+
+        assert_eq!(
+            normalized_laplacian(&a).unwrap(),
+            matrix!([1.0, -1.0, 0.0], [-1.0, 1.0, 0.0], [0.0, 0.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn test_laplacian_rejects_invalid_adjacency() {
+        assert!(matches!(
+            laplacian(&matrix!([0, 1], [2, 0])),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            laplacian(&matrix!([0, -1], [-1, 0])),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            laplacian(&matrix!([0, 1])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_spectral_clustering_splits_weakly_joined_cliques() {
+        let labels = spectral_clustering(&two_cliques(), 2).unwrap();
+
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[1], labels[2]);
+        assert_eq!(labels[3], labels[4]);
+        assert_eq!(labels[4], labels[5]);
+        assert_ne!(labels[0], labels[3]);
+    }
+
+    #[test]
+    fn test_spectral_clustering_invalid_k() {
+        assert_eq!(spectral_clustering(&two_cliques(), 1).unwrap(), vec![0; 6]);
+        for k in [0, 7] {
+            assert!(matches!(
+                spectral_clustering(&two_cliques(), k),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_incidence_matrix() {
+        let b: Matrix<i32> = incidence_matrix(&[(0, 1), (1, 2), (2, 0)], 3).unwrap();
+
+        assert_eq!(b, matrix!([1, 0, -1], [-1, 1, 0], [0, -1, 1]));
+        // B Bᵀ of an undirected graph is its Laplacian, whatever the edge directions
+        assert_eq!(
+            MatrixUtilities::multiply(&b, &MatrixUtilities::transpose(&b)).unwrap(),
+            laplacian(&matrix!([0, 1, 1], [1, 0, 1], [1, 1, 0])).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_incidence_matrix_rejects_invalid_edges() {
+        assert_eq!(
+            incidence_matrix::<f64>(&[(0, 3)], 3),
+            Err(LinalgError::OutOfBounds { index: 3, len: 3 })
+        );
+        assert!(matches!(
+            incidence_matrix::<f64>(&[(1, 1)], 3),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_kirchhoff_system_conserves_current() {
+        // A 1 A source into node 1, with 1 S branches 1-0, 1-2, and 2-0 to ground at node 0
+        let edges = [(1, 0), (1, 2), (2, 0)];
+        let conductances = [1.0, 1.0, 1.0];
+        let system = kirchhoff_system(&edges, &conductances, &[0.0, 1.0, 0.0], 0).unwrap();
+
+        assert_eq!(system.coefficients, matrix!([2.0, -1.0], [-1.0, 2.0]));
+        assert_eq!(system.constants, matrix!([1.0], [0.0]));
+
+        let v = system.solve().unwrap();
+        assert!(approx_eq!(f64, v.mat[0][0], 2.0 / 3.0, ulps = 4));
+        assert!(approx_eq!(f64, v.mat[1][0], 1.0 / 3.0, ulps = 4));
+
+        let potential = [0.0, v.mat[0][0], v.mat[1][0]];
+        let currents: Vec<f64> = edges
+            .iter()
+            .zip(conductances)
+            .map(|(&(from, to), g)| g * (potential[from] - potential[to]))
+            .collect();
+        let b: Matrix<f64> = incidence_matrix(&edges, 3).unwrap();
+        let net =
+            MatrixUtilities::multiply(&b, &matrix!([currents[0]], [currents[1]], [currents[2]]))
+                .unwrap();
+        for (node, expected) in [-1.0, 1.0, 0.0].into_iter().enumerate() {
+            assert!(approx_eq!(f64, net.mat[node][0], expected, epsilon = 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_kirchhoff_system_errors() {
+        let edges = [(0, 1)];
+
+        assert_eq!(
+            kirchhoff_system(&edges, &[1.0, 2.0], &[0.0, 0.0], 0),
+            Err(LinalgError::LengthMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            kirchhoff_system(&edges, &[1.0], &[0.0, 0.0], 2),
+            Err(LinalgError::OutOfBounds { index: 2, len: 2 })
+        );
+        assert!(matches!(
+            kirchhoff_system(&edges, &[-1.0], &[0.0, 0.0], 0),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        // A node with no path to ground leaves its potential undetermined
+        let floating = kirchhoff_system(&edges, &[1.0], &[0.0, 0.0, 1.0], 0).unwrap();
+        assert_eq!(floating.solve(), Err(LinalgError::Singular));
+    }
+}
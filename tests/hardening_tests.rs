@@ -0,0 +1,81 @@
+#![allow(deprecated)]
+
+mod hardening_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::testing::exercise_operations;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_validate() {
+        let mut m = matrix!([1, 2], [3, 4]);
+        assert!(m.validate().is_ok());
+
+        m.cols = 3;
+        assert!(m.validate().unwrap_err().to_string().contains("Row 0"));
+        m.cols = 2;
+        m.rows = 1;
+        assert!(m.validate().is_err());
+    }
+
+    #[test]
+    fn test_invalid_matrices_are_rejected() {
+        let ragged = Matrix {
+            mat: vec![Arc::from(vec![1.0, 2.0]), Arc::from(vec![3.0])],
+            rows: 2,
+            cols: 2,
+        };
+
+        assert!(MatrixUtilities::add(&ragged, &ragged).is_err());
+        assert!(MatrixUtilities::multiply(&ragged, &ragged).is_err());
+        assert!(MatrixUtilities::inverse(ragged.clone()).is_err());
+        assert!(MatrixUtilities::lu_decomposition(&ragged).is_err());
+        assert_eq!(MatrixUtilities::determinant(&ragged), None);
+    }
+
+    #[test]
+    fn test_empty_and_non_square_inputs() {
+        let empty: Matrix<f64> = Matrix::default();
+        let wide = matrix!([1.0, 2.0, 3.0]);
+
+        assert_eq!(MatrixUtilities::multiply(&empty, &empty).unwrap(), empty);
+        assert_eq!(MatrixUtilities::determinant(&empty), Some(1.0));
+        assert!(MatrixUtilities::gaussian_elimination(empty.clone()).is_err());
+        assert!(MatrixUtilities::gaussian_elimination(wide.clone()).is_err());
+        assert_eq!(
+            MatrixUtilities::row_echelon_form(matrix!([2.0], [4.0])),
+            matrix!([1.0], [0.0])
+        );
+        assert_eq!(MatrixUtilities::rref(matrix!([2.0], [4.0])).rows, 2);
+    }
+
+    #[test]
+    fn test_integer_zero_pivots_do_not_panic() {
+        assert!(MatrixUtilities::lu_decomposition(&matrix!([0, 1], [1, 0])).is_err());
+        assert!(MatrixUtilities::gaussian_elimination(matrix!([0, 1, 1], [0, 1, 1])).is_err());
+    }
+
+    #[test]
+    fn test_arbitrary_inputs_do_not_panic() {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for len in 0..2000 {
+            let bytes: Vec<u8> = (0..len % 300)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect();
+            exercise_operations(&bytes);
+        }
+
+        let mut nan = vec![4, 4, 0];
+        nan.extend(f64::NAN.to_le_bytes().repeat(16));
+        exercise_operations(&nan);
+        let mut inf = vec![3, 3, 0];
+        inf.extend(f64::INFINITY.to_le_bytes().repeat(9));
+        exercise_operations(&inf);
+    }
+}
@@ -0,0 +1,111 @@
+mod incremental_qr_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::least_squares::{least_squares, IncrementalQr};
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    fn sample(i: usize) -> ([f64; 3], f64) {
+        let t = i as f64 * 0.1;
+        (
+            [1.0, t, (3.0 * t).sin()],
+            2.0 - t + 0.5 * (3.0 * t).sin() + (i as f64).cos() * 0.1,
+        )
+    }
+
+    fn batch(range: std::ops::Range<usize>) -> (Matrix<f64>, Matrix<f64>) {
+        let rows: Vec<_> = range.map(sample).collect();
+        let a = Matrix {
+            mat: rows.iter().map(|(row, _)| Arc::from(*row)).collect(),
+            rows: rows.len(),
+            cols: 3,
+        };
+        let b = Matrix {
+            mat: rows.iter().map(|&(_, value)| Arc::from([value])).collect(),
+            rows: rows.len(),
+            cols: 1,
+        };
+        (a, b)
+    }
+
+    #[test]
+    fn test_add_rows_matches_least_squares() {
+        let mut qr = IncrementalQr::new(3);
+        for i in 0..50 {
+            let (row, value) = sample(i);
+            qr.add_row(&row, value).unwrap();
+        }
+
+        let (a, b) = batch(0..50);
+        assert_eq!(qr.len(), 50);
+        assert_matrix_eq!(qr.solve().unwrap(), least_squares(&a, &b).unwrap(), 1e-10);
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        let window = 20;
+        let mut qr = IncrementalQr::new(3);
+        for i in 0..200 {
+            let (row, value) = sample(i);
+            qr.add_row(&row, value).unwrap();
+            if qr.len() > window {
+                assert_eq!(
+                    qr.remove_row(0),
+                    Ok((sample(i - window).0.to_vec(), sample(i - window).1))
+                );
+            }
+        }
+
+        let (a, b) = batch(180..200);
+        let x = least_squares(&a, &b).unwrap();
+        assert_matrix_eq!(qr.solve().unwrap(), x, 1e-9);
+
+        let residual: f64 = (0..a.rows)
+            .map(|i| {
+                let fit: f64 = (0..3).map(|j| a.mat[i][j] * x.mat[j][0]).sum();
+                (fit - b.mat[i][0]).powi(2)
+            })
+            .sum();
+        assert!((qr.residual_norm() - residual.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_remove_exact_fit_row() {
+        // Every row lies on y = 1 + 2t, so removing one cannot be done by downdating
+        let mut qr = IncrementalQr::new(2);
+        for t in 0..4 {
+            qr.add_row(&[1.0, t as f64], 1.0 + 2.0 * t as f64).unwrap();
+        }
+
+        qr.remove_row(1).unwrap();
+        assert_matrix_eq!(qr.solve().unwrap(), matrix!([1.0], [2.0]), 1e-12);
+        assert!(qr.residual_norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut qr = IncrementalQr::new(2);
+
+        assert_eq!(qr.solve(), Err(LinalgError::RankDeficient));
+        qr.add_row(&[1.0, 1.0], 2.0).unwrap();
+        assert_eq!(qr.solve(), Err(LinalgError::RankDeficient));
+        assert_eq!(
+            qr.add_row(&[1.0], 2.0),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            qr.add_row(&[1.0, 2.0], f64::NAN),
+            Err(LinalgError::NonFinite { row: 1, col: 2 })
+        );
+        assert_eq!(
+            qr.remove_row(1),
+            Err(LinalgError::OutOfBounds { index: 1, len: 1 })
+        );
+        assert_eq!(qr.remove_row(0), Ok((vec![1.0, 1.0], 2.0)));
+        assert!(qr.is_empty());
+        assert_eq!(qr.r(), Matrix::zeros(2, 2));
+    }
+}
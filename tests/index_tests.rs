@@ -0,0 +1,53 @@
+mod index_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_index_reads_elements() {
+        let m = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+
+        assert_eq!(m[(0, 0)], 1.0);
+        assert_eq!(m[(1, 2)], 6.0);
+    }
+
+    #[test]
+    fn test_index_mut_copies_on_write() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let mut b = a.clone();
+
+        b[(1, 0)] = 5.0;
+        b[(0, 1)] += 1.0;
+
+        assert_eq!(b, matrix!([1.0, 3.0], [5.0, 4.0]));
+        assert_eq!(a, matrix!([1.0, 2.0], [3.0, 4.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds_panics() {
+        let m = matrix!([1, 2]);
+        let _ = m[(1, 0)];
+    }
+
+    #[test]
+    fn test_get() {
+        let m = matrix!([1, 2], [3, 4]);
+
+        assert_eq!(m.get(1, 1), Some(4));
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.get(0, 2), None);
+    }
+
+    #[test]
+    fn test_set() {
+        let a = matrix!([1, 2], [3, 4]);
+        let mut b = a.clone();
+
+        assert!(b.set(0, 0, 9).is_ok());
+        assert_eq!(b, matrix!([9, 2], [3, 4]));
+        assert_eq!(a.get(0, 0), Some(1));
+        assert!(b.set(2, 0, 1).is_err());
+        assert!(b.set(0, 2, 1).is_err());
+    }
+}
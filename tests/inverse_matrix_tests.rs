@@ -1,6 +1,9 @@
+#![allow(deprecated)]
+
 mod test_inverse_matrices {
     use std::sync::Arc;
-    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::{assert_matrix_eq, matrix};
     use linalgrs::matrix::Matrix;
     use linalgrs::matrix_utilities::MatrixUtilities;
 
@@ -24,20 +27,8 @@ mod test_inverse_matrices {
             cols: 2,
         };
 
-        let result = MatrixUtilities::inverse(matrix);
-        assert!(result.is_ok());
-        let inverse = result.unwrap();
-        for i in 0..inverse.rows {
-            for j in 0..inverse.cols {
-                assert!(
-                    approx_eq!(f64, inverse.mat[i][j], expected_inverse.mat[i][j], epsilon = 1e-6),
-                    "Mismatch at ({}, {}): got {}, expected {}",
-                    i, j, inverse.mat[i][j], expected_inverse.mat[i][j]
-                );
-            }
-        }
-        assert_eq!(inverse.rows, expected_inverse.rows);
-        assert_eq!(inverse.cols, expected_inverse.cols);
+        let inverse = MatrixUtilities::inverse(matrix).unwrap();
+        assert_matrix_eq!(inverse, expected_inverse, 1e-6);
     }
 
     #[test]
@@ -54,4 +45,44 @@ mod test_inverse_matrices {
         let result = MatrixUtilities::inverse(singular_matrix);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_inverse_with_tol() {
+        let matrix = matrix!([4.0, 7.0], [2.0, 6.0]);
+        let expected = matrix!([0.6, -0.7], [-0.2, 0.4]);
+
+        let inverse = MatrixUtilities::inverse_with_tol(&matrix, f64::EPSILON).unwrap();
+        assert_matrix_eq!(inverse, expected, 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_with_tol_rejects_ill_conditioned() {
+        // The condition number is about 4e10, so the reciprocal is about 2.5e-11
+        let nearly_singular = matrix!([1.0, 1.0], [1.0, 1.0 + 1e-10]);
+
+        assert!(MatrixUtilities::inverse(nearly_singular.clone()).is_ok());
+        assert_eq!(
+            MatrixUtilities::inverse_with_tol(&nearly_singular, 1e-8),
+            Err(LinalgError::IllConditioned)
+        );
+        assert!(MatrixUtilities::inverse_with_tol(&nearly_singular, 1e-12).is_ok());
+        assert_eq!(
+            MatrixUtilities::inverse_with_tol(&matrix!([2.0, 4.0], [1.0, 2.0]), 1e-12),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_inverse_with_tol_errors() {
+        let matrix = matrix!([1.0, 0.0], [0.0, 1.0]);
+
+        assert!(matches!(
+            MatrixUtilities::inverse_with_tol(&matrix, -1.0),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            MatrixUtilities::inverse_with_tol(&matrix!([1.0, 2.0]), 1e-12),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+}
@@ -0,0 +1,60 @@
+mod iter_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_iter_is_row_major() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+        let elements: Vec<i32> = m.iter().copied().collect();
+
+        assert_eq!(elements, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.iter().len(), 6);
+        assert_eq!(Matrix::<i32>::default().iter().next(), None);
+    }
+
+    #[test]
+    fn test_iter_len_tracks_consumption() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+        let mut iter = m.iter();
+
+        iter.by_ref().take(4).for_each(drop);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.copied().collect::<Vec<_>>(), vec![5, 6]);
+    }
+
+    #[test]
+    fn test_into_iterator_for_reference() {
+        let m = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let mut sum = 0.0;
+        for x in &m {
+            sum += x;
+        }
+
+        assert_eq!(sum, 10.0);
+    }
+
+    #[test]
+    fn test_iter_rows_and_cols() {
+        let m = matrix!([1, 2], [3, 4], [5, 6]);
+
+        let rows: Vec<&[i32]> = m.iter_rows().collect();
+        assert_eq!(rows, vec![&[1, 2][..], &[3, 4], &[5, 6]]);
+
+        let cols: Vec<Vec<i32>> = m.iter_cols().collect();
+        assert_eq!(cols, vec![vec![1, 3, 5], vec![2, 4, 6]]);
+        assert_eq!(m.iter_cols().len(), 2);
+    }
+
+    #[test]
+    fn test_enumerate_elements() {
+        let m = matrix!([1, 2], [3, 4]);
+        let cells: Vec<(usize, usize, i32)> = m.enumerate_elements().collect();
+
+        assert_eq!(cells, vec![(0, 0, 1), (0, 1, 2), (1, 0, 3), (1, 1, 4)]);
+        assert_eq!(
+            m.enumerate_elements().find(|&(_, _, x)| x == 3),
+            Some((1, 0, 3))
+        );
+    }
+}
@@ -0,0 +1,88 @@
+mod iterative_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::context::{CancellationToken, ComputeContext};
+    use linalgrs::error::LinalgError;
+    use linalgrs::iterative::{conjugate_gradient, conjugate_gradient_with_context};
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::sparse::SparseMatrix;
+    use std::sync::{Arc, Mutex};
+
+    fn spd() -> SparseMatrix<f64> {
+        SparseMatrix::from_dense(&matrix!(
+            [4.0, 1.0, 0.0],
+            [1.0, 3.0, -1.0],
+            [0.0, -1.0, 2.0]
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_conjugate_gradient_solves_spd_system() {
+        let a = spd();
+        let result = conjugate_gradient(&a, &[5.0, 3.0, 1.0], 1e-12, 10).unwrap();
+
+        assert!(result.iterations <= 3);
+        assert!(result.residual <= 1e-12);
+        for (&x, expected) in result.solution.iter().zip([1.0, 1.0, 1.0]) {
+            assert!(approx_eq!(f64, x, expected, epsilon = 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_conjugate_gradient_zero_rhs() {
+        let result = conjugate_gradient(&spd(), &[0.0; 3], 1e-8, 10).unwrap();
+
+        assert_eq!(result.solution, vec![0.0; 3]);
+        assert_eq!(result.iterations, 0);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_errors() {
+        let a = spd();
+
+        assert_eq!(
+            conjugate_gradient(&SparseMatrix::<f64>::new(2, 3), &[1.0, 1.0], 1e-8, 10),
+            Err(LinalgError::NonSquare { rows: 2, cols: 3 })
+        );
+        assert_eq!(
+            conjugate_gradient(&a, &[1.0, 1.0], 1e-8, 10),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            conjugate_gradient(&a, &[1.0, 1.0, 1.0], 0.0, 10),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            conjugate_gradient(&a, &[5.0, 3.0, 1.0], 1e-12, 1),
+            Err(LinalgError::NotConverged { iterations: 1 })
+        );
+
+        let indefinite = SparseMatrix::from_dense(&matrix!([1.0, 0.0], [0.0, -1.0])).unwrap();
+        assert_eq!(
+            conjugate_gradient(&indefinite, &[0.0, 1.0], 1e-8, 10),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_conjugate_gradient_with_context() {
+        let residuals = Arc::new(Mutex::new(Vec::new()));
+        let sink = residuals.clone();
+        let ctx = ComputeContext::new().with_progress(move |_, r| sink.lock().unwrap().push(r));
+        let result =
+            conjugate_gradient_with_context(&spd(), &[5.0, 3.0, 1.0], 1e-12, 10, &ctx).unwrap();
+        assert_eq!(residuals.lock().unwrap().len(), result.iterations);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let ctx = ComputeContext::new().with_cancellation(token);
+        assert_eq!(
+            conjugate_gradient_with_context(&spd(), &[5.0, 3.0, 1.0], 1e-12, 10, &ctx),
+            Err(LinalgError::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+mod least_squares_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::least_squares::{least_squares, least_squares_constrained, total_least_squares};
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_least_squares_line_fit() {
+        // Fit y = c0 + c1 t through (0, 1), (1, 3), (2, 5), (3, 7)
+        let a = matrix!([1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]);
+        let b = matrix!([1.0], [3.0], [5.0], [7.0]);
+
+        assert_matrix_eq!(least_squares(&a, &b).unwrap(), matrix!([1.0], [2.0]), 1e-12);
+    }
+
+    #[test]
+    fn test_least_squares_errors() {
+        let a = matrix!([1.0, 2.0], [2.0, 4.0], [3.0, 6.0]);
+        let b = matrix!([1.0], [2.0], [3.0]);
+
+        assert_eq!(least_squares(&a, &b), Err(LinalgError::RankDeficient));
+        assert!(matches!(
+            least_squares(&a, &matrix!([1.0], [2.0])),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_constrained_projection() {
+        // Closest point to (1, 2) on the line x + y = 1
+        let a = matrix!([1.0, 0.0], [0.0, 1.0]);
+        let b = matrix!([1.0], [2.0]);
+        let c = matrix!([1.0, 1.0]);
+        let d = matrix!([1.0]);
+
+        assert_matrix_eq!(
+            least_squares_constrained(&a, &b, &c, &d).unwrap(),
+            matrix!([0.0], [1.0]),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_constrained_line_fit_through_point() {
+        // Fit y = c0 + c1 t through noisy points, forcing the line through (0, 0)
+        let a = matrix!([1.0, 1.0], [1.0, 2.0], [1.0, 3.0]);
+        let b = matrix!([2.0], [4.5], [5.5]);
+        let c = matrix!([1.0, 0.0]);
+        let d = matrix!([0.0]);
+
+        let x = least_squares_constrained(&a, &b, &c, &d).unwrap();
+        // With c0 = 0, c1 = (t · y) / (t · t) = 27.5 / 14
+        assert_matrix_eq!(x, matrix!([0.0], [27.5 / 14.0]), 1e-12);
+    }
+
+    #[test]
+    fn test_fully_constrained_and_unconstrained() {
+        let a = matrix!([1.0, 0.0], [0.0, 1.0], [1.0, 1.0]);
+        let b = matrix!([1.0], [1.0], [5.0]);
+
+        let c = matrix!([1.0, 0.0], [0.0, 2.0]);
+        let d = matrix!([3.0], [4.0]);
+        assert_matrix_eq!(
+            least_squares_constrained(&a, &b, &c, &d).unwrap(),
+            matrix!([3.0], [2.0]),
+            1e-12,
+        );
+
+        let none = Matrix {
+            mat: vec![],
+            rows: 0,
+            cols: 2,
+        };
+        let empty = Matrix {
+            mat: vec![],
+            rows: 0,
+            cols: 1,
+        };
+        assert_eq!(
+            least_squares_constrained(&a, &b, &none, &empty),
+            least_squares(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_constrained_errors() {
+        let a = matrix!([1.0, 0.0], [0.0, 1.0]);
+        let b = matrix!([1.0], [2.0]);
+
+        let dependent = matrix!([1.0, 1.0], [2.0, 2.0]);
+        assert_eq!(
+            least_squares_constrained(&a, &b, &dependent, &matrix!([1.0], [2.0])),
+            Err(LinalgError::RankDeficient)
+        );
+        assert!(least_squares_constrained(&a, &b, &matrix!([1.0]), &matrix!([1.0])).is_err());
+        assert!(
+            least_squares_constrained(&a, &b, &matrix!([1.0, f64::NAN]), &matrix!([1.0])).is_err()
+        );
+    }
+
+    #[test]
+    fn test_total_least_squares_exact_data() {
+        // Consistent data is fitted exactly, just as by ordinary least squares
+        let a = matrix!([1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]);
+        let b = matrix!([1.0], [3.0], [5.0], [7.0]);
+
+        assert_matrix_eq!(
+            total_least_squares(&a, &b).unwrap(),
+            matrix!([1.0], [2.0]),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_total_least_squares_orthogonal_fit() {
+        // The TLS slope of a line through the origin minimizes perpendicular distances:
+        // slope = ((syy - sxx) + sqrt((syy - sxx)^2 + 4 sxy^2)) / (2 sxy)
+        let x = [1.0, 2.0, 3.0];
+        let y = [1.5, 1.8, 3.3];
+        let a = matrix!([x[0]], [x[1]], [x[2]]);
+        let b = matrix!([y[0]], [y[1]], [y[2]]);
+        let dot = |p: &[f64], q: &[f64]| p.iter().zip(q).map(|(a, b)| a * b).sum::<f64>();
+        let (sxx, syy, sxy) = (dot(&x, &x), dot(&y, &y), dot(&x, &y));
+        let slope = ((syy - sxx) + ((syy - sxx).powi(2) + 4.0 * sxy * sxy).sqrt()) / (2.0 * sxy);
+
+        let tls = total_least_squares(&a, &b).unwrap();
+        assert_matrix_eq!(tls, matrix!([slope]), 1e-12);
+        // Errors in x pull the TLS slope above the ordinary least squares slope
+        assert!(tls.mat[0][0] > least_squares(&a, &b).unwrap().mat[0][0]);
+    }
+
+    #[test]
+    fn test_total_least_squares_errors() {
+        // A zero column of a can only be fitted by a vertical line, which no finite x gives
+        let a = matrix!([0.0], [0.0]);
+        let b = matrix!([1.0], [2.0]);
+
+        assert_eq!(total_least_squares(&a, &b), Err(LinalgError::NoSolution));
+        assert!(matches!(
+            total_least_squares(&a, &matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+    }
+}
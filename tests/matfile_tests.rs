@@ -0,0 +1,118 @@
+mod matfile_tests {
+    use linalgrs::io::matfile;
+    use linalgrs::io::matfile::MatError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    fn header(endian: &[u8; 2]) -> Vec<u8> {
+        let mut bytes = b"MATLAB 5.0 MAT-file".to_vec();
+        bytes.resize(124, b' ');
+        bytes.extend_from_slice(&[0x01, 0x00]);
+        bytes.extend_from_slice(endian);
+        bytes
+    }
+
+    fn element(data_type: u32, data: &[u8]) -> Vec<u8> {
+        let mut out = data_type.to_le_bytes().to_vec();
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(data);
+        out.resize(out.len() + (8 - data.len() % 8) % 8, 0);
+        out
+    }
+
+    #[test]
+    fn test_write_then_read() {
+        let a = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+        let b = matrix!([7.5], [-8.25]);
+
+        let mut bytes = Vec::new();
+        matfile::write(&mut bytes, &[("a", &a), ("b_long_name", &b)]).unwrap();
+        assert_eq!(bytes.len() % 8, 0);
+
+        let variables = matfile::read(bytes.as_slice()).unwrap();
+        assert_eq!(variables.len(), 2);
+        assert_eq!(variables[0].name, "a");
+        assert_eq!(variables[0].matrix, a);
+        assert_eq!(variables[1].name, "b_long_name");
+        assert_eq!(variables[1].matrix, b);
+    }
+
+    #[test]
+    fn test_write_integer_matrix() {
+        let mat = matrix!([1, -2], [3, 4]);
+
+        let mut bytes = Vec::new();
+        matfile::write(&mut bytes, &[("m", &mat)]).unwrap();
+
+        let variables = matfile::read(bytes.as_slice()).unwrap();
+        assert_eq!(variables[0].matrix, matrix!([1.0, -2.0], [3.0, 4.0]));
+    }
+
+    #[test]
+    fn test_read_int32_class_stored_as_uint8() {
+        // A 2x2 int32 array [1 2; 3 4] whose values MATLAB packed into miUINT8, with the
+        // array name in the small data element format
+        let mut body = element(6, &[12, 0, 0, 0, 0, 0, 0, 0]);
+        body.extend(element(5, &[2, 0, 0, 0, 2, 0, 0, 0]));
+        body.extend_from_slice(&[1, 0, 1, 0, b'x', 0, 0, 0]);
+        body.extend(element(2, &[1, 3, 2, 4]));
+
+        let mut bytes = header(b"IM");
+        bytes.extend(element(14, &body));
+
+        let variables = matfile::read(bytes.as_slice()).unwrap();
+        assert_eq!(variables[0].name, "x");
+        assert_eq!(variables[0].matrix, matrix!([1.0, 2.0], [3.0, 4.0]));
+    }
+
+    #[test]
+    fn test_read_skips_char_arrays() {
+        let mut body = element(6, &[4, 0, 0, 0, 0, 0, 0, 0]);
+        body.extend(element(5, &[1, 0, 0, 0, 2, 0, 0, 0]));
+        body.extend(element(1, b"s"));
+        body.extend(element(4, &[b'h', 0, b'i', 0]));
+
+        let mut bytes = header(b"IM");
+        bytes.extend(element(14, &body));
+
+        assert!(matfile::read(bytes.as_slice()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert_eq!(
+            matfile::read(&b"not a mat file"[..]),
+            Err(MatError::NotMatFile)
+        );
+
+        let mut compressed = header(b"IM");
+        compressed.extend(element(15, &[0x78, 0x9c, 0, 0]));
+        assert_eq!(
+            matfile::read(compressed.as_slice()),
+            Err(MatError::Compressed)
+        );
+
+        let mut truncated = Vec::new();
+        matfile::write(&mut truncated, &[("a", &matrix!([1.0, 2.0]))]).unwrap();
+        truncated.truncate(truncated.len() - 4);
+        assert_eq!(
+            matfile::read(truncated.as_slice()),
+            Err(MatError::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_write_file_round_trip() {
+        let path = std::env::temp_dir().join("linalgrs_matfile_test.mat");
+        let mat = matrix!([0.5, 1.5], [2.5, 3.5], [4.5, 5.5]);
+
+        matfile::write_file(&path, &[("data", &mat)]).unwrap();
+        let variables = matfile::read_file(&path).unwrap();
+        assert_eq!(variables[0].matrix, mat);
+        assert_eq!(
+            matfile::write(Vec::new(), &[("", &mat)]),
+            Err(MatError::InvalidName(String::new()))
+        );
+    }
+}
@@ -1,4 +1,7 @@
+#![allow(deprecated)]
+
 mod matrix_operations_tests {
+    use linalgrs::error::LinalgError;
     use linalgrs::matrix::Matrix;
     use linalgrs::matrix_utilities::MatrixUtilities;
     use std::sync::Arc;
@@ -149,6 +152,34 @@ mod matrix_operations_tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_dot_product_orientations() {
+        let row = matrix!([1, 2, 3]);
+        let col = matrix!([4], [5], [6]);
+
+        assert_eq!(MatrixUtilities::dot(&row, &col), Ok(32));
+        assert_eq!(MatrixUtilities::dot(&col, &row), Ok(32));
+        assert_eq!(MatrixUtilities::dot(&row, &row), Ok(14));
+        assert_eq!(MatrixUtilities::dot(&col, &col), Ok(77));
+        assert_eq!(
+            MatrixUtilities::dot(&row, &matrix!([1, 2])),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_dot_slices() {
+        assert_eq!(
+            MatrixUtilities::dot_slices(&[1.0, 2.0], &[3.0, 4.0]),
+            Ok(11.0)
+        );
+        assert_eq!(MatrixUtilities::<i32>::dot_slices(&[], &[]), Ok(0));
+        assert!(MatrixUtilities::dot_slices(&[1], &[1, 2]).is_err());
+    }
+
     #[test]
     fn test_gauss_jordan_elimination_unique_solution() {
         let matrix = Matrix {
@@ -212,7 +243,80 @@ mod matrix_operations_tests {
         assert!(result.is_err(), "LU decomposition should fail for a non-square matrix.");
         assert_eq!(
             result.unwrap_err(),
-            "Matrix must be square for LU decomposition.".to_string()
+            LinalgError::NonSquare { rows: 2, cols: 3 }
+        );
+    }
+
+    #[test]
+    fn test_tiled_multiply_matches_naive() {
+        // Shapes that straddle several tiles, with partial tiles on every edge
+        let fill = |rows: usize, cols: usize, seed: usize| Matrix {
+            mat: (0..rows)
+                .map(|i| {
+                    (0..cols)
+                        .map(|j| ((i * 31 + j * 17 + seed) % 23) as f64 * 0.37 - 4.0)
+                        .collect()
+                })
+                .collect(),
+            rows,
+            cols,
+        };
+        let a = fill(70, 130, 1);
+        let b = fill(130, 65, 2);
+
+        let tiled = MatrixUtilities::multiply(&a, &b).unwrap();
+        assert_eq!((tiled.rows, tiled.cols), (70, 65));
+        assert_eq!(tiled, MatrixUtilities::multiply_naive(&a, &b).unwrap());
+        assert_eq!(
+            MatrixUtilities::multiply_naive(&a, &a).unwrap_err(),
+            LinalgError::ShapeMismatch {
+                expected: (130, 130),
+                found: (70, 130)
+            }
+        );
+    }
+
+    #[test]
+    fn test_multiply_empty_inner_dimension() {
+        let a: Matrix<i64> = Matrix::zeros(2, 0);
+        let b: Matrix<i64> = Matrix::zeros(0, 3);
+
+        assert_eq!(MatrixUtilities::multiply(&a, &b).unwrap(), Matrix::zeros(2, 3));
+    }
+
+    #[test]
+    fn test_strassen_matches_multiply() {
+        // 257 is odd at both levels above the crossover, so both padding paths run
+        let n = 257;
+        let fill = |seed: usize| Matrix {
+            mat: (0..n)
+                .map(|i| (0..n).map(|j| ((i * 13 + j * 7 + seed) % 9) as i64 - 4).collect())
+                .collect(),
+            rows: n,
+            cols: n,
+        };
+        let a = fill(1);
+        let b = fill(5);
+
+        let product = MatrixUtilities::multiply_strassen(&a, &b).unwrap();
+        assert_eq!(product, MatrixUtilities::multiply(&a, &b).unwrap());
+    }
+
+    #[test]
+    fn test_strassen_falls_back_for_other_shapes() {
+        let a: Matrix<i32> = matrix!([1, 2, 3], [4, 5, 6]);
+        let b: Matrix<i32> = matrix!([1, 0], [0, 1], [1, 1]);
+
+        assert_eq!(
+            MatrixUtilities::multiply_strassen(&a, &b).unwrap(),
+            matrix!([4, 5], [10, 11])
+        );
+        assert_eq!(
+            MatrixUtilities::multiply_strassen(&a, &a).unwrap_err(),
+            LinalgError::ShapeMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            }
         );
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,72 @@
+#![allow(deprecated)]
+
+mod matrix_pool_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_pool::MatrixPool;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_pool_multiply_matches_utilities() {
+        let mut pool = MatrixPool::new();
+        let a = matrix!([1, 2], [3, 4], [5, 6]);
+        let b = matrix!([7, 8, 9], [10, 11, 12]);
+
+        let pooled = pool.multiply(&a, &b).unwrap();
+        assert_eq!(pooled, MatrixUtilities::multiply(&a, &b).unwrap());
+        assert!(pool.multiply(&a, &a).is_err());
+    }
+
+    #[test]
+    fn test_pool_reuses_recycled_rows() {
+        let mut pool = MatrixPool::new();
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        let first = pool.add(&a, &a).unwrap();
+        let pointers: Vec<*const f64> = first.mat.iter().map(|row| row.as_ptr()).collect();
+        pool.recycle(first);
+        assert_eq!(pool.available_rows(), 2);
+
+        let second = pool.multiply(&a, &a).unwrap();
+        assert_eq!(pool.available_rows(), 0);
+        assert_eq!(second, matrix!([7.0, 10.0], [15.0, 22.0]));
+        for row in &second.mat {
+            assert!(pointers.contains(&row.as_ptr()));
+        }
+    }
+
+    #[test]
+    fn test_pool_skips_shared_rows() {
+        let mut pool = MatrixPool::new();
+        let a = matrix!([1, 2], [3, 4]);
+        let kept = a.clone();
+
+        pool.recycle(a);
+        assert_eq!(pool.available_rows(), 0);
+        assert_eq!(kept, matrix!([1, 2], [3, 4]));
+    }
+
+    #[test]
+    fn test_pool_zeros_clears_reused_rows() {
+        let mut pool = MatrixPool::with_capacity_limit(1);
+        pool.recycle(matrix!([1, 2, 3], [4, 5, 6]));
+        assert_eq!(pool.available_rows(), 1);
+
+        let zeros = pool.zeros(2, 3);
+        assert_eq!(zeros, matrix!([0, 0, 0], [0, 0, 0]));
+    }
+
+    #[test]
+    fn test_pool_transpose_and_scalar() {
+        let mut pool = MatrixPool::new();
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+
+        assert_eq!(pool.transpose(&a), matrix!([1, 4], [2, 5], [3, 6]));
+        assert_eq!(
+            pool.multiply_by_scalar(&a, 2),
+            matrix!([2, 4, 6], [8, 10, 12])
+        );
+        assert!(pool.subtract(&a, &matrix!([1, 2])).is_err());
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 mod matrix_tests {
     use linalgrs::matrix;
     use linalgrs::matrix::Matrix;
@@ -75,4 +77,49 @@ mod matrix_tests {
         let mat = MatrixUtilities::<i32>::identity(3usize);
         println!("{}", mat);
     }
+
+    #[test]
+    fn test_with_row_mut() {
+        let mut mat = matrix!([1, 2], [3, 4]);
+        let original = mat.clone();
+
+        let sum = mat.with_row_mut(1, |row| {
+            row[0] = 10;
+            row.iter().sum::<i32>()
+        });
+        assert_eq!(sum, Ok(14));
+        assert_eq!(mat, matrix!([1, 2], [10, 4]));
+        assert_eq!(original, matrix!([1, 2], [3, 4]));
+
+        assert!(mat.with_row_mut(2, |_| ()).is_err());
+    }
+
+    #[test]
+    fn test_with_rows_mut() {
+        let mut mat = matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+
+        mat.with_rows_mut((0, 2), |pivot, target| {
+            for c in 0..pivot.len() {
+                target[c] -= 5.0 * pivot[c];
+            }
+        })
+        .unwrap();
+        assert_eq!(mat, matrix!([1.0, 2.0], [3.0, 4.0], [0.0, -4.0]));
+
+        mat.with_rows_mut((2, 0), |first, second| {
+            first[0] = 7.0;
+            second[0] = 8.0;
+        })
+        .unwrap();
+        assert_eq!(mat.mat[2][0], 7.0);
+        assert_eq!(mat.mat[0][0], 8.0);
+    }
+
+    #[test]
+    fn test_with_rows_mut_errors() {
+        let mut mat = matrix!([1, 2], [3, 4]);
+
+        assert!(mat.with_rows_mut((0, 0), |_, _| ()).is_err());
+        assert!(mat.with_rows_mut((0, 2), |_, _| ()).is_err());
+    }
 }
@@ -0,0 +1,58 @@
+#![allow(deprecated)]
+#![cfg(feature = "metrics")]
+
+mod metrics_tests {
+    use linalgrs::adaptive::solve_adaptive;
+    use linalgrs::expr::Expr;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::metrics;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_calls_are_counted() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let before = metrics::snapshot();
+        let count = |s: &metrics::MetricsSnapshot, name: &str| s.get(name).map_or(0, |m| m.calls);
+
+        MatrixUtilities::multiply(&a, &a).unwrap();
+        MatrixUtilities::add(&a, &a).unwrap();
+        solve_adaptive(&a, &matrix!([1.0], [1.0])).unwrap();
+
+        let after = metrics::snapshot();
+        assert!(count(&after, "multiply") > count(&before, "multiply"));
+        assert!(count(&after, "add") > count(&before, "add"));
+        assert!(count(&after, "solve_adaptive") > count(&before, "solve_adaptive"));
+
+        let multiply = after.get("multiply").unwrap();
+        assert!(multiply.flops >= 16);
+        assert!(multiply.allocations >= 3);
+        assert!(after.total().calls >= 3);
+        assert!(format!("{}", after).contains("multiply: "));
+    }
+
+    #[test]
+    fn test_failed_calls_are_not_counted() {
+        let before = metrics::snapshot().get("lu_decomposition").copied();
+
+        assert!(MatrixUtilities::lu_decomposition(&matrix!([1.0, 2.0])).is_err());
+        assert_eq!(metrics::snapshot().get("lu_decomposition").copied(), before);
+    }
+
+    #[test]
+    fn test_expr_products_are_fused() {
+        let a = Expr::input("A", matrix!([1.0, 0.0], [1.0, 1.0], [1.0, 2.0]));
+        let b = Expr::input("b", matrix!([1.0], [2.0], [4.0]));
+        let count = || metrics::snapshot().get("expr_gemm").map_or(0, |m| m.calls);
+
+        let before = count();
+        let x = a
+            .transpose()
+            .multiply(&a)
+            .inverse()
+            .multiply(&a.transpose().scale(2.0).multiply(&b));
+        x.add(&x).eval().unwrap();
+        assert_eq!(count() - before, 2);
+    }
+}
@@ -0,0 +1,112 @@
+mod named_matrix_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::named_matrix::{NamedMatrix, NamedMatrixError};
+    use std::sync::Arc;
+
+    fn people() -> NamedMatrix<f64> {
+        let data = matrix!([31.0, 180.0, 75.0], [25.0, 165.0, 60.0]);
+        NamedMatrix::new(data, &["age", "height", "weight"])
+            .unwrap()
+            .with_row_labels(&["alice", "bob"])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_col_by_name() {
+        let people = people();
+
+        assert_eq!(people.col_by_name("age").unwrap(), vec![31.0, 25.0]);
+        assert_eq!(people.column_index("weight"), Some(2));
+        assert_eq!(people.row_by_label("bob").unwrap(), vec![25.0, 165.0, 60.0]);
+        assert_eq!(
+            people.col_by_name("salary"),
+            Err(NamedMatrixError::UnknownColumn("salary".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        let data = matrix!([1, 2]);
+
+        assert_eq!(
+            NamedMatrix::new(data.clone(), &["a"]),
+            Err(NamedMatrixError::ColumnCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            NamedMatrix::new(data.clone(), &["a", "a"]),
+            Err(NamedMatrixError::DuplicateColumn("a".to_string()))
+        );
+        let named = NamedMatrix::new(data, &["a", "b"]).unwrap();
+        assert_eq!(
+            named.with_row_labels(&["r1", "r2"]),
+            Err(NamedMatrixError::RowLabelCount {
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_keeps_names() {
+        let selected = people().select(&["weight", "age"]).unwrap();
+
+        assert_eq!(selected.column_names(), ["weight", "age"]);
+        assert_eq!(selected.matrix(), &matrix!([75.0, 31.0], [60.0, 25.0]));
+        assert_eq!(selected.row_labels().unwrap(), ["alice", "bob"]);
+        assert!(people().select(&["age", "missing"]).is_err());
+    }
+
+    #[test]
+    fn test_hconcat_and_vconcat() {
+        let people = people();
+        let extra = NamedMatrix::new(matrix!([1.0], [0.0]), &["smoker"]).unwrap();
+
+        let wide = people.hconcat(&extra).unwrap();
+        assert_eq!(wide.column_names().len(), 4);
+        assert_eq!(wide.col_by_name("smoker").unwrap(), vec![1.0, 0.0]);
+        assert_eq!(
+            people.hconcat(&people),
+            Err(NamedMatrixError::DuplicateColumn("age".to_string()))
+        );
+
+        let carol = NamedMatrix::new(matrix!([70.0, 40.0, 170.0]), &["weight", "age", "height"])
+            .unwrap()
+            .with_row_labels(&["carol"])
+            .unwrap();
+        let tall = people.vconcat(&carol).unwrap();
+        assert_eq!(tall.col_by_name("age").unwrap(), vec![31.0, 25.0, 40.0]);
+        assert_eq!(tall.row_labels().unwrap(), ["alice", "bob", "carol"]);
+        assert_eq!(people.vconcat(&extra), Err(NamedMatrixError::ColumnsDiffer));
+    }
+
+    #[test]
+    fn test_transpose_swaps_names() {
+        let transposed = people().transpose().unwrap();
+
+        assert_eq!(transposed.column_names(), ["alice", "bob"]);
+        assert_eq!(
+            transposed.row_labels().unwrap(),
+            ["age", "height", "weight"]
+        );
+        assert_eq!(
+            transposed.col_by_name("bob").unwrap(),
+            vec![25.0, 165.0, 60.0]
+        );
+
+        let unlabelled = NamedMatrix::new(matrix!([1, 2]), &["a", "b"]).unwrap();
+        assert_eq!(
+            unlabelled.transpose(),
+            Err(NamedMatrixError::MissingRowLabels)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let text = format!("{}", people().select(&["age"]).unwrap());
+        assert_eq!(text, "      age\nalice  31\nbob    25\n");
+    }
+}
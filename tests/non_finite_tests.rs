@@ -0,0 +1,55 @@
+#![allow(deprecated)]
+
+mod non_finite_tests {
+    use linalgrs::adaptive::solve_adaptive;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_has_nan_and_has_inf() {
+        let finite = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let nan = matrix!([1.0, f64::NAN], [3.0, 4.0]);
+        let inf = matrix!([1.0, 2.0], [f64::NEG_INFINITY, 4.0]);
+
+        assert!(!finite.has_nan() && !finite.has_inf());
+        assert!(nan.has_nan() && !nan.has_inf());
+        assert!(!inf.has_nan() && inf.has_inf());
+        assert!(!matrix!([1, 2], [3, 4]).has_nan());
+    }
+
+    #[test]
+    fn test_check_finite_locates_element() {
+        let m = matrix!([1.0, 2.0], [3.0, f64::INFINITY]);
+        assert_eq!(
+            m.check_finite().unwrap_err(),
+            LinalgError::NonFinite { row: 1, col: 1 }
+        );
+        assert!(matrix!([1.0]).check_finite().is_ok());
+    }
+
+    #[test]
+    fn test_solvers_reject_non_finite_input() {
+        let system = matrix!([1.0, 1.0, f64::NAN], [1.0, -1.0, 0.0]);
+        let square = matrix!([1.0, f64::INFINITY], [0.0, 1.0]);
+
+        assert!(MatrixUtilities::gaussian_elimination(system.clone()).is_err());
+        assert!(MatrixUtilities::gauss_jordan_elimination(system).is_err());
+        assert!(MatrixUtilities::inverse(square.clone()).is_err());
+        assert!(MatrixUtilities::lu_decomposition(&square).is_err());
+        assert!(MatrixUtilities::qr_decomposition(&square).is_err());
+        assert!(solve_adaptive(&square, &matrix!([1.0], [1.0])).is_err());
+        assert!(solve_adaptive(&matrix!([1.0]), &matrix!([f64::NAN])).is_err());
+    }
+
+    #[test]
+    fn test_infallible_operations_propagate() {
+        let a = matrix!([1.0, f64::NAN], [0.0, 1.0]);
+        let b = matrix!([1.0, 0.0], [0.0, 1.0]);
+
+        assert!(MatrixUtilities::add(&a, &b).unwrap().has_nan());
+        assert!(MatrixUtilities::multiply(&a, &b).unwrap().has_nan());
+    }
+}
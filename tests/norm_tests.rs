@@ -0,0 +1,81 @@
+mod norm_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::{Matrix, NormKind};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_norms() {
+        let m = matrix!([1.0, -2.0], [-3.0, 4.0]);
+
+        assert!(approx_eq!(
+            f64,
+            m.norm(NormKind::Frobenius).unwrap(),
+            30f64.sqrt()
+        ));
+        assert_eq!(m.norm(NormKind::One).unwrap(), 6.0);
+        assert_eq!(m.norm(NormKind::Infinity).unwrap(), 7.0);
+        // σ_max² is the larger eigenvalue of MᵀM = [[10, −14], [−14, 20]]
+        let expected = (15.0 + 221f64.sqrt()).sqrt();
+        assert!(approx_eq!(
+            f64,
+            m.norm(NormKind::Spectral).unwrap(),
+            expected,
+            epsilon = 1e-12
+        ));
+    }
+
+    #[test]
+    fn test_norm_ordering_and_empty() {
+        let m = matrix!([2.0, 0.0, 1.0], [-1.0, 3.0, 0.5]);
+        let spectral = m.norm(NormKind::Spectral).unwrap();
+
+        assert!(spectral <= m.norm(NormKind::Frobenius).unwrap());
+        assert!(
+            spectral * spectral
+                <= m.norm(NormKind::One).unwrap() * m.norm(NormKind::Infinity).unwrap()
+        );
+        let empty: Matrix<f64> = Matrix::zeros(0, 0);
+        for kind in [
+            NormKind::Frobenius,
+            NormKind::One,
+            NormKind::Infinity,
+            NormKind::Spectral,
+        ] {
+            assert_eq!(empty.norm(kind).unwrap(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_condition_number() {
+        assert!(approx_eq!(
+            f64,
+            matrix!([1.0, 0.0], [0.0, 1e-3]).condition_number().unwrap(),
+            1e3,
+            epsilon = 1e-9
+        ));
+        assert!(approx_eq!(
+            f64,
+            matrix!([0.0, 2.0], [-2.0, 0.0]).condition_number().unwrap(),
+            1.0,
+            ulps = 4
+        ));
+        assert_eq!(
+            matrix!([1.0, 2.0], [2.0, 4.0]).condition_number().unwrap(),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_condition_number_errors() {
+        assert_eq!(
+            matrix!([1.0, 2.0]).condition_number(),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            Matrix::<f64>::zeros(0, 0).condition_number(),
+            Err(LinalgError::Empty)
+        );
+    }
+}
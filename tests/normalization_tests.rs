@@ -0,0 +1,109 @@
+#![allow(deprecated)]
+
+mod normalization_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    fn det(a: &Matrix<f64>) -> f64 {
+        MatrixUtilities::determinant_fast(a).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_determinant() {
+        let a = matrix!([2.0, 1.0, 0.0], [0.0, 3.0, 1.0], [1.0, 0.0, 4.0]);
+        assert!(approx_eq!(
+            f64,
+            det(&a.normalize_determinant().unwrap()),
+            1.0,
+            epsilon = 1e-12
+        ));
+
+        // A reflection keeps its negative orientation
+        let b = matrix!([0.0, 3.0], [3.0, 0.0]);
+        let normalized = b.normalize_determinant().unwrap();
+        assert_matrix_eq!(normalized, matrix!([0.0, 1.0], [1.0, 0.0]), 1e-12);
+        assert!(approx_eq!(f64, det(&normalized), -1.0, epsilon = 1e-12));
+    }
+
+    #[test]
+    fn test_normalize_determinant_out_of_range() {
+        // A well-conditioned tridiagonal matrix with determinant about 1.87^200
+        let tridiagonal = |scale: f64| Matrix {
+            mat: (0..200)
+                .map(|i: usize| {
+                    (0..200)
+                        .map(|j: usize| match i.abs_diff(j) {
+                            0 => 2.0 * scale,
+                            1 => 0.5 * scale,
+                            _ => 0.0,
+                        })
+                        .collect()
+                })
+                .collect(),
+            rows: 200,
+            cols: 200,
+        };
+        let tiny = tridiagonal(1e-3);
+        let huge = tridiagonal(1e6);
+        assert_eq!(det(&tiny), 0.0);
+        assert_eq!(det(&huge), f64::INFINITY);
+
+        let from_tiny = tiny.normalize_determinant().unwrap();
+        let from_huge = huge.normalize_determinant().unwrap();
+        assert!(approx_eq!(f64, det(&from_tiny), 1.0, epsilon = 1e-9));
+        assert!(approx_eq!(f64, det(&from_huge), 1.0, epsilon = 1e-9));
+        assert_matrix_eq!(from_tiny, from_huge, 1e-12);
+    }
+
+    #[test]
+    fn test_normalize_determinant_errors() {
+        assert_eq!(
+            matrix!([1.0, 2.0], [2.0, 4.0]).normalize_determinant(),
+            Err(LinalgError::Singular)
+        );
+        assert!(matches!(
+            matrix!([1.0, 2.0]).normalize_determinant(),
+            Err(LinalgError::NonSquare { .. })
+        ));
+    }
+
+    #[test]
+    fn test_orthonormalize_drifted_rotation() {
+        let (s, c) = 0.3_f64.sin_cos();
+        let rotation = matrix!([c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]);
+        let drifted = matrix!(
+            [c + 1e-4, -s, 2e-5],
+            [s, c - 3e-5, 0.0],
+            [-1e-5, 0.0, 1.0 + 5e-5]
+        );
+
+        let fixed = drifted.orthonormalize().unwrap();
+        let qtq = MatrixUtilities::multiply(&MatrixUtilities::transpose(&fixed), &fixed).unwrap();
+        assert_matrix_eq!(qtq, MatrixUtilities::identity(3), 1e-12);
+        assert!(approx_eq!(f64, det(&fixed), 1.0, epsilon = 1e-12));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((fixed.mat[i][j] - rotation.mat[i][j]).abs() < 1e-3);
+            }
+        }
+
+        // An exact rotation is left unchanged
+        assert_matrix_eq!(rotation.orthonormalize().unwrap(), rotation, 1e-12);
+    }
+
+    #[test]
+    fn test_orthonormalize_errors() {
+        assert_eq!(
+            matrix!([1.0, 1.0], [1.0, 1.0]).orthonormalize(),
+            Err(LinalgError::Singular)
+        );
+        assert!(matches!(
+            matrix!([1.0], [0.0]).orthonormalize(),
+            Err(LinalgError::NonSquare { .. })
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+mod null_space_tests {
+    use linalgrs::arith;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::solve::{column_space, null_space};
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_null_space_of_rank_two() {
+        // Column 2 is twice column 0, and column 3 is column 0 plus column 1
+        let a = matrix!(
+            [1.0, 0.0, 2.0, 1.0],
+            [2.0, 1.0, 4.0, 3.0],
+            [3.0, 5.0, 6.0, 8.0]
+        );
+
+        let n = null_space(&a).unwrap();
+        assert_eq!((n.rows, n.cols), (4, 2));
+        assert_matrix_eq!(arith::multiply(&a, &n).unwrap(), Matrix::zeros(3, 2), 1e-12);
+        assert_matrix_eq!(
+            n,
+            matrix!([-2.0, -1.0], [0.0, -1.0], [1.0, 0.0], [0.0, 1.0]),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_column_space_takes_pivot_columns() {
+        let a = matrix!(
+            [1.0, 0.0, 2.0, 1.0],
+            [2.0, 1.0, 4.0, 3.0],
+            [3.0, 5.0, 6.0, 8.0]
+        );
+
+        assert_eq!(
+            column_space(&a).unwrap(),
+            matrix!([1.0, 0.0], [2.0, 1.0], [3.0, 5.0])
+        );
+    }
+
+    #[test]
+    fn test_full_rank_and_zero_matrices() {
+        let a = matrix!([2.0, 1.0], [1.0, 3.0]);
+        assert_eq!(null_space(&a).unwrap(), Matrix::zeros(2, 0));
+        assert_eq!(column_space(&a).unwrap(), a);
+
+        let zero = Matrix::<f64>::zeros(2, 3);
+        assert_eq!(null_space(&zero).unwrap(), arith::identity(3));
+        assert_eq!(column_space(&zero).unwrap(), Matrix::zeros(2, 0));
+    }
+
+    #[test]
+    fn test_singular_up_to_rounding() {
+        // The third row is 0.1 times the first plus 0.2 times the second, inexactly
+        let a = matrix!(
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [
+                0.1 * 1.0 + 0.2 * 4.0,
+                0.1 * 2.0 + 0.2 * 5.0,
+                0.1 * 3.0 + 0.2 * 6.0
+            ]
+        );
+
+        let n = null_space(&a).unwrap();
+        assert_eq!(n.cols, 1);
+        assert_matrix_eq!(arith::multiply(&a, &n).unwrap(), Matrix::zeros(3, 1), 1e-12);
+        assert_eq!(
+            null_space(&matrix!([f64::INFINITY])),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_null_space_facade() {
+        let a = matrix!([1.0, 2.0, 3.0], [2.0, 4.0, 6.0]);
+
+        assert_eq!(MatrixUtilities::null_space(&a), null_space(&a));
+        assert_eq!(MatrixUtilities::column_space(&a), column_space(&a));
+    }
+}
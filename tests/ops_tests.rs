@@ -0,0 +1,53 @@
+mod ops_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_add_sub_operators() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([4, 3], [2, 1]);
+
+        assert_eq!(&a + &b, matrix!([5, 5], [5, 5]));
+        assert_eq!(&a - &b, matrix!([-3, -1], [1, 3]));
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+        assert_eq!(a.clone() - &b, &a - b.clone());
+    }
+
+    #[test]
+    fn test_mul_operators() {
+        let a = matrix!([1, 2], [3, 4]);
+        let i = matrix!([1, 0], [0, 1]);
+
+        assert_eq!(&a * &i, a);
+        assert_eq!(&a * &a, matrix!([7, 10], [15, 22]));
+        assert_eq!(&a * 2, matrix!([2, 4], [6, 8]));
+        assert_eq!(a.clone() * 3, matrix!([3, 6], [9, 12]));
+        assert_eq!(&a * &a + &a * 2 - &i, matrix!([8, 14], [21, 29]));
+    }
+
+    #[test]
+    fn test_neg_operator() {
+        let a = matrix!([1.0, -2.0]);
+
+        assert_eq!(-&a, matrix!([-1.0, 2.0]));
+        assert_eq!(-(-a.clone()), a);
+    }
+
+    #[test]
+    fn test_checked_variants() {
+        let a = matrix!([1, 2, 3]);
+        let b = matrix!([1, 2]);
+
+        assert!(a.checked_add(&b).is_err());
+        assert!(a.checked_sub(&b).is_err());
+        assert!(a.checked_mul(&a).is_err());
+        assert_eq!(b.checked_add(&b).unwrap(), matrix!([2, 4]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_operator_panics_on_shape_mismatch() {
+        let _ = &matrix!([1, 2, 3]) + &matrix!([1, 2]);
+    }
+}
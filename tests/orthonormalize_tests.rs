@@ -0,0 +1,86 @@
+mod orthonormalize_tests {
+    use linalgrs::decompose::orthonormalize;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{arith, transform};
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    /// Checks that `q` has orthonormal columns and that `QᵀA` is the upper triangular `R`
+    /// of `A = QR`
+    fn check_basis(a: &Matrix<f64>, q: &Matrix<f64>, epsilon: f64) {
+        let qt = transform::transpose(q);
+        let qtq = arith::multiply(&qt, q).unwrap();
+        assert_matrix_eq!(qtq, arith::identity(a.cols), epsilon);
+
+        let r = arith::multiply(&qt, a).unwrap();
+        for i in 0..r.rows {
+            assert!(r.mat[i][i] > 0.0);
+            for j in 0..i {
+                assert!(r.mat[i][j].abs() < epsilon);
+            }
+        }
+        assert_matrix_eq!(arith::multiply(q, &r).unwrap(), a, epsilon);
+    }
+
+    #[test]
+    fn test_orthonormalize_tall() {
+        let a = matrix!([3.0, 1.0], [4.0, -2.0], [0.0, 5.0]);
+
+        let q = orthonormalize(&a).unwrap();
+        check_basis(&a, &q, 1e-12);
+        assert_matrix_eq!(
+            q.view(0..3, 0..1).unwrap().to_matrix(),
+            matrix!([0.6], [0.8], [0.0]),
+            1e-15,
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_orthonormalize_facade() {
+        let a = matrix!([3.0, 1.0], [4.0, -2.0], [0.0, 5.0]);
+
+        assert_eq!(MatrixUtilities::orthonormalize(&a), orthonormalize(&a));
+    }
+
+    #[test]
+    fn test_orthonormalize_nearly_dependent() {
+        // Läuchli's matrix: classical Gram–Schmidt loses all orthogonality here
+        let e = 1e-7;
+        let a = matrix!([1.0, 1.0, 1.0], [e, 0.0, 0.0], [0.0, e, 0.0], [0.0, 0.0, e]);
+
+        let q = orthonormalize(&a).unwrap();
+        let qtq = arith::multiply(&transform::transpose(&q), &q).unwrap();
+        assert_matrix_eq!(qtq, arith::identity(3), 1e-14);
+    }
+
+    #[test]
+    fn test_orthonormalize_dependent_columns() {
+        assert_eq!(
+            orthonormalize(&matrix!([1.0, 2.0, 3.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0])),
+            Err(LinalgError::LinearlyDependent { col: 2 })
+        );
+        assert_eq!(
+            orthonormalize(&matrix!([0.0, 1.0], [0.0, 2.0])),
+            Err(LinalgError::LinearlyDependent { col: 0 })
+        );
+        // More columns than rows can never be independent
+        assert_eq!(
+            orthonormalize(&matrix!([1.0, 0.0, 2.0], [0.0, 1.0, 3.0])),
+            Err(LinalgError::LinearlyDependent { col: 2 })
+        );
+        assert_eq!(
+            orthonormalize(&matrix!([1.0], [f64::NAN])),
+            Err(LinalgError::NonFinite { row: 1, col: 0 })
+        );
+    }
+
+    #[test]
+    fn test_orthonormalize_no_columns() {
+        let a = Matrix::<f64>::zeros(3, 0);
+
+        assert_eq!(orthonormalize(&a).unwrap(), a);
+    }
+}
@@ -0,0 +1,60 @@
+mod owned_paths_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{arith, solve};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_add_and_subtract_owned_reuse_rows() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let b = matrix!([0.5, 0.5], [1.0, -1.0]);
+        let rows: Vec<*const f64> = a.mat.iter().map(|row| row.as_ptr()).collect();
+
+        let sum = arith::add_owned(a, &b).unwrap();
+        assert_eq!(sum, matrix!([1.5, 2.5], [4.0, 3.0]));
+        assert!(sum.mat.iter().zip(&rows).all(|(row, &p)| row.as_ptr() == p));
+
+        let difference = arith::subtract_owned(sum, &b).unwrap();
+        assert_eq!(difference, matrix!([1.0, 2.0], [3.0, 4.0]));
+        assert!(difference
+            .mat
+            .iter()
+            .zip(&rows)
+            .all(|(row, &p)| row.as_ptr() == p));
+        assert_eq!(
+            arith::add_owned(difference, &matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_owned_paths_leave_shared_rows_alone() {
+        let a = matrix!([1, 2], [3, 4]);
+        let shared = a.clone();
+
+        let scaled = arith::multiply_by_scalar_owned(a, 3);
+        assert_eq!(scaled, matrix!([3, 6], [9, 12]));
+        assert_eq!(shared, matrix!([1, 2], [3, 4]));
+        assert_eq!(arith::multiply_by_scalar(&shared, 3), scaled);
+        assert_eq!(shared.clone() + &shared, &shared + &shared);
+    }
+
+    #[test]
+    fn test_echelon_forms_by_reference() {
+        let m = matrix!([2.0, 4.0, 6.0], [1.0, 3.0, 5.0]);
+
+        assert_eq!(solve::rref(&m), matrix!([1.0, 0.0, -1.0], [0.0, 1.0, 2.0]));
+        assert_eq!(solve::rref(&m), solve::rref_owned(m.clone()));
+        assert_eq!(
+            solve::row_echelon_form(&m),
+            solve::row_echelon_form_owned(m.clone())
+        );
+        assert_eq!(m, matrix!([2.0, 4.0, 6.0], [1.0, 3.0, 5.0]));
+        assert_eq!(solve::gaussian_elimination(&m).unwrap()[&'b'], 2.0);
+        assert_eq!(solve::gauss_jordan_elimination(&m).unwrap()[&'a'], -1.0);
+    }
+}
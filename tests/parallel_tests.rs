@@ -0,0 +1,81 @@
+#![allow(deprecated)]
+
+mod parallel_tests {
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    // Large enough that the `rayon` feature, when enabled, splits the loops across threads
+    const N: usize = 96;
+
+    fn integer_matrix(n: usize, seed: i64) -> Matrix<i64> {
+        Matrix {
+            mat: (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| ((i as i64 * 31 + j as i64 * 17 + seed) % 11) - 5)
+                        .collect()
+                })
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    fn diagonally_dominant(n: usize) -> Matrix<f64> {
+        Matrix {
+            mat: (0..n)
+                .map(|i| {
+                    let row: Vec<f64> = (0..n)
+                        .map(|j| if i == j { 4.0 * n as f64 } else { ((i + 2 * j) % 7) as f64 })
+                        .collect();
+                    Arc::from(row)
+                })
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    #[test]
+    fn test_large_multiply_matches_row_by_row() {
+        let a = integer_matrix(N, 3);
+        let b = integer_matrix(N, 7);
+
+        let product = MatrixUtilities::multiply(&a, &b).unwrap();
+        for r in [0, N / 2, N - 1] {
+            let row = Matrix {
+                mat: vec![a.mat[r].clone()],
+                rows: 1,
+                cols: N,
+            };
+            let expected = MatrixUtilities::multiply(&row, &b).unwrap();
+            assert_eq!(product.mat[r], expected.mat[0]);
+        }
+        assert_eq!(
+            MatrixUtilities::multiply(&a, &MatrixUtilities::identity(N)).unwrap(),
+            a
+        );
+    }
+
+    #[test]
+    fn test_large_elimination() {
+        let a = diagonally_dominant(N);
+
+        let reduced = MatrixUtilities::rref(a.clone());
+        let identity: Matrix<f64> = MatrixUtilities::identity(N);
+        for i in 0..N {
+            for j in 0..N {
+                assert!((reduced.mat[i][j] - identity.mat[i][j]).abs() < 1e-9);
+            }
+        }
+
+        let inverse = MatrixUtilities::inverse(a.clone()).unwrap();
+        let product = MatrixUtilities::multiply(&a, &inverse).unwrap();
+        for i in 0..N {
+            for j in 0..N {
+                assert!((product.mat[i][j] - identity.mat[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+mod parse_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parse_matlab_style() {
+        let mat: Matrix<i64> = Matrix::parse("1 2 3; 4 5 6").unwrap();
+        assert_eq!(mat, matrix!([1, 2, 3], [4, 5, 6]));
+    }
+
+    #[test]
+    fn test_parse_brackets_commas_and_newlines() {
+        let mat: Matrix<f64> = "[1.5, -2\n 3e2, 4;]".parse().unwrap();
+        assert_eq!(mat, matrix!([1.5, -2.0], [300.0, 4.0]));
+        assert_eq!((mat.rows, mat.cols), (2, 2));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let mat: Matrix<i32> = "[]".parse().unwrap();
+        assert_eq!(mat, Matrix::default());
+    }
+
+    #[test]
+    fn test_parse_invalid_number_position() {
+        let err = Matrix::<i32>::parse("1 2; 3 x4").unwrap_err();
+        assert_eq!(err.position, 7);
+        assert_eq!(err.to_string(), "invalid number `x4` at position 7");
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let err = Matrix::<f64>::parse("1 2 3; 4 5").unwrap_err();
+        assert_eq!(err.position, 7);
+        assert!(err.message.contains("row 1 has 2 elements"));
+    }
+
+    #[test]
+    fn test_parse_unbalanced_brackets() {
+        assert_eq!(Matrix::<i32>::parse("[1 2").unwrap_err().position, 4);
+        assert_eq!(Matrix::<i32>::parse("1 ] 2").unwrap_err().position, 2);
+    }
+}
@@ -0,0 +1,149 @@
+#![allow(deprecated)]
+
+mod pde_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::pde::{apply_dirichlet, apply_neumann, poisson_2d};
+    use linalgrs::system::System;
+    use std::f64::consts::PI;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_poisson_2d_assembles_stencil() {
+        // hx = hy = 1/3, so every stencil weight is 9
+        let system = poisson_2d(2, 2, |x: f64, _| x).unwrap();
+
+        assert_eq!(system.matrix.nnz(), 12);
+        assert_eq!(system.matrix.get(0, 0), Some(36.0));
+        assert_eq!(system.matrix.get(0, 1), Some(-9.0));
+        assert_eq!(system.matrix.get(0, 2), Some(-9.0));
+        assert_eq!(system.matrix.get(0, 3), Some(0.0));
+        // Point (1, 0) touches the right edge at x = 1 and the bottom edge at x = 2/3
+        assert!((system.rhs[1] - 9.0 * (1.0 + 2.0 / 3.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_laplace_reproduces_harmonic_boundary() {
+        // The 5-point stencil is exact for the harmonic function x² − y² + x y
+        let u = |x: f64, y: f64| x * x - y * y + x * y;
+        let (nx, ny) = (7, 5);
+        let grid = poisson_2d(nx, ny, u).unwrap().solve(1e-12).unwrap();
+
+        assert_eq!((grid.rows, grid.cols), (ny, nx));
+        for j in 0..ny {
+            for i in 0..nx {
+                let (x, y) = ((i + 1) as f64 / 8.0, (j + 1) as f64 / 6.0);
+                assert!((grid.mat[j][i] - u(x, y)).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_poisson_converges_to_analytic_solution() {
+        let exact = |x: f64, y: f64| (PI * x).sin() * (PI * y).sin();
+        let error = |n: usize| {
+            let grid = poisson_2d(n, n, |_, _| 0.0)
+                .unwrap()
+                .with_source(|x, y| 2.0 * PI * PI * exact(x, y))
+                .solve(1e-12)
+                .unwrap();
+            let h = 1.0 / (n + 1) as f64;
+            (0..n * n)
+                .map(|k| {
+                    (grid.mat[k / n][k % n] - exact((k % n + 1) as f64 * h, (k / n + 1) as f64 * h))
+                        .abs()
+                })
+                .fold(0.0, f64::max)
+        };
+
+        // Halving h should cut the error by about four, the stencil being second order
+        let (coarse, fine) = (error(9), error(19));
+        assert!(coarse < 1e-2);
+        assert!(coarse / fine > 3.5);
+    }
+
+    #[test]
+    fn test_poisson_errors() {
+        assert!(matches!(
+            poisson_2d(0, 3, |_, _| 0.0f64),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        let system = poisson_2d(2, 2, |_, _| 1.0f64).unwrap();
+        assert_eq!(
+            system.to_grid(&[1.0]),
+            Err(LinalgError::LengthMismatch {
+                expected: 4,
+                found: 1
+            })
+        );
+    }
+
+    /// The 1D stencil `−u'' = 0` on five nodes, with half-cell rows at both ends
+    fn rod() -> System<f64> {
+        System::new(
+            matrix!(
+                [1.0, -1.0, 0.0, 0.0, 0.0],
+                [-1.0, 2.0, -1.0, 0.0, 0.0],
+                [0.0, -1.0, 2.0, -1.0, 0.0],
+                [0.0, 0.0, -1.0, 2.0, -1.0],
+                [0.0, 0.0, 0.0, -1.0, 1.0]
+            ),
+            Matrix::zeros(5, 2),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dirichlet_keeps_system_symmetric() {
+        let mut system = rod();
+        apply_dirichlet(&mut system, 0, 1.0).unwrap();
+        apply_dirichlet(&mut system, 4, 3.0).unwrap();
+
+        assert_eq!(
+            system.coefficients,
+            MatrixUtilities::transpose(&system.coefficients)
+        );
+        assert_eq!(system.constants.mat[1].as_ref(), &[1.0, 1.0]);
+        let u = system.solve().unwrap();
+        for (i, expected) in [1.0, 1.5, 2.0, 2.5, 3.0].into_iter().enumerate() {
+            assert!((u.mat[i][0] - expected).abs() < 1e-12);
+            assert!((u.mat[i][1] - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_neumann_sets_boundary_flux() {
+        let mut system = rod();
+        apply_dirichlet(&mut system, 0, 0.0).unwrap();
+        apply_neumann(&mut system, 4, 0.5).unwrap();
+
+        let u = system.solve().unwrap();
+        for i in 0..5 {
+            assert!((u.mat[i][0] - 0.5 * i as f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_boundary_condition_errors() {
+        let mut system = rod();
+        assert_eq!(
+            apply_dirichlet(&mut system, 5, 1.0),
+            Err(LinalgError::OutOfBounds { index: 5, len: 5 })
+        );
+        assert_eq!(
+            apply_neumann(&mut system, 7, 1.0),
+            Err(LinalgError::OutOfBounds { index: 7, len: 5 })
+        );
+
+        system.constants = Matrix::zeros(4, 1);
+        assert_eq!(
+            apply_neumann(&mut system, 0, 1.0),
+            Err(LinalgError::ShapeMismatch {
+                expected: (5, 1),
+                found: (4, 1)
+            })
+        );
+    }
+}
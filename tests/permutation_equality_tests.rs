@@ -0,0 +1,89 @@
+mod permutation_equality_tests {
+    use linalgrs::arith::{self, eq_up_to_col_permutation, eq_up_to_row_permutation};
+    use linalgrs::decompose;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_row_permutation() {
+        let a = matrix!([1, 2], [3, 4], [5, 6]);
+
+        assert_eq!(
+            eq_up_to_row_permutation(&a, &matrix!([5, 6], [1, 2], [3, 4])),
+            Some(vec![2, 0, 1])
+        );
+        assert_eq!(eq_up_to_row_permutation(&a, &a), Some(vec![0, 1, 2]));
+        assert_eq!(
+            eq_up_to_row_permutation(&a, &matrix!([5, 6], [1, 2], [3, 5])),
+            None
+        );
+        assert_eq!(eq_up_to_row_permutation(&a, &matrix!([1, 2], [3, 4])), None);
+        // A repeated row must be matched as often as it occurs
+        assert_eq!(
+            eq_up_to_row_permutation(
+                &matrix!([1, 1], [2, 2], [1, 1]),
+                &matrix!([1, 1], [1, 1], [2, 2])
+            ),
+            Some(vec![0, 2, 1])
+        );
+        assert_eq!(
+            eq_up_to_row_permutation(
+                &matrix!([1, 1], [2, 2], [2, 2]),
+                &matrix!([1, 1], [1, 1], [2, 2])
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_col_permutation() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+
+        assert_eq!(
+            eq_up_to_col_permutation(&a, &matrix!([3, 1, 2], [6, 4, 5])),
+            Some(vec![2, 0, 1])
+        );
+        assert_eq!(
+            eq_up_to_col_permutation(&a, &matrix!([3, 1, 2], [6, 5, 4])),
+            None
+        );
+        assert_eq!(eq_up_to_col_permutation(&a, &a), Some(vec![0, 1, 2]));
+        assert_eq!(
+            eq_up_to_col_permutation(&Matrix::<i32>::zeros(0, 0), &Matrix::zeros(0, 0)),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_matches_pivoted_rows() {
+        let a = matrix!([0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]);
+
+        let (p, _, _) = decompose::plu_decomposition(&a).unwrap();
+        let pivoted = arith::multiply(&p, &a).unwrap();
+        let order = eq_up_to_row_permutation(&a, &pivoted).unwrap();
+        assert_eq!(order, vec![2, 0, 1]);
+        assert_eq!(
+            eq_up_to_row_permutation(&matrix!([f64::NAN]), &matrix!([f64::NAN])),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_permutation_facade() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+        let rows = matrix!([4, 5, 6], [1, 2, 3]);
+        let cols = matrix!([3, 1, 2], [6, 4, 5]);
+
+        assert_eq!(
+            MatrixUtilities::eq_up_to_row_permutation(&a, &rows),
+            eq_up_to_row_permutation(&a, &rows)
+        );
+        assert_eq!(
+            MatrixUtilities::eq_up_to_col_permutation(&a, &cols),
+            eq_up_to_col_permutation(&a, &cols)
+        );
+    }
+}
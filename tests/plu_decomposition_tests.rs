@@ -0,0 +1,93 @@
+#![allow(deprecated)]
+
+mod plu_decomposition_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    fn check_plu(a: &Matrix<f64>) {
+        let (p, l, u) = MatrixUtilities::plu_decomposition(a).unwrap();
+
+        let pa = MatrixUtilities::multiply(&p, a).unwrap();
+        assert_matrix_eq!(MatrixUtilities::multiply(&l, &u).unwrap(), pa, 1e-12);
+        for i in 0..a.rows {
+            assert_eq!(p.mat[i].iter().filter(|&&x| x == 1.0).count(), 1);
+            assert_eq!(l.mat[i][i], 1.0);
+            for j in 0..i {
+                assert_eq!(u.mat[i][j], 0.0);
+                assert!(l.mat[j][i] == 0.0 && l.mat[i][j].abs() <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plu_needs_row_exchange() {
+        // Invertible, but the zero in the corner stops LU without pivoting
+        let a = matrix!([0.0, 1.0], [1.0, 1.0]);
+        assert_eq!(
+            MatrixUtilities::lu_decomposition(&a),
+            Err(LinalgError::ZeroPivot)
+        );
+
+        check_plu(&a);
+        let (p, _, _) = MatrixUtilities::plu_decomposition(&a).unwrap();
+        assert_eq!(p, matrix!([0.0, 1.0], [1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_plu_general_and_singular() {
+        check_plu(&matrix!(
+            [2.0, 1.0, 1.0, 0.0],
+            [4.0, 3.0, 3.0, 1.0],
+            [8.0, 7.0, 9.0, 5.0],
+            [6.0, 7.0, 9.0, 8.0]
+        ));
+        check_plu(&matrix!([1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]));
+        check_plu(&matrix!([0.0, 0.0], [0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_plu_errors() {
+        assert!(matches!(
+            MatrixUtilities::plu_decomposition(&matrix!([1.0, 2.0, 3.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 3 })
+        ));
+        assert_eq!(
+            MatrixUtilities::plu_decomposition(&matrix!([1.0, f64::INFINITY], [0.0, 1.0]))
+                .map(|_| ()),
+            Err(LinalgError::NonFinite { row: 0, col: 1 })
+        );
+    }
+
+    #[test]
+    fn test_ldu_decomposition() {
+        let a = matrix!([2.0, 4.0, -2.0], [4.0, 9.0, -3.0], [-2.0, -3.0, 7.0]);
+        let (l, d, u) = MatrixUtilities::ldu_decomposition(&a).unwrap();
+
+        assert_eq!(
+            d,
+            matrix!([2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 4.0])
+        );
+        // A is symmetric, so U is the transpose of L
+        assert_matrix_eq!(u, MatrixUtilities::transpose(&l), 1e-12);
+        let ld = MatrixUtilities::multiply(&l, &d).unwrap();
+        assert_matrix_eq!(MatrixUtilities::multiply(&ld, &u).unwrap(), a, 1e-12);
+        for i in 0..3 {
+            assert_eq!((l.mat[i][i], u.mat[i][i]), (1.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_ldu_errors() {
+        assert_eq!(
+            MatrixUtilities::ldu_decomposition(&matrix!([0.0, 1.0], [1.0, 1.0])),
+            Err(LinalgError::ZeroPivot)
+        );
+        assert!(matches!(
+            MatrixUtilities::ldu_decomposition(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { .. })
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+mod polynomial_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::polynomial::roots;
+    use num::Complex;
+    use std::sync::Arc;
+
+    /// Checks that `found` holds the `expected` roots in any order, since roots with equal
+    /// real parts may be ordered either way after rounding
+    fn assert_roots(found: &[Complex<f64>], expected: &[(f64, f64)]) {
+        assert_eq!(found.len(), expected.len());
+        let mut unmatched = found.to_vec();
+        for &(re, im) in expected {
+            let i = unmatched
+                .iter()
+                .position(|root| (root - Complex::new(re, im)).norm() < 1e-10)
+                .unwrap_or_else(|| panic!("{} + {}i not in {:?}", re, im, found));
+            unmatched.swap_remove(i);
+        }
+    }
+
+    #[test]
+    fn test_companion() {
+        assert_eq!(
+            Matrix::companion(&[2.0, -4.0, 6.0, -8.0]).unwrap(),
+            matrix!([2.0, -3.0, 4.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0])
+        );
+        assert_eq!(Matrix::companion(&[1.0, 5.0]).unwrap(), matrix!([-5.0]));
+
+        for coeffs in [&[][..], &[3.0], &[0.0, 1.0, 2.0], &[1.0, f64::NAN]] {
+            assert!(matches!(
+                Matrix::companion(coeffs),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_real_roots() {
+        // (x − 1)(x − 2)(x − 3)
+        assert_roots(
+            &roots(&[1.0, -6.0, 11.0, -6.0]).unwrap(),
+            &[(1.0, 0.0), (2.0, 0.0), (3.0, 0.0)],
+        );
+        // 2(x + 4)(x − 0.5), after dropping a leading zero
+        assert_roots(
+            &roots(&[0.0, 2.0, 7.0, -4.0]).unwrap(),
+            &[(-4.0, 0.0), (0.5, 0.0)],
+        );
+    }
+
+    #[test]
+    fn test_complex_and_zero_roots() {
+        // (x² + 1)(x − 2) x², whose trailing zeros are exact roots
+        let found = roots(&[1.0, -2.0, 1.0, -2.0, 0.0, 0.0]).unwrap();
+        assert_roots(
+            &found,
+            &[(0.0, -1.0), (0.0, 0.0), (0.0, 0.0), (0.0, 1.0), (2.0, 0.0)],
+        );
+        assert_eq!(
+            found
+                .iter()
+                .filter(|&&root| root == Complex::new(0.0, 0.0))
+                .count(),
+            2
+        );
+
+        // The fifth roots of unity
+        let found = roots(&[1.0, 0.0, 0.0, 0.0, 0.0, -1.0]).unwrap();
+        assert_eq!(found.len(), 5);
+        for root in &found {
+            assert!(approx_eq!(f64, root.norm(), 1.0, epsilon = 1e-12));
+            assert!((root.powu(5) - Complex::new(1.0, 0.0)).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_degenerate_polynomials() {
+        assert_eq!(roots(&[5.0]), Ok(vec![]));
+        assert_eq!(roots(&[0.0, 0.0, 3.0]), Ok(vec![]));
+        assert!(matches!(
+            roots(&[0.0, 0.0]),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            roots::<f64>(&[]),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            roots(&[1.0, f64::INFINITY]),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+}
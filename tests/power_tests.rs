@@ -0,0 +1,100 @@
+#![allow(deprecated)]
+
+mod power_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_pow_fibonacci() {
+        let q: Matrix<i64> = matrix!([1, 1], [1, 0]);
+
+        assert_eq!(
+            MatrixUtilities::pow(&q, 10).unwrap(),
+            matrix!([89, 55], [55, 34])
+        );
+        assert_eq!(MatrixUtilities::pow(&q, 1).unwrap(), q);
+        assert_eq!(
+            MatrixUtilities::pow(&q, 0).unwrap(),
+            MatrixUtilities::identity(2)
+        );
+    }
+
+    #[test]
+    fn test_pow_matches_repeated_multiplication() {
+        let a = matrix!([1, 2, 0], [0, 1, -1], [3, 0, 1]);
+        let mut expected = a.clone();
+        for _ in 1..7 {
+            expected = MatrixUtilities::multiply(&expected, &a).unwrap();
+        }
+
+        assert_eq!(MatrixUtilities::pow(&a, 7).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_pow_non_square() {
+        assert_eq!(
+            MatrixUtilities::pow(&matrix!([1, 2]), 2),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn test_matrix_exponential_of_diagonal_and_nilpotent() {
+        let zero: Matrix<f64> = Matrix::zeros(3, 3);
+        assert_eq!(
+            MatrixUtilities::matrix_exponential(&zero).unwrap(),
+            MatrixUtilities::identity(3)
+        );
+
+        let diagonal = matrix!([1.0, 0.0], [0.0, -2.0]);
+        assert_matrix_eq!(
+            MatrixUtilities::matrix_exponential(&diagonal).unwrap(),
+            matrix!([1f64.exp(), 0.0], [0.0, (-2f64).exp()]),
+            1e-14,
+        );
+
+        let nilpotent = matrix!([0.0, 1.0], [0.0, 0.0]);
+        assert_matrix_eq!(
+            MatrixUtilities::matrix_exponential(&nilpotent).unwrap(),
+            matrix!([1.0, 1.0], [0.0, 1.0]),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn test_matrix_exponential_of_rotation_generator() {
+        // e^(tJ) rotates by t; t = 10 needs several squarings
+        for t in [0.3, 10.0] {
+            let generator = matrix!([0.0, -t], [t, 0.0]);
+            let (c, s) = (f64::cos(t), f64::sin(t));
+
+            assert_matrix_eq!(
+                MatrixUtilities::matrix_exponential(&generator).unwrap(),
+                matrix!([c, -s], [s, c]),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn test_matrix_exponential_inverse_and_errors() {
+        let a = matrix!([0.5, 1.0, -2.0], [0.0, -1.0, 3.0], [1.5, 0.2, 0.1]);
+        let e = MatrixUtilities::matrix_exponential(&a).unwrap();
+        let e_neg =
+            MatrixUtilities::matrix_exponential(&MatrixUtilities::multiply_by_scalar(a, -1.0))
+                .unwrap();
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&e, &e_neg).unwrap(),
+            MatrixUtilities::identity(3),
+            1e-12,
+        );
+
+        assert_eq!(
+            MatrixUtilities::matrix_exponential(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+}
@@ -0,0 +1,59 @@
+mod prelude_tests {
+    use linalgrs::prelude::*;
+
+    #[test]
+    fn test_prelude_is_self_sufficient() {
+        let a = matrix!([2.0, 1.0], [1.0, 3.0]);
+        let b = vector![3.0, 5.0].to_column_matrix();
+
+        let system = System::new(a.clone(), b).unwrap();
+        let x: Matrix<f64> = system.solve().unwrap();
+        assert_eq!(Vector::from_matrix(&x).unwrap().len(), 2);
+        assert!(a.norm(NormKind::Frobenius).unwrap() > 0.0);
+        assert_eq!(solve::determinant_fast(&a), Ok(5.0));
+        assert!(decompose::qr_decomposition(&a).is_ok());
+        assert_eq!(transform::transpose(&a), a);
+        assert_eq!(
+            arith::multiply(&a, &matrix!([1.0, 2.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_prelude_storage_types() {
+        fn sum<T: Number>(values: &[T]) -> T {
+            values.iter().fold(T::default(), |acc, &x| acc + x)
+        }
+
+        let a = matrix!([1, 0], [0, 2]);
+        assert_eq!(sum(&DenseMatrix::from(&a).data), 3);
+        assert_eq!(SparseMatrix::from_dense(&a).unwrap().nnz(), 2);
+        assert_eq!(a.mat[1], Arc::from([0, 2]));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_prelude_keeps_facade() {
+        let a = matrix!([1, 2], [3, 4]);
+
+        assert_eq!(MatrixUtilities::transpose(&a), transform::transpose(&a));
+    }
+
+    #[test]
+    fn test_prelude_traits_for_generic_code() {
+        fn negated_corner<T: Number + Neg<Output = T>>(m: &Matrix<T>) -> T {
+            -m[(0, 0)]
+        }
+        fn total<T: Number + Sum<T>>(m: &Matrix<T>) -> T {
+            m.iter().copied().sum()
+        }
+
+        let a = matrix!([1, 2], [3, 4]);
+        assert_eq!(negated_corner(&a), -1);
+        assert_eq!(total(&a), 10);
+        assert_eq!(&a + &a, matrix!([2, 4], [6, 8]));
+    }
+}
@@ -0,0 +1,128 @@
+#![allow(deprecated)]
+
+mod product_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_kronecker() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([0, 5], [6, 7]);
+
+        assert_eq!(
+            MatrixUtilities::kronecker(&a, &b).unwrap(),
+            matrix!(
+                [0, 5, 0, 10],
+                [6, 7, 12, 14],
+                [0, 15, 0, 20],
+                [18, 21, 24, 28]
+            )
+        );
+    }
+
+    #[test]
+    fn test_kronecker_shapes_and_mixed_product() {
+        let a = matrix!([1, 2, 3]);
+        let b = matrix!([1], [-1]);
+        let k = MatrixUtilities::kronecker(&a, &b).unwrap();
+        assert_eq!(k, matrix!([1, 2, 3], [-1, -2, -3]));
+
+        // (A ⊗ B)(C ⊗ D) = AC ⊗ BD
+        let (a, b) = (matrix!([1, 2], [0, 1]), matrix!([2, 0], [1, 3]));
+        let (c, d) = (matrix!([1, 1], [1, 0]), matrix!([0, 1], [1, 1]));
+        let lhs = MatrixUtilities::multiply(
+            &MatrixUtilities::kronecker(&a, &b).unwrap(),
+            &MatrixUtilities::kronecker(&c, &d).unwrap(),
+        )
+        .unwrap();
+        let rhs = MatrixUtilities::kronecker(
+            &MatrixUtilities::multiply(&a, &c).unwrap(),
+            &MatrixUtilities::multiply(&b, &d).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let b = matrix!([0.5, -1.0], [2.0, 0.0]);
+
+        assert_eq!(
+            MatrixUtilities::hadamard(&a, &b).unwrap(),
+            matrix!([0.5, -2.0], [6.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn test_hadamard_shape_mismatch() {
+        assert_eq!(
+            MatrixUtilities::hadamard(&matrix!([1, 2]), &matrix!([1], [2])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 2),
+                found: (2, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_commutator_of_pauli_matrices() {
+        // [σx, σz] = −2iσy, which is the real matrix below
+        let x = matrix!([0, 1], [1, 0]);
+        let z = matrix!([1, 0], [0, -1]);
+
+        assert_eq!(
+            MatrixUtilities::commutator(&x, &z).unwrap(),
+            matrix!([0, -2], [2, 0])
+        );
+        assert_eq!(
+            MatrixUtilities::anticommutator(&x, &z).unwrap(),
+            Matrix::zeros(2, 2)
+        );
+        assert_eq!(
+            MatrixUtilities::anticommutator(&x, &x).unwrap(),
+            matrix!([2, 0], [0, 2])
+        );
+    }
+
+    #[test]
+    fn test_commutator_matches_products() {
+        let a = matrix!([1, 2, 0], [0, 1, -1], [3, 0, 1]);
+        let b = matrix!([2, 0, 1], [1, 1, 0], [0, -2, 3]);
+        let ab = MatrixUtilities::multiply(&a, &b).unwrap();
+        let ba = MatrixUtilities::multiply(&b, &a).unwrap();
+
+        assert_eq!(
+            MatrixUtilities::commutator(&a, &b).unwrap(),
+            MatrixUtilities::subtract(&ab, &ba).unwrap()
+        );
+        assert_eq!(
+            MatrixUtilities::anticommutator(&a, &b).unwrap(),
+            MatrixUtilities::add(&ab, &ba).unwrap()
+        );
+        assert_eq!(
+            MatrixUtilities::commutator(&a, &a).unwrap(),
+            Matrix::zeros(3, 3)
+        );
+    }
+
+    #[test]
+    fn test_commutator_shape_errors() {
+        let square = matrix!([1, 2], [3, 4]);
+
+        assert_eq!(
+            MatrixUtilities::commutator(&matrix!([1, 2]), &square),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            MatrixUtilities::anticommutator(&square, &matrix!([1])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 1)
+            })
+        );
+    }
+}
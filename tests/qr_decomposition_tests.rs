@@ -0,0 +1,51 @@
+#![allow(deprecated)]
+
+mod qr_decomposition_tests {
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    fn check_qr(a: &Matrix<f64>) {
+        let (q, r) = MatrixUtilities::qr_decomposition(a).unwrap();
+
+        assert_matrix_eq!(MatrixUtilities::multiply(&q, &r).unwrap(), a, 1e-10);
+        let qtq = MatrixUtilities::multiply(&MatrixUtilities::transpose(&q), &q).unwrap();
+        assert_matrix_eq!(qtq, MatrixUtilities::identity(a.rows), 1e-10);
+        for i in 0..r.rows {
+            for j in 0..i.min(r.cols) {
+                assert_eq!(r.mat[i][j], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_square() {
+        check_qr(&matrix!(
+            [12.0, -51.0, 4.0],
+            [6.0, 167.0, -68.0],
+            [-4.0, 24.0, -41.0]
+        ));
+    }
+
+    #[test]
+    fn test_qr_tall_and_wide() {
+        check_qr(&matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]));
+        check_qr(&matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]));
+    }
+
+    #[test]
+    fn test_qr_rank_deficient() {
+        check_qr(&matrix!([1.0, 2.0], [2.0, 4.0], [0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_qr_empty() {
+        let empty: Matrix<f64> = Matrix {
+            mat: vec![],
+            rows: 0,
+            cols: 0,
+        };
+        assert!(MatrixUtilities::qr_decomposition(&empty).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+mod reduction_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_trace() {
+        assert_eq!(matrix!([1, 2], [3, 4]).trace(), Ok(5));
+        assert_eq!(Matrix::<i32>::default().trace(), Ok(0));
+        assert!(matrix!([1, 2, 3]).trace().is_err());
+    }
+
+    #[test]
+    fn test_sum_and_mean() {
+        let m = matrix!([1.0, 2.0], [3.0, 6.0]);
+
+        assert_eq!(m.sum(), 12.0);
+        assert_eq!(m.mean(), Some(3.0));
+        assert_eq!(matrix!([1, 2]).mean(), Some(1));
+        assert_eq!(Matrix::<f64>::default().mean(), None);
+
+        // The element count does not fit in i8
+        assert_eq!(Matrix::<i8>::zeros(200, 1).mean(), Some(0));
+        let mut mixed = Matrix::<i8>::full(200, 1, 127);
+        for r in 150..200 {
+            mixed[(r, 0)] = -128;
+        }
+        assert_eq!(mixed.mean(), Some(63));
+    }
+
+    #[test]
+    fn test_min_and_max() {
+        let m = matrix!([3, -7], [12, 0]);
+
+        assert_eq!(m.min(), Some(-7));
+        assert_eq!(m.max(), Some(12));
+        assert_eq!(Matrix::<i32>::default().max(), None);
+
+        let with_nan = matrix!([1.0, f64::NAN], [3.0, 0.0]);
+        assert!(with_nan.min().unwrap().is_nan());
+        assert!(with_nan.max().unwrap().is_nan());
+    }
+}
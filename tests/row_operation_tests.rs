@@ -0,0 +1,65 @@
+mod row_operation_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_insert_row() {
+        let mut m = matrix!([1, 2], [5, 6]);
+
+        assert!(m.insert_row(1, &[3, 4]).is_ok());
+        assert!(m.insert_row(3, &[7, 8]).is_ok());
+        assert_eq!(m, matrix!([1, 2], [3, 4], [5, 6], [7, 8]));
+        assert_eq!(
+            m.insert_row(5, &[0, 0]),
+            Err(LinalgError::OutOfBounds { index: 5, len: 5 })
+        );
+        assert_eq!(
+            m.insert_row(0, &[0]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_insert_row_into_empty_matrix() {
+        let mut m = Matrix::default();
+
+        assert!(m.insert_row(0, &[1.0, 2.0, 3.0]).is_ok());
+        assert_eq!(m, matrix!([1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_remove_row() {
+        let mut m = matrix!([1, 2], [3, 4], [5, 6]);
+
+        assert_eq!(m.remove_row(1), Ok(vec![3, 4]));
+        assert_eq!(m, matrix!([1, 2], [5, 6]));
+        assert!(m.remove_row(2).is_err());
+        assert!(m.validate().is_ok());
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut m = matrix!([1, 2], [3, 4], [5, 6]);
+
+        assert!(m.swap_rows(0, 2).is_ok());
+        assert_eq!(m, matrix!([5, 6], [3, 4], [1, 2]));
+        assert!(m.swap_rows(1, 1).is_ok());
+        assert!(m.swap_rows(0, 3).is_err());
+    }
+
+    #[test]
+    fn test_scale_row_copies_on_write() {
+        let a = matrix!([1, 2], [3, 4]);
+        let mut b = a.clone();
+
+        assert!(b.scale_row(1, -2).is_ok());
+        assert_eq!(b, matrix!([1, 2], [-6, -8]));
+        assert_eq!(a, matrix!([1, 2], [3, 4]));
+        assert!(b.scale_row(2, 1).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+#![cfg(feature = "serde")]
+
+mod serde_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::vector;
+    use linalgrs::vector::Vector;
+    use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+    use serde::de::{self, Deserialize, IntoDeserializer, Visitor};
+    use serde::ser::{self, Impossible, Serialize};
+    use std::sync::Arc;
+
+    /// A minimal self-describing data model, standing in for a format such as JSON
+    #[derive(Clone, Debug, PartialEq)]
+    enum Value {
+        Int(i64),
+        Float(f64),
+        Seq(Vec<Value>),
+        Map(Vec<(&'static str, Value)>),
+    }
+
+    fn map(entries: &[(&'static str, Value)]) -> Value {
+        Value::Map(entries.to_vec())
+    }
+
+    fn floats(rows: &[&[f64]]) -> Value {
+        Value::Seq(
+            rows.iter()
+                .map(|row| Value::Seq(row.iter().map(|&x| Value::Float(x)).collect()))
+                .collect(),
+        )
+    }
+
+    fn to_value<S: Serialize>(value: &S) -> Value {
+        value.serialize(ValueSerializer).unwrap()
+    }
+
+    fn from_value<'de, D: Deserialize<'de>>(value: Value) -> Result<D, Error> {
+        D::deserialize(value)
+    }
+
+    struct ValueSerializer;
+
+    struct SeqBuilder(Vec<Value>);
+
+    struct MapBuilder(Vec<(&'static str, Value)>);
+
+    fn unsupported<T>() -> Result<T, Error> {
+        Err(ser::Error::custom("unsupported by the test data model"))
+    }
+
+    impl ser::Serializer for ValueSerializer {
+        type Ok = Value;
+        type Error = Error;
+        type SerializeSeq = SeqBuilder;
+        type SerializeTuple = Impossible<Value, Error>;
+        type SerializeTupleStruct = Impossible<Value, Error>;
+        type SerializeTupleVariant = Impossible<Value, Error>;
+        type SerializeMap = Impossible<Value, Error>;
+        type SerializeStruct = MapBuilder;
+        type SerializeStructVariant = Impossible<Value, Error>;
+
+        fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+            Ok(Value::Int(v))
+        }
+        fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+            Ok(Value::Int(v.into()))
+        }
+        fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+            Ok(Value::Int(v as i64))
+        }
+        fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+            Ok(Value::Float(v))
+        }
+        fn serialize_seq(self, len: Option<usize>) -> Result<SeqBuilder, Error> {
+            Ok(SeqBuilder(Vec::with_capacity(len.unwrap_or(0))))
+        }
+        fn serialize_struct(self, _: &'static str, len: usize) -> Result<MapBuilder, Error> {
+            Ok(MapBuilder(Vec::with_capacity(len)))
+        }
+        fn serialize_newtype_struct<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            value: &T,
+        ) -> Result<Value, Error> {
+            value.serialize(self)
+        }
+
+        fn serialize_bool(self, _: bool) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_i8(self, _: i8) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_i16(self, _: i16) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_u8(self, _: u8) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_u16(self, _: u16) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_u32(self, _: u32) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_f32(self, _: f32) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_char(self, _: char) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_str(self, _: &str) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_bytes(self, _: &[u8]) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_none(self) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_unit(self) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_unit_struct(self, _: &'static str) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_unit_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+        ) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Value, Error> {
+            unsupported()
+        }
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> {
+            unsupported()
+        }
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            unsupported()
+        }
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            unsupported()
+        }
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            unsupported()
+        }
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            unsupported()
+        }
+    }
+
+    impl ser::SerializeSeq for SeqBuilder {
+        type Ok = Value;
+        type Error = Error;
+
+        fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+            self.0.push(value.serialize(ValueSerializer)?);
+            Ok(())
+        }
+        fn end(self) -> Result<Value, Error> {
+            Ok(Value::Seq(self.0))
+        }
+    }
+
+    impl ser::SerializeStruct for MapBuilder {
+        type Ok = Value;
+        type Error = Error;
+
+        fn serialize_field<T: Serialize + ?Sized>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            self.0.push((key, value.serialize(ValueSerializer)?));
+            Ok(())
+        }
+        fn end(self) -> Result<Value, Error> {
+            Ok(Value::Map(self.0))
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for Value {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            match self {
+                Value::Int(v) => visitor.visit_i64(v),
+                Value::Float(v) => visitor.visit_f64(v),
+                Value::Seq(v) => visitor.visit_seq(SeqDeserializer::new(v.into_iter())),
+                Value::Map(v) => visitor.visit_map(MapDeserializer::new(v.into_iter())),
+            }
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+            byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map
+            struct enum identifier ignored_any
+        }
+    }
+
+    impl IntoDeserializer<'_, Error> for Value {
+        type Deserializer = Value;
+
+        fn into_deserializer(self) -> Value {
+            self
+        }
+    }
+
+    #[test]
+    fn test_matrix_serializes_as_shape_and_nested_array() {
+        let mat = matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+
+        assert_eq!(
+            to_value(&mat),
+            map(&[
+                ("rows", Value::Int(2)),
+                ("cols", Value::Int(3)),
+                ("data", floats(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let mat = matrix!([1, -2], [3, 4], [5, 6]);
+        assert_eq!(from_value::<Matrix<i32>>(to_value(&mat)).unwrap(), mat);
+
+        let empty: Matrix<f64> = Matrix::zeros(0, 0);
+        assert_eq!(from_value::<Matrix<f64>>(to_value(&empty)).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_matrix_rejects_inconsistent_shape() {
+        let ragged = map(&[
+            ("rows", Value::Int(2)),
+            ("cols", Value::Int(2)),
+            ("data", floats(&[&[1.0, 2.0], &[3.0]])),
+        ]);
+        let wrong_rows = map(&[
+            ("rows", Value::Int(3)),
+            ("cols", Value::Int(1)),
+            ("data", floats(&[&[1.0], &[2.0]])),
+        ]);
+        let missing_data = map(&[("rows", Value::Int(0)), ("cols", Value::Int(0))]);
+
+        for value in [ragged, wrong_rows, missing_data] {
+            assert!(from_value::<Matrix<f64>>(value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_vector_serializes_as_array() {
+        let v = vector![1.5, -2.0];
+
+        assert_eq!(
+            to_value(&v),
+            Value::Seq(vec![Value::Float(1.5), Value::Float(-2.0)])
+        );
+        assert_eq!(from_value::<Vector<f64>>(to_value(&v)).unwrap(), v);
+    }
+}
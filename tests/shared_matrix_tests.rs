@@ -0,0 +1,73 @@
+mod shared_matrix_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::shared_matrix::SharedMatrix;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_clone_shares_data() {
+        let shared = SharedMatrix::new(matrix!([1, 2], [3, 4]));
+        let other = shared.clone();
+
+        assert!(SharedMatrix::ptr_eq(&shared, &other));
+        assert_eq!(shared.share_count(), 2);
+        assert!(!shared.is_unique());
+        assert_eq!(other.mat[1][0], 3);
+    }
+
+    #[test]
+    fn test_make_mut_copies_on_write() {
+        let mut shared = SharedMatrix::new(matrix!([1, 2], [3, 4]));
+        let other = shared.clone();
+
+        Arc::make_mut(&mut shared.make_mut().mat[0])[0] = 10;
+
+        assert!(!SharedMatrix::ptr_eq(&shared, &other));
+        assert!(shared.is_unique());
+        assert_eq!(shared.mat[0][0], 10);
+        assert_eq!(other.mat[0][0], 1);
+    }
+
+    #[test]
+    fn test_make_mut_unique_does_not_copy() {
+        let mut shared = SharedMatrix::new(matrix!([1, 2], [3, 4]));
+        let before = &*shared as *const Matrix<i32>;
+
+        shared.make_mut().rows = 2;
+
+        assert_eq!(&*shared as *const Matrix<i32>, before);
+    }
+
+    #[test]
+    fn test_into_matrix() {
+        let shared = SharedMatrix::from(matrix!([1.0, 2.0]));
+        let other = shared.clone();
+
+        assert_eq!(shared.into_matrix(), matrix!([1.0, 2.0]));
+        assert_eq!(other.into_matrix(), matrix!([1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_share_across_threads() {
+        let shared = SharedMatrix::new(matrix!([1, 2, 3], [4, 5, 6]));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let local = shared.clone();
+                thread::spawn(move || {
+                    local
+                        .mat
+                        .iter()
+                        .map(|row| row.iter().sum::<i32>())
+                        .sum::<i32>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 21);
+        }
+        assert!(shared.is_unique());
+    }
+}
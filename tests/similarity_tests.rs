@@ -0,0 +1,93 @@
+#![allow(deprecated)]
+
+mod similarity_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_similarity_transform_diagonalizes() {
+        // The columns of P are eigenvectors of A for the eigenvalues 5 and 2
+        let a = matrix!([4.0, 1.0], [2.0, 3.0]);
+        let p = matrix!([1.0, 1.0], [1.0, -2.0]);
+
+        let d = MatrixUtilities::similarity_transform(&p, &a).unwrap();
+        assert_matrix_eq!(d, matrix!([5.0, 0.0], [0.0, 2.0]), 1e-12);
+
+        let p_inv = MatrixUtilities::inverse_with_tol(&p, f64::EPSILON).unwrap();
+        let with_inverse = MatrixUtilities::similarity_transform_with_inverse(&p, &p_inv, &a);
+        assert_matrix_eq!(with_inverse.unwrap(), d, 1e-12);
+    }
+
+    #[test]
+    fn test_similarity_transform_by_permutation() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+        let p = matrix!([0.0, 1.0], [1.0, 0.0]);
+
+        let result = MatrixUtilities::similarity_transform(&p, &a).unwrap();
+        assert_matrix_eq!(result, matrix!([4.0, 3.0], [2.0, 1.0]), 1e-12);
+    }
+
+    #[test]
+    fn test_similarity_transform_errors() {
+        let a = matrix!([1.0, 2.0], [3.0, 4.0]);
+
+        assert_eq!(
+            MatrixUtilities::similarity_transform(&matrix!([1.0, 2.0], [2.0, 4.0]), &a),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            MatrixUtilities::similarity_transform(&a, &matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            MatrixUtilities::similarity_transform_with_inverse(&a, &matrix!([1.0]), &a),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_change_of_basis() {
+        let from = matrix!([1.0, 1.0], [0.0, 1.0]);
+        let to = matrix!([2.0, 0.0], [0.0, 4.0]);
+
+        let change = MatrixUtilities::change_of_basis(&from, &to).unwrap();
+        assert_matrix_eq!(change, matrix!([0.5, 0.5], [0.0, 0.25]), 1e-12);
+
+        // Coordinates (3, -1) in `from` name the same vector as the converted ones in `to`
+        let x = matrix!([3.0], [-1.0]);
+        let y = MatrixUtilities::multiply(&change, &x).unwrap();
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&to, &y).unwrap(),
+            MatrixUtilities::multiply(&from, &x).unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_change_of_basis_errors() {
+        let basis = matrix!([1.0, 0.0], [0.0, 1.0]);
+        let dependent = matrix!([1.0, 2.0], [2.0, 4.0]);
+
+        assert_eq!(
+            MatrixUtilities::change_of_basis(&dependent, &basis),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            MatrixUtilities::change_of_basis(&basis, &dependent),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            MatrixUtilities::change_of_basis(&basis, &matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 1)
+            })
+        );
+    }
+}
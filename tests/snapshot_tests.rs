@@ -0,0 +1,81 @@
+#![allow(deprecated)]
+
+mod snapshot_tests {
+    use linalgrs::assert_matrix_snapshot;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::testing;
+    use std::fs;
+    use std::sync::Arc;
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("linalgrs-snapshots-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_snapshot_format() {
+        let mat = matrix!([1.5, -2.0], [3.0, 4.25]);
+
+        assert_eq!(
+            testing::to_snapshot_string(&mat),
+            "# linalgrs matrix snapshot\nshape: 2x2\n1.5 -2\n3 4.25\n"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_written_then_matched() {
+        let dir = scratch_dir("roundtrip");
+        let path = testing::snapshot_path(&dir, "identity");
+        let eye = MatrixUtilities::<f64>::identity(3);
+
+        assert!(testing::check_snapshot(&eye, &path, 0.0).is_ok());
+        assert!(path.exists());
+        assert!(testing::check_snapshot(&eye, &path, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_snapshot_tolerance() {
+        let dir = scratch_dir("tolerance");
+        let path = testing::snapshot_path(&dir, "values");
+        testing::check_snapshot(&matrix!([1.0, 2.0]), &path, 0.0).unwrap();
+
+        let close = matrix!([1.0, 2.0 + 1e-12]);
+        assert!(testing::check_snapshot(&close, &path, 1e-9).is_ok());
+
+        let far = matrix!([1.0, 2.1]);
+        let err = testing::check_snapshot(&far, &path, 1e-9).unwrap_err();
+        assert!(err.contains("(0, 1): expected 2, got 2.1"));
+    }
+
+    #[test]
+    fn test_snapshot_shape_mismatch() {
+        let dir = scratch_dir("shape");
+        let path = testing::snapshot_path(&dir, "shape");
+        testing::check_snapshot(&matrix!([1, 2], [3, 4]), &path, 0.0).unwrap();
+
+        assert!(testing::check_snapshot(&matrix!([1, 2]), &path, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_snapshot_truncated() {
+        let dir = scratch_dir("truncated");
+        let path = testing::snapshot_path(&dir, "truncated");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "# linalgrs matrix snapshot\nshape: 3x2\n1 2\n3 4\n").unwrap();
+
+        let err =
+            testing::check_snapshot(&matrix!([1, 2], [3, 4], [5, 6]), &path, 0.0).unwrap_err();
+        assert!(err.contains("snapshot has 2 rows but the shape has 3 rows"));
+    }
+
+    #[test]
+    fn test_assert_matrix_snapshot_macro() {
+        let a = matrix!([2.0, 1.0], [1.0, 3.0]);
+        let inverse = MatrixUtilities::inverse(a).unwrap();
+
+        assert_matrix_snapshot!(inverse, "inverse_2x2", 1e-12);
+    }
+}
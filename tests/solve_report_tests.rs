@@ -0,0 +1,106 @@
+mod solve_report_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::solve::SolveReport;
+    use linalgrs::{arith, solve};
+    use std::sync::Arc;
+
+    fn hilbert(n: usize) -> Matrix<f64> {
+        Matrix {
+            mat: (0..n)
+                .map(|i| (0..n).map(|j| 1.0 / (i + j + 1) as f64).collect())
+                .collect(),
+            rows: n,
+            cols: n,
+        }
+    }
+
+    #[test]
+    fn test_backward_error() {
+        let a = matrix!([2.0, 1.0], [1.0, 3.0]);
+        let b = matrix!([3.0, 0.0], [4.0, 0.0]);
+
+        let exact = matrix!([1.0, 0.0], [1.0, 0.0]);
+        assert_eq!(solve::backward_error(&a, &exact, &b), Ok(0.0));
+
+        // r = [-0.2, -0.1], ‖A‖∞ = 4, ‖x‖∞ = 1.1, ‖b‖∞ = 4
+        let close = matrix!([1.1, 0.0], [1.0, 0.0]);
+        let eta: f64 = solve::backward_error(&a, &close, &b).unwrap();
+        assert!((eta - 0.2 / 8.4).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_report_bounds_the_forward_error() {
+        let a = hilbert(8);
+        let ones = Matrix {
+            mat: vec![Arc::from([1.0]); 8],
+            rows: 8,
+            cols: 1,
+        };
+        let b = arith::multiply(&a, &ones).unwrap();
+
+        let report = solve::solve_with_report(&a, &b).unwrap();
+        assert!(report.condition_number > 1e9);
+        assert!(report.backward_error < 1e-15);
+        assert!(report.forward_error < 1e-3);
+        let error = report
+            .solution
+            .mat
+            .iter()
+            .map(|row| (row[0] - 1.0).abs())
+            .fold(0.0, f64::max);
+        assert!(error <= report.forward_error);
+    }
+
+    #[test]
+    fn test_report_well_conditioned() {
+        let report: SolveReport<f64> =
+            solve::solve_with_report(&matrix!([2.0, 1.0], [1.0, 3.0]), &matrix!([3.0], [4.0]))
+                .unwrap();
+
+        assert!(report
+            .solution
+            .mat
+            .iter()
+            .all(|row| (row[0] - 1.0).abs() < 1e-15));
+        assert!((report.condition_number - 3.2).abs() < 1e-12);
+        assert!(report.backward_error <= f64::EPSILON);
+        assert!(report.forward_error < 1e-14);
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = matrix!([2.0, 1.0], [1.0, 3.0]);
+
+        assert_eq!(
+            solve::backward_error(&a, &matrix!([1.0], [1.0]), &matrix!([3.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        assert_eq!(
+            solve::backward_error(&a, &matrix!([f64::NAN], [1.0]), &matrix!([3.0], [4.0])),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+        assert_eq!(
+            solve::solve_with_report(&matrix!([1.0, 2.0], [2.0, 4.0]), &matrix!([1.0], [1.0])),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_backward_error_facade() {
+        let a = matrix!([4.0, 1.0], [1.0, 3.0]);
+        let b = matrix!([1.0], [2.0]);
+        let x = matrix!([0.1], [0.6]);
+
+        assert_eq!(
+            MatrixUtilities::backward_error(&a, &x, &b),
+            solve::backward_error(&a, &x, &b)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+#![allow(deprecated)]
+
+mod solve_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_cholesky_decomposition() {
+        let a = matrix!(
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0]
+        );
+        let l = MatrixUtilities::cholesky_decomposition(&a).unwrap();
+
+        assert_eq!(
+            l,
+            matrix!([2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0])
+        );
+        let llt = MatrixUtilities::multiply(&l, &MatrixUtilities::transpose(&l)).unwrap();
+        assert_matrix_eq!(llt, a, 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_decomposition_errors() {
+        // Symmetric but indefinite
+        assert_eq!(
+            MatrixUtilities::cholesky_decomposition(&matrix!([1.0, 2.0], [2.0, 1.0])),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        // Positive diagonal but not symmetric
+        assert_eq!(
+            MatrixUtilities::cholesky_decomposition(&matrix!([2.0, 1.0], [0.0, 2.0])),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        assert!(matches!(
+            MatrixUtilities::cholesky_decomposition(&matrix!([1.0, 2.0])),
+            Err(LinalgError::NonSquare { .. })
+        ));
+    }
+
+    #[test]
+    fn test_solve_spd_multiple_right_hand_sides() {
+        let a = matrix!([4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]);
+        let x = matrix!([1.0, 0.0], [2.0, -1.0], [3.0, 4.0]);
+        let b = MatrixUtilities::multiply(&a, &x).unwrap();
+
+        assert_matrix_eq!(MatrixUtilities::solve(&a, &b).unwrap(), x, 1e-12);
+    }
+
+    #[test]
+    fn test_solve_needs_pivoting() {
+        // Not symmetric, and a zero pivot stops elimination without row exchanges
+        let a = matrix!([0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]);
+        let x = matrix!([1.0, 2.0, 3.0], [-1.0, 0.0, 1.0], [2.0, 2.0, 2.0]);
+        let b = MatrixUtilities::multiply(&a, &x).unwrap();
+
+        assert_matrix_eq!(MatrixUtilities::solve(&a, &b).unwrap(), x, 1e-12);
+        // Inverting is solving against the identity
+        let inverse = MatrixUtilities::solve(&a, &MatrixUtilities::identity(3)).unwrap();
+        assert_matrix_eq!(
+            MatrixUtilities::multiply(&a, &inverse).unwrap(),
+            MatrixUtilities::identity(3),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let singular = matrix!([1.0, 2.0], [2.0, 4.0]);
+        let b = matrix!([1.0], [2.0]);
+
+        assert_eq!(
+            MatrixUtilities::solve(&singular, &b),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            MatrixUtilities::solve(&singular, &matrix!([1.0, 2.0, 3.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (1, 3)
+            })
+        );
+        assert!(matches!(
+            MatrixUtilities::solve(&matrix!([1.0, 2.0]), &b),
+            Err(LinalgError::NonSquare { .. })
+        ));
+        assert_eq!(
+            MatrixUtilities::solve(
+                &matrix!([1.0, 0.0], [0.0, 1.0]),
+                &matrix!([1.0], [f64::NAN])
+            ),
+            Err(LinalgError::NonFinite { row: 1, col: 0 })
+        );
+    }
+}
@@ -0,0 +1,166 @@
+mod solver_registry_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::solver_registry::{self, MatrixProperties, Solver};
+    use linalgrs::{assert_matrix_eq, solve};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Solves diagonal systems by division, counting its calls
+    struct DiagonalSolver {
+        name: &'static str,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Solver<f64> for DiagonalSolver {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn accepts(&self, properties: &MatrixProperties) -> bool {
+            properties.rows >= 3 && properties.lower_bandwidth + properties.upper_bandwidth == 0
+        }
+
+        fn solve(&self, a: &Matrix<f64>, b: &Matrix<f64>) -> Result<Matrix<f64>, LinalgError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let d = a.diagonal();
+            Ok(Matrix {
+                mat: b
+                    .mat
+                    .iter()
+                    .zip(&d)
+                    .map(|(row, &di)| row.iter().map(|x| x / di).collect())
+                    .collect(),
+                rows: b.rows,
+                cols: b.cols,
+            })
+        }
+    }
+
+    #[test]
+    fn test_properties() {
+        let a = matrix!(
+            [1.0, 2.0, 0.0, 0.0],
+            [3.0, 4.0, 5.0, 0.0],
+            [0.0, 6.0, 7.0, 8.0],
+            [0.0, 0.0, 9.0, 1.0]
+        );
+        assert_eq!(
+            MatrixProperties::of(&a),
+            Ok(MatrixProperties {
+                rows: 4,
+                cols: 4,
+                symmetric: false,
+                lower_bandwidth: 1,
+                upper_bandwidth: 1,
+                nonzeros: 10,
+            })
+        );
+
+        let l = MatrixProperties::of(&matrix!([1.0, 0.0], [2.0, 1.0])).unwrap();
+        assert!(l.is_square() && l.is_lower_triangular() && !l.is_upper_triangular());
+        assert!(
+            MatrixProperties::of(&matrix!([1.0, 2.0], [2.0, 1.0]))
+                .unwrap()
+                .symmetric
+        );
+    }
+
+    #[test]
+    fn test_registered_solver_is_dispatched_to() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        assert!(!solver_registry::register_solver(DiagonalSolver {
+            name: "diagonal",
+            calls: calls.clone(),
+        }));
+        assert_eq!(
+            solver_registry::registered_solvers::<f64>(),
+            vec!["diagonal".to_string()]
+        );
+        assert!(solver_registry::registered_solvers::<f32>().is_empty());
+
+        let d = matrix!([2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 8.0]);
+        let b = matrix!([2.0, 1.0], [2.0, 1.0], [2.0, 1.0]);
+        let props = MatrixProperties::of(&d).unwrap();
+        assert_eq!(solver_registry::solver_for::<f64>(&props), "diagonal");
+        assert_matrix_eq!(
+            solver_registry::solve_auto(&d, &b).unwrap(),
+            solve::solve(&d, &b).unwrap(),
+            1e-15
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A non-diagonal system falls through to the built-in solvers
+        let a = matrix!([4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]);
+        let x = solver_registry::solve_auto(&a, &b).unwrap();
+        assert_matrix_eq!(x, solve::solve(&a, &b).unwrap(), 1e-12);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            solver_registry::solver_for::<f64>(&MatrixProperties::of(&a).unwrap()),
+            "solve"
+        );
+
+        // Later registrations take precedence, and a repeated name replaces the old one
+        let newer = Arc::new(AtomicUsize::new(0));
+        solver_registry::register_solver(DiagonalSolver {
+            name: "diagonal_v2",
+            calls: newer.clone(),
+        });
+        solver_registry::solve_auto(&d, &b).unwrap();
+        assert_eq!(
+            (calls.load(Ordering::SeqCst), newer.load(Ordering::SeqCst)),
+            (1, 1)
+        );
+        assert!(solver_registry::register_solver(DiagonalSolver {
+            name: "diagonal",
+            calls: calls.clone(),
+        }));
+        assert_eq!(
+            solver_registry::registered_solvers::<f64>(),
+            vec!["diagonal".to_string(), "diagonal_v2".to_string()]
+        );
+        solver_registry::solve_auto(&d, &b).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        assert!(solver_registry::unregister_solver::<f64>("diagonal"));
+        assert!(solver_registry::unregister_solver::<f64>("diagonal_v2"));
+        assert!(!solver_registry::unregister_solver::<f64>("diagonal"));
+        assert_eq!(
+            solver_registry::solver_for::<f64>(&props),
+            "lower_triangular"
+        );
+    }
+
+    #[test]
+    fn test_builtin_fallbacks() {
+        let l = matrix!([2.0f32, 0.0], [1.0, 4.0]);
+        let u = matrix!([2.0f32, 1.0], [0.0, 4.0]);
+        let b = matrix!([2.0f32], [6.0]);
+
+        let x = solver_registry::solve_auto(&l, &b).unwrap();
+        assert_matrix_eq!(x, matrix!([1.0f32], [1.25]), 1e-6);
+        let x = solver_registry::solve_auto(&u, &b).unwrap();
+        assert_matrix_eq!(x, matrix!([0.25f32], [1.5]), 1e-6);
+        assert_eq!(
+            solver_registry::solver_for::<f32>(&MatrixProperties::of(&u).unwrap()),
+            "upper_triangular"
+        );
+
+        assert_eq!(
+            solver_registry::solve_auto(&matrix!([1.0f32, 2.0]), &b),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            solver_registry::solve_auto(&l, &matrix!([1.0f32])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        assert_eq!(
+            solver_registry::solve_auto(&matrix!([1.0f32, 0.0], [0.0, 0.0]), &b),
+            Err(LinalgError::Singular)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+mod solvers_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::solvers::{gauss_seidel, jacobi};
+    use std::sync::Arc;
+
+    /// A strictly diagonally dominant system whose solution is `[1, 2, -1]`
+    fn dominant() -> (Matrix<f64>, Vec<f64>) {
+        (
+            matrix!([10.0, -1.0, 2.0], [-1.0, 11.0, -1.0], [2.0, -1.0, 10.0]),
+            vec![6.0, 22.0, -10.0],
+        )
+    }
+
+    #[test]
+    fn test_jacobi_converges() {
+        let (a, b) = dominant();
+
+        let result = jacobi(&a, &b, 1e-12, 100).unwrap();
+        for (x, expected) in result.solution.iter().zip([1.0, 2.0, -1.0]) {
+            assert!((x - expected).abs() < 1e-10);
+        }
+        assert!(result.residual <= 1e-12);
+        assert_eq!(result.history.len(), result.iterations);
+        assert_eq!(result.history.last(), Some(&result.residual));
+        assert!(result.history.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_gauss_seidel_is_faster() {
+        let (a, b) = dominant();
+
+        let slow = jacobi(&a, &b, 1e-12, 100).unwrap();
+        let fast = gauss_seidel(&a, &b, 1e-12, 100).unwrap();
+        for (x, expected) in fast.solution.iter().zip([1.0, 2.0, -1.0]) {
+            assert!((x - expected).abs() < 1e-10);
+        }
+        assert!(fast.iterations < slow.iterations);
+    }
+
+    #[test]
+    fn test_zero_right_hand_side() {
+        let (a, _) = dominant();
+
+        let result = gauss_seidel(&a, &[0.0; 3], 1e-12, 10).unwrap();
+        assert_eq!(result.solution, vec![0.0; 3]);
+        assert_eq!(result.iterations, 0);
+        assert!(result.history.is_empty());
+    }
+
+    #[test]
+    fn test_divergence_and_errors() {
+        // Not diagonally dominant: the Jacobi iteration matrix has spectral radius 2
+        let a = matrix!([1.0, 2.0], [2.0, 1.0]);
+        assert_eq!(
+            jacobi(&a, &[3.0, 3.0], 1e-10, 50),
+            Err(LinalgError::NotConverged { iterations: 50 })
+        );
+
+        let (a, b) = dominant();
+        assert_eq!(
+            jacobi(&a, &b[..2], 1e-10, 50),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            gauss_seidel(&a, &b, 0.0, 50),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            gauss_seidel(&matrix!([0.0, 1.0], [1.0, 0.0]), &[1.0, 1.0], 1e-10, 50),
+            Err(LinalgError::ZeroPivot)
+        );
+        assert_eq!(
+            jacobi(&matrix!([1.0, 2.0]), &[1.0], 1e-10, 50),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+    }
+}
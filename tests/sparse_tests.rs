@@ -0,0 +1,120 @@
+mod sparse_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::sparse::SparseMatrix;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_from_triplets_sums_duplicates() {
+        let sparse = SparseMatrix::from_triplets(
+            3,
+            3,
+            &[(2, 0, 4), (0, 1, 1), (2, 0, 3), (0, 0, 5), (0, 1, -2)],
+        )
+        .unwrap();
+
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.row_ptr, vec![0, 2, 2, 3]);
+        assert_eq!(sparse.to_dense(), matrix!([5, -1, 0], [0, 0, 0], [7, 0, 0]));
+        assert_eq!(sparse.get(1, 1), Some(0));
+        assert_eq!(sparse.get(2, 0), Some(7));
+        assert_eq!(sparse.get(3, 0), None);
+    }
+
+    #[test]
+    fn test_from_triplets_out_of_bounds() {
+        assert_eq!(
+            SparseMatrix::from_triplets(2, 2, &[(0, 2, 1.0)]),
+            Err(LinalgError::OutOfBounds { index: 2, len: 2 })
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip_and_mul_vec() {
+        let dense = matrix!([0.0, 2.0, 0.0], [1.0, 0.0, 3.0]);
+        let sparse = SparseMatrix::from_dense(&dense).unwrap();
+
+        assert_eq!(sparse.nnz(), 3);
+        assert_eq!(sparse.density(), 0.5);
+        assert_eq!(sparse.to_dense(), dense);
+        assert_eq!(sparse.mul_vec(&[1.0, 2.0, 3.0]), Ok(vec![4.0, 10.0]));
+        assert_eq!(
+            sparse.mul_vec(&[1.0]),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 1
+            })
+        );
+        assert_eq!(
+            SparseMatrix::<f64>::new(2, 2).to_dense(),
+            Matrix::zeros(2, 2)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() {
+        use rand::distr::Uniform;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let m = SparseMatrix::random(20, 10, 0.25, Uniform::new(1.0, 2.0).unwrap()).unwrap();
+        assert_eq!((m.rows, m.cols, m.nnz()), (20, 10, 50));
+        assert!(m.values.iter().all(|x| (1.0..2.0).contains(x)));
+        assert_eq!(SparseMatrix::from_dense(&m.to_dense()).unwrap(), m);
+
+        let seeded = |seed| {
+            SparseMatrix::random_with_rng(
+                5,
+                5,
+                0.4,
+                Uniform::new(0, 10).unwrap(),
+                &mut StdRng::seed_from_u64(seed),
+            )
+            .unwrap()
+        };
+        assert_eq!(seeded(3), seeded(3));
+
+        assert!(matches!(
+            SparseMatrix::random(2, 2, 1.5, Uniform::new(0, 1).unwrap()),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_banded() {
+        use rand::distr::Uniform;
+
+        let m = SparseMatrix::random_banded(6, 1, 2, Uniform::new(1, 5).unwrap());
+        assert_eq!(m.nnz(), 6 + 5 + 5 + 4);
+        for r in 0..6 {
+            for (c, _) in m.row(r) {
+                assert!(c + 1 >= r && c <= r + 2);
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_block_sparse() {
+        use rand::distr::Uniform;
+
+        let m =
+            SparseMatrix::random_block_sparse(4, 3, 2, 0.5, Uniform::new(1, 5).unwrap()).unwrap();
+        assert_eq!((m.rows, m.cols, m.nnz()), (8, 6, 6 * 4));
+
+        // Every block is either full or empty
+        let dense = m.to_dense();
+        for top in (0..8).step_by(2) {
+            for left in (0..6).step_by(2) {
+                let filled = (top..top + 2)
+                    .flat_map(|r| (left..left + 2).map(move |c| (r, c)))
+                    .filter(|&(r, c)| dense.mat[r][c] != 0)
+                    .count();
+                assert!(filled == 0 || filled == 4);
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+#![allow(deprecated)]
+
+mod stats_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::stats::{kernel_matrix, kmeans, Kernel};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_linear_kernel_is_row_gram() {
+        let x = matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0]);
+
+        assert_eq!(
+            kernel_matrix(&x, Kernel::Linear).unwrap(),
+            MatrixUtilities::row_gram(&x).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rbf_kernel() {
+        let x = matrix!([0.0, 0.0], [1.0, 1.0]);
+        let k = kernel_matrix(&x, Kernel::RBF { gamma: 0.5 }).unwrap();
+
+        assert_eq!(k.mat[0][0], 1.0);
+        assert_eq!(k.mat[1][1], 1.0);
+        assert!(approx_eq!(f64, k.mat[0][1], (-1.0f64).exp()));
+        assert_eq!(k.mat[0][1], k.mat[1][0]);
+        assert!(kernel_matrix(&x, Kernel::RBF { gamma: 0.0 }).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_kernel() {
+        let x = matrix!([1.0, 2.0], [0.0, 1.0]);
+        let k = kernel_matrix(&x, Kernel::Polynomial { deg: 2, coef: 1.0 }).unwrap();
+
+        assert_eq!(k, matrix!([36.0, 9.0], [9.0, 4.0]));
+    }
+
+    #[test]
+    fn test_kernel_apply_and_empty_dataset() {
+        assert_eq!(Kernel::Linear.apply(&[1.0, 2.0], &[3.0, 4.0]), Ok(11.0));
+        assert_eq!(
+            Kernel::RBF { gamma: 1.0 }.apply(&[1.0, 2.0], &[1.0]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+
+        let empty = kernel_matrix(&Matrix::<f64>::default(), Kernel::Linear).unwrap();
+        assert_eq!((empty.rows, empty.cols), (0, 0));
+    }
+
+    #[test]
+    fn test_kmeans_separates_groups() {
+        let x = matrix!(
+            [0.0, 0.0],
+            [10.0, 10.0],
+            [0.5, 0.0],
+            [10.0, 9.5],
+            [0.0, 0.5],
+            [9.5, 10.0]
+        );
+        let result = kmeans(&x, 2, 100).unwrap();
+
+        assert_eq!(result.labels, vec![0, 1, 0, 1, 0, 1]);
+        assert!(approx_eq!(f64, result.centroids.mat[0][0], 0.5 / 3.0));
+        assert!(approx_eq!(f64, result.centroids.mat[1][1], 29.5 / 3.0));
+        assert_eq!(result.iterations, 2);
+    }
+
+    #[test]
+    fn test_kmeans_rejects_invalid_k() {
+        let x = matrix!([1.0], [2.0]);
+
+        assert_eq!(kmeans(&x, 2, 10).unwrap().labels, vec![0, 1]);
+        for k in [0, 3] {
+            assert!(matches!(
+                kmeans(&x, k, 10),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+    }
+}
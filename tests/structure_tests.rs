@@ -0,0 +1,66 @@
+mod structure_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::solve;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_from_diagonal_and_diagonal() {
+        let d = Matrix::from_diagonal(&[1, 2, 3]);
+
+        assert_eq!(d, matrix!([1, 0, 0], [0, 2, 0], [0, 0, 3]));
+        assert_eq!(d.diagonal(), vec![1, 2, 3]);
+        assert_eq!(matrix!([1, 2, 3], [4, 5, 6]).diagonal(), vec![1, 5]);
+        assert_eq!(matrix!([1, 2], [3, 4], [5, 6]).diagonal(), vec![1, 4]);
+        assert_eq!(Matrix::<i32>::from_diagonal(&[]), Matrix::zeros(0, 0));
+    }
+
+    #[test]
+    fn test_triangular_and_diagonal_predicates() {
+        let upper = matrix!([1, 2, 3], [0, 4, 5], [0, 0, 6]);
+        let lower = matrix!([1, 0, 0], [2, 3, 0]);
+        let full = matrix!([1, 2], [3, 4]);
+
+        assert!(upper.is_upper_triangular() && !upper.is_lower_triangular());
+        assert!(lower.is_lower_triangular() && !lower.is_upper_triangular());
+        assert!(!full.is_upper_triangular() && !full.is_lower_triangular());
+        assert!(!upper.is_diagonal() && !full.is_diagonal());
+        assert!(Matrix::from_diagonal(&[1, 0, 2]).is_diagonal());
+        assert!(matrix!([5, 0, 0], [0, 6, 0]).is_diagonal());
+        assert!(Matrix::<i32>::zeros(0, 0).is_diagonal());
+
+        let mut corrupt = upper.clone();
+        corrupt.rows = 4;
+        assert!(!corrupt.is_upper_triangular());
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        assert!(matrix!([1.0, 2.0], [2.0, 3.0]).is_symmetric());
+        assert!(!matrix!([1.0, 2.0], [2.5, 3.0]).is_symmetric());
+        assert!(!matrix!([1.0, 2.0]).is_symmetric());
+        assert!(Matrix::from_diagonal(&[1.0, -1.0]).is_symmetric());
+    }
+
+    #[test]
+    fn test_determinant_fast_triangular() {
+        assert_eq!(
+            solve::determinant_fast(&matrix!([2.0, 7.0, -1.0], [0.0, 3.0, 4.0], [0.0, 0.0, 5.0])),
+            Ok(30.0)
+        );
+        assert_eq!(
+            solve::determinant_fast(&matrix!([2.0, 0.0], [9.0, 0.0])),
+            Ok(0.0)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_from_diagonal_facade() {
+        assert_eq!(
+            MatrixUtilities::from_diagonal(&[1, 2, 3]),
+            Matrix::from_diagonal(&[1, 2, 3])
+        );
+    }
+}
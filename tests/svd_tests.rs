@@ -0,0 +1,78 @@
+#![allow(deprecated)]
+
+mod svd_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use linalgrs::{assert_matrix_eq, matrix};
+    use std::sync::Arc;
+
+    fn check_svd(a: &Matrix<f64>) -> Vec<f64> {
+        let (u, sigma, v) = MatrixUtilities::svd(a).unwrap();
+
+        let mut s = MatrixUtilities::identity(a.cols);
+        for (i, &x) in sigma.iter().enumerate() {
+            s.mat[i] = (0..a.cols).map(|j| if i == j { x } else { 0.0 }).collect();
+        }
+        let us = MatrixUtilities::multiply(&u, &s).unwrap();
+        let usvt = MatrixUtilities::multiply(&us, &MatrixUtilities::transpose(&v)).unwrap();
+        assert_matrix_eq!(usvt, a, 1e-10);
+        let vtv = MatrixUtilities::multiply(&MatrixUtilities::transpose(&v), &v).unwrap();
+        assert_matrix_eq!(vtv, MatrixUtilities::identity(a.cols), 1e-10);
+        assert!(sigma.windows(2).all(|w| w[0] >= w[1]));
+
+        sigma
+    }
+
+    #[test]
+    fn test_svd_diagonal() {
+        let sigma = check_svd(&matrix!([2.0, 0.0, 0.0], [0.0, -5.0, 0.0], [0.0, 0.0, 3.0]));
+
+        assert_eq!(sigma.len(), 3);
+        for (actual, expected) in sigma.iter().zip([5.0, 3.0, 2.0]) {
+            assert!(approx_eq!(f64, *actual, expected, epsilon = 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_svd_tall_and_wide() {
+        let sigma = check_svd(&matrix!([3.0, 2.0, 2.0], [2.0, 3.0, -2.0]));
+        assert!(approx_eq!(f64, sigma[0], 5.0, epsilon = 1e-12));
+        assert!(approx_eq!(f64, sigma[1], 3.0, epsilon = 1e-12));
+        assert!(approx_eq!(f64, sigma[2], 0.0, epsilon = 1e-12));
+
+        check_svd(&matrix!([1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]));
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let sigma = check_svd(&matrix!([1.0, 2.0], [2.0, 4.0], [3.0, 6.0]));
+
+        assert!(approx_eq!(f64, sigma[0], 70.0_f64.sqrt(), epsilon = 1e-12));
+        assert!(sigma[1].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_svd_tiny_singular_value_keeps_relative_accuracy() {
+        // σ₁σ₂ = |det| = 1e-20 and σ₁ ≈ √2, so σ₂ ≈ 1e-20 / √2
+        let sigma = check_svd(&matrix!([1.0, 1e-20], [1.0, 2e-20]));
+
+        assert!(approx_eq!(
+            f64,
+            sigma[1] * 2.0_f64.sqrt(),
+            1e-20,
+            epsilon = 1e-32
+        ));
+    }
+
+    #[test]
+    fn test_svd_rejects_non_finite() {
+        let a = matrix!([1.0, f64::NAN], [0.0, 1.0]);
+
+        assert_eq!(
+            MatrixUtilities::svd(&a).map(|_| ()),
+            Err(LinalgError::NonFinite { row: 0, col: 1 })
+        );
+    }
+}
@@ -0,0 +1,233 @@
+mod system_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::system::{Perturbation, System};
+    use std::sync::Arc;
+
+    /// The first equation is written in units 1e20 times smaller than the second, which
+    /// leads partial pivoting to the wrong pivot
+    fn badly_scaled() -> System<f64> {
+        System::new(matrix!([2.0, 2e20], [1.0, 1.0]), matrix!([2e20], [2.0])).unwrap()
+    }
+
+    #[test]
+    fn test_solve() {
+        let system: System<f64> = System::new(
+            matrix!([2.0, 1.0], [1.0, 3.0]),
+            matrix!([3.0, 1.0], [4.0, 2.0]),
+        )
+        .unwrap();
+
+        let x = system.solve().unwrap();
+        for (row, expected) in x.mat.iter().zip([[1.0, 0.2], [1.0, 0.6]]) {
+            assert!((row[0] - expected[0]).abs() < 1e-12);
+            assert!((row[1] - expected[1]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_equilibration_recovers_accuracy() {
+        let system = badly_scaled();
+        assert!((system.solve().unwrap().mat[0][0] - 1.0).abs() > 0.5);
+
+        let result = system.solve_equilibrated().unwrap();
+        assert!((result.solution.mat[0][0] - 1.0).abs() < 1e-12);
+        assert!((result.solution.mat[1][0] - 1.0).abs() < 1e-12);
+        assert_eq!(result.row_scale, vec![2f64.powi(-67), 1.0]);
+        assert_eq!(result.col_scale, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_equilibration_scales_columns() {
+        let system: System<f64> =
+            System::new(matrix!([1e-6, 1.0], [2e-6, 3.0]), matrix!([3.0], [7.0])).unwrap();
+        let result = system.solve_equilibrated().unwrap();
+
+        assert!(result.col_scale[0] > 1e5);
+        assert!(result
+            .col_scale
+            .iter()
+            .all(|c: &f64| c.log2().fract() == 0.0));
+        assert!((result.solution.mat[0][0] - 2e6).abs() < 1e-6);
+        assert!((result.solution.mat[1][0] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_equilibration_errors() {
+        let zero_row = System::new(matrix!([1.0, 2.0], [0.0, 0.0]), matrix!([1.0], [1.0])).unwrap();
+        assert_eq!(zero_row.solve_equilibrated(), Err(LinalgError::Singular));
+
+        let mut system = badly_scaled();
+        system.constants = Matrix::zeros(3, 1);
+        assert_eq!(
+            system.solve_equilibrated(),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_sensitivity_bounds() {
+        let system: System<f64> =
+            System::new(matrix!([2.0, 1.0], [1.0, 3.0]), matrix!([3.0], [4.0])).unwrap();
+        let perturbation = Perturbation {
+            coefficients: 0.01,
+            constants: 0.01,
+        };
+
+        let result = system.sensitivity(perturbation).unwrap();
+        assert!((result.condition_number - 3.2).abs() < 1e-12);
+        assert!((result.relative_error - 0.064 / 0.968).abs() < 1e-12);
+        assert_eq!(result.error_bounds.len(), 1);
+        assert!((result.error_bounds[0] - result.relative_error).abs() < 1e-12);
+
+        // A perturbation as large as allowed moves the solution by less than the bound
+        let perturbed =
+            System::new(matrix!([2.02, 0.98], [0.98, 3.02]), matrix!([3.04], [3.96])).unwrap();
+        let x = perturbed.solve().unwrap();
+        let change = x
+            .mat
+            .iter()
+            .zip(&result.solution.mat)
+            .map(|(a, b)| (a[0] - b[0]).abs())
+            .fold(0.0, f64::max);
+        assert!(change > 0.0 && change <= result.error_bounds[0]);
+    }
+
+    #[test]
+    fn test_sensitivity_near_singular() {
+        let system: System<f64> = System::new(
+            matrix!([1.0, 1.0], [1.0, 1.0001]),
+            matrix!([2.0, 1.0], [2.0001, 0.0]),
+        )
+        .unwrap();
+        let result = system
+            .sensitivity(Perturbation {
+                coefficients: 1e-3,
+                constants: 0.0,
+            })
+            .unwrap();
+        assert!(result.condition_number > 1e4);
+        assert_eq!(result.relative_error, f64::INFINITY);
+        assert_eq!(result.error_bounds, vec![f64::INFINITY; 2]);
+
+        let exact = system
+            .sensitivity(Perturbation {
+                coefficients: 0.0,
+                constants: 0.0,
+            })
+            .unwrap();
+        assert_eq!(exact.error_bounds, vec![0.0; 2]);
+    }
+
+    #[test]
+    fn test_sensitivity_errors() {
+        let system = badly_scaled();
+        for bound in [-0.1, f64::NAN] {
+            let result = system.sensitivity(Perturbation {
+                coefficients: 0.0,
+                constants: bound,
+            });
+            assert!(matches!(result, Err(LinalgError::InvalidArgument(_))));
+        }
+
+        let singular = System::new(matrix!([1.0, 2.0], [2.0, 4.0]), matrix!([1.0], [1.0])).unwrap();
+        let bounds = Perturbation {
+            coefficients: 0.0,
+            constants: 0.0,
+        };
+        assert_eq!(singular.sensitivity(bounds), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_new_checks_shapes() {
+        assert_eq!(
+            System::new(
+                matrix!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]),
+                matrix!([1.0], [2.0])
+            ),
+            Err(LinalgError::NonSquare { rows: 2, cols: 3 })
+        );
+        assert_eq!(
+            System::new(matrix!([1.0, 2.0], [3.0, 4.0]), matrix!([1.0, 2.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            })
+        );
+
+        let system = System::new(matrix!([1, 2], [3, 4]), matrix!([5], [6])).unwrap();
+        assert_eq!(system.variables, None);
+        assert_eq!(system.variable_names(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_from_augmented() {
+        let system = System::from_augmented(matrix!([2.0, 1.0, 3.0, 1.0], [1.0, 3.0, 4.0, 2.0]));
+        assert_eq!(
+            system.unwrap(),
+            System::new(
+                matrix!([2.0, 1.0], [1.0, 3.0]),
+                matrix!([3.0, 1.0], [4.0, 2.0])
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            System::from_augmented(matrix!([1.0, 2.0], [3.0, 4.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_named_gaussian_elimination() {
+        let system: System<f64> = System::from_augmented(matrix!(
+            [1.0, 1.0, 1.0, 6.0],
+            [0.0, 2.0, 5.0, -4.0],
+            [2.0, 5.0, -1.0, 27.0]
+        ))
+        .unwrap();
+
+        let default_names = system.gaussian_elimination().unwrap();
+        assert_eq!(default_names.len(), 3);
+        assert!((default_names["a"] - 5.0).abs() < 1e-12);
+
+        let named = system.with_variables(&["x", "y", "z"]).unwrap();
+        let solution = named.gaussian_elimination().unwrap();
+        for (name, expected) in [("x", 5.0), ("y", 3.0), ("z", -2.0)] {
+            assert!((solution[name] - expected).abs() < 1e-12);
+        }
+        assert_eq!(named.variable_names(), vec!["x", "y", "z"]);
+
+        let two_sides = System::new(matrix!([1.0]), matrix!([1.0, 2.0])).unwrap();
+        assert_eq!(
+            two_sides.gaussian_elimination(),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 1),
+                found: (1, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_variables_errors() {
+        let system = System::new(matrix!([1, 0], [0, 1]), matrix!([1], [2])).unwrap();
+
+        assert_eq!(
+            system.clone().with_variables(&["x"]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            system.with_variables(&["x", "x"]),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "async")]
+
+mod task_tests {
+    use linalgrs::arith;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::task;
+    use std::sync::Arc;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_spawn_multiply() {
+        let a = matrix!([1, 2], [3, 4]);
+        let b = matrix!([5, 6], [7, 8]);
+
+        let product = block_on(async { task::spawn_multiply(a, b).await });
+        assert_eq!(product.unwrap(), matrix!([19, 22], [43, 50]));
+    }
+
+    #[test]
+    fn test_operations_run_concurrently() {
+        let results = block_on(async {
+            let det = task::spawn_determinant(matrix!([4.0, 3.0], [6.0, 3.0]));
+            let inv = task::spawn_inverse(matrix!([2.0, 0.0], [0.0, 4.0]));
+            let solve =
+                task::spawn_solve_adaptive(matrix!([2.0, 0.0], [0.0, 4.0]), matrix!([2.0], [4.0]));
+            (det.await, inv.await, solve.await)
+        });
+
+        assert_eq!(results.0, Ok(-6.0));
+        assert_eq!(results.1.unwrap(), matrix!([0.5, 0.0], [0.0, 0.25]));
+        assert_eq!(results.2.unwrap().solution, matrix!([1.0], [1.0]));
+    }
+
+    #[test]
+    fn test_determinant_of_large_matrix() {
+        let matrix = arith::multiply_by_scalar(&arith::identity(20), 2.0);
+
+        let det = block_on(async { task::spawn_determinant(matrix).await });
+        assert_eq!(det, Ok(1048576.0));
+    }
+
+    #[test]
+    fn test_errors_and_panics_resolve_to_err() {
+        let results = block_on(async {
+            let bad = task::spawn_multiply(matrix!([1, 2]), matrix!([1, 2]));
+            let panicked = task::spawn(|| -> Result<(), LinalgError> { panic!("boom") });
+            (bad.await, panicked.await)
+        });
+
+        assert!(results.0.is_err());
+        assert!(matches!(results.1, Err(LinalgError::TaskFailed(_))));
+    }
+}
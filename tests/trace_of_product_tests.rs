@@ -0,0 +1,56 @@
+#![allow(deprecated)]
+
+mod trace_of_product_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_trace_of_product_matches_product() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+        let b = matrix!([7, -1], [0, 2], [3, 5]);
+        let product = MatrixUtilities::multiply(&a, &b).unwrap();
+
+        assert_eq!(MatrixUtilities::trace_of_product(&a, &b), product.trace());
+        assert_eq!(MatrixUtilities::trace_of_product(&a, &b), Ok(52));
+        // tr(AB) = tr(BA) even though the products have different sizes
+        assert_eq!(MatrixUtilities::trace_of_product(&b, &a), Ok(52));
+    }
+
+    #[test]
+    fn test_trace_of_transpose_product() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+        let b = matrix!([7, -1, 0], [2, 3, 5]);
+        let product = MatrixUtilities::multiply(&MatrixUtilities::transpose(&a), &b).unwrap();
+
+        assert_eq!(
+            MatrixUtilities::trace_of_transpose_product(&a, &b),
+            product.trace()
+        );
+        assert_eq!(MatrixUtilities::trace_of_transpose_product(&a, &b), Ok(58));
+        // tr(AᵀA) is the squared Frobenius norm
+        assert_eq!(MatrixUtilities::trace_of_transpose_product(&a, &a), Ok(91));
+    }
+
+    #[test]
+    fn test_trace_of_product_shape_errors() {
+        let a = matrix!([1, 2, 3], [4, 5, 6]);
+
+        assert_eq!(
+            MatrixUtilities::trace_of_product(&a, &a),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            })
+        );
+        assert_eq!(
+            MatrixUtilities::trace_of_transpose_product(&a, &matrix!([1, 2], [3, 4], [5, 6])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (3, 2)
+            })
+        );
+    }
+}
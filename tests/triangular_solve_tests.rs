@@ -0,0 +1,114 @@
+mod triangular_solve_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{arith, assert_matrix_eq, decompose, matrix, solve};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_triangular_solves() {
+        let l = matrix!([2.0, 0.0, 0.0], [1.0, 4.0, 0.0], [-1.0, 3.0, 5.0]);
+        let u = matrix!([3.0, 1.0, -2.0], [0.0, 2.0, 1.0], [0.0, 0.0, 4.0]);
+        let b = matrix!([2.0, 1.0], [5.0, 0.0], [7.0, -3.0]);
+
+        let x = solve::solve_lower_triangular(&l, &b).unwrap();
+        assert_matrix_eq!(arith::multiply(&l, &x).unwrap(), b, 1e-12);
+        let x = solve::solve_upper_triangular(&u, &b).unwrap();
+        assert_matrix_eq!(arith::multiply(&u, &x).unwrap(), b, 1e-12);
+    }
+
+    #[test]
+    fn test_solve_with_lu_factors() {
+        let a = matrix!([4.0, 3.0, 1.0], [6.0, 3.0, 2.0], [2.0, 5.0, 7.0]);
+        let b = matrix!([1.0], [2.0], [3.0]);
+
+        let (l, u) = decompose::lu_decomposition(&a).unwrap();
+        let y = solve::solve_lower_triangular(&l, &b).unwrap();
+        let x = solve::solve_upper_triangular(&u, &y).unwrap();
+        assert_matrix_eq!(x, solve::solve(&a, &b).unwrap(), 1e-12);
+    }
+
+    #[test]
+    fn test_solve_banded() {
+        // A tridiagonal system with a zero on the diagonal, which needs row exchanges
+        let n = 40;
+        let a = Matrix {
+            mat: (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| match i as isize - j as isize {
+                            0 if i == 3 => 0.0,
+                            0 => 2.0 + i as f64 / 10.0,
+                            1 => -1.0,
+                            -1 => 0.5,
+                            _ => 0.0,
+                        })
+                        .collect()
+                })
+                .collect(),
+            rows: n,
+            cols: n,
+        };
+        let b = Matrix {
+            mat: (0..n).map(|i| Arc::from([i as f64, 1.0])).collect(),
+            rows: n,
+            cols: 2,
+        };
+
+        let x = solve::solve_banded(&a, 1, 1, &b).unwrap();
+        assert_matrix_eq!(x, solve::solve(&a, &b).unwrap(), 1e-10);
+
+        let pentadiagonal = matrix!(
+            [5.0, 1.0, 2.0, 0.0],
+            [1.0, 6.0, -1.0, 1.0],
+            [0.0, 2.0, 7.0, 1.0],
+            [0.0, 0.0, 3.0, 8.0]
+        );
+        let b = matrix!([1.0], [2.0], [3.0], [4.0]);
+        assert_matrix_eq!(
+            solve::solve_banded(&pentadiagonal, 1, 2, &b).unwrap(),
+            solve::solve(&pentadiagonal, &b).unwrap(),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let b = matrix!([1.0], [1.0]);
+
+        assert!(matches!(
+            solve::solve_lower_triangular(&matrix!([1.0, 2.0], [0.0, 1.0]), &b),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            solve::solve_upper_triangular(&matrix!([1.0, 0.0], [2.0, 1.0]), &b),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            solve::solve_upper_triangular(&matrix!([1.0, 2.0], [0.0, 0.0]), &b),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            solve::solve_lower_triangular(&matrix!([1.0, 0.0]), &b),
+            Err(LinalgError::NonSquare { rows: 1, cols: 2 })
+        );
+        assert_eq!(
+            solve::solve_banded(&matrix!([1.0, 0.0], [0.0, 1.0]), 0, 0, &matrix!([1.0])),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        assert!(matches!(
+            solve::solve_banded(&matrix!([1.0, 2.0], [3.0, 4.0]), 0, 1, &b),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            solve::solve_banded(&matrix!([1.0, 2.0], [2.0, 4.0]), 1, 1, &b),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            solve::solve_banded(&matrix!([f64::NAN]), 0, 0, &matrix!([1.0])),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+    }
+}
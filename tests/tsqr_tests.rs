@@ -0,0 +1,98 @@
+mod tsqr_tests {
+    use linalgrs::error::LinalgError;
+    use linalgrs::least_squares::{least_squares, least_squares_tsqr};
+    use linalgrs::matrix::Matrix;
+    use linalgrs::{arith, assert_matrix_eq, decompose, matrix, transform};
+    use std::sync::Arc;
+
+    fn tall(m: usize) -> Matrix<f64> {
+        Matrix {
+            mat: (0..m)
+                .map(|i| {
+                    let t = i as f64 / m as f64;
+                    Arc::from([1.0, t, t * t, (7.0 * t).sin()])
+                })
+                .collect(),
+            rows: m,
+            cols: 4,
+        }
+    }
+
+    #[test]
+    fn test_tsqr_matches_gram_factor() {
+        let a = tall(1000);
+        let gram = arith::multiply(&transform::transpose(&a), &a).unwrap();
+        let expected = transform::transpose(&decompose::cholesky_decomposition(&gram).unwrap());
+
+        for block_rows in [4, 7, 64, 1000, 5000] {
+            let r = decompose::tsqr(&a, block_rows).unwrap();
+            assert!((0..4).all(|i| (0..i).all(|j| r.mat[i][j] == 0.0)));
+            assert_matrix_eq!(r, expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_tsqr_short_last_block_and_wide_block() {
+        let a = matrix!([3.0, 0.0], [4.0, 0.0], [0.0, 2.0]);
+
+        for block_rows in [2, 3] {
+            assert_matrix_eq!(
+                decompose::tsqr(&a, block_rows).unwrap(),
+                matrix!([5.0, 0.0], [0.0, 2.0]),
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_least_squares_tsqr_matches_least_squares() {
+        let a = tall(2000);
+        let b = Matrix {
+            mat: (0..2000)
+                .map(|i| Arc::from([(i as f64 * 0.37).cos() + i as f64 / 500.0]))
+                .collect(),
+            rows: 2000,
+            cols: 1,
+        };
+
+        assert_matrix_eq!(
+            least_squares_tsqr(&a, &b, 128).unwrap(),
+            least_squares(&a, &b).unwrap(),
+            1e-9
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = matrix!([1.0, 2.0], [2.0, 4.0], [3.0, 6.0]);
+        let b = matrix!([1.0], [2.0], [3.0]);
+
+        assert!(matches!(
+            decompose::tsqr(&a, 1),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            decompose::tsqr(&Matrix::<f64>::zeros(0, 2), 4),
+            Err(LinalgError::Empty)
+        );
+        assert_eq!(
+            decompose::tsqr(&matrix!([f64::NAN]), 4),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+        assert_eq!(
+            least_squares_tsqr(&a, &b, 3),
+            Err(LinalgError::RankDeficient)
+        );
+        assert!(matches!(
+            least_squares_tsqr(&a, &b, 2),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            least_squares_tsqr(&a, &matrix!([1.0]), 3),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 1),
+                found: (1, 1)
+            })
+        );
+    }
+}
@@ -0,0 +1,52 @@
+mod units_tests {
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::units::{Unit, UnitError, UnitMatrix};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_unit_arithmetic() {
+        let velocity = Unit::meter() / Unit::second();
+        let force = Unit::kilogram() * Unit::meter() / Unit::second().powi(2);
+
+        assert_eq!(
+            velocity / Unit::second(),
+            Unit::meter() / Unit::second().powi(2)
+        );
+        assert!((velocity / velocity).is_dimensionless());
+        assert_eq!(format!("{}", force), "m kg s^-2");
+        assert_eq!(format!("{}", Unit::dimensionless()), "1");
+    }
+
+    #[test]
+    fn test_add_requires_same_unit() {
+        let a = UnitMatrix::new(matrix!([1.0, 2.0]), Unit::meter());
+        let b = UnitMatrix::new(matrix!([3.0, 4.0]), Unit::meter());
+        let t = UnitMatrix::new(matrix!([3.0, 4.0]), Unit::second());
+
+        let sum = a.add(&b).unwrap();
+        assert_eq!(sum.matrix, matrix!([4.0, 6.0]));
+        assert_eq!(sum.unit, Unit::meter());
+        let mismatch = UnitError::UnitMismatch {
+            left: Unit::meter(),
+            right: Unit::second(),
+        };
+        assert_eq!(a.add(&t).unwrap_err(), mismatch);
+        assert_eq!(a.subtract(&t).unwrap_err(), mismatch);
+        assert!(mismatch.to_string().contains("units"));
+    }
+
+    #[test]
+    fn test_multiply_derives_unit() {
+        let distances = UnitMatrix::new(matrix!([1.0, 2.0]), Unit::meter());
+        let times = UnitMatrix::new(matrix!([3.0], [4.0]), Unit::second());
+
+        let product = distances.multiply(&times).unwrap();
+        assert_eq!(product.matrix, matrix!([11.0]));
+        assert_eq!(product.unit, Unit::meter() * Unit::second());
+
+        let scaled = distances.multiply_by_scalar(2.0, Unit::second().powi(-1));
+        assert_eq!(scaled.unit, Unit::meter() / Unit::second());
+        assert_eq!(scaled.transpose().matrix, matrix!([2.0], [4.0]));
+    }
+}
@@ -0,0 +1,155 @@
+mod vector_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::Matrix;
+    use linalgrs::vector;
+    use linalgrs::vector::{cosine_similarity, cosine_similarity_matrix, Vector};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_vector_macro() {
+        let v = vector![1, 2, 3];
+        assert_eq!(v, Vector::new(vec![1, 2, 3]));
+        assert_eq!(v.len(), 3);
+        assert!(Vector::<i32>::zeros(0).is_empty());
+    }
+
+    #[test]
+    fn test_dot() {
+        let a = vector![1, 2, 3];
+        let b = vector![4, -5, 6];
+
+        assert_eq!(a.dot(&b), Ok(12));
+        assert!(a.dot(&vector![1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_cross() {
+        let x = vector![1.0, 0.0, 0.0];
+        let y = vector![0.0, 1.0, 0.0];
+
+        assert_eq!(x.cross(&y).unwrap(), vector![0.0, 0.0, 1.0]);
+        assert_eq!(y.cross(&x).unwrap(), vector![0.0, 0.0, -1.0]);
+        assert!(vector![1, 2].cross(&vector![3, 4]).is_err());
+    }
+
+    #[test]
+    fn test_add_subtract_scale() {
+        let a = vector![1, 2, 3];
+        let b = vector![3, 2, 1];
+
+        assert_eq!(a.add(&b).unwrap(), vector![4, 4, 4]);
+        assert_eq!(a.subtract(&b).unwrap(), vector![-2, 0, 2]);
+        assert_eq!(a.multiply_by_scalar(3), vector![3, 6, 9]);
+        assert!(a.add(&vector![1]).is_err());
+    }
+
+    #[test]
+    fn test_norm_and_normalize() {
+        let v = vector![3.0, 4.0];
+
+        assert_eq!(v.norm_squared(), 25.0);
+        assert_eq!(v.norm(), 5.0);
+        let unit = v.normalize().unwrap();
+        assert!(approx_eq!(f64, unit.norm(), 1.0, ulps = 2));
+        assert!(Vector::<f64>::zeros(3).normalize().is_err());
+    }
+
+    #[test]
+    fn test_matrix_conversions() {
+        let v = vector![1, 2, 3];
+
+        assert_eq!(v.to_row_matrix(), matrix!([1, 2, 3]));
+        assert_eq!(v.to_column_matrix(), matrix!([1], [2], [3]));
+        assert_eq!(Vector::from_matrix(&matrix!([1, 2, 3])).unwrap(), v);
+        assert_eq!(Vector::from_matrix(&matrix!([1], [2], [3])).unwrap(), v);
+        assert!(Vector::from_matrix(&matrix!([1, 2], [3, 4])).is_err());
+        assert_eq!(format!("{}", v), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_angle_and_cosine_similarity() {
+        let x = vector![1.0, 0.0];
+        let y = vector![0.0, 2.0];
+        let d = vector![3.0, 3.0];
+
+        assert!(approx_eq!(
+            f64,
+            x.angle(&y).unwrap(),
+            std::f64::consts::FRAC_PI_2
+        ));
+        assert!(approx_eq!(
+            f64,
+            x.angle(&d).unwrap(),
+            std::f64::consts::FRAC_PI_4,
+            ulps = 4
+        ));
+        assert_eq!(x.angle(&x.multiply_by_scalar(5.0)), Ok(0.0));
+        assert_eq!(cosine_similarity(&x, &x.multiply_by_scalar(-1.0)), Ok(-1.0));
+        assert!(x.cosine_similarity(&vector![0.0, 0.0]).is_err());
+        assert!(x.angle(&vector![1.0]).is_err());
+    }
+
+    #[test]
+    fn test_cosine_similarity_matrix() {
+        let m = matrix!([1.0, 0.0], [0.0, 1.0], [1.0, 1.0]);
+        let s = cosine_similarity_matrix(&m).unwrap();
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+
+        assert_eq!((s.rows, s.cols), (3, 3));
+        assert_eq!(s.column(0).unwrap()[..2], [1.0, 0.0]);
+        assert!(approx_eq!(f64, s.mat[2][0], h, ulps = 4));
+        assert!(approx_eq!(f64, s.mat[1][2], h, ulps = 4));
+        assert_eq!(s.mat[2][1], s.mat[1][2]);
+        assert!(cosine_similarity_matrix(&matrix!([1.0, 0.0], [0.0, 0.0])).is_err());
+        assert_eq!(
+            cosine_similarity_matrix(&matrix!([1.0, 0.0], [f64::NAN, 1.0])),
+            Err(LinalgError::NonFinite { row: 1, col: 0 })
+        );
+    }
+
+    #[test]
+    fn test_cosine_similarity_non_finite() {
+        let x = vector![1.0, 2.0];
+
+        assert_eq!(
+            cosine_similarity(&x, &vector![1.0, f64::NAN]),
+            Err(LinalgError::NonFinite { row: 1, col: 1 })
+        );
+        assert_eq!(
+            vector![f64::INFINITY, 0.0].angle(&x),
+            Err(LinalgError::NonFinite { row: 0, col: 0 })
+        );
+    }
+
+    #[test]
+    fn test_cosine_similarity_extreme_magnitudes() {
+        let huge = vector![1e200, 1e200];
+        let tiny = vector![1e-200, 0.0];
+
+        assert!(approx_eq!(
+            f64,
+            huge.cosine_similarity(&huge).unwrap(),
+            1.0,
+            ulps = 4
+        ));
+        assert!(approx_eq!(
+            f64,
+            huge.angle(&huge).unwrap(),
+            0.0,
+            epsilon = 1e-7
+        ));
+        assert!(approx_eq!(
+            f64,
+            huge.cosine_similarity(&huge.multiply_by_scalar(-1.0))
+                .unwrap(),
+            -1.0,
+            ulps = 4
+        ));
+        assert_eq!(tiny.cosine_similarity(&tiny), Ok(1.0));
+
+        let s = cosine_similarity_matrix(&matrix!([1e200, 1e200], [1e-200, 1e-200])).unwrap();
+        assert!(approx_eq!(f64, s.mat[0][1], 1.0, ulps = 4));
+    }
+}
@@ -0,0 +1,89 @@
+#![allow(deprecated)]
+
+mod view_tests {
+    use float_cmp::approx_eq;
+    use linalgrs::error::LinalgError;
+    use linalgrs::matrix;
+    use linalgrs::matrix::{Matrix, NormKind};
+    use linalgrs::matrix_utilities::MatrixUtilities;
+    use std::sync::Arc;
+
+    fn sample() -> Matrix<f64> {
+        matrix!(
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, -6.0, 7.0, 8.0],
+            [9.0, 10.0, -11.0, 12.0]
+        )
+    }
+
+    #[test]
+    fn test_view_reads_block_in_place() {
+        let m = sample();
+
+        let view = m.view(1..3, 1..4).unwrap();
+        assert_eq!(view.shape(), (2, 3));
+        assert_eq!(view.row(0), Some(&[-6.0, 7.0, 8.0][..]));
+        assert_eq!(view.row(2), None);
+        assert_eq!(view.get(1, 2), Some(12.0));
+        assert_eq!(view.get(0, 3), None);
+        assert_eq!(view[(1, 0)], 10.0);
+        assert!(std::ptr::eq(view.row(0).unwrap(), &m.mat[1][1..4]));
+        assert_eq!(view.to_matrix(), m.clone().sub_matrix(1..3, 1..4).unwrap());
+
+        let inner = view.view(0..1, 1..3).unwrap();
+        assert_eq!(inner.to_matrix(), matrix!([7.0, 8.0]));
+        assert_eq!(inner, m.view(1..2, 2..4).unwrap());
+    }
+
+    #[test]
+    fn test_view_multiply_and_norm() {
+        let m = sample();
+        let left = m.view(0..2, 0..3).unwrap();
+        let right = m.view(0..3, 2..4).unwrap();
+
+        assert_eq!(
+            left.multiply(&right).unwrap(),
+            MatrixUtilities::multiply(&left.to_matrix(), &right.to_matrix()).unwrap()
+        );
+        assert_eq!(
+            left.multiply(&left),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            })
+        );
+
+        let block = left.to_matrix();
+        for kind in [
+            NormKind::Frobenius,
+            NormKind::One,
+            NormKind::Infinity,
+            NormKind::Spectral,
+        ] {
+            assert!(approx_eq!(
+                f64,
+                left.norm(kind).unwrap(),
+                block.norm(kind).unwrap(),
+                ulps = 4
+            ));
+        }
+    }
+
+    #[test]
+    fn test_view_errors() {
+        let m = sample();
+
+        assert_eq!(
+            m.view(0..4, 0..1).unwrap_err(),
+            LinalgError::OutOfBounds { index: 4, len: 3 }
+        );
+        assert_eq!(
+            m.view(0..1, 2..5).unwrap_err(),
+            LinalgError::OutOfBounds { index: 5, len: 4 }
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = m.view(2..1, 0..1);
+        assert!(matches!(reversed, Err(LinalgError::InvalidArgument(_))));
+        assert_eq!(m.as_view().unwrap().to_matrix(), m);
+    }
+}